@@ -0,0 +1,62 @@
+//! Completions subcommand handler.
+//!
+//! `completions <SHELL>` writes a tab-completion script for bash, zsh, fish,
+//! elvish, or PowerShell to stdout, generated from the clap command definition
+//! so it always matches the current subcommands and flags.
+//!
+//! `completions --systems` instead prints every system name in the dataset, one
+//! per line, for completion setups that suggest names for `--from`/`--to`.
+
+use std::io::Write;
+
+use anyhow::{Context, Result};
+use clap::CommandFactory;
+use clap_complete::{generate, Shell};
+use evefrontier_lib::{ensure_dataset, load_starmap, DatasetRelease, Starmap};
+
+use crate::CompletionsArgs;
+
+/// Name the completion script registers itself under.
+const BIN_NAME: &str = "evefrontier-cli";
+
+/// Handle the `completions` subcommand.
+pub fn handle_completions(
+    args: &CompletionsArgs,
+    data_dir: Option<&std::path::Path>,
+    release: DatasetRelease,
+) -> Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    if args.systems {
+        let paths = tokio::task::block_in_place(|| ensure_dataset(data_dir, release))
+            .context("failed to locate or download the EVE Frontier dataset")?;
+        let starmap = load_starmap(&paths.database, None)
+            .with_context(|| format!("failed to load dataset from {}", paths.database.display()))?;
+        return write_system_names(&starmap, &mut stdout);
+    }
+
+    // clap rejects the command line unless a shell or `--systems` is given.
+    let shell = args.shell.context("a shell or --systems is required")?;
+    write_completions(shell, &mut crate::Cli::command(), &mut stdout)
+}
+
+/// Write the completion script for `shell` to `out`.
+pub fn write_completions(
+    shell: Shell,
+    command: &mut clap::Command,
+    out: &mut impl Write,
+) -> Result<()> {
+    generate(shell, command, BIN_NAME, out);
+    out.flush().context("failed to write completion script")
+}
+
+/// Write every system name in `starmap` to `out`, sorted and one per line.
+pub fn write_system_names(starmap: &Starmap, out: &mut impl Write) -> Result<()> {
+    let mut names: Vec<&str> = starmap.systems.values().map(|s| s.name.as_str()).collect();
+    names.retain(|name| !name.trim().is_empty());
+    names.sort_unstable();
+    names.dedup();
+    for name in names {
+        writeln!(out, "{name}").context("failed to write system names")?;
+    }
+    out.flush().context("failed to write system names")
+}
@@ -169,6 +169,7 @@ pub async fn run_server_loop(mut transport: StdioTransport, server: McpServerSta
                                    {"name": "route_plan", "description": "Plan a route between two solar systems."},
                                    {"name": "system_info", "description": "Get information about a solar system."},
                                    {"name": "systems_nearby", "description": "List systems within a number of jumps."},
+                                   {"name": "reachable_systems", "description": "List systems reachable within a travel budget."},
                                    {"name": "gates_from", "description": "List outbound stargates from a system."}
                                ]
                            });
@@ -186,7 +187,7 @@ pub async fn run_server_loop(mut transport: StdioTransport, server: McpServerSta
                            };
 
                            if let Some(tool_name) = tool_name_opt {
-                               let known_tool = matches!(tool_name, "route_plan" | "system_info" | "systems_nearby" | "gates_from");
+                               let known_tool = matches!(tool_name, "route_plan" | "system_info" | "systems_nearby" | "reachable_systems" | "gates_from");
 
                                if !known_tool {
                                    let error = json!({"code": -32601, "message": format!("Unknown tool: {}", tool_name)});
@@ -3,5 +3,11 @@
 // Each module handles a specific CLI subcommand, following the Single Responsibility Principle.
 // The main.rs dispatches to these handlers, keeping the entry point focused on parsing and coordination.
 
+pub mod completions;
 pub mod mcp;
+pub mod reachable;
+pub mod request_file;
+pub mod route_batch;
+pub mod route_compare;
 pub mod scout;
+pub mod systems;
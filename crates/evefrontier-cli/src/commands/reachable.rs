@@ -0,0 +1,186 @@
+//! Reachable subcommand handler.
+//!
+//! `reachable <SYSTEM> --max-distance <LY>` (or `--max-fuel <UNITS> --ship <NAME>`)
+//! lists every system that can be reached from the origin within the budget,
+//! cheapest first.
+
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use evefrontier_lib::{
+    ensure_dataset, load_starmap, plan_reachable, try_load_spatial_index, DatasetRelease,
+    FuelConfig, HeatConfig, ReachBudget, ReachableRequest, RouteConstraints, ShipLoadout,
+    MAX_REACHABLE_RESULTS,
+};
+
+use crate::commands::scout::{format_unknown_system_error, load_ship_catalog};
+use crate::output::{to_json_string, OutputFormat};
+use crate::output_helpers::{
+    format_reachable_basic, format_reachable_note, format_reachable_text, ReachableBudgetParams,
+    ReachableNeighbor, ReachableResult,
+};
+use crate::ReachableArgs;
+
+/// Handle the `reachable` subcommand.
+///
+/// Floods outward from the origin over the hybrid gate/spatial graph and prints
+/// each system whose cheapest distance (or fuel) cost fits within the budget.
+pub fn handle_reachable(
+    args: &ReachableArgs,
+    format: OutputFormat,
+    data_dir: Option<&std::path::Path>,
+    ship_data_json: Option<&std::path::Path>,
+) -> Result<()> {
+    if args.limit < 1 || args.limit > MAX_REACHABLE_RESULTS {
+        return Err(anyhow::anyhow!(
+            "limit must be between 1 and {}",
+            MAX_REACHABLE_RESULTS
+        ));
+    }
+
+    let (budget, unit) = match (args.max_distance, args.max_fuel) {
+        (Some(ly), _) => (ReachBudget::Distance(ly), "ly"),
+        (None, Some(fuel)) => (ReachBudget::Fuel(fuel), "fuel"),
+        (None, None) => return Err(anyhow::anyhow!("--max-distance or --max-fuel is required")),
+    };
+    if !budget.value().is_finite() || budget.value() <= 0.0 {
+        return Err(anyhow::anyhow!("budget must be a positive number"));
+    }
+
+    // Unlike `scout range`, no ship is injected by default: a distance budget does not
+    // need one, and a fuel budget must name the ship it is priced for.
+    let ship_name = args
+        .ship_config
+        .ship
+        .as_deref()
+        .filter(|s| !s.eq_ignore_ascii_case("none"));
+    let avoid_critical = args.heat.should_avoid_critical_state();
+    if ship_name.is_none() {
+        if matches!(budget, ReachBudget::Fuel(_)) {
+            return Err(anyhow::anyhow!("--ship is required with --max-fuel"));
+        }
+        if avoid_critical {
+            return Err(anyhow::anyhow!(
+                "--ship is required for heat-aware planning"
+            ));
+        }
+    }
+
+    // Load dataset
+    let paths = tokio::task::block_in_place(|| ensure_dataset(data_dir, DatasetRelease::latest()))
+        .context("failed to locate or download the EVE Frontier dataset")?;
+
+    let starmap = load_starmap(&paths.database, Some(args.heat.sys_temp_curve.into()))
+        .with_context(|| format!("failed to load dataset from {}", paths.database.display()))?;
+
+    let Some(system_id) = starmap.system_id_by_name(&args.system) else {
+        let suggestions = starmap.fuzzy_system_matches(&args.system, 5);
+        return Err(anyhow::anyhow!(format_unknown_system_error(
+            &args.system,
+            &suggestions
+        )));
+    };
+
+    let mut request = ReachableRequest {
+        start: args.system.clone(),
+        budget,
+        constraints: RouteConstraints {
+            max_jump: args.constraints.max_jump,
+            avoid_systems: args.constraints.avoid.clone(),
+            avoid_regions: args.constraints.avoid_region.clone(),
+            avoid_gates: args.constraints.avoid_gates,
+            max_temperature: args.constraints.max_temp,
+            avoid_temperature_range: None,
+            avoid_critical_state: avoid_critical,
+            ship: None,
+            loadout: None,
+            heat_config: None,
+            round_trip: false,
+            max_hops: None,
+            gate_penalty: 1.0,
+            jump_penalty: 1.0,
+            gate_time_seconds: Default::default(),
+        },
+        spatial_index: try_load_spatial_index(&paths.database).map(Arc::new),
+        max_spatial_neighbors: args.max_spatial_neighbours,
+        fuel_config: FuelConfig {
+            quality: args.ship_config.fuel_quality,
+            dynamic_mass: args.ship_config.dynamic_mass,
+            reserve: args.ship_config.fuel_reserve,
+        },
+        limit: args.limit,
+    };
+
+    if let Some(ship_name) = ship_name {
+        let catalog =
+            load_ship_catalog(&paths, ship_data_json).context("failed to load ship catalog")?;
+        let ship = catalog
+            .get(ship_name)
+            .ok_or_else(|| anyhow::anyhow!("ship {} not found in catalog", ship_name))?;
+        let fuel_load = args.ship_config.fuel_load.unwrap_or(ship.fuel_capacity);
+        let loadout = ShipLoadout::new(ship, fuel_load, args.ship_config.cargo_mass)
+            .map_err(|e| anyhow::anyhow!("Invalid loadout: {}", e))?;
+
+        request.constraints.ship = Some(ship.clone());
+        request.constraints.loadout = Some(loadout);
+        if avoid_critical {
+            request.constraints.heat_config = Some(HeatConfig {
+                calibration_constant: 1e-7,
+                dynamic_mass: args.ship_config.dynamic_mass,
+            });
+        }
+    }
+
+    let reachable = plan_reachable(&starmap, &request)
+        .with_context(|| format!("failed to compute systems reachable from {}", args.system))?;
+
+    let systems: Vec<ReachableNeighbor> = reachable
+        .into_iter()
+        .filter_map(|(id, cost)| {
+            let system = starmap.systems.get(&id)?;
+            Some(ReachableNeighbor {
+                name: system.name.clone(),
+                id,
+                cost,
+                min_temp_k: system.metadata.min_external_temp,
+            })
+        })
+        .collect();
+
+    let result = ReachableResult {
+        system: args.system.clone(),
+        system_id,
+        budget: ReachableBudgetParams {
+            unit,
+            value: budget.value(),
+            limit: args.limit,
+        },
+        ship: request.constraints.ship.as_ref().map(|s| s.name.clone()),
+        count: systems.len(),
+        systems,
+    };
+
+    // Format and print output
+    match format {
+        OutputFormat::Json => {
+            println!("{}", to_json_string(&result)?);
+        }
+        OutputFormat::Basic => {
+            print!("{}", format_reachable_basic(&result));
+        }
+        OutputFormat::Note => {
+            print!("{}", format_reachable_note(&result));
+        }
+        OutputFormat::Text
+        | OutputFormat::Rich
+        | OutputFormat::Emoji
+        | OutputFormat::Enhanced
+        | OutputFormat::Map
+        | OutputFormat::Csv
+        | OutputFormat::GeoJson => {
+            print!("{}", format_reachable_text(&result, true));
+        }
+    }
+
+    Ok(())
+}
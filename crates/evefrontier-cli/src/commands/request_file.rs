@@ -0,0 +1,253 @@
+//! Route request files for `route --request-file <FILE>`.
+//!
+//! A request file is a JSON object using the same field names as the route service's request
+//! body (`from`, `to`, `algorithm`, `preset`, `max_jump`, `avoid`, `avoid_gates`,
+//! `max_temperature`), so scripts can reuse payloads between the CLI and the HTTP API. Instead of
+//! surfacing the first serde error, every field is checked and all problems are reported
+//! together, each tagged with the field it belongs to.
+
+use std::fmt;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::{RouteAlgorithmArg, RoutePresetArg};
+
+const KNOWN_FIELDS: [&str; 8] = [
+    "from",
+    "to",
+    "algorithm",
+    "preset",
+    "max_jump",
+    "avoid",
+    "avoid_gates",
+    "max_temperature",
+];
+
+/// Route request read from a JSON file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteRequestFile {
+    pub from: String,
+    pub to: String,
+    pub algorithm: Option<RouteAlgorithmArg>,
+    pub preset: Option<RoutePresetArg>,
+    pub max_jump: Option<f64>,
+    pub avoid: Vec<String>,
+    pub avoid_gates: bool,
+    pub max_temperature: Option<f64>,
+}
+
+/// A problem with one field of a request file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// Field name, or `$` for the document itself.
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    fn new(field: &str, message: impl Into<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Read and validate a route request file, listing every field error on failure.
+pub fn load_route_request_file(path: &Path) -> Result<RouteRequestFile> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read request file {}", path.display()))?;
+
+    parse_route_request(&contents).map_err(|errors| {
+        let details: Vec<String> = errors.iter().map(|e| format!("  - {e}")).collect();
+        anyhow::anyhow!(
+            "invalid route request in {}:\n{}",
+            path.display(),
+            details.join("\n")
+        )
+    })
+}
+
+/// Parse a route request document, collecting field-level errors instead of stopping at the first.
+pub fn parse_route_request(json: &str) -> std::result::Result<RouteRequestFile, Vec<FieldError>> {
+    let value: Value = serde_json::from_str(json)
+        .map_err(|e| vec![FieldError::new("$", format!("invalid JSON: {e}"))])?;
+    let Value::Object(object) = value else {
+        return Err(vec![FieldError::new("$", "expected a JSON object")]);
+    };
+
+    let mut errors = Vec::new();
+    for key in object.keys() {
+        if !KNOWN_FIELDS.contains(&key.as_str()) {
+            errors.push(FieldError::new(
+                key,
+                format!("unknown field; expected one of {}", KNOWN_FIELDS.join(", ")),
+            ));
+        }
+    }
+
+    let from = required_system(&object, "from", &mut errors);
+    let to = required_system(&object, "to", &mut errors);
+    let algorithm = named_value::<RouteAlgorithmArg>(&object, "algorithm", &mut errors);
+    let preset = named_value::<RoutePresetArg>(&object, "preset", &mut errors);
+    let max_jump = positive_number(&object, "max_jump", &mut errors);
+    let avoid = optional::<Vec<String>>(&object, "avoid", &mut errors)
+        .flatten()
+        .unwrap_or_default();
+    let avoid_gates = optional::<bool>(&object, "avoid_gates", &mut errors)
+        .flatten()
+        .unwrap_or(false);
+    let max_temperature = positive_number(&object, "max_temperature", &mut errors);
+
+    match (from, to) {
+        (Some(from), Some(to)) if errors.is_empty() => Ok(RouteRequestFile {
+            from,
+            to,
+            algorithm,
+            preset,
+            max_jump,
+            avoid,
+            avoid_gates,
+            max_temperature,
+        }),
+        _ => Err(errors),
+    }
+}
+
+/// Deserialize `field` when present; `Some(None)` means absent or `null`, `None` means invalid.
+fn optional<T: DeserializeOwned>(
+    object: &Map<String, Value>,
+    field: &str,
+    errors: &mut Vec<FieldError>,
+) -> Option<Option<T>> {
+    match object.get(field) {
+        None | Some(Value::Null) => Some(None),
+        Some(value) => match serde_json::from_value(value.clone()) {
+            Ok(parsed) => Some(Some(parsed)),
+            Err(e) => {
+                errors.push(FieldError::new(field, e.to_string()));
+                None
+            }
+        },
+    }
+}
+
+/// Parse a field holding one of the CLI's value names (e.g. `a-star`), listing the valid names
+/// on error.
+fn named_value<T: ValueEnum>(
+    object: &Map<String, Value>,
+    field: &str,
+    errors: &mut Vec<FieldError>,
+) -> Option<T> {
+    let name = optional::<String>(object, field, errors).flatten()?;
+    if let Ok(value) = T::from_str(&name, false) {
+        return Some(value);
+    }
+    let expected: Vec<String> = T::value_variants()
+        .iter()
+        .filter_map(|v| v.to_possible_value())
+        .map(|v| v.get_name().to_string())
+        .collect();
+    errors.push(FieldError::new(
+        field,
+        format!(
+            "unknown {field} `{name}`; expected one of {}",
+            expected.join(", ")
+        ),
+    ));
+    None
+}
+
+fn required_system(
+    object: &Map<String, Value>,
+    field: &str,
+    errors: &mut Vec<FieldError>,
+) -> Option<String> {
+    match optional::<String>(object, field, errors)? {
+        None => {
+            errors.push(FieldError::new(field, "missing required field"));
+            None
+        }
+        Some(name) if name.trim().is_empty() => {
+            errors.push(FieldError::new(field, "must not be empty"));
+            None
+        }
+        Some(name) => Some(name),
+    }
+}
+
+fn positive_number(
+    object: &Map<String, Value>,
+    field: &str,
+    errors: &mut Vec<FieldError>,
+) -> Option<f64> {
+    let value = optional::<f64>(object, field, errors).flatten()?;
+    if value <= 0.0 {
+        errors.push(FieldError::new(field, "must be a positive number"));
+        return None;
+    }
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_every_invalid_field() {
+        let errors = parse_route_request(
+            r#"{"to": "", "algorithm": "fastest", "max_jump": -1, "via": ["Nod"]}"#,
+        )
+        .expect_err("invalid request");
+
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, ["via", "from", "to", "algorithm", "max_jump"]);
+        assert!(errors[3].message.contains("a-star"), "{}", errors[3]);
+    }
+
+    #[test]
+    fn parses_service_shaped_request() {
+        let request = parse_route_request(
+            r#"{"from": "Nod", "to": "Brana", "algorithm": "a-star", "avoid": ["J:35IA"]}"#,
+        )
+        .expect("valid request");
+
+        assert_eq!(request.from, "Nod");
+        assert_eq!(request.algorithm, Some(RouteAlgorithmArg::AStar));
+        assert_eq!(request.avoid, vec!["J:35IA".to_string()]);
+        assert!(!request.avoid_gates);
+    }
+
+    #[test]
+    fn omitted_algorithm_defers_to_flags_and_defaults() {
+        let request = parse_route_request(r#"{"from": "Nod", "to": "Brana"}"#)
+            .expect("algorithm is optional");
+        assert_eq!(request.algorithm, None);
+    }
+
+    #[test]
+    fn parses_preset_and_rejects_unknown_names() {
+        let request = parse_route_request(r#"{"from": "Nod", "to": "Brana", "preset": "safe"}"#)
+            .expect("valid request");
+        assert_eq!(request.preset, Some(RoutePresetArg::Safe));
+
+        let errors = parse_route_request(r#"{"from": "Nod", "to": "Brana", "preset": "scenic"}"#)
+            .expect_err("unknown preset");
+        assert_eq!(errors[0].field, "preset");
+        assert!(
+            errors[0].message.contains("fast, safe, cheap"),
+            "{}",
+            errors[0]
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! Route batch files for `route --batch <FILE>`.
+//!
+//! A batch file lists route endpoints to plan against a single loaded dataset, either as a JSON
+//! array of `{"from": ..., "to": ...}` objects or as CSV with one `from,to` pair per line. The
+//! CSV form accepts an optional `from,to` header, skips blank lines and `#` comments, and does not
+//! support quoting (system names never contain commas).
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// One route to plan from a batch file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoutePair {
+    pub from: String,
+    pub to: String,
+}
+
+/// Read a batch file, detecting JSON (first character `[`) or CSV from its contents.
+pub fn load_route_batch(path: &Path) -> Result<Vec<RoutePair>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read batch file {}", path.display()))?;
+
+    parse_route_batch(&contents)
+        .with_context(|| format!("invalid route batch in {}", path.display()))
+}
+
+/// Parse batch file contents in either supported format.
+pub fn parse_route_batch(contents: &str) -> Result<Vec<RoutePair>> {
+    let pairs = if contents.trim_start().starts_with('[') {
+        serde_json::from_str::<Vec<RoutePair>>(contents).context("invalid JSON batch")?
+    } else {
+        parse_csv(contents)?
+    };
+
+    for (index, pair) in pairs.iter().enumerate() {
+        if pair.from.trim().is_empty() || pair.to.trim().is_empty() {
+            anyhow::bail!("entry {}: `from` and `to` must not be empty", index + 1);
+        }
+    }
+    if pairs.is_empty() {
+        anyhow::bail!("batch contains no routes");
+    }
+    Ok(pairs)
+}
+
+fn parse_csv(contents: &str) -> Result<Vec<RoutePair>> {
+    let mut pairs = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let [from, to] = fields[..] else {
+            anyhow::bail!(
+                "line {}: expected `from,to`, found {} fields",
+                index + 1,
+                fields.len()
+            );
+        };
+        if pairs.is_empty() && from.eq_ignore_ascii_case("from") && to.eq_ignore_ascii_case("to") {
+            continue;
+        }
+        pairs.push(RoutePair {
+            from: from.to_string(),
+            to: to.to_string(),
+        });
+    }
+    Ok(pairs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pair(from: &str, to: &str) -> RoutePair {
+        RoutePair {
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_csv_with_header_and_comments() {
+        let pairs = parse_route_batch("from,to\n# first leg\nNod, Brana\n\nJ:35IA,H:2L2S\n")
+            .expect("valid CSV");
+        assert_eq!(pairs, [pair("Nod", "Brana"), pair("J:35IA", "H:2L2S")]);
+    }
+
+    #[test]
+    fn parses_json_array() {
+        let pairs = parse_route_batch(r#"[{"from": "Nod", "to": "Brana"}]"#).expect("valid JSON");
+        assert_eq!(pairs, [pair("Nod", "Brana")]);
+    }
+
+    #[test]
+    fn rejects_malformed_rows() {
+        let err = parse_route_batch("Nod,Brana\nNod\n").expect_err("missing field");
+        assert!(err.to_string().contains("line 2"), "{err}");
+
+        let err = parse_route_batch(r#"[{"from": "Nod", "to": " "}]"#).expect_err("empty goal");
+        assert!(format!("{err:#}").contains("entry 1"), "{err:#}");
+
+        assert!(parse_route_batch("from,to\n").is_err());
+    }
+}
@@ -0,0 +1,103 @@
+//! Multi-ship fuel and heat comparison for `route --compare-ships <a,b,c>`.
+//!
+//! The route is planned once without a ship; each ship's fuel and heat projections are then
+//! attached to its own copy of the route summary, so every hull is compared over the same hops.
+
+use anyhow::{Context, Result};
+use evefrontier_lib::{
+    FuelConfig, HeatConfig, RouteSummary, ShipAttributes, ShipLoadout, HEAT_NOMINAL,
+};
+use serde::Serialize;
+
+use crate::common_args::CommonShipConfig;
+
+/// Fuel and heat totals for one ship flying the compared route.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ShipComparison {
+    pub ship: String,
+    /// Fuel burned across the route's jumps (units).
+    pub total_fuel: f64,
+    /// Fuel left in the tank on arrival (units).
+    pub remaining_fuel: f64,
+    /// True when the tank runs dry or dips below the fuel reserve on the way.
+    pub fuel_exhausted: bool,
+    /// Hull temperature on arrival after any required cooldown.
+    pub final_heat: f64,
+    /// Total time spent cooling between hops (seconds).
+    pub total_wait_seconds: f64,
+}
+
+/// Project fuel and heat for `summary` in each ship, in the order given.
+///
+/// Every ship departs with `--fuel-load` (or a full tank) and `--cargo-mass`; the fuel quality,
+/// reserve, and dynamic-mass settings apply to all ships alike.
+pub fn compare_ships(
+    summary: &RouteSummary,
+    ships: &[&ShipAttributes],
+    ship_config: &CommonShipConfig,
+) -> Result<Vec<ShipComparison>> {
+    let fuel_config = FuelConfig {
+        quality: ship_config.fuel_quality,
+        dynamic_mass: ship_config.dynamic_mass,
+        reserve: ship_config.fuel_reserve,
+    };
+    let heat_config = HeatConfig {
+        calibration_constant: 1e-7,
+        dynamic_mass: ship_config.dynamic_mass,
+    };
+
+    ships
+        .iter()
+        .map(|ship| {
+            let fuel_load = ship_config.fuel_load.unwrap_or(ship.fuel_capacity);
+            let loadout = ShipLoadout::new(ship, fuel_load, ship_config.cargo_mass)
+                .with_context(|| format!("invalid loadout for ship {}", ship.name))?;
+
+            let mut projected = summary.clone();
+            projected
+                .attach_fuel(ship, &loadout, &fuel_config)
+                .with_context(|| format!("failed to project fuel for ship {}", ship.name))?;
+            projected
+                .attach_heat(ship, &loadout, &heat_config)
+                .with_context(|| format!("failed to project heat for ship {}", ship.name))?;
+
+            // Routes without hops carry no projections: nothing is burned and the hull stays
+            // at its nominal temperature.
+            let fuel = projected.fuel.as_ref();
+            let heat = projected.heat.as_ref();
+            Ok(ShipComparison {
+                ship: ship.name.clone(),
+                total_fuel: fuel.map_or(0.0, |f| f.total),
+                remaining_fuel: fuel.and_then(|f| f.remaining).unwrap_or(fuel_load),
+                fuel_exhausted: fuel.is_some_and(|f| f.fuel_exhausted),
+                final_heat: heat.map_or(HEAT_NOMINAL, |h| h.final_residual_heat),
+                total_wait_seconds: heat.map_or(0.0, |h| h.total_wait_time_seconds),
+            })
+        })
+        .collect()
+}
+
+/// Render the comparison as a fixed-width table, flagging ships that need to refuel.
+pub fn format_comparison_table(comparisons: &[ShipComparison]) -> String {
+    let mut out = format!(
+        "{:<16} {:>12} {:>12} {:>12} {:>10}\n",
+        "Ship", "Fuel Used", "Remaining", "Final Heat", "Wait (s)"
+    );
+    for entry in comparisons {
+        let note = if entry.fuel_exhausted {
+            "  needs refuel"
+        } else {
+            ""
+        };
+        out.push_str(&format!(
+            "{:<16} {:>12.2} {:>12.2} {:>12.2} {:>10.1}{}\n",
+            entry.ship,
+            entry.total_fuel,
+            entry.remaining_fuel,
+            entry.final_heat,
+            entry.total_wait_seconds,
+            note
+        ));
+    }
+    out
+}
@@ -6,11 +6,12 @@
 
 use anyhow::{Context, Result};
 use evefrontier_lib::{
-    ensure_dataset, load_starmap, try_load_spatial_index, DatasetRelease, FuelConfig, HeatConfig,
-    NeighbourQuery, ShipCatalog, ShipLoadout,
+    ensure_dataset, load_starmap, rank_by_suitability, try_load_spatial_index, DatasetRelease,
+    FuelConfig, HeatConfig, NeighbourQuery, ShipAttributes, ShipCatalog, ShipLoadout, Starmap,
+    SuitabilityWeights,
 };
 
-use crate::output::OutputFormat;
+use crate::output::{to_json_string, OutputFormat};
 use crate::output_helpers::{
     build_message_box, format_scout_gates_basic, format_scout_gates_emoji,
     format_scout_gates_enhanced, format_scout_gates_note, format_scout_gates_text,
@@ -21,6 +22,9 @@ use crate::output_helpers::{
 use crate::terminal::ColorPalette;
 use crate::{ScoutGatesArgs, ScoutRangeArgs};
 
+/// Maximum gate-hop depth for `scout gates` (matches the scout-gates service limit).
+const MAX_SCOUT_GATES_DEPTH: usize = 3;
+
 // =============================================================================
 // CCP System Filter
 // =============================================================================
@@ -103,12 +107,203 @@ fn nearest_neighbor_order(
     ordered
 }
 
+/// Visit systems in the given order, updating `distance_ly` to the hop distance from the
+/// previous system as [`nearest_neighbor_order`] does.
+fn visit_in_order(
+    origin_position: [f64; 3],
+    systems: Vec<SystemWithPosition>,
+) -> Vec<RangeNeighbor> {
+    let mut current_position = origin_position;
+    systems
+        .into_iter()
+        .map(|mut next| {
+            next.neighbor.distance_ly = euclidean_distance(&current_position, &next.position);
+            current_position = next.position;
+            next.neighbor
+        })
+        .collect()
+}
+
+/// Sort systems by the default suitability score, best first, recording each score.
+///
+/// Scores use the distance from the origin, so this must run before any visit ordering
+/// rewrites `distance_ly` as a hop distance.
+fn order_by_suitability(
+    starmap: &Starmap,
+    systems: Vec<SystemWithPosition>,
+) -> Vec<SystemWithPosition> {
+    let neighbours: Vec<(i64, f64)> = systems
+        .iter()
+        .map(|s| (s.neighbor.id, s.neighbor.distance_ly))
+        .collect();
+    let ranked = rank_by_suitability(starmap, &neighbours, &SuitabilityWeights::default());
+
+    let mut by_id: std::collections::HashMap<i64, SystemWithPosition> =
+        systems.into_iter().map(|s| (s.neighbor.id, s)).collect();
+    ranked
+        .into_iter()
+        .filter_map(|scored| {
+            let mut system = by_id.remove(&scored.system_id)?;
+            system.neighbor.score = Some(scored.score);
+            Some(system)
+        })
+        .collect()
+}
+
+// =============================================================================
+// Ship Projections
+// =============================================================================
+
+/// Aggregates produced while projecting fuel and heat across a scout sequence.
+struct ScoutProjectionTotals {
+    total_distance: f64,
+    total_fuel: f64,
+    final_heat: f64,
+}
+
+/// Populate per-hop fuel and heat projections for systems in visit order.
+///
+/// Uses the same semantics as `RouteSummary::attach_fuel`/`attach_heat` so an
+/// equivalent jump sequence reports identical values:
+/// - `cumulative_fuel` is the total burned since the origin and is never reset by a refuel
+/// - `cumulative_heat` is the residual hull temperature on arrival after any cooldown
+/// - mass uses the fuel on board at departure only when `fuel_config.dynamic_mass` is set
+fn project_scout_hops(
+    ship: &ShipAttributes,
+    loadout: &ShipLoadout,
+    fuel_config: &FuelConfig,
+    heat_config: &HeatConfig,
+    origin_ambient: Option<f64>,
+    systems: &mut [RangeNeighbor],
+) -> Result<ScoutProjectionTotals> {
+    // Pre-collect ambient temperatures for lookback during iteration
+    let ambient_temps: Vec<Option<f64>> = systems.iter().map(|s| s.min_temp_k).collect();
+    let total_hops = systems.len();
+
+    let mut cumulative_fuel = 0.0;
+    let mut remaining_fuel = loadout.fuel_load;
+    let mut total_distance = 0.0;
+    let mut last_residual_heat = evefrontier_lib::HEAT_NOMINAL;
+
+    for (hop_index, sys) in systems.iter_mut().enumerate() {
+        let hop_distance = sys.distance_ly;
+        total_distance += hop_distance;
+
+        let effective_fuel = if fuel_config.dynamic_mass {
+            remaining_fuel
+        } else {
+            loadout.fuel_load
+        };
+        let current_mass = ship.base_mass_kg
+            + loadout.cargo_mass_kg
+            + (effective_fuel * evefrontier_lib::FUEL_MASS_PER_UNIT_KG);
+        let hop_fuel =
+            evefrontier_lib::calculate_jump_fuel_cost(current_mass, hop_distance, fuel_config)
+                .unwrap_or(0.0);
+
+        // Track cumulative fuel before projection (cumulative always increases by hop_fuel)
+        cumulative_fuel += hop_fuel;
+
+        // Use shared helper to detect refuel and update remaining fuel
+        let (projection, new_remaining) = evefrontier_lib::project_fuel_for_hop_with_reserve(
+            hop_fuel,
+            cumulative_fuel,
+            remaining_fuel,
+            loadout.fuel_load,
+            fuel_config.reserve,
+        );
+
+        remaining_fuel = new_remaining;
+        sys.fuel_warning = projection.warning.clone();
+
+        // Calculate heat using shared helper to keep DRY and match route semantics
+        let prev_ambient = if hop_index > 0 {
+            ambient_temps.get(hop_index - 1).copied().flatten()
+        } else {
+            origin_ambient
+        };
+        let is_goal = hop_index + 1 == total_hops;
+        let next_is_gate = false; // scout range visits are jumps, not gates
+        let proj = evefrontier_lib::ship::project_heat_for_jump(
+            evefrontier_lib::ship::HeatProjectionParams {
+                mass: current_mass,
+                specific_heat: ship.specific_heat,
+                distance_ly: hop_distance,
+                hull_mass_kg: ship.base_mass_kg,
+                calibration_constant: heat_config.calibration_constant,
+                prev_ambient,
+                current_min_external_temp: sys.min_temp_k,
+                is_goal,
+                next_is_gate,
+            },
+        )
+        .map_err(|e| anyhow::anyhow!("heat projection failed: {}", e))?;
+
+        if let Some(w) = proj.warning.clone() {
+            sys.heat_warning = Some(w);
+        }
+        if let Some(wait) = proj.wait_time_seconds {
+            sys.cooldown_seconds = Some(wait);
+        }
+
+        sys.hop_fuel = Some(hop_fuel);
+        sys.cumulative_fuel = Some(cumulative_fuel);
+        sys.remaining_fuel = Some(remaining_fuel);
+        sys.hop_heat = Some(proj.hop_heat);
+        sys.cumulative_heat = proj.residual_heat;
+        if let Some(r) = proj.residual_heat {
+            last_residual_heat = r;
+        }
+    }
+
+    Ok(ScoutProjectionTotals {
+        total_distance,
+        total_fuel: cumulative_fuel,
+        final_heat: last_residual_heat,
+    })
+}
+
 // =============================================================================
 // Ship Catalog Loading
 // =============================================================================
 
-/// Load ship catalog from dataset paths or environment.
-fn load_ship_catalog(paths: &evefrontier_lib::DatasetPaths) -> anyhow::Result<ShipCatalog> {
+/// Load ship catalog from dataset paths or environment, with `--ship-data-json` ships
+/// merged over it.
+pub(crate) fn load_ship_catalog(
+    paths: &evefrontier_lib::DatasetPaths,
+    ship_data_json: Option<&std::path::Path>,
+) -> anyhow::Result<ShipCatalog> {
+    overlay_ship_data_json(load_csv_ship_catalog(paths), ship_data_json)
+}
+
+/// Merge custom JSON ship definitions over a CSV catalog.
+///
+/// Without a JSON path the CSV result is returned unchanged. With one, the JSON ships
+/// replace same-named CSV ships, and a missing or unreadable CSV catalog is replaced by
+/// the JSON ships alone.
+pub(crate) fn overlay_ship_data_json(
+    csv_catalog: anyhow::Result<ShipCatalog>,
+    ship_data_json: Option<&std::path::Path>,
+) -> anyhow::Result<ShipCatalog> {
+    let Some(json_path) = ship_data_json else {
+        return csv_catalog;
+    };
+
+    let file = std::fs::File::open(json_path)
+        .with_context(|| format!("failed to read ship data JSON {}", json_path.display()))?;
+    let custom = ShipCatalog::from_json_reader(std::io::BufReader::new(file))
+        .with_context(|| format!("failed to load ship data from {}", json_path.display()))?;
+
+    let mut catalog = csv_catalog.unwrap_or_else(|err| {
+        tracing::debug!(error = %err, "no CSV ship data; using --ship-data-json ships only");
+        ShipCatalog::default()
+    });
+    catalog.merge(custom);
+    Ok(catalog)
+}
+
+/// Load the CSV ship catalog from dataset paths or environment.
+fn load_csv_ship_catalog(paths: &evefrontier_lib::DatasetPaths) -> anyhow::Result<ShipCatalog> {
     use std::path::PathBuf;
 
     // Prefer ship data discovered by the dataset resolver
@@ -172,7 +367,15 @@ pub fn handle_scout_gates(
     args: &ScoutGatesArgs,
     format: OutputFormat,
     data_dir: Option<&std::path::Path>,
+    include_coordinates: bool,
 ) -> Result<()> {
+    if args.depth < 1 || args.depth > MAX_SCOUT_GATES_DEPTH {
+        return Err(anyhow::anyhow!(
+            "depth must be between 1 and {}",
+            MAX_SCOUT_GATES_DEPTH
+        ));
+    }
+
     // Load dataset
     let paths = tokio::task::block_in_place(|| ensure_dataset(data_dir, DatasetRelease::latest()))
         .context("failed to locate or download the EVE Frontier dataset")?;
@@ -192,42 +395,41 @@ pub fn handle_scout_gates(
         }
     };
 
-    // Get gate-connected neighbors from adjacency list with full metadata
+    // Breadth-first search over gate adjacency up to the requested depth, with full metadata
     let include_ccp = args.include_ccp_systems;
-    let neighbors: Vec<GateNeighbor> = starmap
-        .adjacency
-        .get(&system_id)
-        .map(|ids| {
-            let mut neighbors: Vec<GateNeighbor> = ids
-                .iter()
-                .filter_map(|&id| {
-                    let name = starmap.system_name(id)?;
-                    // Filter out CCP developer/staging systems unless explicitly included
-                    if !include_ccp && is_ccp_system(name) {
-                        return None;
-                    }
-                    let system = starmap.systems.get(&id);
-                    let min_temp_k = system.and_then(|s| s.metadata.min_external_temp);
-                    let planet_count = system.and_then(|s| s.metadata.planet_count);
-                    let moon_count = system.and_then(|s| s.metadata.moon_count);
-                    Some(GateNeighbor {
-                        name: name.to_string(),
-                        id,
-                        min_temp_k,
-                        planet_count,
-                        moon_count,
-                    })
-                })
-                .collect();
-            // Sort alphabetically by name for consistent output
-            neighbors.sort_by(|a, b| a.name.cmp(&b.name));
-            neighbors
+    let mut neighbors: Vec<GateNeighbor> = starmap
+        .gate_neighbors_within(system_id, args.depth)
+        .into_iter()
+        .filter_map(|(id, hops)| {
+            let name = starmap.system_name(id)?;
+            // Filter out CCP developer/staging systems unless explicitly included
+            if !include_ccp && is_ccp_system(name) {
+                return None;
+            }
+            let system = starmap.systems.get(&id);
+            let min_temp_k = system.and_then(|s| s.metadata.min_external_temp);
+            let planet_count = system.and_then(|s| s.metadata.planet_count);
+            let moon_count = system.and_then(|s| s.metadata.moon_count);
+            Some(GateNeighbor {
+                name: name.to_string(),
+                id,
+                hops,
+                min_temp_k,
+                planet_count,
+                moon_count,
+                position: system
+                    .and_then(|s| s.position)
+                    .filter(|_| include_coordinates),
+            })
         })
-        .unwrap_or_default();
+        .collect();
+    // Sort by hop count, then alphabetically by name for consistent output
+    neighbors.sort_by(|a, b| a.hops.cmp(&b.hops).then_with(|| a.name.cmp(&b.name)));
 
     let result = ScoutGatesResult {
         system: args.system.clone(),
         system_id,
+        depth: args.depth,
         count: neighbors.len(),
         neighbors,
     };
@@ -235,13 +437,13 @@ pub fn handle_scout_gates(
     // Format and print output
     match format {
         OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(&result)?);
+            println!("{}", to_json_string(&result)?);
         }
         OutputFormat::Enhanced => {
             let palette = ColorPalette::detect();
             print!("{}", format_scout_gates_enhanced(&result, &palette));
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Map | OutputFormat::Csv | OutputFormat::GeoJson => {
             print!("{}", format_scout_gates_text(&result, true));
         }
         OutputFormat::Rich => {
@@ -270,6 +472,8 @@ pub fn handle_scout_range(
     args: &ScoutRangeArgs,
     format: OutputFormat,
     data_dir: Option<&std::path::Path>,
+    include_coordinates: bool,
+    ship_data_json: Option<&std::path::Path>,
 ) -> Result<()> {
     // Additional runtime validation for the limit range; clap should also enforce this via its value parser.
     if args.limit < 1 || args.limit > 100 {
@@ -374,6 +578,16 @@ pub fn handle_scout_range(
         .map(|s| s.to_lowercase())
         .collect();
 
+    // Systems in any avoided region are excluded by ID
+    let mut avoided_region_ids: std::collections::HashSet<i64> = std::collections::HashSet::new();
+    for region in &args.constraints.avoid_region {
+        let members = starmap.systems_in_region(region);
+        if members.is_empty() {
+            return Err(anyhow::anyhow!("unknown region: {}", region));
+        }
+        avoided_region_ids.extend(members);
+    }
+
     // Build set of gate-connected system IDs to exclude (any system in the gate network)
     let gate_connected_ids: std::collections::HashSet<i64> = if args.constraints.avoid_gates {
         starmap.adjacency.keys().copied().collect()
@@ -392,8 +606,8 @@ pub fn handle_scout_range(
             if !include_ccp && is_ccp_system(name) {
                 return None;
             }
-            // Filter out avoided systems (case-insensitive)
-            if avoid_set.contains(&name.to_lowercase()) {
+            // Filter out avoided systems (case-insensitive) and avoided regions
+            if avoid_set.contains(&name.to_lowercase()) || avoided_region_ids.contains(&id) {
                 return None;
             }
             // Filter out gate-connected systems when --avoid-gates is specified
@@ -421,6 +635,8 @@ pub fn handle_scout_range(
                     cooldown_seconds: None,
                     fuel_warning: None,
                     heat_warning: None,
+                    position: include_coordinates.then_some(pos),
+                    score: None,
                 },
                 position: [pos.x, pos.y, pos.z],
             })
@@ -428,10 +644,17 @@ pub fn handle_scout_range(
         .take(args.limit)
         .collect();
 
+    // With --score, rank the nearest systems by suitability instead of distance
+    let systems_with_positions = if args.score {
+        order_by_suitability(&starmap, systems_with_positions)
+    } else {
+        systems_with_positions
+    };
+
     // Build result based on whether ship is specified (explicit or default)
     let result = if let Some(ref ship_name) = effective_ship_name {
         // Load ship catalog - handle errors differently for explicit vs implicit ship
-        let ship_catalog_result = load_ship_catalog(&paths);
+        let ship_catalog_result = load_ship_catalog(&paths, ship_data_json);
 
         let ship_and_catalog = match ship_catalog_result {
             Ok(catalog) => {
@@ -493,99 +716,32 @@ pub fn handle_scout_range(
         if let Some((ship, _catalog)) = ship_and_catalog {
             // Create ship loadout (validates fuel_load and cargo_mass)
             let fuel_load = args.ship_config.fuel_load.unwrap_or(ship.fuel_capacity);
-            let _loadout = ShipLoadout::new(&ship, fuel_load, args.ship_config.cargo_mass)
+            let loadout = ShipLoadout::new(&ship, fuel_load, args.ship_config.cargo_mass)
                 .map_err(|e| anyhow::anyhow!("Invalid loadout: {}", e))?;
 
             // Create fuel config
             let fuel_config = FuelConfig {
                 quality: args.ship_config.fuel_quality,
                 dynamic_mass: args.ship_config.dynamic_mass,
+                reserve: args.ship_config.fuel_reserve,
             };
 
-            // Apply nearest-neighbor ordering
-            let mut ordered_systems = nearest_neighbor_order(position, systems_with_positions);
-            let total_hops = ordered_systems.len();
+            // Visit in suitability order when scoring, otherwise use nearest-neighbor ordering
+            let mut ordered_systems = if args.score {
+                visit_in_order(position, systems_with_positions)
+            } else {
+                nearest_neighbor_order(position, systems_with_positions)
+            };
 
-            // Pre-collect ambient temperatures for lookback during iteration
-            let ambient_temps: Vec<Option<f64>> =
-                ordered_systems.iter().map(|s| s.min_temp_k).collect();
             let origin_ambient = system.metadata.min_external_temp;
-
-            // Calculate fuel and heat projections for each hop
-            let mut cumulative_fuel = 0.0;
-            let mut remaining_fuel = fuel_load;
-            let mut total_distance = 0.0;
-            let mut last_residual_heat = evefrontier_lib::HEAT_NOMINAL;
-            let heat_cfg = HeatConfig::default();
-
-            for (hop_index, sys) in ordered_systems.iter_mut().enumerate() {
-                let hop_distance = sys.distance_ly;
-                total_distance += hop_distance;
-
-                // Calculate fuel cost using current mass (dynamic mass mode)
-                let current_mass = ship.base_mass_kg
-                    + (remaining_fuel * evefrontier_lib::FUEL_MASS_PER_UNIT_KG)
-                    + args.ship_config.cargo_mass;
-                let hop_fuel = evefrontier_lib::calculate_jump_fuel_cost(
-                    current_mass,
-                    hop_distance,
-                    &fuel_config,
-                )
-                .unwrap_or(0.0);
-
-                // Track cumulative fuel before projection (cumulative always increases by hop_fuel)
-                cumulative_fuel += hop_fuel;
-
-                // Use shared helper to detect refuel and update remaining fuel
-                let (projection, new_remaining) = evefrontier_lib::project_fuel_for_hop(
-                    hop_fuel,
-                    cumulative_fuel,
-                    remaining_fuel,
-                    fuel_load,
-                );
-
-                remaining_fuel = new_remaining;
-                sys.fuel_warning = projection.warning.clone();
-
-                // Calculate heat using shared helper to keep DRY and match route semantics
-                let prev_ambient = if hop_index > 0 {
-                    ambient_temps.get(hop_index - 1).copied().flatten()
-                } else {
-                    origin_ambient
-                };
-                let is_goal = hop_index + 1 == total_hops;
-                let next_is_gate = false; // scout range visits are jumps, not gates
-                let proj = evefrontier_lib::ship::project_heat_for_jump(
-                    evefrontier_lib::ship::HeatProjectionParams {
-                        mass: current_mass,
-                        specific_heat: ship.specific_heat,
-                        distance_ly: hop_distance,
-                        hull_mass_kg: ship.base_mass_kg,
-                        calibration_constant: heat_cfg.calibration_constant,
-                        prev_ambient,
-                        current_min_external_temp: sys.min_temp_k,
-                        is_goal,
-                        next_is_gate,
-                    },
-                )
-                .map_err(|e| anyhow::anyhow!("heat projection failed: {}", e))?;
-
-                if let Some(w) = proj.warning.clone() {
-                    sys.heat_warning = Some(w);
-                }
-                if let Some(wait) = proj.wait_time_seconds {
-                    sys.cooldown_seconds = Some(wait);
-                }
-
-                sys.hop_fuel = Some(hop_fuel);
-                sys.cumulative_fuel = Some(cumulative_fuel);
-                sys.remaining_fuel = Some(remaining_fuel);
-                sys.hop_heat = Some(proj.hop_heat);
-                sys.cumulative_heat = proj.residual_heat; // align with route: store residual
-                if let Some(r) = proj.residual_heat {
-                    last_residual_heat = r;
-                }
-            }
+            let totals = project_scout_hops(
+                &ship,
+                &loadout,
+                &fuel_config,
+                &HeatConfig::default(),
+                origin_ambient,
+                &mut ordered_systems,
+            )?;
 
             // Sum all cooldown times for total wait time
             let total_wait_time_seconds: f64 = ordered_systems
@@ -607,9 +763,9 @@ pub fn handle_scout_range(
                     fuel_quality: args.ship_config.fuel_quality,
                 }),
                 count: ordered_systems.len(),
-                total_distance_ly: Some(total_distance),
-                total_fuel: Some(cumulative_fuel),
-                final_heat: Some(last_residual_heat), // Match route: final residual at destination
+                total_distance_ly: Some(totals.total_distance),
+                total_fuel: Some(totals.total_fuel),
+                final_heat: Some(totals.final_heat), // Match route: final residual at destination
                 total_wait_time_seconds: if total_wait_time_seconds > 0.0 {
                     Some(total_wait_time_seconds)
                 } else {
@@ -669,7 +825,7 @@ pub fn handle_scout_range(
     // Format and print output
     match format {
         OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(&result)?);
+            println!("{}", to_json_string(&result)?);
         }
         OutputFormat::Enhanced => {
             let palette = ColorPalette::detect();
@@ -680,7 +836,7 @@ pub fn handle_scout_range(
                 crate::output_helpers::print_estimation_warning_box_with_palette(&palette);
             }
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Map | OutputFormat::Csv | OutputFormat::GeoJson => {
             print!("{}", format_scout_range_text(&result, true));
             // Add info box when ship data is present
             if result.ship.is_some() {
@@ -726,7 +882,7 @@ pub fn handle_scout_range(
 }
 
 /// Format error message for unknown system with fuzzy suggestions.
-fn format_unknown_system_error(name: &str, suggestions: &[String]) -> String {
+pub(crate) fn format_unknown_system_error(name: &str, suggestions: &[String]) -> String {
     let mut message = format!("Unknown system '{}'.", name);
     if !suggestions.is_empty() {
         let formatted = if suggestions.len() == 1 {
@@ -749,6 +905,7 @@ fn format_unknown_system_error(name: &str, suggestions: &[String]) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use evefrontier_lib::RouteSummary;
 
     #[test]
     fn test_is_ccp_system_ad_pattern() {
@@ -800,4 +957,162 @@ mod tests {
         assert!(!is_ccp_system("A:ABC1"));
         assert!(!is_ccp_system("ADVL-something")); // Starts with AD but has V after
     }
+
+    fn range_neighbor(id: i64, distance_ly: f64, min_temp_k: Option<f64>) -> RangeNeighbor {
+        RangeNeighbor {
+            name: format!("S{}", id),
+            id,
+            distance_ly,
+            min_temp_k,
+            planet_count: None,
+            moon_count: None,
+            hop_fuel: None,
+            cumulative_fuel: None,
+            remaining_fuel: None,
+            hop_heat: None,
+            cumulative_heat: None,
+            cooldown_seconds: None,
+            fuel_warning: None,
+            heat_warning: None,
+            position: None,
+            score: None,
+        }
+    }
+
+    fn parity_ship() -> ShipAttributes {
+        ShipAttributes {
+            name: "Parity".to_string(),
+            base_mass_kg: 1_000_000.0,
+            specific_heat: 1.0,
+            fuel_capacity: 200.0,
+            cargo_capacity: 1_000.0,
+        }
+    }
+
+    fn equivalent_route(origin_temp: Option<f64>, hops: &[(f64, Option<f64>)]) -> RouteSummary {
+        use crate::test_helpers::RouteStepBuilder;
+        use evefrontier_lib::{RouteAlgorithm, RouteEndpoint, RouteOutputKind};
+
+        let mut origin = RouteStepBuilder::new()
+            .index(0)
+            .id(0)
+            .name("Origin")
+            .build();
+        origin.distance = None;
+        origin.method = None;
+        origin.min_external_temp = origin_temp;
+
+        let mut steps = vec![origin];
+        for (idx, (distance, temp)) in hops.iter().enumerate() {
+            let mut step = RouteStepBuilder::new()
+                .index(idx + 1)
+                .id(idx as i64 + 1)
+                .distance(*distance)
+                .method("jump")
+                .build();
+            step.min_external_temp = *temp;
+            steps.push(step);
+        }
+
+        let total: f64 = hops.iter().map(|(d, _)| d).sum();
+        RouteSummary {
+            kind: RouteOutputKind::Route,
+            algorithm: RouteAlgorithm::AStar,
+            hops: hops.len(),
+            gates: 0,
+            jumps: hops.len(),
+            total_distance: total,
+            jump_distance: total,
+            average_jump_distance: None,
+            max_jump_distance: None,
+            start: RouteEndpoint { id: 0, name: None },
+            goal: RouteEndpoint {
+                id: hops.len() as i64,
+                name: None,
+            },
+            steps,
+            fuel: None,
+            refuel_at: Vec::new(),
+            heat: None,
+            cooling_stops: Vec::new(),
+            fmap_url: None,
+            parameters: None,
+            effective_constraints: None,
+        }
+    }
+
+    fn assert_cumulative_parity(dynamic_mass: bool) {
+        use crate::output_helpers::RenderableStep;
+
+        let ship = parity_ship();
+        // Small tank so the sequence exercises a refuel stop mid-way.
+        let loadout = ShipLoadout::new(&ship, 120.0, 500.0).unwrap();
+        let fuel_config = FuelConfig {
+            quality: 10.0,
+            dynamic_mass,
+            reserve: 0.0,
+        };
+        let heat_config = HeatConfig {
+            dynamic_mass,
+            ..HeatConfig::default()
+        };
+        let origin_temp = Some(12.0);
+        let hops = [
+            (40.0, Some(25.0)),
+            (55.0, Some(400.0)),
+            (60.0, None),
+            (35.0, Some(5.0)),
+        ];
+
+        let mut route = equivalent_route(origin_temp, &hops);
+        route.attach_fuel(&ship, &loadout, &fuel_config).unwrap();
+        route.attach_heat(&ship, &loadout, &heat_config).unwrap();
+
+        let mut scout: Vec<RangeNeighbor> = hops
+            .iter()
+            .enumerate()
+            .map(|(idx, (d, t))| range_neighbor(idx as i64 + 1, *d, *t))
+            .collect();
+        let totals = project_scout_hops(
+            &ship,
+            &loadout,
+            &fuel_config,
+            &heat_config,
+            origin_temp,
+            &mut scout,
+        )
+        .unwrap();
+
+        for (route_step, scout_step) in route.steps[1..].iter().zip(scout.iter()) {
+            assert_eq!(route_step.hop_fuel(), scout_step.hop_fuel());
+            assert_eq!(route_step.cumulative_fuel(), scout_step.cumulative_fuel());
+            assert_eq!(route_step.remaining_fuel(), scout_step.remaining_fuel());
+            assert_eq!(route_step.fuel_warning(), scout_step.fuel_warning());
+            assert_eq!(route_step.hop_heat(), scout_step.hop_heat());
+            assert_eq!(route_step.cumulative_heat(), scout_step.cumulative_heat());
+            assert_eq!(route_step.cooldown_seconds(), scout_step.cooldown_seconds());
+        }
+
+        assert!(
+            scout
+                .iter()
+                .any(|s| s.fuel_warning.as_deref() == Some("REFUEL")),
+            "fixture should trigger a refuel so cumulative vs remaining is exercised"
+        );
+        let route_fuel = route.fuel.as_ref().unwrap();
+        let route_heat = route.heat.as_ref().unwrap();
+        assert_eq!(route_fuel.total, totals.total_fuel);
+        assert_eq!(route_heat.final_residual_heat, totals.final_heat);
+        assert_eq!(route.total_distance, totals.total_distance);
+    }
+
+    #[test]
+    fn test_scout_and_route_cumulative_parity_static_mass() {
+        assert_cumulative_parity(false);
+    }
+
+    #[test]
+    fn test_scout_and_route_cumulative_parity_dynamic_mass() {
+        assert_cumulative_parity(true);
+    }
 }
@@ -0,0 +1,272 @@
+//! System lookup subcommand handlers.
+//!
+//! This module provides the implementation for:
+//! - `systems-search <QUERY>` — list system names matching the query so users can check
+//!   spelling before running `route` or `scout`
+//! - `system-info <SYSTEM>` — show one system's metadata and gate neighbors
+
+use anyhow::{Context, Result};
+use evefrontier_lib::{
+    ensure_dataset, load_starmap, DatasetRelease, Starmap, SystemId, SystemPosition,
+};
+use serde::Serialize;
+
+use super::scout::format_unknown_system_error;
+use crate::output::{to_json_string, OutputFormat};
+use crate::output_helpers::{format_position, GateNeighbor};
+use crate::{SystemInfoArgs, SystemsSearchArgs};
+
+/// Maximum value accepted by `--limit`.
+const MAX_SEARCH_LIMIT: usize = 100;
+
+/// One system matched by a search.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SystemMatch {
+    pub id: SystemId,
+    pub name: String,
+}
+
+/// Handle the `systems-search` subcommand.
+pub fn handle_systems_search(
+    args: &SystemsSearchArgs,
+    format: OutputFormat,
+    data_dir: Option<&std::path::Path>,
+    release: DatasetRelease,
+) -> Result<()> {
+    if args.limit < 1 || args.limit > MAX_SEARCH_LIMIT {
+        return Err(anyhow::anyhow!(
+            "limit must be between 1 and {}",
+            MAX_SEARCH_LIMIT
+        ));
+    }
+    if args.query.trim().is_empty() {
+        return Err(anyhow::anyhow!("search query must not be empty"));
+    }
+
+    let paths = tokio::task::block_in_place(|| ensure_dataset(data_dir, release))
+        .context("failed to locate or download the EVE Frontier dataset")?;
+    let starmap = load_starmap(&paths.database, None)
+        .with_context(|| format!("failed to load dataset from {}", paths.database.display()))?;
+
+    let matches = search_systems(&starmap, args.query.trim(), args.limit);
+
+    match format {
+        OutputFormat::Json => println!("{}", to_json_string(&matches)?),
+        OutputFormat::Basic | OutputFormat::Note => {
+            for system in &matches {
+                println!("{}", system.name);
+            }
+        }
+        OutputFormat::Text
+        | OutputFormat::Rich
+        | OutputFormat::Emoji
+        | OutputFormat::Enhanced
+        | OutputFormat::Map
+        | OutputFormat::Csv
+        | OutputFormat::GeoJson => {
+            if matches.is_empty() {
+                println!("No systems match '{}'.", args.query);
+            } else {
+                println!(
+                    "Systems matching '{}' ({} found):",
+                    args.query,
+                    matches.len()
+                );
+                for system in &matches {
+                    println!("  {} ({})", system.name, system.id);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Systems whose names start with `query` (case-insensitive, alphabetical), followed by
+/// fuzzy matches in similarity order, up to `limit` entries without duplicates.
+pub fn search_systems(starmap: &Starmap, query: &str, limit: usize) -> Vec<SystemMatch> {
+    let needle = query.to_lowercase();
+    let mut prefix: Vec<SystemMatch> = starmap
+        .systems
+        .values()
+        .filter(|system| system.name.to_lowercase().starts_with(&needle))
+        .map(|system| SystemMatch {
+            id: system.id,
+            name: system.name.clone(),
+        })
+        .collect();
+    prefix.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut matches: Vec<SystemMatch> = prefix.into_iter().take(limit).collect();
+    for name in starmap.fuzzy_system_matches(query, limit) {
+        if matches.len() >= limit {
+            break;
+        }
+        if matches.iter().any(|m| m.name == name) {
+            continue;
+        }
+        if let Some(id) = starmap.system_id_by_name(&name) {
+            matches.push(SystemMatch { id, name });
+        }
+    }
+    matches
+}
+
+/// Details of a single system, as printed by `system-info`.
+#[derive(Debug, Clone, Serialize)]
+pub struct SystemInfo {
+    /// System name.
+    pub name: String,
+    /// System ID.
+    pub id: SystemId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constellation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security_status: Option<f64>,
+    /// Star surface temperature in Kelvin (if known).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub star_temp_k: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub star_luminosity: Option<f64>,
+    /// Minimum external temperature in Kelvin (if known).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_temp_k: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub planet_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moon_count: Option<u32>,
+    /// System coordinates in light-years.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<SystemPosition>,
+    /// Directly gate-connected systems, ordered by name.
+    pub gate_neighbors: Vec<GateNeighbor>,
+}
+
+/// Handle the `system-info` subcommand.
+pub fn handle_system_info(
+    args: &SystemInfoArgs,
+    format: OutputFormat,
+    data_dir: Option<&std::path::Path>,
+    release: DatasetRelease,
+    include_coordinates: bool,
+) -> Result<()> {
+    let paths = tokio::task::block_in_place(|| ensure_dataset(data_dir, release))
+        .context("failed to locate or download the EVE Frontier dataset")?;
+    let starmap = load_starmap(&paths.database, None)
+        .with_context(|| format!("failed to load dataset from {}", paths.database.display()))?;
+
+    let Some(system_id) = starmap.resolve_system_id(&args.system) else {
+        let suggestions = starmap.fuzzy_system_matches(&args.system, 5);
+        return Err(anyhow::anyhow!(format_unknown_system_error(
+            &args.system,
+            &suggestions
+        )));
+    };
+
+    let info = system_info(&starmap, system_id, include_coordinates)
+        .context("resolved system is missing from the starmap")?;
+
+    match format {
+        OutputFormat::Json => println!("{}", to_json_string(&info)?),
+        _ => print!("{}", format_system_info_text(&info)),
+    }
+
+    Ok(())
+}
+
+/// Collect a system's metadata and its direct gate neighbors.
+///
+/// The system's own position is always included; neighbor positions only when
+/// `include_coordinates` is set, matching `scout gates`.
+pub fn system_info(
+    starmap: &Starmap,
+    system_id: SystemId,
+    include_coordinates: bool,
+) -> Option<SystemInfo> {
+    let system = starmap.systems.get(&system_id)?;
+    let metadata = &system.metadata;
+
+    let mut gate_neighbors: Vec<GateNeighbor> = starmap
+        .gate_neighbors_within(system_id, 1)
+        .into_iter()
+        .filter_map(|(id, hops)| {
+            let neighbor = starmap.systems.get(&id)?;
+            Some(GateNeighbor {
+                name: neighbor.name.clone(),
+                id,
+                hops,
+                min_temp_k: neighbor.metadata.min_external_temp,
+                planet_count: neighbor.metadata.planet_count,
+                moon_count: neighbor.metadata.moon_count,
+                position: neighbor.position.filter(|_| include_coordinates),
+            })
+        })
+        .collect();
+    gate_neighbors.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Some(SystemInfo {
+        name: system.name.clone(),
+        id: system.id,
+        region: metadata.region_name.clone(),
+        constellation: metadata.constellation_name.clone(),
+        security_status: metadata.security_status,
+        star_temp_k: metadata.star_temperature,
+        star_luminosity: metadata.star_luminosity,
+        min_temp_k: metadata.min_external_temp,
+        planet_count: metadata.planet_count,
+        moon_count: metadata.moon_count,
+        position: system.position,
+        gate_neighbors,
+    })
+}
+
+fn format_system_info_text(info: &SystemInfo) -> String {
+    let mut out = format!("System {} ({})\n", info.name, info.id);
+    let mut field = |label: &str, value: Option<String>| {
+        if let Some(value) = value {
+            out.push_str(&format!("  {:<18} {}\n", format!("{label}:"), value));
+        }
+    };
+    field("Region", info.region.clone());
+    field("Constellation", info.constellation.clone());
+    field(
+        "Position (ly)",
+        info.position
+            .as_ref()
+            .map(|p| format_position(p).trim().to_string()),
+    );
+    field("Security", info.security_status.map(|s| format!("{s:.2}")));
+    field(
+        "Star temperature",
+        info.star_temp_k.map(|t| format!("{t:.0}K")),
+    );
+    field(
+        "Star luminosity",
+        info.star_luminosity.map(|l| format!("{l:.4}")),
+    );
+    field(
+        "Min external temp",
+        info.min_temp_k.map(|t| format!("{t:.2}K")),
+    );
+    field("Planets", info.planet_count.map(|n| n.to_string()));
+    field("Moons", info.moon_count.map(|n| n.to_string()));
+
+    out.push_str(&format!(
+        "Gate neighbors ({}):\n",
+        info.gate_neighbors.len()
+    ));
+    if info.gate_neighbors.is_empty() {
+        out.push_str(" (none)\n");
+    }
+    for neighbor in &info.gate_neighbors {
+        let position = neighbor
+            .position
+            .as_ref()
+            .map(format_position)
+            .unwrap_or_default();
+        out.push_str(&format!(" - {}{}\n", neighbor.name, position));
+    }
+    out
+}
@@ -46,6 +46,7 @@ use clap::{ArgAction, Args, ValueEnum};
 /// let constraints = CommonRouteConstraints {
 ///     max_jump: Some(50.0),
 ///     avoid: vec!["Brana".to_string(), "H:2L2S".to_string()],
+///     avoid_region: vec![],
 ///     avoid_gates: false,
 ///     max_temp: Some(8000.0),
 /// };
@@ -72,6 +73,19 @@ pub struct CommonRouteConstraints {
     #[arg(long = "avoid", help_heading = "ROUTING CONSTRAINTS")]
     pub avoid: Vec<String>,
 
+    /// Regions to avoid, by name or numeric ID. Repeat for multiple regions.
+    ///
+    /// Every system in the region is excluded, exactly as if each had been passed to
+    /// `--avoid`. An unknown region is an error.
+    ///
+    /// # Example
+    ///
+    /// ```bash
+    /// evefrontier-cli route --from Nod --to Brana --avoid-region "451-Y-F1"
+    /// ```
+    #[arg(long = "avoid-region", help_heading = "ROUTING CONSTRAINTS")]
+    pub avoid_region: Vec<String>,
+
     /// Avoid gates entirely (prefer spatial or traversal routes).
     ///
     /// When enabled, the pathfinding algorithm will only consider spatial jumps
@@ -108,6 +122,7 @@ pub struct CommonRouteConstraints {
 ///     fuel_quality: 50.0,
 ///     cargo_mass: 1000.0,
 ///     fuel_load: Some(500.0),
+///     fuel_reserve: 100.0,
 ///     dynamic_mass: true,
 /// };
 /// ```
@@ -152,6 +167,13 @@ pub struct CommonShipConfig {
     #[arg(long = "fuel-load", value_parser = parse_non_negative, help_heading = "SHIP & FUEL")]
     pub fuel_load: Option<f64>,
 
+    /// Emergency fuel reserve in units that the route should never dip below.
+    ///
+    /// Hops that take the tank below the reserve are flagged with a RESERVE warning
+    /// (distinct from REFUEL, which means the tank ran dry). Default is 0 (no reserve).
+    #[arg(long = "fuel-reserve", default_value = "0", value_parser = parse_non_negative, help_heading = "SHIP & FUEL")]
+    pub fuel_reserve: f64,
+
     /// Recalculate mass after each hop as fuel is consumed.
     ///
     /// When enabled (dynamic mass mode), the route planner recalculates fuel consumption
@@ -275,7 +297,35 @@ fn parse_fuel_quality(s: &str) -> Result<f64, String> {
     Ok(val)
 }
 
-/// Parse non-negative f64 values (for cargo_mass, fuel_load)
+/// Parse a `MIN:MAX` temperature range in Kelvin (for --avoid-temp-range)
+pub fn parse_temperature_range(s: &str) -> Result<(f64, f64), String> {
+    let (min, max) = s
+        .split_once(':')
+        .ok_or_else(|| format!("'{}' is not a range; expected MIN:MAX", s))?;
+    let parse = |v: &str| -> Result<f64, String> {
+        let val: f64 = v
+            .trim()
+            .parse()
+            .map_err(|_| format!("'{}' is not a valid number", v))?;
+        if !val.is_finite() || val < 0.0 {
+            return Err(format!(
+                "temperature must be finite and non-negative, got {}",
+                val
+            ));
+        }
+        Ok(val)
+    };
+    let (min, max) = (parse(min)?, parse(max)?);
+    if min > max {
+        return Err(format!(
+            "range minimum {} is greater than maximum {}",
+            min, max
+        ));
+    }
+    Ok((min, max))
+}
+
+/// Parse non-negative f64 values (for cargo_mass, fuel_load, fuel_reserve)
 fn parse_non_negative(s: &str) -> Result<f64, String> {
     let val: f64 = s
         .parse()
@@ -285,3 +335,17 @@ fn parse_non_negative(s: &str) -> Result<f64, String> {
     }
     Ok(val)
 }
+
+/// Parse a gate/jump edge penalty (for --gate-penalty, --jump-penalty): finite and positive
+pub fn parse_edge_penalty(s: &str) -> Result<f64, String> {
+    let val: f64 = s
+        .parse()
+        .map_err(|_| format!("'{}' is not a valid number", s))?;
+    if !val.is_finite() || val <= 0.0 {
+        return Err(format!(
+            "penalty must be finite and greater than 0, got {}",
+            val
+        ));
+    }
+    Ok(val)
+}
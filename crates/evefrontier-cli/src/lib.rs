@@ -4,6 +4,7 @@
 //! pathfinder, including terminal styling and output formatting.
 
 pub mod common_args;
+pub mod logging;
 pub mod output;
 pub mod output_helpers;
 pub mod terminal;
@@ -0,0 +1,55 @@
+//! Tracing setup for the CLI.
+//!
+//! `-v`/`-vv`/`-vvv` and `--quiet` override `RUST_LOG`; without either, `RUST_LOG`
+//! (or `info`) decides the filter.
+
+use tracing_subscriber::{EnvFilter, FmtSubscriber};
+
+/// Filter directive implied by the verbosity flags, or `None` to defer to `RUST_LOG`.
+///
+/// `--quiet` wins over any number of `-v` flags and limits output to errors.
+pub fn verbosity_directive(verbose: u8, quiet: bool) -> Option<&'static str> {
+    if quiet {
+        return Some("error");
+    }
+    match verbose {
+        0 => None,
+        1 => Some("info"),
+        2 => Some("debug"),
+        _ => Some("trace"),
+    }
+}
+
+/// Install the global stderr tracing subscriber.
+pub fn init_tracing(verbose: u8, quiet: bool) {
+    let env_filter = match verbosity_directive(verbose, quiet) {
+        Some(directive) => EnvFilter::new(directive),
+        None => EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+    };
+    let subscriber = FmtSubscriber::builder()
+        .with_env_filter(env_filter)
+        .with_writer(std::io::stderr)
+        .finish();
+
+    let _ = tracing::subscriber::set_global_default(subscriber);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verbosity_count_maps_to_level() {
+        assert_eq!(verbosity_directive(0, false), None);
+        assert_eq!(verbosity_directive(1, false), Some("info"));
+        assert_eq!(verbosity_directive(2, false), Some("debug"));
+        assert_eq!(verbosity_directive(3, false), Some("trace"));
+        assert_eq!(verbosity_directive(7, false), Some("trace"));
+    }
+
+    #[test]
+    fn quiet_wins_over_verbose() {
+        assert_eq!(verbosity_directive(0, true), Some("error"));
+        assert_eq!(verbosity_directive(3, true), Some("error"));
+    }
+}
@@ -3,12 +3,12 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
-use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use clap::{ArgAction, ArgGroup, Args, Parser, Subcommand, ValueEnum};
 use serde::Serialize;
-use tracing_subscriber::{EnvFilter, FmtSubscriber};
 
 mod commands;
 mod common_args;
+mod logging;
 mod output;
 mod output_helpers;
 mod terminal;
@@ -16,11 +16,13 @@ mod terminal;
 mod test_helpers;
 
 use evefrontier_lib::{
-    compute_dataset_checksum, decode_fmap_token, encode_fmap_token, ensure_dataset, load_starmap,
-    plan_route, read_release_tag, spatial_index_path, try_load_spatial_index, verify_freshness,
-    DatasetMetadata, DatasetRelease, Error as RouteError, FreshnessResult, RouteAlgorithm,
-    RouteConstraints, RouteDiagnostic, RouteOutputKind, RouteRequest, RouteSummary, ShipCatalog,
-    ShipLoadout, SpatialIndex, VerifyDiagnostics, VerifyOutput, Waypoint, WaypointType,
+    compute_dataset_checksum_with_progress, decode_fmap_token, encode_fmap_token, ensure_dataset,
+    load_starmap, plan_route, plan_route_with_debug, read_release_tag, spatial_index_path,
+    try_load_spatial_index, verify_freshness, DatasetMetadata, DatasetRelease, Error as RouteError,
+    FreshnessResult, RouteAlgorithm, RouteConstraints, RouteDiagnostic, RouteOptimization,
+    RouteOutputKind, RoutePlan, RoutePreset, RouteRequest, RouteSummary, ShipCatalog, ShipLoadout,
+    SpatialIndex, Starmap, TravelTimeConfig, VerifyDiagnostics, VerifyOutput, Waypoint,
+    WaypointType,
 };
 
 use output_helpers::{build_message_box, MessageBoxLevel};
@@ -61,6 +63,11 @@ struct GlobalOptions {
     #[arg(long, global = true)]
     dataset: Option<String>,
 
+    /// JSON array of custom ship definitions merged over the CSV ship data; ships here
+    /// replace same-named ships and take precedence over EVEFRONTIER_SHIP_DATA.
+    #[arg(long = "ship-data-json", global = true, value_name = "PATH")]
+    ship_data_json: Option<PathBuf>,
+
     /// Select the output format for CLI responses.
     #[arg(long, value_enum, default_value_t = OutputFormat::default(), global = true)]
     format: OutputFormat,
@@ -80,6 +87,22 @@ struct GlobalOptions {
     /// Override the fmap base URL used in rendered route outputs (default: https://fmap.scetrov.live).
     #[arg(long, global = true, value_name = "URL")]
     fmap_base_url: Option<String>,
+
+    /// Increase log verbosity (-v info, -vv debug, -vvv trace); overrides RUST_LOG.
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+
+    /// Include each system's x/y/z coordinates (light-years) in route and scout output.
+    #[arg(long = "include-coordinates", action = ArgAction::SetTrue, global = true)]
+    include_coordinates: bool,
+
+    /// Print JSON output on a single line instead of pretty-printing it.
+    #[arg(long = "json-compact", action = ArgAction::SetTrue, global = true)]
+    json_compact: bool,
+
+    /// Only log errors; wins over --verbose. `index-verify` also prints only on failure.
+    #[arg(short, long, action = ArgAction::SetTrue, global = true)]
+    quiet: bool,
 }
 
 #[derive(Args, Debug, Clone)]
@@ -108,6 +131,10 @@ pub struct ScoutGatesArgs {
     /// System name to query (case-sensitive; fuzzy suggestions on mismatch).
     pub system: String,
 
+    /// Number of gate hops to explore (1-3).
+    #[arg(long, default_value = "1")]
+    pub depth: usize,
+
     /// Include CCP developer/staging systems (AD###, V-###) in results.
     #[arg(long, action = ArgAction::SetTrue)]
     pub include_ccp_systems: bool,
@@ -138,6 +165,69 @@ pub struct ScoutRangeArgs {
     /// Include CCP developer/staging systems (AD###, V-###) in results.
     #[arg(long, action = ArgAction::SetTrue)]
     pub include_ccp_systems: bool,
+
+    /// Sort results by suitability score (planets, moons, coldness, distance) instead of distance.
+    #[arg(long, action = ArgAction::SetTrue)]
+    pub score: bool,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct SystemInfoArgs {
+    /// System name or numeric ID (case-sensitive; fuzzy suggestions on mismatch).
+    pub system: String,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct CompletionsArgs {
+    /// Shell to generate completions for.
+    #[arg(value_enum, required_unless_present = "systems")]
+    pub shell: Option<clap_complete::Shell>,
+
+    /// Print every system name in the dataset, one per line, for completing `--from`/`--to`.
+    #[arg(long, action = ArgAction::SetTrue, conflicts_with = "shell")]
+    pub systems: bool,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct SystemsSearchArgs {
+    /// Full or partial system name to look up (case-insensitive).
+    pub query: String,
+
+    /// Maximum number of matches to list (1-100).
+    #[arg(long, short = 'n', default_value = "10")]
+    pub limit: usize,
+}
+
+#[derive(Args, Debug, Clone)]
+#[command(group(ArgGroup::new("budget").required(true).args(["max_distance", "max_fuel"])))]
+pub struct ReachableArgs {
+    /// System name to start from (case-sensitive; fuzzy suggestions on mismatch).
+    pub system: String,
+
+    /// Total travel budget in light-years across gate and spatial hops.
+    #[arg(long = "max-distance", value_name = "LY")]
+    pub max_distance: Option<f64>,
+
+    /// Total fuel budget in units (requires --ship). Gate hops burn no fuel.
+    #[arg(long = "max-fuel", value_name = "UNITS")]
+    pub max_fuel: Option<f64>,
+
+    #[command(flatten)]
+    pub constraints: common_args::CommonRouteConstraints,
+
+    #[command(flatten)]
+    pub ship_config: common_args::CommonShipConfig,
+
+    #[command(flatten)]
+    pub heat: common_args::CommonHeatConfig,
+
+    /// Maximum number of systems to return, cheapest first.
+    #[arg(long, short = 'n', default_value = "50")]
+    pub limit: usize,
+
+    /// Maximum number of spatial neighbours to consider when building the hybrid graph.
+    #[arg(long = "max-spatial-neighbours", default_value_t = 250usize)]
+    pub max_spatial_neighbours: usize,
 }
 
 #[derive(Subcommand, Debug)]
@@ -146,6 +236,8 @@ enum Command {
     Download,
     /// Compute a route between two system names using the loaded dataset.
     Route(RouteCommandArgs),
+    /// Re-render a route saved with `route --save` without recomputing it.
+    RouteShow(RouteShowArgs),
     /// Build or rebuild the spatial index for faster routing.
     IndexBuild(IndexBuildArgs),
     /// Verify that the spatial index is fresh (matches the current dataset).
@@ -160,6 +252,14 @@ enum Command {
     Mcp(McpCommandArgs),
     /// Scout nearby systems (gates or spatial range).
     Scout(ScoutCommandArgs),
+    /// List every system reachable within a distance or fuel budget.
+    Reachable(ReachableArgs),
+    /// Search system names (exact prefix matches first, then fuzzy matches).
+    SystemsSearch(SystemsSearchArgs),
+    /// Show a system's metadata and gate neighbors.
+    SystemInfo(SystemInfoArgs),
+    /// Print a shell completion script to stdout.
+    Completions(CompletionsArgs),
 }
 
 #[derive(Args, Debug, Clone)]
@@ -175,10 +275,6 @@ struct IndexVerifyArgs {
     #[arg(long, action = ArgAction::SetTrue)]
     json: bool,
 
-    /// Only output on failure (quiet mode for scripts).
-    #[arg(short, long, action = ArgAction::SetTrue)]
-    quiet: bool,
-
     /// Require release tag match in addition to checksum (strict mode).
     #[arg(long, action = ArgAction::SetTrue)]
     strict: bool,
@@ -193,30 +289,67 @@ struct RouteCommandArgs {
 }
 
 impl RouteCommandArgs {
+    /// Merge `--request-file` (when given) into a copy of these arguments.
+    fn resolve_request_file(&self) -> Result<Self> {
+        let mut resolved = self.clone();
+        let Some(path) = &self.endpoints.request_file else {
+            return Ok(resolved);
+        };
+
+        let file = commands::request_file::load_route_request_file(path)?;
+        resolved.endpoints.from = Some(file.from);
+        resolved.endpoints.to = Some(file.to);
+        if let Some(algorithm) = file.algorithm {
+            resolved.options.algorithm = Some(algorithm);
+        }
+        if let Some(preset) = file.preset {
+            resolved.options.preset = Some(preset);
+        }
+        let constraints = &mut resolved.options.constraints;
+        constraints.max_jump = file.max_jump.or(constraints.max_jump);
+        if !file.avoid.is_empty() {
+            constraints.avoid = file.avoid;
+        }
+        constraints.avoid_gates |= file.avoid_gates;
+        constraints.max_temp = file.max_temperature.or(constraints.max_temp);
+        Ok(resolved)
+    }
+
     fn to_request(&self) -> RouteRequest {
+        // Preset values only fill in constraints the user left unset.
+        let preset = self
+            .options
+            .preset
+            .map(|p| RouteConstraints::preset(p.into()))
+            .unwrap_or_default();
         RouteRequest {
-            start: self.endpoints.from.clone(),
-            goal: self.endpoints.to.clone(),
-            algorithm: self.options.algorithm.into(),
+            start: self.endpoints.from.clone().unwrap_or_default(),
+            goal: self.endpoints.to.clone().unwrap_or_default(),
+            algorithm: self.options.algorithm().into(),
             constraints: RouteConstraints {
-                max_jump: self.options.constraints.max_jump,
+                max_jump: self.options.constraints.max_jump.or(preset.max_jump),
                 avoid_systems: self.options.constraints.avoid.clone(),
-                avoid_gates: self.options.constraints.avoid_gates,
-                max_temperature: self.options.constraints.max_temp,
-                avoid_critical_state: self.options.heat.avoid_critical_state,
+                avoid_regions: self.options.constraints.avoid_region.clone(),
+                avoid_gates: self.options.constraints.avoid_gates || preset.avoid_gates,
+                max_temperature: self.options.constraints.max_temp.or(preset.max_temperature),
+                avoid_temperature_range: self.options.avoid_temp_range,
+                avoid_critical_state: self.options.avoid_critical_state(),
                 ship: None,
                 loadout: None,
                 heat_config: None,
+                round_trip: self.options.round_trip,
+                max_hops: None,
+                gate_penalty: self.options.gate_penalty,
+                jump_penalty: self.options.jump_penalty,
+                gate_time_seconds: Default::default(),
             },
             spatial_index: None, // Will be set separately after loading
             max_spatial_neighbors: self.options.max_spatial_neighbours,
-            optimization: match self.options.optimize.unwrap_or_default() {
-                RouteOptimizeArg::Distance => evefrontier_lib::routing::RouteOptimization::Distance,
-                RouteOptimizeArg::Fuel => evefrontier_lib::routing::RouteOptimization::Fuel,
-            },
+            optimization: self.options.optimization(),
             fuel_config: evefrontier_lib::ship::FuelConfig {
                 quality: self.options.ship_config.fuel_quality,
                 dynamic_mass: self.options.ship_config.dynamic_mass,
+                reserve: self.options.ship_config.fuel_reserve,
             },
         }
     }
@@ -224,26 +357,88 @@ impl RouteCommandArgs {
 
 #[derive(Args, Debug, Clone)]
 struct RouteEndpoints {
-    /// Starting system name.
-    #[arg(long = "from")]
-    from: String,
-    /// Destination system name.
-    #[arg(long = "to")]
-    to: String,
+    /// Starting system name or numeric system ID.
+    #[arg(long = "from", required_unless_present_any = ["request_file", "batch"])]
+    from: Option<String>,
+    /// Destination system name or numeric system ID.
+    #[arg(long = "to", required_unless_present_any = ["request_file", "batch"])]
+    to: Option<String>,
+    /// Read the route request (endpoints, algorithm, and constraints) from a JSON file using the
+    /// route service's field names. Values in the file take precedence over equivalent flags.
+    #[arg(long = "request-file", value_name = "FILE", conflicts_with_all = ["from", "to"])]
+    request_file: Option<PathBuf>,
+    /// Plan every `from`/`to` pair in FILE (JSON array or CSV) with the other route options,
+    /// printing one result per route (a JSON array with `--format json`).
+    #[arg(
+        long = "batch",
+        value_name = "FILE",
+        conflicts_with_all = ["from", "to", "request_file", "save"]
+    )]
+    batch: Option<PathBuf>,
 }
 
 #[derive(Args, Debug, Clone)]
 struct RouteOptionsArgs {
-    /// Algorithm to use when planning the route.
-    #[arg(long, value_enum, default_value_t = RouteAlgorithmArg::default())]
-    algorithm: RouteAlgorithmArg,
+    /// Algorithm to use when planning the route [default: dijkstra, or the preset's algorithm].
+    #[arg(long, value_enum)]
+    algorithm: Option<RouteAlgorithmArg>,
+
+    /// Seed the algorithm, optimization, and constraints from a named preset. Explicit flags
+    /// override the preset's values.
+    #[arg(long = "preset", value_enum)]
+    preset: Option<RoutePresetArg>,
 
     #[command(flatten)]
     constraints: common_args::CommonRouteConstraints,
 
+    /// Avoid spatial jumps into systems whose star temperature lies within MIN:MAX Kelvin
+    /// (inclusive), e.g. `3000:4000`. Cooler and hotter systems stay reachable; combines with
+    /// --max-temp, and gate jumps are unaffected.
+    #[arg(
+        long = "avoid-temp-range",
+        value_name = "MIN:MAX",
+        value_parser = common_args::parse_temperature_range,
+        help_heading = "ROUTING CONSTRAINTS"
+    )]
+    avoid_temp_range: Option<(f64, f64)>,
+
+    /// Multiply gate hop weights by FACTOR when planning over gates and jumps together; values
+    /// above 1 favour spatial jumps. Distances in the output are unaffected.
+    #[arg(
+        long = "gate-penalty",
+        value_name = "FACTOR",
+        default_value_t = 1.0,
+        value_parser = common_args::parse_edge_penalty,
+        help_heading = "ROUTING CONSTRAINTS"
+    )]
+    gate_penalty: f64,
+
+    /// Multiply spatial jump weights by FACTOR when planning over gates and jumps together;
+    /// values above 1 favour gate-heavy routes. Distances in the output are unaffected.
+    #[arg(
+        long = "jump-penalty",
+        value_name = "FACTOR",
+        default_value_t = 1.0,
+        value_parser = common_args::parse_edge_penalty,
+        help_heading = "ROUTING CONSTRAINTS"
+    )]
+    jump_penalty: f64,
+
     #[command(flatten)]
     ship_config: common_args::CommonShipConfig,
 
+    /// Plan the route once and compare fuel and heat projections for each listed ship
+    /// (comma-separated), printing a table (or a JSON array with `--format json`) instead of the
+    /// route itself.
+    #[arg(
+        long = "compare-ships",
+        value_name = "SHIPS",
+        value_delimiter = ',',
+        conflicts_with_all = ["ship", "batch", "save"],
+        help_heading = "SHIP & FUEL"
+    )]
+    compare_ships: Vec<String>,
+
     #[command(flatten)]
     heat: common_args::CommonHeatConfig,
 
@@ -259,11 +454,72 @@ struct RouteOptionsArgs {
     /// Optimization objective for planning: distance or fuel.
     #[arg(long = "optimize", value_enum)]
     optimize: Option<RouteOptimizeArg>,
+
+    /// Save the computed route summary as JSON to FILE (re-render it later with `route-show`).
+    #[arg(long = "save", value_name = "FILE")]
+    save: Option<PathBuf>,
+
+    /// Plan the return leg too, ending the route back at the starting system.
+    #[arg(long = "round-trip", action = ArgAction::SetTrue)]
+    round_trip: bool,
+
+    /// Print statistics about the graph the planner searched to stderr (troubleshooting aid).
+    #[arg(long = "debug-graph", action = ArgAction::SetTrue, hide = true)]
+    debug_graph: bool,
+}
+
+impl RouteOptionsArgs {
+    /// Explicit `--algorithm`, else the preset's algorithm, else the CLI default.
+    fn algorithm(&self) -> RouteAlgorithmArg {
+        self.algorithm
+            .or_else(|| {
+                let preset = RoutePreset::from(self.preset?);
+                preset.algorithm().map(RouteAlgorithmArg::from)
+            })
+            .unwrap_or_default()
+    }
+
+    /// Explicit `--optimize`, else the preset's objective, else distance.
+    fn optimization(&self) -> RouteOptimization {
+        match self.optimize {
+            Some(RouteOptimizeArg::Distance) => RouteOptimization::Distance,
+            Some(RouteOptimizeArg::Fuel) => RouteOptimization::Fuel,
+            Some(RouteOptimizeArg::Time) => RouteOptimization::Time(TravelTimeConfig::default()),
+            None => self
+                .preset
+                .and_then(|p| RoutePreset::from(p).optimization())
+                .unwrap_or(RouteOptimization::Distance),
+        }
+    }
+
+    /// Explicit heat flags win (`--no-avoid-critical-state` first); otherwise the preset decides,
+    /// and without a preset heat-aware routing stays on.
+    fn avoid_critical_state(&self) -> bool {
+        if self.heat.no_avoid_critical_state {
+            false
+        } else if self.heat.avoid_critical_state {
+            true
+        } else {
+            self.preset
+                .is_none_or(|p| RouteConstraints::preset(p.into()).avoid_critical_state)
+        }
+    }
+}
+
+#[derive(Args, Debug, Clone)]
+struct RouteShowArgs {
+    /// JSON file written by `route --save`.
+    #[arg(value_name = "FILE")]
+    file: PathBuf,
+
+    /// Suppress minimum external temperature annotations in route output.
+    #[arg(long = "no-temp", action = ArgAction::SetTrue)]
+    no_temp: bool,
 }
 
 #[derive(Args, Debug, Clone)]
 struct FmapEncodeArgs {
-    /// System names to encode (comma-separated or repeated --system flags).
+    /// Systems to encode, by dataset name (e.g. "Nod") or numeric system ID.
     /// First system is the start, last is the destination.
     #[arg(value_name = "SYSTEM", required = true)]
     systems: Vec<String>,
@@ -287,6 +543,10 @@ struct FmapDecodeArgs {
     /// Output in JSON format (includes metadata).
     #[arg(long = "json", action = ArgAction::SetTrue)]
     json: bool,
+
+    /// Print system IDs only, skipping the dataset lookup that resolves them to names.
+    #[arg(long = "no-resolve", action = ArgAction::SetTrue)]
+    no_resolve: bool,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum, Default)]
@@ -305,6 +565,18 @@ enum RouteOptimizeArg {
     Distance,
     /// Minimize fuel consumption (requires --ship)
     Fuel,
+    /// Minimize travel time (gate activations vs jump-drive spool-ups)
+    Time,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+enum RoutePresetArg {
+    /// A* search with no temperature limit or heat avoidance
+    Fast,
+    /// Heat-aware routing that also skips hot stars (max 5000 K)
+    Safe,
+    /// Fuel optimization, favouring gates
+    Cheap,
 }
 
 // Note: Dijkstra is the intentionally selected default algorithm (marked with #[default]).
@@ -320,6 +592,26 @@ impl From<RouteAlgorithmArg> for RouteAlgorithm {
     }
 }
 
+impl From<RouteAlgorithm> for RouteAlgorithmArg {
+    fn from(value: RouteAlgorithm) -> Self {
+        match value {
+            RouteAlgorithm::Bfs => RouteAlgorithmArg::Bfs,
+            RouteAlgorithm::Dijkstra => RouteAlgorithmArg::Dijkstra,
+            RouteAlgorithm::AStar => RouteAlgorithmArg::AStar,
+        }
+    }
+}
+
+impl From<RoutePresetArg> for RoutePreset {
+    fn from(value: RoutePresetArg) -> Self {
+        match value {
+            RoutePresetArg::Fast => RoutePreset::Fast,
+            RoutePresetArg::Safe => RoutePreset::Safe,
+            RoutePresetArg::Cheap => RoutePreset::Cheap,
+        }
+    }
+}
+
 // Views removed; CLI always uses RouteOutputKind::Route.
 // OutputFormat is now defined in output.rs and re-exported from crate root.
 
@@ -388,6 +680,10 @@ impl AppContext {
         self.options.data_dir.as_deref()
     }
 
+    fn ship_data_json(&self) -> Option<&Path> {
+        self.options.ship_data_json.as_deref()
+    }
+
     fn output_format(&self) -> OutputFormat {
         self.options.format
     }
@@ -419,19 +715,22 @@ async fn main() -> Result<()> {
     let context = AppContext::new(cli.global);
     // Apply --no-color override early so all downstream rendering respects it.
     crate::terminal::set_color_disabled(context.options.no_color);
+    output::set_json_compact(context.options.json_compact);
 
     // For JSON output, suppress tracing to keep stdout clean. If launching the MCP
     // subcommand, skip global tracing initialization so the MCP command can set
     // up a stderr-only tracing subscriber without conflicting with the global default.
     if context.output_format() != OutputFormat::Json && !matches!(cli.command, Command::Mcp(_)) {
-        init_tracing();
+        logging::init_tracing(context.options.verbose, context.options.quiet);
     }
 
     let start = std::time::Instant::now();
 
     // Suppress CLI banner when acting as a stdio-based MCP server to avoid
-    // corrupting the JSON-RPC protocol on stdout.
-    if !matches!(cli.command, Command::Mcp(_)) && context.should_show_logo() {
+    // corrupting the JSON-RPC protocol on stdout, and when printing a completion
+    // script that will be sourced by the shell.
+    let is_completions = matches!(cli.command, Command::Completions(_));
+    if !matches!(cli.command, Command::Mcp(_)) && !is_completions && context.should_show_logo() {
         output::print_logo();
     }
 
@@ -440,18 +739,43 @@ async fn main() -> Result<()> {
         Command::Route(route_args) => {
             handle_route_command(&context, &route_args, RouteOutputKind::Route)
         }
+        Command::RouteShow(args) => handle_route_show(&context, &args),
         Command::IndexBuild(args) => handle_index_build(&context, &args),
         Command::IndexVerify(args) => handle_index_verify(&context, &args),
         Command::Ships => handle_list_ships(&context),
         Command::FmapEncode(args) => handle_fmap_encode(&context, &args),
-        Command::FmapDecode(args) => handle_fmap_decode(&args),
+        Command::FmapDecode(args) => handle_fmap_decode(&context, &args),
         Command::Mcp(args) => {
             commands::mcp::run_mcp_server(&context.options, args.log_level.as_deref()).await
         }
         Command::Scout(args) => handle_scout_command(&context, &args),
+        Command::Reachable(args) => commands::reachable::handle_reachable(
+            &args,
+            context.output_format(),
+            context.target_path(),
+            context.ship_data_json(),
+        ),
+        Command::SystemsSearch(args) => commands::systems::handle_systems_search(
+            &args,
+            context.output_format(),
+            context.target_path(),
+            context.dataset_release(),
+        ),
+        Command::SystemInfo(args) => commands::systems::handle_system_info(
+            &args,
+            context.output_format(),
+            context.target_path(),
+            context.dataset_release(),
+            context.options.include_coordinates,
+        ),
+        Command::Completions(args) => commands::completions::handle_completions(
+            &args,
+            context.target_path(),
+            context.dataset_release(),
+        ),
     };
 
-    if result.is_ok() && context.should_show_footer() {
+    if result.is_ok() && !is_completions && context.should_show_footer() {
         let elapsed = start.elapsed();
         crate::output_helpers::print_footer(elapsed);
     }
@@ -503,10 +827,25 @@ fn handle_index_build(context: &AppContext, args: &IndexBuildArgs) -> Result<()>
     let starmap = load_starmap(&paths.database, None)
         .with_context(|| format!("failed to load dataset from {}", paths.database.display()))?;
 
-    // Compute dataset checksum for freshness verification (v2 format)
-    println!("Computing dataset checksum...");
+    // Compute dataset checksum for freshness verification (v2 format), showing progress
+    // because hashing a large dataset can take a while
+    let mut last_percent = None;
+    let mut report_progress = |hashed: u64, total: u64| {
+        let percent = if total == 0 {
+            100
+        } else {
+            hashed * 100 / total
+        };
+        if last_percent != Some(percent) {
+            last_percent = Some(percent);
+            print!("\rComputing dataset checksum... {:>3}%", percent);
+            let _ = std::io::Write::flush(&mut std::io::stdout());
+        }
+    };
     let checksum =
-        compute_dataset_checksum(&paths.database).context("failed to compute dataset checksum")?;
+        compute_dataset_checksum_with_progress(&paths.database, Some(&mut report_progress))
+            .context("failed to compute dataset checksum")?;
+    println!();
 
     // Read release tag from marker file if present
     let release_tag = read_release_tag(&paths.database);
@@ -625,13 +964,13 @@ fn handle_index_verify(context: &AppContext, args: &IndexVerifyArgs) -> Result<(
     // Output based on format and quiet mode
     if args.json {
         // JSON output
-        let json = serde_json::to_string_pretty(&output)?;
-        if !args.quiet || !is_fresh {
+        let json = output::to_json_string(&output)?;
+        if !context.options.quiet || !is_fresh {
             println!("{}", json);
         }
     } else {
         // Human-readable output
-        if !args.quiet || !is_fresh {
+        if !context.options.quiet || !is_fresh {
             print_human_readable_result(&result, &output);
         }
     }
@@ -736,6 +1075,16 @@ fn handle_route_command(
     args: &RouteCommandArgs,
     kind: RouteOutputKind,
 ) -> Result<()> {
+    // Validate any request or batch file before touching the dataset so authoring mistakes fail
+    // fast.
+    let args = &args.resolve_request_file()?;
+    let batch = args
+        .endpoints
+        .batch
+        .as_deref()
+        .map(commands::route_batch::load_route_batch)
+        .transpose()?;
+
     // Resolve dataset in a blocking region to avoid constructing blocking
     // HTTP clients inside the async runtime thread (see tokio reqwest runtime drop issue).
     let paths = tokio::task::block_in_place(|| {
@@ -751,12 +1100,16 @@ fn handle_route_command(
 
     // Only load the spatial index when the selected algorithm can make use of it.
     // BFS does not use spatial indexing, so we avoid unnecessary I/O in that case.
-    let needs_spatial_index = !matches!(args.options.algorithm, RouteAlgorithmArg::Bfs);
-    let spatial_index = if needs_spatial_index {
+    let needs_spatial_index = !matches!(args.options.algorithm(), RouteAlgorithmArg::Bfs);
+    let mut spatial_index = if needs_spatial_index {
         try_load_spatial_index(&paths.database).map(Arc::new)
     } else {
         None
     };
+    if needs_spatial_index && spatial_index.is_none() && batch.is_some() {
+        // Build the in-memory index once for the whole batch rather than once per route.
+        spatial_index = Some(Arc::new(SpatialIndex::build(&starmap)));
+    }
 
     let mut request = args.to_request();
     if let Some(index) = spatial_index {
@@ -773,15 +1126,37 @@ fn handle_route_command(
         ));
     }
 
+    // Fuel optimization needs a ship to price each hop; without one the planner would silently
+    // fall back to distance, so reject the combination up front.
+    let has_ship = args
+        .options
+        .ship_config
+        .ship
+        .as_deref()
+        .is_some_and(|s| !s.eq_ignore_ascii_case("none"));
+    if args.options.optimization() == RouteOptimization::Fuel && !has_ship {
+        return Err(anyhow::anyhow!(
+            "--ship is required for --optimize fuel and --preset cheap (or use --optimize distance)"
+        ));
+    }
+
     // Determine whether the user provided any route-specific options; if not, we're in
     // a zero-config invocation and may apply friendly defaults (like default ship).
     let user_provided_options = args.options.constraints.max_jump.is_some()
-        || args.options.algorithm != RouteAlgorithmArg::default()
+        || args
+            .options
+            .algorithm
+            .is_some_and(|a| a != RouteAlgorithmArg::default())
         || args.options.optimize.is_some()
         || !args.options.constraints.avoid.is_empty()
+        || !args.options.constraints.avoid_region.is_empty()
         || args.options.constraints.avoid_gates
         || args.options.constraints.max_temp.is_some()
+        || args.options.avoid_temp_range.is_some()
+        || args.options.gate_penalty != 1.0
+        || args.options.jump_penalty != 1.0
         || args.options.ship_config.ship.is_some()
+        || !args.options.compare_ships.is_empty()
         || args.options.ship_config.fuel_quality != 10.0
         || args.options.ship_config.cargo_mass != 0.0
         || args.options.ship_config.fuel_load.is_some()
@@ -804,15 +1179,10 @@ fn handle_route_command(
         }
     };
 
-    // Heat-aware routing is enabled by default (uses Reflex as default ship).
-    // Only disabled if user explicitly passes --no-avoid-critical-state.
-    let avoid_critical = !args.options.heat.no_avoid_critical_state;
-
-    request.constraints.avoid_critical_state = avoid_critical;
-
     // If it's a zero-config run, we want to default to Fuel optimization (with our default ship)
-    // to provide the most feature-rich initial experience for users.
-    if !user_provided_options && args.options.optimize.is_none() {
+    // to provide the most feature-rich initial experience for users. A preset still counts as
+    // zero-config for the default ship, but its own objective (or distance) is kept.
+    if !user_provided_options && args.options.optimize.is_none() && args.options.preset.is_none() {
         request.optimization = evefrontier_lib::routing::RouteOptimization::Fuel;
     }
 
@@ -822,7 +1192,7 @@ fn handle_route_command(
 
         // Attempt to load the ship catalog, but treat failures differently depending on
         // whether the user explicitly requested a ship.
-        match load_ship_catalog(&paths) {
+        match load_ship_catalog(&paths, context.ship_data_json()) {
             Ok(catalog) => {
                 let ship = catalog.get(&ship_name).ok_or_else(|| {
                     anyhow::anyhow!(format!("ship {} not found in catalog", ship_name))
@@ -866,13 +1236,103 @@ fn handle_route_command(
         }
     }
 
-    let plan = match plan_route(&starmap, &request) {
+    if !args.options.compare_ships.is_empty() {
+        return handle_route_compare_ships(context, args, kind, &paths, &starmap, &request);
+    }
+
+    if let Some(pairs) = batch {
+        return handle_route_batch(context, args, kind, &starmap, &request, &pairs);
+    }
+
+    let plan = match plan_route_for_args(args, &starmap, &request) {
         Ok(plan) => plan,
         Err(err) => return Err(handle_route_failure(&request, err)),
     };
+    let summary = build_route_summary(context, kind, &starmap, &plan, &request)?;
+
+    // Display diagnostic message boxes for warnings/info
+    let palette = crate::terminal::ColorPalette::default();
+    let supports_unicode = crate::terminal::supports_unicode();
+
+    for diagnostic in &plan.diagnostics {
+        match diagnostic {
+            RouteDiagnostic::SpatialIndexBuiltInMemory { system_count } => {
+                let msg = format!(
+                    "Spatial index not provided, building in-memory ({} systems). This may be slow for large datasets.",
+                    system_count
+                );
+                let box_content = build_message_box(
+                    MessageBoxLevel::Warn,
+                    &msg,
+                    &palette,
+                    supports_unicode,
+                    None,
+                );
+                eprintln!("{}", box_content);
+            }
+            RouteDiagnostic::SpatialIndexBuilt {
+                node_count,
+                systems_with_temp,
+            } => {
+                let msg = format!(
+                    "Built spatial index with {} nodes ({} systems with temperature data).",
+                    node_count, systems_with_temp
+                );
+                let box_content = build_message_box(
+                    MessageBoxLevel::Info,
+                    &msg,
+                    &palette,
+                    supports_unicode,
+                    None,
+                );
+                eprintln!("{}", box_content);
+            }
+        }
+    }
 
-    let mut summary = RouteSummary::from_plan(kind, &starmap, &plan, Some(&request))
+    if let Some(path) = &args.options.save {
+        save_route_summary(path, &summary)?;
+    }
+
+    let show_temps = !args.options.no_temp;
+    context
+        .output_format()
+        .render_route_result(&summary, show_temps, context.fmap_base_url())
+}
+
+/// Plan `request`, printing graph statistics to stderr when `--debug-graph` is set.
+fn plan_route_for_args(
+    args: &RouteCommandArgs,
+    starmap: &Starmap,
+    request: &RouteRequest,
+) -> std::result::Result<RoutePlan, RouteError> {
+    if !args.options.debug_graph {
+        return plan_route(starmap, request);
+    }
+    let (result, debug) = plan_route_with_debug(starmap, request);
+    match debug {
+        Some(debug) => eprintln!("[debug-graph] {debug}"),
+        None => eprintln!("[debug-graph] planning failed before a graph was built"),
+    }
+    result
+}
+
+/// Build the displayed summary for a planned route, including its fmap URL and any fuel and heat
+/// projections for the request's ship.
+fn build_route_summary(
+    context: &AppContext,
+    kind: RouteOutputKind,
+    starmap: &Starmap,
+    plan: &RoutePlan,
+    request: &RouteRequest,
+) -> Result<RouteSummary> {
+    let mut summary = RouteSummary::from_plan(kind, starmap, plan, Some(request))
         .context("failed to build route summary for display")?;
+    // The map sketch and GeoJSON export plot step coordinates, so they need them even without
+    // --include-coordinates.
+    if context.options.include_coordinates || context.output_format().needs_positions() {
+        summary.attach_positions(starmap);
+    }
 
     // Generate fmap URL for the route using the summary steps which have method info
     let waypoints: Result<Vec<Waypoint>> = summary
@@ -931,6 +1391,7 @@ fn handle_route_command(
         let fuel_config = evefrontier_lib::ship::FuelConfig {
             quality: request.fuel_config.quality,
             dynamic_mass: request.fuel_config.dynamic_mass,
+            reserve: request.fuel_config.reserve,
         };
 
         summary
@@ -948,50 +1409,150 @@ fn handle_route_command(
             .context("failed to attach heat projection")?;
     }
 
-    // Display diagnostic message boxes for warnings/info
-    let palette = crate::terminal::ColorPalette::default();
-    let supports_unicode = crate::terminal::supports_unicode();
+    Ok(summary)
+}
 
-    for diagnostic in &plan.diagnostics {
-        match diagnostic {
-            RouteDiagnostic::SpatialIndexBuiltInMemory { system_count } => {
-                let msg = format!(
-                    "Spatial index not provided, building in-memory ({} systems). This may be slow for large datasets.",
-                    system_count
-                );
-                let box_content = build_message_box(
-                    MessageBoxLevel::Warn,
-                    &msg,
-                    &palette,
-                    supports_unicode,
-                    None,
-                );
-                eprintln!("{}", box_content);
-            }
-            RouteDiagnostic::SpatialIndexBuilt {
-                node_count,
-                systems_with_temp,
-            } => {
-                let msg = format!(
-                    "Built spatial index with {} nodes ({} systems with temperature data).",
-                    node_count, systems_with_temp
-                );
-                let box_content = build_message_box(
-                    MessageBoxLevel::Info,
-                    &msg,
-                    &palette,
-                    supports_unicode,
-                    None,
-                );
-                eprintln!("{}", box_content);
+/// Outcome of one route in a `--batch` run.
+#[derive(Debug, Serialize)]
+struct RouteBatchEntry {
+    from: String,
+    to: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    route: Option<RouteSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Plan every pair in a batch against the already loaded starmap and spatial index. A failing
+/// pair is reported in its own entry instead of aborting the remaining routes.
+fn handle_route_batch(
+    context: &AppContext,
+    args: &RouteCommandArgs,
+    kind: RouteOutputKind,
+    starmap: &Starmap,
+    base_request: &RouteRequest,
+    pairs: &[commands::route_batch::RoutePair],
+) -> Result<()> {
+    let entries: Vec<RouteBatchEntry> = pairs
+        .iter()
+        .map(|pair| {
+            let mut request = base_request.clone();
+            request.start = pair.from.clone();
+            request.goal = pair.to.clone();
+            let outcome = plan_route_for_args(args, starmap, &request)
+                .map_err(|err| handle_route_failure(&request, err))
+                .and_then(|plan| build_route_summary(context, kind, starmap, &plan, &request));
+            let (route, error) = match outcome {
+                Ok(summary) => (Some(summary), None),
+                Err(err) => (None, Some(format!("{err:#}"))),
+            };
+            RouteBatchEntry {
+                from: pair.from.clone(),
+                to: pair.to.clone(),
+                route,
+                error,
             }
+        })
+        .collect();
+
+    if context.output_format() == OutputFormat::Json {
+        println!("{}", output::to_json_string(&entries)?);
+        return Ok(());
+    }
+    for entry in &entries {
+        match (&entry.route, &entry.error) {
+            (Some(route), _) => println!(
+                "{} -> {}: {} hops ({} gates, {} jumps), {:.2} ly",
+                entry.from, entry.to, route.hops, route.gates, route.jumps, route.total_distance
+            ),
+            (None, error) => println!(
+                "{} -> {}: error: {}",
+                entry.from,
+                entry.to,
+                error
+                    .as_deref()
+                    .and_then(|e| e.lines().next())
+                    .unwrap_or("unknown error")
+            ),
         }
     }
+    Ok(())
+}
 
-    let show_temps = !args.options.no_temp;
+/// Plan the route once without a ship, then compare the fuel and heat projections of every
+/// `--compare-ships` hull over it. Unknown ship names fail before the route is planned.
+fn handle_route_compare_ships(
+    context: &AppContext,
+    args: &RouteCommandArgs,
+    kind: RouteOutputKind,
+    paths: &evefrontier_lib::DatasetPaths,
+    starmap: &Starmap,
+    request: &RouteRequest,
+) -> Result<()> {
+    let catalog = load_ship_catalog(paths, context.ship_data_json())
+        .context("failed to load ship data for --compare-ships")?;
+    let ships = args
+        .options
+        .compare_ships
+        .iter()
+        .map(|name| {
+            let name = name.trim();
+            catalog
+                .get(name)
+                .ok_or_else(|| anyhow::anyhow!("ship {} not found in catalog", name))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let plan = plan_route_for_args(args, starmap, request)
+        .map_err(|err| handle_route_failure(request, err))?;
+    let summary = build_route_summary(context, kind, starmap, &plan, request)?;
+    let comparisons =
+        commands::route_compare::compare_ships(&summary, &ships, &args.options.ship_config)?;
+
+    if context.output_format() == OutputFormat::Json {
+        println!("{}", output::to_json_string(&comparisons)?);
+        return Ok(());
+    }
+    println!(
+        "{} -> {}: {} hops ({} gates, {} jumps), {:.2} ly",
+        summary.start.name.as_deref().unwrap_or("<unknown>"),
+        summary.goal.name.as_deref().unwrap_or("<unknown>"),
+        summary.hops,
+        summary.gates,
+        summary.jumps,
+        summary.total_distance
+    );
+    println!();
+    print!(
+        "{}",
+        commands::route_compare::format_comparison_table(&comparisons)
+    );
+    Ok(())
+}
+
+fn handle_route_show(context: &AppContext, args: &RouteShowArgs) -> Result<()> {
+    let mut summary = load_route_summary(&args.file)?;
+    if !context.options.include_coordinates && !context.output_format().needs_positions() {
+        for step in &mut summary.steps {
+            step.position = None;
+        }
+    }
     context
         .output_format()
-        .render_route_result(&summary, show_temps, context.fmap_base_url())
+        .render_route_result(&summary, !args.no_temp, context.fmap_base_url())
+}
+
+fn save_route_summary(path: &Path, summary: &RouteSummary) -> Result<()> {
+    let json = serde_json::to_string_pretty(summary).context("failed to serialize route")?;
+    std::fs::write(path, json)
+        .with_context(|| format!("failed to save route to {}", path.display()))
+}
+
+fn load_route_summary(path: &Path) -> Result<RouteSummary> {
+    let json = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read saved route from {}", path.display()))?;
+    serde_json::from_str(&json)
+        .with_context(|| format!("{} is not a saved route summary", path.display()))
 }
 
 fn handle_list_ships(context: &AppContext) -> Result<()> {
@@ -1000,7 +1561,7 @@ fn handle_list_ships(context: &AppContext) -> Result<()> {
     })
     .context("failed to locate or download the EVE Frontier dataset")?;
 
-    let catalog = load_ship_catalog(&paths)?;
+    let catalog = load_ship_catalog(&paths, context.ship_data_json())?;
     print_ship_catalog(&catalog);
     Ok(())
 }
@@ -1057,6 +1618,9 @@ fn format_route_not_found_message(
     if constraints.max_temperature.is_some() {
         tips.push("raise --max-temp");
     }
+    if constraints.avoid_temperature_range.is_some() {
+        tips.push("narrow or omit --avoid-temp-range");
+    }
     if constraints.avoid_critical_state {
         // If the user explicitly asked to avoid critical engine states, suggest removing
         // the restriction. If no ship was supplied, also suggest specifying one so the
@@ -1079,7 +1643,14 @@ fn format_route_not_found_message(
     message
 }
 
-fn load_ship_catalog(paths: &evefrontier_lib::DatasetPaths) -> Result<ShipCatalog> {
+fn load_ship_catalog(
+    paths: &evefrontier_lib::DatasetPaths,
+    ship_data_json: Option<&Path>,
+) -> Result<ShipCatalog> {
+    commands::scout::overlay_ship_data_json(load_csv_ship_catalog(paths), ship_data_json)
+}
+
+fn load_csv_ship_catalog(paths: &evefrontier_lib::DatasetPaths) -> Result<ShipCatalog> {
     // Prefer ship data discovered by the dataset resolver (populated in `DatasetPaths`)
     if let Some(ref ship_path) = paths.ship_data {
         if ship_path.exists() {
@@ -1143,16 +1714,6 @@ fn print_ship_catalog(catalog: &ShipCatalog) {
     }
 }
 
-fn init_tracing() {
-    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
-    let subscriber = FmtSubscriber::builder()
-        .with_env_filter(env_filter)
-        .with_writer(std::io::stderr)
-        .finish();
-
-    let _ = tracing::subscriber::set_global_default(subscriber);
-}
-
 fn handle_fmap_encode(context: &AppContext, args: &FmapEncodeArgs) -> Result<()> {
     if args.systems.is_empty() {
         anyhow::bail!("At least one system name is required");
@@ -1211,20 +1772,8 @@ fn handle_fmap_encode(context: &AppContext, args: &FmapEncodeArgs) -> Result<()>
                 match db.system_id_by_name(system_name) {
                     Some(id) => id as u32,
                     None => {
-                        // System not found, provide helpful suggestions
                         let suggestions = db.fuzzy_system_matches(system_name, 5);
-                        if suggestions.is_empty() {
-                            anyhow::bail!(
-                                "unknown system '{}'. Use a numeric system ID or an exact system name from the database",
-                                system_name
-                            );
-                        } else {
-                            anyhow::bail!(
-                                "unknown system '{}'. Did you mean one of: {}? Or use a numeric system ID",
-                                system_name,
-                                suggestions.join(", ")
-                            );
-                        }
+                        anyhow::bail!(format_unknown_system_message(system_name, &suggestions));
                     }
                 }
             }
@@ -1256,7 +1805,7 @@ fn handle_fmap_encode(context: &AppContext, args: &FmapEncodeArgs) -> Result<()>
             version: token.version,
         };
 
-        println!("{}", serde_json::to_string_pretty(&output)?);
+        println!("{}", output::to_json_string(&output)?);
     } else {
         println!("fmap token: {}", token.token);
         println!("waypoints: {}", token.waypoint_count);
@@ -1266,15 +1815,50 @@ fn handle_fmap_encode(context: &AppContext, args: &FmapEncodeArgs) -> Result<()>
     Ok(())
 }
 
-fn handle_fmap_decode(args: &FmapDecodeArgs) -> Result<()> {
+fn handle_fmap_decode(context: &AppContext, args: &FmapDecodeArgs) -> Result<()> {
     // Decode the token
     let decoded =
         decode_fmap_token(&args.token).map_err(|e| anyhow::anyhow!("decoding failed: {}", e))?;
 
+    // Names are a convenience: if the dataset can't be loaded, fall back to IDs only.
+    let starmap = if args.no_resolve {
+        None
+    } else {
+        match tokio::task::block_in_place(|| {
+            ensure_dataset(context.target_path(), context.dataset_release())
+        })
+        .context("failed to locate or download the EVE Frontier dataset")
+        .and_then(|paths| {
+            load_starmap(&paths.database, None).with_context(|| {
+                format!("failed to load dataset from {}", paths.database.display())
+            })
+        }) {
+            Ok(starmap) => Some(starmap),
+            Err(e) => {
+                eprintln!("Warning: {:#}. Printing system IDs without names.", e);
+                None
+            }
+        }
+    };
+    // `None` when not resolving (field omitted); `Some(None)` for unknown IDs (`null`).
+    let resolve_name = |system_id: u32| -> Option<Option<String>> {
+        if args.no_resolve {
+            return None;
+        }
+        Some(
+            starmap
+                .as_ref()
+                .and_then(|m| m.system_name(i64::from(system_id)))
+                .map(str::to_string),
+        )
+    };
+
     if args.json {
         #[derive(Serialize)]
         struct WaypointOutput {
             system_id: u32,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            name: Option<Option<String>>,
             waypoint_type: String,
         }
 
@@ -1291,6 +1875,7 @@ fn handle_fmap_decode(args: &FmapDecodeArgs) -> Result<()> {
             .iter()
             .map(|wp| WaypointOutput {
                 system_id: wp.system_id,
+                name: resolve_name(wp.system_id),
                 waypoint_type: format!("{:?}", wp.waypoint_type).to_lowercase(),
             })
             .collect();
@@ -1302,21 +1887,29 @@ fn handle_fmap_decode(args: &FmapDecodeArgs) -> Result<()> {
             waypoints,
         };
 
-        println!("{}", serde_json::to_string_pretty(&output)?);
+        println!("{}", output::to_json_string(&output)?);
     } else {
         println!("fmap decoded successfully");
         println!("version: {}", decoded.version);
         println!("bit width: {}", decoded.bit_width);
         println!("waypoints: {}", decoded.waypoint_count);
         println!();
-        println!("{:<15} {:<20}", "System ID", "Type");
-        println!("{}", "-".repeat(35));
+        if args.no_resolve {
+            println!("{:<15} {:<20}", "System ID", "Type");
+            println!("{}", "-".repeat(35));
+        } else {
+            println!("{:<15} {:<20} Name", "System ID", "Type");
+            println!("{}", "-".repeat(50));
+        }
         for wp in decoded.waypoints {
-            println!(
-                "{:<15} {:<20}",
+            let name = resolve_name(wp.system_id).flatten().unwrap_or_default();
+            let line = format!(
+                "{:<15} {:<20} {}",
                 wp.system_id,
-                format!("{:?}", wp.waypoint_type)
+                format!("{:?}", wp.waypoint_type),
+                name
             );
+            println!("{}", line.trim_end());
         }
     }
 
@@ -1329,11 +1922,14 @@ fn handle_scout_command(context: &AppContext, args: &ScoutCommandArgs) -> Result
             gate_args,
             context.output_format(),
             context.target_path(),
+            context.options.include_coordinates,
         ),
         ScoutSubcommand::Range(range_args) => commands::scout::handle_scout_range(
             range_args,
             context.output_format(),
             context.target_path(),
+            context.options.include_coordinates,
+            context.ship_data_json(),
         ),
     }
 }
@@ -3,6 +3,8 @@
 //! This module provides formatters for rendering route summaries
 //! in various output formats (text, rich, enhanced, etc.).
 
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use anyhow::Result;
 use clap::ValueEnum;
 use serde::Serialize;
@@ -10,8 +12,14 @@ use serde::Serialize;
 use crate::terminal::{supports_color, ColorPalette};
 use evefrontier_lib::RouteSummary;
 
+mod csv;
+pub use csv::render_csv;
 mod enhanced;
 pub use enhanced::EnhancedRenderer;
+mod geojson;
+pub use geojson::render_geojson;
+mod map;
+pub use map::render_map;
 mod text;
 pub use text::{render_basic, render_emoji, render_json, render_note, render_rich, render_text};
 
@@ -37,6 +45,14 @@ pub enum OutputFormat {
     /// In-game note format.
     #[value(alias = "notepad")]
     Note,
+    /// ASCII map sketch of the route's systems (other commands fall back to text).
+    Map,
+    /// One CSV row per route step, for spreadsheets (other commands fall back to text).
+    Csv,
+    /// GeoJSON line and points through the route's 3D coordinates (other commands fall back
+    /// to text).
+    #[value(name = "geojson")]
+    GeoJson,
 }
 
 impl OutputFormat {
@@ -48,6 +64,12 @@ impl OutputFormat {
         )
     }
 
+    /// Check if this format draws route systems by position, so route steps need their
+    /// coordinates even without `--include-coordinates`.
+    pub fn needs_positions(self) -> bool {
+        matches!(self, OutputFormat::Map | OutputFormat::GeoJson)
+    }
+
     /// Check if this format supports the CLI footer.
     pub fn supports_footer(self) -> bool {
         matches!(
@@ -109,6 +131,15 @@ impl OutputFormat {
             OutputFormat::Enhanced => {
                 render_enhanced(summary, base_url);
             }
+            OutputFormat::Map => {
+                render_map(summary);
+            }
+            OutputFormat::Csv => {
+                render_csv(summary);
+            }
+            OutputFormat::GeoJson => {
+                render_geojson(summary)?;
+            }
         }
         Ok(())
     }
@@ -126,6 +157,30 @@ pub fn render_enhanced(summary: &RouteSummary, base_url: &str) {
     renderer.render(summary, base_url);
 }
 
+/// Global override set by `--json-compact`.
+static JSON_COMPACT: AtomicBool = AtomicBool::new(false);
+
+/// Choose between pretty-printed (default) and single-line compact JSON output.
+pub fn set_json_compact(compact: bool) {
+    JSON_COMPACT.store(compact, Ordering::SeqCst);
+}
+
+/// Serialize `value` as pretty or compact JSON.
+///
+/// Compact output is a single line with no insignificant whitespace.
+pub fn format_json<T: Serialize + ?Sized>(value: &T, compact: bool) -> serde_json::Result<String> {
+    if compact {
+        serde_json::to_string(value)
+    } else {
+        serde_json::to_string_pretty(value)
+    }
+}
+
+/// Serialize `value` for stdout, honouring the `--json-compact` setting.
+pub fn to_json_string<T: Serialize + ?Sized>(value: &T) -> serde_json::Result<String> {
+    format_json(value, JSON_COMPACT.load(Ordering::SeqCst))
+}
+
 /// Default base URL for fmap route viewer (route token appended directly).
 pub const DEFAULT_FMAP_BASE_URL: &str = "https://fmap.scetrov.live/?route=";
 /// Type width parameter indicating 3-bit waypoint type encoding.
@@ -182,6 +237,26 @@ mod tests {
     use crate::terminal::colors;
     // FuelProjection no longer required directly here; builders are used instead.
 
+    #[test]
+    fn test_format_json_compact_is_single_line() {
+        let value = serde_json::json!({
+            "route": ["Nod", "Brana"],
+            "hops": 1,
+            "fuel": { "total": 12.5, "warnings": [] },
+        });
+
+        let pretty = format_json(&value, false).unwrap();
+        let compact = format_json(&value, true).unwrap();
+
+        assert!(pretty.contains('\n'));
+        assert!(!compact.contains('\n'));
+        assert!(!compact.contains(": "));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&compact).unwrap(),
+            serde_json::from_str::<serde_json::Value>(&pretty).unwrap()
+        );
+    }
+
     #[test]
     fn test_get_temp_circle_hot() {
         let palette = ColorPalette::colored();
@@ -282,6 +357,7 @@ mod tests {
                 residual_heat: Some(0.0001),
                 can_proceed: true,
             }),
+            position: None,
         };
 
         let widths = compute_details_column_widths(std::slice::from_ref(&step));
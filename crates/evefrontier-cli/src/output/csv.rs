@@ -0,0 +1,194 @@
+//! CSV rendering of route steps (`--format csv`).
+//!
+//! One row per step, after a header row, so routes can be loaded into a spreadsheet. Values are
+//! written unrounded and left empty when a step has no data for a column (the origin has no
+//! distance, fuel, or heat). Fields containing commas, quotes, or line breaks are quoted.
+
+use evefrontier_lib::RouteSummary;
+
+/// Column names, in output order.
+pub const CSV_HEADER: [&str; 8] = [
+    "index",
+    "system_id",
+    "name",
+    "method",
+    "distance_ly",
+    "min_temp_k",
+    "hop_fuel",
+    "hop_heat",
+];
+
+/// Print the route steps as CSV.
+pub fn render_csv(summary: &RouteSummary) {
+    print!("{}", format_route_csv(summary));
+}
+
+/// Build the CSV document for `summary`: a header row followed by one row per step.
+pub fn format_route_csv(summary: &RouteSummary) -> String {
+    let mut out = csv_row(CSV_HEADER.iter().map(|column| column.to_string()));
+    for step in &summary.steps {
+        out.push_str(&csv_row([
+            step.index.to_string(),
+            step.id.to_string(),
+            step.name.clone().unwrap_or_default(),
+            step.method.clone().unwrap_or_default(),
+            optional(step.distance),
+            optional(step.min_external_temp),
+            optional(step.fuel.as_ref().map(|fuel| fuel.hop_cost)),
+            optional(step.heat.as_ref().map(|heat| heat.hop_heat)),
+        ]));
+    }
+    out
+}
+
+fn optional(value: Option<f64>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+fn csv_row(fields: impl IntoIterator<Item = String>) -> String {
+    let mut row = fields
+        .into_iter()
+        .map(|field| escape_field(&field))
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push('\n');
+    row
+}
+
+/// Quote `field` per RFC 4180 when it contains a delimiter, quote, or line break.
+fn escape_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::RouteStepBuilder;
+    use evefrontier_lib::routing::RouteAlgorithm;
+    use evefrontier_lib::ship::HeatProjection;
+    use evefrontier_lib::{RouteEndpoint, RouteOutputKind};
+
+    /// Split CSV text into records, honouring quoted fields.
+    fn parse_csv(text: &str) -> Vec<Vec<String>> {
+        let mut records = Vec::new();
+        let mut record = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            match (quoted, c) {
+                (true, '"') if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                (true, '"') => quoted = false,
+                (true, c) => field.push(c),
+                (false, '"') => quoted = true,
+                (false, ',') => record.push(std::mem::take(&mut field)),
+                (false, '\n') => {
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                }
+                (false, c) => field.push(c),
+            }
+        }
+        records
+    }
+
+    fn summary() -> RouteSummary {
+        let steps = vec![
+            RouteStepBuilder::new()
+                .index(0)
+                .id(30000191)
+                .name("Nod")
+                .min_temp(12.5)
+                .build(),
+            RouteStepBuilder::new()
+                .index(1)
+                .id(30000201)
+                .name("Outpost, \"North\"")
+                .method("jump")
+                .distance(18.25)
+                .fuel(7.5, 7.5, Some(1742.5))
+                .with_heat(HeatProjection {
+                    hop_heat: 31.0,
+                    warning: None,
+                    wait_time_seconds: None,
+                    residual_heat: None,
+                    can_proceed: true,
+                })
+                .build(),
+            RouteStepBuilder::new()
+                .index(2)
+                .id(30000200)
+                .name("Brana")
+                .method("gate")
+                .distance(4.0)
+                .build(),
+        ];
+        RouteSummary {
+            kind: RouteOutputKind::Route,
+            algorithm: RouteAlgorithm::AStar,
+            hops: 2,
+            gates: 1,
+            jumps: 1,
+            total_distance: 22.25,
+            jump_distance: 18.25,
+            average_jump_distance: None,
+            max_jump_distance: None,
+            start: RouteEndpoint {
+                id: 30000191,
+                name: Some("Nod".to_string()),
+            },
+            goal: RouteEndpoint {
+                id: 30000200,
+                name: Some("Brana".to_string()),
+            },
+            steps,
+            fuel: None,
+            refuel_at: Vec::new(),
+            heat: None,
+            cooling_stops: Vec::new(),
+            fmap_url: None,
+            parameters: None,
+            effective_constraints: None,
+        }
+    }
+
+    #[test]
+    fn csv_has_header_and_one_row_per_step() {
+        let summary = summary();
+        let records = parse_csv(&format_route_csv(&summary));
+
+        assert_eq!(records[0], CSV_HEADER);
+        assert_eq!(records.len() - 1, summary.steps.len());
+        assert!(records.iter().all(|r| r.len() == CSV_HEADER.len()));
+    }
+
+    #[test]
+    fn csv_quotes_fields_and_leaves_missing_values_empty() {
+        let csv = format_route_csv(&summary());
+        assert!(csv.contains(",\"Outpost, \"\"North\"\"\","), "{csv}");
+
+        let records = parse_csv(&csv);
+        assert_eq!(records[1], ["0", "30000191", "Nod", "", "", "12.5", "", ""]);
+        assert_eq!(
+            records[2],
+            [
+                "1",
+                "30000201",
+                "Outpost, \"North\"",
+                "jump",
+                "18.25",
+                "",
+                "7.5",
+                "31"
+            ]
+        );
+        assert_eq!(records[3][6], "");
+    }
+}
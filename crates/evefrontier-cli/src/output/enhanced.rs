@@ -36,6 +36,9 @@ impl EnhancedRenderer {
             let is_last = i + 1 == len;
             self.render_step(step, i == 0, is_last);
             self.render_step_details(step, &widths);
+            if summary.refuel_at.contains(&i) {
+                println!("{}", crate::output_helpers::build_refuel_marker_line(p));
+            }
         }
 
         // Render footer via helper to keep this file smaller
@@ -71,7 +74,7 @@ impl EnhancedRenderer {
             _ => "",
         };
 
-        if let Some(distance) = step.distance {
+        let mut line = if let Some(distance) = step.distance {
             let dist_str = format_with_separators(distance as u64);
             let mut line = if !jump_type.is_empty() {
                 format!(
@@ -125,7 +128,12 @@ impl EnhancedRenderer {
                 name,
                 p.reset
             )
+        };
+
+        if let Some(position) = crate::output_helpers::format_position_suffix(step) {
+            line.push_str(&format!("{}{}{}", p.gray, position, p.reset));
         }
+        line
     }
 
     fn get_step_tag(&self, step: &RouteStep, is_first: bool, is_last: bool) -> (&str, &str) {
@@ -0,0 +1,168 @@
+//! GeoJSON export of a route (`--format geojson`).
+//!
+//! The route is written as a `FeatureCollection` holding one `LineString` through every step,
+//! followed by one `Point` per system, so it can be loaded into 3D plotting tools. Coordinates
+//! are the starmap's `[x, y, z]` in light-years rather than longitude/latitude; steps without a
+//! position are left out of both the line and the points.
+
+use std::io::{self, Write};
+
+use evefrontier_lib::{RouteStep, RouteSummary};
+use serde_json::{json, Value};
+
+/// Print the route as a GeoJSON `FeatureCollection`.
+pub fn render_geojson(summary: &RouteSummary) -> io::Result<()> {
+    let json = super::to_json_string(&route_geojson(summary)).map_err(io::Error::other)?;
+    let mut stdout = io::stdout();
+    stdout.write_all(json.as_bytes())?;
+    stdout.write_all(b"\n")
+}
+
+/// Build the GeoJSON `FeatureCollection` for `summary`.
+pub fn route_geojson(summary: &RouteSummary) -> Value {
+    let points: Vec<(&RouteStep, [f64; 3])> = summary
+        .steps
+        .iter()
+        .filter_map(|step| {
+            let position = step.position?;
+            Some((step, [position.x, position.y, position.z]))
+        })
+        .collect();
+
+    let line = json!({
+        "type": "Feature",
+        "geometry": {
+            "type": "LineString",
+            "coordinates": points.iter().map(|(_, coords)| coords).collect::<Vec<_>>(),
+        },
+        "properties": {
+            "start": summary.start.name,
+            "goal": summary.goal.name,
+            "algorithm": summary.algorithm.to_string(),
+            "hops": summary.hops,
+            "gates": summary.gates,
+            "jumps": summary.jumps,
+            "total_distance_ly": summary.total_distance,
+        },
+    });
+
+    let features = std::iter::once(line)
+        .chain(points.iter().map(|(step, coords)| {
+            json!({
+                "type": "Feature",
+                "geometry": { "type": "Point", "coordinates": coords },
+                "properties": {
+                    "index": step.index,
+                    "id": step.id,
+                    "name": step.name,
+                    "method": step.method,
+                    "distance_ly": step.distance,
+                    "min_temp_k": step.min_external_temp,
+                },
+            })
+        }))
+        .collect::<Vec<_>>();
+
+    json!({ "type": "FeatureCollection", "features": features })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::RouteStepBuilder;
+    use evefrontier_lib::routing::RouteAlgorithm;
+    use evefrontier_lib::{RouteEndpoint, RouteOutputKind, SystemPosition};
+
+    fn summary(positions: &[Option<[f64; 3]>]) -> RouteSummary {
+        let steps = positions
+            .iter()
+            .enumerate()
+            .map(|(idx, position)| {
+                let mut step = RouteStepBuilder::new()
+                    .index(idx)
+                    .id(30000000 + idx as i64)
+                    .name(&format!("S{idx}"))
+                    .build();
+                step.position = position.map(|[x, y, z]| SystemPosition { x, y, z });
+                step
+            })
+            .collect();
+        RouteSummary {
+            kind: RouteOutputKind::Route,
+            algorithm: RouteAlgorithm::AStar,
+            hops: positions.len() - 1,
+            gates: 0,
+            jumps: positions.len() - 1,
+            total_distance: 0.0,
+            jump_distance: 0.0,
+            average_jump_distance: None,
+            max_jump_distance: None,
+            start: RouteEndpoint {
+                id: 30000000,
+                name: Some("S0".to_string()),
+            },
+            goal: RouteEndpoint {
+                id: 30000000 + positions.len() as i64 - 1,
+                name: Some(format!("S{}", positions.len() - 1)),
+            },
+            steps,
+            fuel: None,
+            refuel_at: Vec::new(),
+            heat: None,
+            cooling_stops: Vec::new(),
+            fmap_url: None,
+            parameters: None,
+            effective_constraints: None,
+        }
+    }
+
+    fn geometry_types(geojson: &Value) -> Vec<&str> {
+        geojson["features"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|f| f["geometry"]["type"].as_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn geojson_has_one_line_and_a_point_per_system() {
+        let summary = summary(&[
+            Some([0.0, 0.0, 0.0]),
+            Some([1.0, 2.0, 3.0]),
+            Some([4.0, 5.0, 6.0]),
+        ]);
+        let text = serde_json::to_string(&route_geojson(&summary)).unwrap();
+        let geojson: Value = serde_json::from_str(&text).expect("valid JSON");
+
+        assert_eq!(geojson["type"], "FeatureCollection");
+        assert_eq!(
+            geometry_types(&geojson),
+            ["LineString", "Point", "Point", "Point"]
+        );
+        assert_eq!(
+            geojson["features"][0]["geometry"]["coordinates"],
+            json!([[0.0, 0.0, 0.0], [1.0, 2.0, 3.0], [4.0, 5.0, 6.0]])
+        );
+        assert_eq!(geojson["features"][2]["properties"]["name"], "S1");
+        assert_eq!(
+            geojson["features"][2]["geometry"]["coordinates"],
+            json!([1.0, 2.0, 3.0])
+        );
+    }
+
+    #[test]
+    fn geojson_skips_steps_without_positions() {
+        let summary = summary(&[Some([0.0, 0.0, 0.0]), None, Some([4.0, 5.0, 6.0])]);
+        let geojson = route_geojson(&summary);
+
+        assert_eq!(geometry_types(&geojson), ["LineString", "Point", "Point"]);
+        assert_eq!(
+            geojson["features"][0]["geometry"]["coordinates"]
+                .as_array()
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! ASCII map sketch of a route (`--format map`).
+//!
+//! Each step's coordinates are projected onto the two axes along which the route spreads the
+//! most and plotted on a small character grid. Consecutive systems are joined with dotted lines,
+//! and every system is marked with the first letter or digit of its name, listed in a legend
+//! below the grid. The sketch is not to scale: each axis is stretched to fill the grid.
+
+use evefrontier_lib::RouteSummary;
+
+use crate::terminal::supports_unicode;
+
+/// Grid width in characters (excluding the border).
+const MAP_WIDTH: usize = 60;
+/// Grid height in lines (excluding the border).
+const MAP_HEIGHT: usize = 20;
+const AXIS_NAMES: [&str; 3] = ["x", "y", "z"];
+
+/// Print the route as an ASCII map sketch.
+pub fn render_map(summary: &RouteSummary) {
+    print!("{}", format_route_map(summary, supports_unicode()));
+}
+
+/// Build the map sketch for `summary`, joining systems with `·` when `unicode` is set and `.`
+/// otherwise. Steps without coordinates are left off the map.
+pub fn format_route_map(summary: &RouteSummary, unicode: bool) -> String {
+    let start = summary.start.name.as_deref().unwrap_or("<unknown>");
+    let goal = summary.goal.name.as_deref().unwrap_or("<unknown>");
+    let mut out = format!(
+        "Route map: {} -> {} ({} jumps)\n",
+        start, goal, summary.hops
+    );
+
+    let points: Vec<(char, &str, [f64; 3])> = summary
+        .steps
+        .iter()
+        .filter_map(|step| {
+            let position = step.position?;
+            let name = step.name.as_deref().unwrap_or("<unknown>");
+            Some((map_marker(name), name, [position.x, position.y, position.z]))
+        })
+        .collect();
+    if points.is_empty() {
+        out.push_str("(no system coordinates available to plot)\n");
+        return out;
+    }
+
+    let bounds: Vec<(f64, f64)> = (0..3).map(|axis| axis_bounds(&points, axis)).collect();
+    // Plot the two widest axes so a route is never flattened onto a single line; keep them in
+    // x/y/z order so the horizontal axis is stable.
+    let mut axes = [0usize, 1, 2];
+    axes.sort_by(|a, b| {
+        let extent = |axis: usize| bounds[axis].1 - bounds[axis].0;
+        extent(*b).total_cmp(&extent(*a))
+    });
+    let (h_axis, v_axis) = (axes[0].min(axes[1]), axes[0].max(axes[1]));
+
+    let cells: Vec<(usize, usize)> = points
+        .iter()
+        .map(|(_, _, p)| {
+            let col = scale(p[h_axis], bounds[h_axis], MAP_WIDTH);
+            let row = MAP_HEIGHT - 1 - scale(p[v_axis], bounds[v_axis], MAP_HEIGHT);
+            (col, row)
+        })
+        .collect();
+
+    let mut grid = vec![vec![' '; MAP_WIDTH]; MAP_HEIGHT];
+    let trail = if unicode { '·' } else { '.' };
+    for pair in cells.windows(2) {
+        draw_trail(&mut grid, pair[0], pair[1], trail);
+    }
+    for ((marker, _, _), &(col, row)) in points.iter().zip(&cells) {
+        grid[row][col] = *marker;
+    }
+
+    let border = format!("+{}+\n", "-".repeat(MAP_WIDTH));
+    out.push_str(&border);
+    for row in &grid {
+        out.push('|');
+        out.extend(row.iter());
+        out.push_str("|\n");
+    }
+    out.push_str(&border);
+    out.push_str(&format!(
+        "{} horizontal, {} vertical (not to scale)\n",
+        AXIS_NAMES[h_axis], AXIS_NAMES[v_axis]
+    ));
+
+    for (marker, name, _) in &points {
+        out.push_str(&format!("  {}  {}\n", marker, name));
+    }
+    out
+}
+
+/// First letter or digit of a system name, upper-cased (`?` when there is none).
+fn map_marker(name: &str) -> char {
+    name.chars()
+        .find(|c| c.is_ascii_alphanumeric())
+        .map_or('?', |c| c.to_ascii_uppercase())
+}
+
+fn axis_bounds(points: &[(char, &str, [f64; 3])], axis: usize) -> (f64, f64) {
+    points
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), (_, _, p)| {
+            (lo.min(p[axis]), hi.max(p[axis]))
+        })
+}
+
+/// Map `value` within `(lo, hi)` onto `0..cells`, centering it when the range is empty.
+fn scale(value: f64, (lo, hi): (f64, f64), cells: usize) -> usize {
+    let span = hi - lo;
+    if span <= f64::EPSILON {
+        return cells / 2;
+    }
+    (((value - lo) / span) * (cells - 1) as f64).round() as usize
+}
+
+/// Fill the cells strictly between `from` and `to` with `trail`.
+fn draw_trail(grid: &mut [Vec<char>], from: (usize, usize), to: (usize, usize), trail: char) {
+    let (c0, r0) = (from.0 as f64, from.1 as f64);
+    let (dc, dr) = (to.0 as f64 - c0, to.1 as f64 - r0);
+    let steps = dc.abs().max(dr.abs()) as usize;
+    for i in 1..steps {
+        let t = i as f64 / steps as f64;
+        let col = (c0 + dc * t).round() as usize;
+        let row = (r0 + dr * t).round() as usize;
+        grid[row][col] = trail;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::RouteStepBuilder;
+    use evefrontier_lib::routing::RouteAlgorithm;
+    use evefrontier_lib::{RouteEndpoint, RouteOutputKind, SystemPosition};
+
+    fn summary_with_positions(systems: &[(&str, [f64; 3])]) -> RouteSummary {
+        let steps = systems
+            .iter()
+            .enumerate()
+            .map(|(idx, (name, [x, y, z]))| {
+                let mut step = RouteStepBuilder::new()
+                    .index(idx)
+                    .id(idx as i64)
+                    .name(name)
+                    .build();
+                step.position = Some(SystemPosition {
+                    x: *x,
+                    y: *y,
+                    z: *z,
+                });
+                step
+            })
+            .collect();
+        RouteSummary {
+            kind: RouteOutputKind::Route,
+            algorithm: RouteAlgorithm::AStar,
+            hops: systems.len() - 1,
+            gates: 0,
+            jumps: systems.len() - 1,
+            total_distance: 0.0,
+            jump_distance: 0.0,
+            average_jump_distance: None,
+            max_jump_distance: None,
+            start: RouteEndpoint {
+                id: 0,
+                name: Some(systems[0].0.to_string()),
+            },
+            goal: RouteEndpoint {
+                id: systems.len() as i64 - 1,
+                name: Some(systems[systems.len() - 1].0.to_string()),
+            },
+            steps,
+            fuel: None,
+            refuel_at: Vec::new(),
+            heat: None,
+            cooling_stops: Vec::new(),
+            fmap_url: None,
+            parameters: None,
+            effective_constraints: None,
+        }
+    }
+
+    #[test]
+    fn map_marks_every_system_and_draws_trail() {
+        let summary = summary_with_positions(&[
+            ("Nod", [0.0, 0.0, 0.0]),
+            ("H:2L2S", [10.0, 1.0, 40.0]),
+            ("Brana", [60.0, 2.0, 10.0]),
+        ]);
+        let map = format_route_map(&summary, false);
+
+        assert!(
+            map.starts_with("Route map: Nod -> Brana (2 jumps)\n"),
+            "{map}"
+        );
+        let grid: Vec<&str> = map.lines().filter(|l| l.starts_with('|')).collect();
+        assert_eq!(grid.len(), MAP_HEIGHT);
+        for marker in ['N', 'H', 'B'] {
+            assert!(
+                grid.iter().any(|l| l.contains(marker)),
+                "{marker} missing: {map}"
+            );
+        }
+        assert!(grid.iter().any(|l| l.contains('.')), "{map}");
+        assert!(map.contains("x horizontal, z vertical"), "{map}");
+        assert!(map.contains("  B  Brana\n"), "{map}");
+    }
+
+    #[test]
+    fn map_without_positions_says_so() {
+        let mut summary = summary_with_positions(&[("Nod", [0.0; 3]), ("Brana", [1.0; 3])]);
+        for step in &mut summary.steps {
+            step.position = None;
+        }
+        let map = format_route_map(&summary, true);
+        assert!(map.contains("no system coordinates"), "{map}");
+    }
+
+    #[test]
+    fn coincident_systems_are_centered() {
+        let summary = summary_with_positions(&[("Nod", [5.0; 3]), ("Nod", [5.0; 3])]);
+        let map = format_route_map(&summary, true);
+        let middle = map
+            .lines()
+            .filter(|l| l.starts_with('|'))
+            .nth(MAP_HEIGHT - 1 - MAP_HEIGHT / 2)
+            .expect("grid row");
+        assert_eq!(middle.chars().nth(1 + MAP_WIDTH / 2), Some('N'), "{map}");
+    }
+}
@@ -3,7 +3,9 @@ use std::io::Write;
 
 use evefrontier_lib::{RouteRenderMode, RouteStep, RouteSummary};
 
-use crate::output_helpers::{format_fuel_suffix, print_estimation_warning_box_gray_reset};
+use crate::output_helpers::{
+    format_fuel_suffix, format_position_suffix, print_estimation_warning_box_gray_reset,
+};
 use crate::terminal::colors;
 use crate::terminal::supports_color;
 
@@ -21,6 +23,9 @@ pub fn render_text(summary: &RouteSummary, show_temps: bool, base_url: &str) {
     }
     println!("\nTotal distance: {:.0}ly", summary.total_distance);
     println!("Total ly jumped: {:.0}ly", summary.jump_distance);
+    if let (Some(avg), Some(max)) = (summary.average_jump_distance, summary.max_jump_distance) {
+        println!("Average jump: {:.1}ly (longest {:.1}ly)", avg, max);
+    }
 
     if let Some(fuel) = &summary.fuel {
         if let Some(ship) = &fuel.ship_name {
@@ -59,50 +64,33 @@ pub fn render_text(summary: &RouteSummary, show_temps: bool, base_url: &str) {
 
 fn render_text_step(step: &RouteStep, show_temps: bool) {
     let name = step.name.as_deref().unwrap_or("<unknown>");
-    let fuel_suffix = format_fuel_suffix(step);
+    let suffix = format!(
+        "{}{}",
+        format_position_suffix(step).unwrap_or_default(),
+        format_fuel_suffix(step).unwrap_or_default()
+    );
 
     if let (Some(distance), Some(method)) = (step.distance, step.method.as_deref()) {
         if show_temps {
             if let Some(t) = step.min_external_temp {
                 println!(
                     " - {} [min {:.2}K] ({:.0}ly via {}){}",
-                    name,
-                    t,
-                    distance,
-                    method,
-                    fuel_suffix.as_deref().unwrap_or("")
+                    name, t, distance, method, suffix
                 );
             } else {
-                println!(
-                    " - {} ({:.0}ly via {}){}",
-                    name,
-                    distance,
-                    method,
-                    fuel_suffix.as_deref().unwrap_or("")
-                );
+                println!(" - {} ({:.0}ly via {}){}", name, distance, method, suffix);
             }
         } else {
-            println!(
-                " - {} ({:.0}ly via {}){}",
-                name,
-                distance,
-                method,
-                fuel_suffix.as_deref().unwrap_or("")
-            );
+            println!(" - {} ({:.0}ly via {}){}", name, distance, method, suffix);
         }
     } else if show_temps {
         if let Some(t) = step.min_external_temp {
-            println!(
-                " - {} [min {:.2}K]{}",
-                name,
-                t,
-                fuel_suffix.as_deref().unwrap_or("")
-            );
+            println!(" - {} [min {:.2}K]{}", name, t, suffix);
         } else {
-            println!(" - {}{}", name, fuel_suffix.as_deref().unwrap_or(""));
+            println!(" - {}{}", name, suffix);
         }
     } else {
-        println!(" - {}{}", name, fuel_suffix.as_deref().unwrap_or(""));
+        println!(" - {}{}", name, suffix);
     }
 }
 
@@ -125,10 +113,13 @@ pub fn render_rich(summary: &RouteSummary, show_temps: bool, _base_url: &str) {
 }
 
 /// Render a route summary in JSON format.
+///
+/// Pretty-printed by default; a single line when `--json-compact` is set.
 pub fn render_json(summary: &RouteSummary) -> io::Result<()> {
+    let json = super::to_json_string(summary).map_err(io::Error::other)?;
     let mut stdout = io::stdout();
-    serde_json::to_writer_pretty(&mut stdout, summary).map_err(io::Error::other)?;
-    stdout.write_all(b"\n").map_err(io::Error::other)
+    stdout.write_all(json.as_bytes())?;
+    stdout.write_all(b"\n")
 }
 
 /// Render a route summary in basic path format.
@@ -213,6 +204,9 @@ pub fn render_emoji(summary: &RouteSummary, show_temps: bool, _base_url: &str) {
     }
     println!("\nTotal distance: {:.0}ly", summary.total_distance);
     println!("Total ly jumped: {:.0}ly", summary.jump_distance);
+    if let (Some(avg), Some(max)) = (summary.average_jump_distance, summary.max_jump_distance) {
+        println!("Average jump: {:.1}ly (longest {:.1}ly)", avg, max);
+    }
 
     if summary.fuel.is_some() || summary.heat.is_some() {
         let (gray, reset) = if supports_color() {
@@ -258,13 +252,18 @@ mod tests {
             jumps: 0,
             total_distance: 0.0,
             jump_distance: 0.0,
+            average_jump_distance: None,
+            max_jump_distance: None,
             start: RouteEndpoint { id: 0, name: None },
             goal: RouteEndpoint { id: 0, name: None },
             steps: Vec::new(),
             fuel: None,
+            refuel_at: Vec::new(),
             heat: None,
+            cooling_stops: Vec::new(),
             fmap_url: None,
             parameters: None,
+            effective_constraints: None,
         }
     }
 
@@ -1,8 +1,9 @@
 use crate::output::FMAP_TYPE_WIDTH_PARAM;
 use crate::terminal::{colors, ColorPalette};
-use evefrontier_lib::{RouteStep, RouteSummary};
+use evefrontier_lib::{RouteStep, RouteSummary, SystemPosition};
 
-const COOLDOWN_DISPLAY_THRESHOLD_SECONDS: f64 = 0.5;
+const COOLDOWN_DISPLAY_THRESHOLD_SECONDS: f64 =
+    evefrontier_lib::ship::COOLDOWN_STOP_THRESHOLD_SECONDS;
 const FOOTER_LABEL_WIDTH: usize = 20;
 
 // =============================================================================
@@ -35,19 +36,24 @@ pub(crate) trait RenderableStep {
     /// Fuel cost for this hop (if fuel projection available).
     fn hop_fuel(&self) -> Option<f64>;
 
-    /// Cumulative fuel consumed up to and including this hop.
+    /// Total fuel burned from the origin up to and including this hop.
+    ///
+    /// Never reset by a refuel stop; `remaining_fuel` reflects refuels instead.
     fn cumulative_fuel(&self) -> Option<f64>;
 
     /// Fuel remaining after this hop.
     fn remaining_fuel(&self) -> Option<f64>;
 
-    /// Fuel warning message (e.g., "REFUEL").
+    /// Fuel warning message (e.g., "REFUEL" or "RESERVE").
     fn fuel_warning(&self) -> Option<&str>;
 
     /// Heat generated for this hop.
     fn hop_heat(&self) -> Option<f64>;
 
-    /// Cumulative/instantaneous heat at this hop.
+    /// Residual hull temperature (K) on arrival at this hop after any cooldown.
+    ///
+    /// Routes and scouts both report `HeatProjection::residual_heat` here; jumps start
+    /// from ambient so heat is not summed across hops.
     fn cumulative_heat(&self) -> Option<f64>;
 
     /// Heat warning message (e.g., "OVERHEATED", "CRITICAL").
@@ -204,7 +210,7 @@ pub(crate) fn build_fuel_segment_generic<T: RenderableStep>(
             };
 
             if let Some(w) = step.fuel_warning() {
-                if w == "REFUEL" {
+                if w == "REFUEL" || w == "RESERVE" {
                     res.push(' ');
                     res.push_str(&format_label(w, palette.tag_refuel, palette.reset));
                 }
@@ -383,12 +389,12 @@ pub(crate) fn compute_details_column_widths(steps: &[RouteStep]) -> ColumnWidths
 #[test]
 fn build_enhanced_footer_includes_params() {
     use crate::terminal::ColorPalette;
-    use evefrontier_lib::output::RouteParametersSummary;
+    use evefrontier_lib::output::{EffectiveConstraintsSummary, RouteParametersSummary};
     use evefrontier_lib::routing::{RouteAlgorithm, RouteOptimization};
     use evefrontier_lib::{RouteEndpoint, RouteOutputKind};
 
     let palette = ColorPalette::plain();
-    let summary = RouteSummary {
+    let mut summary = RouteSummary {
         kind: RouteOutputKind::Route,
         algorithm: RouteAlgorithm::AStar,
         hops: 3,
@@ -396,6 +402,8 @@ fn build_enhanced_footer_includes_params() {
         jumps: 2,
         total_distance: 100.0,
         jump_distance: 50.0,
+        average_jump_distance: None,
+        max_jump_distance: None,
         start: RouteEndpoint {
             id: 1,
             name: Some("A".to_string()),
@@ -406,7 +414,9 @@ fn build_enhanced_footer_includes_params() {
         },
         steps: Vec::new(),
         fuel: None,
+        refuel_at: Vec::new(),
         heat: None,
+        cooling_stops: Vec::new(),
         fmap_url: None,
         parameters: Some(RouteParametersSummary {
             algorithm: RouteAlgorithm::AStar,
@@ -418,6 +428,7 @@ fn build_enhanced_footer_includes_params() {
             avoid_gates: false,
             max_jump: None,
         }),
+        effective_constraints: None,
     };
 
     let lines = build_enhanced_footer(&summary, "https://fmap/", &palette);
@@ -431,6 +442,25 @@ fn build_enhanced_footer_includes_params() {
     assert!(pl_clean.contains("Optimize") || pl_clean.contains("Optimize:"));
     assert!(pl_clean.contains("Ship: Reflex"));
     assert!(pl_clean.contains("Fuel quality: 10%"));
+    assert!(
+        !lines.iter().any(|l| l.contains("Adjusted:")),
+        "no adjustment line without effective constraints"
+    );
+
+    summary.effective_constraints = Some(EffectiveConstraintsSummary {
+        max_jump: Some(42.5),
+        adjusted: vec!["max_jump".to_string()],
+    });
+    let lines = build_enhanced_footer(&summary, "https://fmap/", &palette);
+    let adjusted = lines
+        .iter()
+        .map(|l| strip_ansi_to_string(l))
+        .find(|l| l.contains("Adjusted:"))
+        .expect("adjusted line");
+    assert!(
+        adjusted.contains("Max jump: 42.50 ly (requested none)"),
+        "{adjusted}"
+    );
 }
 
 /// Format a small fuel suffix used in compact text renderers.
@@ -447,6 +477,16 @@ pub(crate) fn format_fuel_suffix(step: &RouteStep) -> Option<String> {
     ))
 }
 
+/// Format a system position as ` (x, y, z)` in light-years.
+pub(crate) fn format_position(position: &SystemPosition) -> String {
+    format!(" ({:.2}, {:.2}, {:.2})", position.x, position.y, position.z)
+}
+
+/// Format the coordinates suffix for a step, when positions were attached.
+pub(crate) fn format_position_suffix(step: &RouteStep) -> Option<String> {
+    step.position.as_ref().map(format_position)
+}
+
 /// Format a cooling duration into a concise string like "2m4s".
 pub(crate) fn format_cooldown_duration(seconds: f64) -> String {
     if seconds <= 0.0 {
@@ -877,6 +917,16 @@ pub(crate) fn build_fuel_segment(
     build_fuel_segment_generic(step, widths, palette)
 }
 
+/// Build the marker line shown under a step listed in `RouteSummary::refuel_at`.
+pub(crate) fn build_refuel_marker_line(palette: &ColorPalette) -> String {
+    format!(
+        "       {}│{} {} refill the tank here before jumping on",
+        palette.gray,
+        palette.reset,
+        format_label("REFUEL STOP", palette.tag_refuel, palette.reset)
+    )
+}
+
 /// Build the heat cost segment (if any).
 ///
 /// Delegates to `build_heat_segment_generic` for consistent behavior across
@@ -911,7 +961,13 @@ pub fn build_enhanced_footer(
     let gates_str = format_with_separators(gate_distance as u64);
     let jumps_str = format_with_separators(summary.jump_distance as u64);
 
+    let avg_jump_str = summary.average_jump_distance.map(|d| format!("{:.1}", d));
+    let max_jump_str = summary.max_jump_distance.map(|d| format!("{:.1}", d));
+
     let mut num_width = total_str.len().max(gates_str.len()).max(jumps_str.len());
+    for value in avg_jump_str.iter().chain(max_jump_str.iter()) {
+        num_width = num_width.max(value.len());
+    }
 
     if let Some(fuel) = &summary.fuel {
         num_width = num_width.max(format_with_separators(fuel.total.ceil() as u64).len());
@@ -972,6 +1028,23 @@ pub fn build_enhanced_footer(
         width = num_width
     ));
 
+    // Jump lengths are only meaningful when the route uses the jump drive.
+    for (label, value) in [("Avg Jump:", &avg_jump_str), ("Max Jump:", &max_jump_str)] {
+        if let Some(value) = value {
+            lines.push(format!(
+                "  {}{:<lw$}{}  {}{:>width$} ly{}",
+                p.orange,
+                label,
+                p.reset,
+                p.white_bold,
+                value,
+                p.reset,
+                lw = lw,
+                width = num_width
+            ));
+        }
+    }
+
     // Fuel Section
     if let Some(fuel) = &summary.fuel {
         let ship = fuel.ship_name.as_deref().unwrap_or("<unknown ship>");
@@ -1026,6 +1099,37 @@ pub fn build_enhanced_footer(
             lw = lw,
             width = num_width
         ));
+
+        let stops_str = heat.cooldown_stops.to_string();
+        let l_stops = "Cooldown stops:";
+        lines.push(format!(
+            "  {}{:<lw$}{}  {}{:>width$}{}",
+            p.cyan,
+            l_stops,
+            p.reset,
+            p.white_bold,
+            stops_str,
+            p.reset,
+            lw = lw,
+            width = num_width
+        ));
+
+        // Total time spent at the forced cooling stops (excludes sub-threshold waits).
+        if !summary.cooling_stops.is_empty() {
+            let pause: f64 = summary.cooling_stops.iter().map(|s| s.wait_seconds).sum();
+            let l_pause = "Cooling pause:";
+            lines.push(format!(
+                "  {}{:<lw$}{}  {}{:>width$}{}",
+                p.cyan,
+                l_pause,
+                p.reset,
+                p.white_bold,
+                format_cooldown_duration(pause),
+                p.reset,
+                lw = lw,
+                width = num_width
+            ));
+        }
     }
 
     if let Some(fmap_url) = &summary.fmap_url {
@@ -1042,6 +1146,8 @@ pub fn build_enhanced_footer(
         let optimization = match params.optimization {
             evefrontier_lib::routing::RouteOptimization::Fuel => "Fuel",
             evefrontier_lib::routing::RouteOptimization::Distance => "Distance",
+            evefrontier_lib::routing::RouteOptimization::Weighted { .. } => "Weighted",
+            evefrontier_lib::routing::RouteOptimization::Time(_) => "Time",
         };
         let ship = params.ship_name.as_deref().unwrap_or("<none>");
         let fuel_q = format!("{:.0}%", params.fuel_quality);
@@ -1085,6 +1191,24 @@ pub fn build_enhanced_footer(
         ));
     }
 
+    // Call out constraints the planner tightened on its own (e.g. a heat-clamped max jump).
+    if let Some(effective) = &summary.effective_constraints {
+        if effective.adjusted.iter().any(|name| name == "max_jump") {
+            let requested = summary
+                .parameters
+                .as_ref()
+                .and_then(|params| params.max_jump)
+                .map_or_else(|| "none".to_string(), |ly| format!("{:.2} ly", ly));
+            let applied = effective
+                .max_jump
+                .map_or_else(|| "none".to_string(), |ly| format!("{:.2} ly", ly));
+            lines.push(format!(
+                "  {}Adjusted:{}    {}Max jump:{} {} (requested {})",
+                p.cyan, p.reset, p.magenta, p.reset, applied, requested
+            ));
+        }
+    }
+
     lines
 }
 
@@ -1108,6 +1232,8 @@ pub(crate) struct GateNeighbor {
     pub name: String,
     /// System ID.
     pub id: i64,
+    /// Gate hops from the queried system (1 for direct neighbors).
+    pub hops: usize,
     /// Minimum external temperature in Kelvin (if known).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub min_temp_k: Option<f64>,
@@ -1117,6 +1243,9 @@ pub(crate) struct GateNeighbor {
     /// Number of moons in the system.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub moon_count: Option<u32>,
+    /// System coordinates in light-years (with `--include-coordinates`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<SystemPosition>,
 }
 
 /// Result of a gate neighbors query.
@@ -1126,9 +1255,11 @@ pub(crate) struct ScoutGatesResult {
     pub system: String,
     /// The queried system ID.
     pub system_id: i64,
+    /// Gate-hop depth that was searched.
+    pub depth: usize,
     /// Number of gate-connected neighbors.
     pub count: usize,
-    /// List of neighboring systems.
+    /// Neighboring systems within `depth` hops, ordered by hop count then name.
     pub neighbors: Vec<GateNeighbor>,
 }
 
@@ -1154,7 +1285,7 @@ pub(crate) struct RangeNeighbor {
     /// Fuel units consumed for this hop (when ship specified).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hop_fuel: Option<f64>,
-    /// Cumulative fuel consumed up to and including this hop.
+    /// Total fuel burned from the origin up to and including this hop (not reset by refuels).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cumulative_fuel: Option<f64>,
     /// Fuel remaining after this hop.
@@ -1163,18 +1294,24 @@ pub(crate) struct RangeNeighbor {
     /// Heat generated for this hop (when ship specified).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hop_heat: Option<f64>,
-    /// Cumulative heat accumulated up to and including this hop.
+    /// Residual hull temperature (K) on arrival after any cooldown, matching route steps.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cumulative_heat: Option<f64>,
     /// Cooldown time in seconds if overheated (when heat exceeds critical).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cooldown_seconds: Option<f64>,
-    /// Fuel warning message (e.g., "REFUEL" when insufficient fuel).
+    /// Fuel warning message ("REFUEL" when insufficient fuel, "RESERVE" when dipping into the reserve).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fuel_warning: Option<String>,
     /// Heat warning message (e.g., "OVERHEATED" or "CRITICAL").
     #[serde(skip_serializing_if = "Option::is_none")]
     pub heat_warning: Option<String>,
+    /// System coordinates in light-years (with `--include-coordinates`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<SystemPosition>,
+    /// Suitability score (with `--score`); results are sorted by it, highest first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
 }
 
 /// Implement RenderableStep for RangeNeighbor (scout range results).
@@ -1282,7 +1419,8 @@ pub(crate) struct ScoutRangeResult {
     /// Total wait time in seconds for cooling when overheated (sum of cooldown_seconds).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total_wait_time_seconds: Option<f64>,
-    /// List of nearby systems ordered by distance (or visit order when ship specified).
+    /// List of nearby systems ordered by distance (or visit order when ship specified, or
+    /// suitability score with `--score`).
     pub systems: Vec<RangeNeighbor>,
 }
 
@@ -1316,14 +1454,25 @@ pub(crate) fn format_scout_gates_text(result: &ScoutGatesResult, show_temps: boo
         result.system, result.count
     ));
     for neighbor in &result.neighbors {
+        let mut position = neighbor
+            .position
+            .as_ref()
+            .map(format_position)
+            .unwrap_or_default();
+        if neighbor.hops > 1 {
+            position.push_str(&format!(" [{} hops]", neighbor.hops));
+        }
         if show_temps {
             if let Some(t) = neighbor.min_temp_k {
-                out.push_str(&format!(" - {} [min {:.2}K]\n", neighbor.name, t));
+                out.push_str(&format!(
+                    " - {} [min {:.2}K]{}\n",
+                    neighbor.name, t, position
+                ));
             } else {
-                out.push_str(&format!(" - {}\n", neighbor.name));
+                out.push_str(&format!(" - {}{}\n", neighbor.name, position));
             }
         } else {
-            out.push_str(&format!(" - {}\n", neighbor.name));
+            out.push_str(&format!(" - {}{}\n", neighbor.name, position));
         }
     }
     out
@@ -1589,12 +1738,25 @@ pub(crate) fn format_scout_range_text(result: &ScoutRangeResult, show_temps: boo
             String::new()
         };
 
+        let position_str = system
+            .position
+            .as_ref()
+            .map(format_position)
+            .unwrap_or_default();
+
+        let score_str = system
+            .score
+            .map(|score| format!(" score {:.2}", score))
+            .unwrap_or_default();
+
         out.push_str(&format!(
-            " {}. {}{} ({:.1}ly){}{}\n",
+            " {}. {}{} ({:.1}ly){}{}{}{}\n",
             i + 1,
             system.name,
             temp_str,
             system.distance_ly,
+            score_str,
+            position_str,
             fuel_heat_str,
             warning_str
         ));
@@ -2050,6 +2212,108 @@ pub(crate) fn build_scout_range_footer(
     lines
 }
 
+// =============================================================================
+// Reachable command output formatters
+// =============================================================================
+
+/// A system reachable from the origin within the budget.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct ReachableNeighbor {
+    /// System name.
+    pub name: String,
+    /// System ID.
+    pub id: i64,
+    /// Cheapest cost from the origin, in the budget's unit.
+    pub cost: f64,
+    /// Minimum external temperature in Kelvin (if known).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_temp_k: Option<f64>,
+}
+
+/// Budget used for a reachability query (echoed in response).
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct ReachableBudgetParams {
+    /// Budget unit: "ly" for distance or "fuel" for fuel units.
+    pub unit: &'static str,
+    /// Budget value in `unit`.
+    pub value: f64,
+    /// Maximum number of results requested.
+    pub limit: usize,
+}
+
+/// Result of a reachability query.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct ReachableResult {
+    /// The origin system name.
+    pub system: String,
+    /// The origin system ID.
+    pub system_id: i64,
+    /// Budget parameters.
+    pub budget: ReachableBudgetParams,
+    /// Ship name used to price fuel (when specified).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ship: Option<String>,
+    /// Number of reachable systems returned.
+    pub count: usize,
+    /// Reachable systems ordered by ascending cost.
+    pub systems: Vec<ReachableNeighbor>,
+}
+
+impl ReachableResult {
+    fn header(&self) -> String {
+        format!(
+            "Systems reachable from {} within {:.1} {} ({} found):\n",
+            self.system, self.budget.value, self.budget.unit, self.count
+        )
+    }
+}
+
+/// Format reachable result in basic (plain text) format.
+#[allow(dead_code)]
+pub(crate) fn format_reachable_basic(result: &ReachableResult) -> String {
+    let mut out = result.header();
+    for (i, system) in result.systems.iter().enumerate() {
+        out.push_str(&format!(
+            "  {}. {} ({:.1} {})\n",
+            i + 1,
+            system.name,
+            system.cost,
+            result.budget.unit
+        ));
+    }
+    out
+}
+
+/// Format reachable result in text format (with temperatures).
+#[allow(dead_code)]
+pub(crate) fn format_reachable_text(result: &ReachableResult, show_temps: bool) -> String {
+    let mut out = result.header();
+    for system in &result.systems {
+        let temp_str = match system.min_temp_k {
+            Some(t) if show_temps => format!(" [min {:.2}K]", t),
+            _ => String::new(),
+        };
+        out.push_str(&format!(
+            " - {} ({:.1} {}){}\n",
+            system.name, system.cost, result.budget.unit, temp_str
+        ));
+    }
+    out
+}
+
+/// Format reachable result in note (in-game notepad) format.
+#[allow(dead_code)]
+pub(crate) fn format_reachable_note(result: &ReachableResult) -> String {
+    let mut out = result.header();
+    for system in &result.systems {
+        out.push_str(&format!(
+            "<a href=\"showinfo:5//{}\">{}</a> ({:.1} {})\n",
+            system.id, system.name, system.cost, result.budget.unit
+        ));
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2181,6 +2445,7 @@ mod tests {
             moon_count: None,
             fuel: None,
             heat: None,
+            position: None,
         };
         let seg = build_min_segment(&step, &p);
         assert!(seg.contains("Black Hole"));
@@ -2205,6 +2470,7 @@ mod tests {
                 warning: None,
             }),
             heat: None,
+            position: None,
         };
 
         let widths = ColumnWidths {
@@ -2238,6 +2504,7 @@ mod tests {
                 residual_heat: Some(0.0001),
                 can_proceed: true,
             }),
+            position: None,
         };
 
         let widths = ColumnWidths {
@@ -2248,6 +2515,15 @@ mod tests {
         assert!(s.contains("<0.01"));
     }
 
+    #[test]
+    fn refuel_marker_line_labels_the_stop() {
+        let line = strip_ansi_to_string(&build_refuel_marker_line(&ColorPalette::plain()));
+        assert!(
+            line.contains("│  REFUEL STOP  refill the tank here"),
+            "line: {line}"
+        );
+    }
+
     #[test]
     fn build_segments_include_warning_tags() {
         let p = ColorPalette::plain();
@@ -2273,6 +2549,7 @@ mod tests {
                 residual_heat: None,
                 can_proceed: false,
             }),
+            position: None,
         };
 
         let widths = ColumnWidths {
@@ -2310,6 +2587,7 @@ mod tests {
                 residual_heat: None,
                 can_proceed: true,
             }),
+            position: None,
         };
 
         let widths = ColumnWidths {
@@ -2500,4 +2778,168 @@ mod tests {
         assert_eq!(width, 120);
         std::env::remove_var("COLUMNS");
     }
+
+    #[test]
+    fn footer_reports_jump_length_stats() {
+        use evefrontier_lib::routing::RouteAlgorithm;
+        use evefrontier_lib::{RouteEndpoint, RouteOutputKind};
+
+        let mut summary = RouteSummary {
+            kind: RouteOutputKind::Route,
+            algorithm: RouteAlgorithm::AStar,
+            hops: 3,
+            gates: 1,
+            jumps: 2,
+            total_distance: 90.0,
+            jump_distance: 60.0,
+            average_jump_distance: Some(30.0),
+            max_jump_distance: Some(42.5),
+            start: RouteEndpoint {
+                id: 1,
+                name: Some("A".to_string()),
+            },
+            goal: RouteEndpoint {
+                id: 2,
+                name: Some("B".to_string()),
+            },
+            steps: Vec::new(),
+            fuel: None,
+            refuel_at: Vec::new(),
+            heat: None,
+            cooling_stops: Vec::new(),
+            fmap_url: None,
+            parameters: None,
+            effective_constraints: None,
+        };
+
+        let lines: Vec<String> =
+            build_enhanced_footer(&summary, "https://fmap/", &ColorPalette::plain())
+                .iter()
+                .map(|l| strip_ansi_to_string(l))
+                .collect();
+        let avg = lines
+            .iter()
+            .find(|l| l.contains("Avg Jump:"))
+            .expect("avg line");
+        let max = lines
+            .iter()
+            .find(|l| l.contains("Max Jump:"))
+            .expect("max line");
+        assert!(avg.trim_end().ends_with("30.0 ly"), "line: {avg}");
+        assert!(max.trim_end().ends_with("42.5 ly"), "line: {max}");
+
+        summary.average_jump_distance = None;
+        summary.max_jump_distance = None;
+        let lines = build_enhanced_footer(&summary, "https://fmap/", &ColorPalette::plain());
+        assert!(!lines
+            .iter()
+            .any(|l| l.contains("Avg Jump:") || l.contains("Max Jump:")));
+    }
+
+    #[test]
+    fn footer_reports_cooldown_stops() {
+        use evefrontier_lib::routing::RouteAlgorithm;
+        use evefrontier_lib::{RouteEndpoint, RouteOutputKind};
+
+        let summary = RouteSummary {
+            kind: RouteOutputKind::Route,
+            algorithm: RouteAlgorithm::AStar,
+            hops: 3,
+            gates: 0,
+            jumps: 3,
+            total_distance: 90.0,
+            jump_distance: 90.0,
+            average_jump_distance: None,
+            max_jump_distance: None,
+            start: RouteEndpoint {
+                id: 1,
+                name: Some("A".to_string()),
+            },
+            goal: RouteEndpoint {
+                id: 2,
+                name: Some("B".to_string()),
+            },
+            steps: Vec::new(),
+            fuel: None,
+            refuel_at: Vec::new(),
+            heat: Some(evefrontier_lib::ship::HeatSummary {
+                total_wait_time_seconds: 120.0,
+                final_residual_heat: 30.0,
+                cooldown_stops: 2,
+                warnings: Vec::new(),
+            }),
+            cooling_stops: Vec::new(),
+            fmap_url: None,
+            parameters: None,
+            effective_constraints: None,
+        };
+
+        let lines = build_enhanced_footer(&summary, "https://fmap/", &ColorPalette::plain());
+        let stops = lines
+            .iter()
+            .map(|l| strip_ansi_to_string(l))
+            .find(|l| l.contains("Cooldown stops:"))
+            .expect("footer should include a cooldown stops line");
+        assert!(stops.trim_end().ends_with('2'), "line: {stops}");
+    }
+
+    #[test]
+    fn footer_reports_total_cooling_pause() {
+        use evefrontier_lib::routing::RouteAlgorithm;
+        use evefrontier_lib::ship::CoolingStop;
+        use evefrontier_lib::{RouteEndpoint, RouteOutputKind};
+
+        let stop = |step_index, wait_seconds| CoolingStop {
+            step_index,
+            wait_seconds,
+            peak_heat: 140.0,
+            residual_heat: 30.0,
+        };
+        let mut summary = RouteSummary {
+            kind: RouteOutputKind::Route,
+            algorithm: RouteAlgorithm::AStar,
+            hops: 3,
+            gates: 0,
+            jumps: 3,
+            total_distance: 90.0,
+            jump_distance: 90.0,
+            average_jump_distance: None,
+            max_jump_distance: None,
+            start: RouteEndpoint {
+                id: 1,
+                name: Some("A".to_string()),
+            },
+            goal: RouteEndpoint {
+                id: 2,
+                name: Some("B".to_string()),
+            },
+            steps: Vec::new(),
+            fuel: None,
+            refuel_at: Vec::new(),
+            heat: Some(evefrontier_lib::ship::HeatSummary {
+                total_wait_time_seconds: 120.4,
+                final_residual_heat: 30.0,
+                cooldown_stops: 2,
+                warnings: Vec::new(),
+            }),
+            cooling_stops: vec![stop(1, 60.0), stop(2, 60.0)],
+            fmap_url: None,
+            parameters: None,
+        };
+
+        let pause_line = |summary: &RouteSummary| {
+            build_enhanced_footer(summary, "https://fmap/", &ColorPalette::plain())
+                .iter()
+                .map(|l| strip_ansi_to_string(l))
+                .find(|l| l.contains("Cooling pause:"))
+        };
+        let line = pause_line(&summary).expect("footer should include a cooling pause line");
+        assert!(
+            line.trim_end().ends_with(&format_cooldown_duration(120.0)),
+            "line: {line}"
+        );
+
+        summary.cooling_stops.clear();
+        assert!(pause_line(&summary).is_none());
+    }
 }
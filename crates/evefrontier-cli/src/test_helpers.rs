@@ -23,6 +23,7 @@ impl RouteStepBuilder {
                 moon_count: None,
                 fuel: None,
                 heat: None,
+                position: None,
             },
         }
     }
@@ -2,6 +2,7 @@
 //!
 //! These tests use `assert_cmd` to verify CLI behavior including:
 //! - index-verify with fresh, stale, missing, and legacy format scenarios
+//! - index-build checksum progress
 //! - JSON output format
 //! - Exit codes
 
@@ -279,3 +280,26 @@ fn test_index_verify_quiet_mode() {
         .success()
         .stdout(predicate::str::is_empty());
 }
+
+// index-build reports checksum progress up to 100%
+#[test]
+fn test_index_build_reports_checksum_progress() {
+    let env = TestEnv::new();
+
+    env.command()
+        .args([
+            "--no-logo",
+            "--data-dir",
+            env.data_dir.to_str().unwrap(),
+            "index-build",
+            "--force",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Computing dataset checksum...   0%",
+        ))
+        .stdout(predicate::str::contains(
+            "Computing dataset checksum... 100%",
+        ));
+}
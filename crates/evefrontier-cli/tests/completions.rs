@@ -0,0 +1,61 @@
+use std::fs;
+use std::path::PathBuf;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use tempfile::tempdir;
+
+fn fixture_db() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../docs/fixtures/minimal/static_data.db")
+        .canonicalize()
+        .expect("fixture dataset present")
+}
+
+#[test]
+fn bash_completions_cover_subcommands_and_flags() {
+    cargo_bin_cmd!("evefrontier-cli")
+        .args(["completions", "bash"])
+        .assert()
+        .success()
+        .stdout(contains("evefrontier-cli"))
+        .stdout(contains("route").and(contains("--algorithm")));
+}
+
+#[test]
+fn systems_export_lists_dataset_system_names() {
+    let temp_dir = tempdir().expect("create temp dir");
+    let cache_dir = temp_dir.path().join("cache");
+    fs::create_dir_all(&cache_dir).expect("create cache dir");
+
+    let output = cargo_bin_cmd!("evefrontier-cli")
+        .env("EVEFRONTIER_DATASET_SOURCE", fixture_db())
+        .env("EVEFRONTIER_DATASET_CACHE_DIR", &cache_dir)
+        .env("RUST_LOG", "error")
+        .arg("--data-dir")
+        .arg(temp_dir.path())
+        .args(["completions", "--systems"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output).unwrap();
+    let names: Vec<&str> = stdout.lines().collect();
+
+    for name in ["Nod", "Brana", "H:2L2S", "E1J-M5G"] {
+        assert!(names.contains(&name), "{name} missing: {stdout}");
+    }
+    let mut sorted = names.clone();
+    sorted.sort_unstable();
+    assert_eq!(names, sorted);
+}
+
+#[test]
+fn completions_require_shell_or_systems() {
+    cargo_bin_cmd!("evefrontier-cli")
+        .arg("completions")
+        .assert()
+        .failure();
+}
@@ -0,0 +1,137 @@
+//! Integration tests for the `fmap-encode` and `fmap-decode` subcommands.
+//!
+//! Tests verify:
+//! - System names resolve through the dataset and round-trip as fixture IDs
+//! - Numeric system IDs are accepted as-is
+//! - Unknown names fail with fuzzy suggestions
+//! - Decoding resolves IDs back to names (`null` for unknown IDs) unless `--no-resolve` is set
+
+use std::fs;
+use std::path::PathBuf;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::tempdir;
+
+const NOD_ID: u64 = 30000191;
+const BRANA_ID: u64 = 30000200;
+
+fn fixture_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../docs/fixtures/minimal/static_data.db")
+        .canonicalize()
+        .expect("fixture dataset present")
+}
+
+fn cli() -> Command {
+    cargo_bin_cmd!("evefrontier-cli")
+}
+
+fn prepare_command() -> (Command, tempfile::TempDir) {
+    let temp_dir = tempdir().expect("create temp dir");
+    let cache_dir = temp_dir.path().join("cache");
+    fs::create_dir_all(&cache_dir).expect("create cache dir");
+    let mut cmd = cli();
+    cmd.env("EVEFRONTIER_DATASET_SOURCE", fixture_path())
+        .env("EVEFRONTIER_DATASET_CACHE_DIR", &cache_dir)
+        .env("RUST_LOG", "error")
+        .arg("--no-logo")
+        .arg("--data-dir")
+        .arg(temp_dir.path());
+    (cmd, temp_dir)
+}
+
+fn run_json(args: &[&str]) -> serde_json::Value {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.args(args).arg("--json");
+    let output = cmd.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    serde_json::from_str(&stdout).expect("valid JSON")
+}
+
+fn decoded_ids(token: &str) -> Vec<u64> {
+    let decoded = run_json(&["fmap-decode", token]);
+    decoded["waypoints"]
+        .as_array()
+        .expect("waypoints array")
+        .iter()
+        .map(|wp| wp["system_id"].as_u64().expect("numeric system_id"))
+        .collect()
+}
+
+#[test]
+fn encode_resolves_names_and_round_trips_ids() {
+    let encoded = run_json(&["fmap-encode", "Nod", "Brana"]);
+    let token = encoded["token"].as_str().expect("token string");
+    assert_eq!(encoded["waypoint_count"], 2);
+
+    assert_eq!(decoded_ids(token), [NOD_ID, BRANA_ID]);
+}
+
+#[test]
+fn encode_accepts_numeric_ids() {
+    let by_id = run_json(&["fmap-encode", "30000191", "30000200"]);
+    let by_name = run_json(&["fmap-encode", "Nod", "Brana"]);
+    assert_eq!(by_id["token"], by_name["token"]);
+}
+
+#[test]
+fn encode_unknown_name_suggests_matches() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("fmap-encode").arg("Nodd").arg("Brana");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown system 'Nodd'"))
+        .stderr(predicate::str::contains("Nod"));
+}
+
+#[test]
+fn decode_resolves_system_names() {
+    let encoded = run_json(&["fmap-encode", "Nod", "Brana"]);
+    let decoded = run_json(&["fmap-decode", encoded["token"].as_str().unwrap()]);
+
+    let names: Vec<&str> = decoded["waypoints"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|wp| wp["name"].as_str().expect("resolved name"))
+        .collect();
+    assert_eq!(names, ["Nod", "Brana"]);
+}
+
+#[test]
+fn decode_reports_unknown_ids_as_null() {
+    // 30000142 is not part of the fixture dataset.
+    let encoded = run_json(&["fmap-encode", "30000191", "30000142"]);
+    let decoded = run_json(&["fmap-decode", encoded["token"].as_str().unwrap()]);
+
+    let waypoints = decoded["waypoints"].as_array().unwrap();
+    assert_eq!(waypoints[0]["name"], "Nod");
+    assert!(waypoints[1]["name"].is_null());
+    assert_eq!(waypoints[1]["system_id"], 30000142);
+}
+
+#[test]
+fn decode_no_resolve_prints_ids_only() {
+    let encoded = run_json(&["fmap-encode", "Nod", "Brana"]);
+    let token = encoded["token"].as_str().unwrap();
+    let decoded = run_json(&["fmap-decode", token, "--no-resolve"]);
+
+    for wp in decoded["waypoints"].as_array().unwrap() {
+        assert!(wp.get("name").is_none(), "{wp}");
+    }
+}
+
+#[test]
+fn decode_text_output_lists_names() {
+    let encoded = run_json(&["fmap-encode", "Nod", "Brana"]);
+    let token = encoded["token"].as_str().unwrap();
+
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("fmap-decode").arg(token);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Nod"))
+        .stdout(predicate::str::contains("Brana"));
+}
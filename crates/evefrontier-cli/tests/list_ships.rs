@@ -3,6 +3,7 @@ use std::path::PathBuf;
 
 use assert_cmd::cargo::cargo_bin_cmd;
 use assert_cmd::Command;
+use predicates::prelude::PredicateBooleanExt;
 use predicates::str::contains;
 use tempfile::tempdir;
 
@@ -77,3 +78,51 @@ fn lists_ships_from_cached_dataset() {
         .stdout(contains("Available ships (3):"))
         .stdout(contains("Reflex"));
 }
+
+#[test]
+fn ship_data_json_merges_over_csv_ships() {
+    let (mut cmd, temp) = prepare_command();
+    let json_path = temp.path().join("custom_ships.json");
+    fs::write(
+        &json_path,
+        r#"[
+            {"name": "Reflex", "base_mass_kg": 10000000, "specific_heat": 0.9,
+             "fuel_capacity": 4321, "cargo_capacity": 800000},
+            {"name": "Prototype", "base_mass_kg": 5000000, "specific_heat": 1.2,
+             "fuel_capacity": 900, "cargo_capacity": 100000}
+        ]"#,
+    )
+    .expect("write ship JSON");
+
+    cmd.arg("--no-footer")
+        .arg("ships")
+        .arg("--ship-data-json")
+        .arg(&json_path);
+
+    cmd.assert()
+        .success()
+        .stdout(contains("Available ships (4):"))
+        .stdout(contains("Prototype"))
+        .stdout(contains("Forager"))
+        .stdout(contains("4321"))
+        .stdout(contains("1750").not());
+}
+
+#[test]
+fn ship_data_json_reports_offending_ship() {
+    let (mut cmd, temp) = prepare_command();
+    let json_path = temp.path().join("custom_ships.json");
+    fs::write(
+        &json_path,
+        r#"[{"name": "Prototype", "base_mass_kg": 5000000, "specific_heat": 1.2,
+             "fuel_capacity": "lots", "cargo_capacity": 100000}]"#,
+    )
+    .expect("write ship JSON");
+
+    cmd.arg("ships").arg("--ship-data-json").arg(&json_path);
+
+    cmd.assert()
+        .failure()
+        .stderr(contains("fuel_capacity"))
+        .stderr(contains("Prototype"));
+}
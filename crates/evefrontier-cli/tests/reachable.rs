@@ -0,0 +1,105 @@
+//! Integration tests for the `reachable` subcommand.
+//!
+//! Tests verify:
+//! - JSON output lists systems within the distance budget, cheapest first
+//! - `--limit` caps the number of results
+//! - A budget flag is required and `--max-fuel` needs a ship
+//! - Unknown systems return fuzzy suggestions
+
+use std::fs;
+use std::path::PathBuf;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::tempdir;
+
+fn fixture_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../docs/fixtures/minimal/static_data.db")
+        .canonicalize()
+        .expect("fixture dataset present")
+}
+
+fn prepare_command() -> (Command, tempfile::TempDir) {
+    let temp_dir = tempdir().expect("create temp dir");
+    let cache_dir = temp_dir.path().join("cache");
+    fs::create_dir_all(&cache_dir).expect("create cache dir");
+    let mut cmd = cargo_bin_cmd!("evefrontier-cli");
+    cmd.env("EVEFRONTIER_DATASET_SOURCE", fixture_path())
+        .env("EVEFRONTIER_DATASET_CACHE_DIR", &cache_dir)
+        .env("RUST_LOG", "error")
+        .arg("--no-logo")
+        .arg("--data-dir")
+        .arg(temp_dir.path());
+    (cmd, temp_dir)
+}
+
+fn reachable_json(extra_args: &[&str]) -> serde_json::Value {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("--format").arg("json").arg("reachable").arg("Nod");
+    cmd.args(extra_args);
+
+    let assert = cmd.assert().success();
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout).to_string();
+    serde_json::from_str(&stdout).expect("valid JSON output")
+}
+
+#[test]
+fn test_reachable_json_respects_distance_budget() {
+    let json = reachable_json(&["--max-distance", "250"]);
+
+    assert_eq!(json["system"], "Nod");
+    assert_eq!(json["budget"]["unit"], "ly");
+    let systems = json["systems"].as_array().expect("systems array");
+    assert!(!systems.is_empty());
+    assert_eq!(json["count"].as_u64(), Some(systems.len() as u64));
+
+    let costs: Vec<f64> = systems
+        .iter()
+        .map(|s| s["cost"].as_f64().expect("cost"))
+        .collect();
+    assert!(costs.iter().all(|&c| c <= 250.0));
+    assert!(costs.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert!(systems.iter().all(|s| s["name"] != "Nod"));
+}
+
+#[test]
+fn test_reachable_limit_caps_results() {
+    let json = reachable_json(&["--max-distance", "5000", "--limit", "2"]);
+    assert_eq!(json["count"], 2);
+}
+
+#[test]
+fn test_reachable_requires_budget() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("reachable").arg("Nod");
+
+    cmd.assert().failure().stderr(
+        predicate::str::contains("--max-distance").or(predicate::str::contains("--max-fuel")),
+    );
+}
+
+#[test]
+fn test_reachable_fuel_budget_requires_ship() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("reachable").arg("Nod").arg("--max-fuel").arg("100");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--ship is required"));
+}
+
+#[test]
+fn test_reachable_unknown_system_suggests_matches() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("reachable")
+        .arg("Nodd")
+        .arg("--max-distance")
+        .arg("100");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown system 'Nodd'"))
+        .stderr(predicate::str::contains("Did you mean"));
+}
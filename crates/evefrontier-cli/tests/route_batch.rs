@@ -0,0 +1,108 @@
+//! Integration tests for `route --batch`.
+//!
+//! Tests verify:
+//! - CSV and JSON batch files plan every pair against one loaded dataset
+//! - JSON output is a top-level array with one entry per pair
+//! - An unreachable pair is reported inline without aborting the batch
+
+use std::fs;
+use std::path::PathBuf;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::tempdir;
+
+fn fixture_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../docs/fixtures/minimal/static_data.db")
+        .canonicalize()
+        .expect("fixture dataset present")
+}
+
+fn cli() -> Command {
+    cargo_bin_cmd!("evefrontier-cli")
+}
+
+fn prepare_command() -> (Command, tempfile::TempDir) {
+    let temp_dir = tempdir().expect("create temp dir");
+    let cache_dir = temp_dir.path().join("cache");
+    fs::create_dir_all(&cache_dir).expect("create cache dir");
+    let mut cmd = cli();
+    cmd.env("EVEFRONTIER_DATASET_SOURCE", fixture_path())
+        .env("EVEFRONTIER_DATASET_CACHE_DIR", &cache_dir)
+        .env("RUST_LOG", "error")
+        .arg("--no-logo")
+        .arg("--data-dir")
+        .arg(temp_dir.path());
+    (cmd, temp_dir)
+}
+
+/// Gate-only (BFS) pairs; E1J-M5G has no gates, so the last pair is unreachable.
+const BATCH_CSV: &str = "from,to\nNod,Brana\nJ:35IA,D:2NAS\nNod,E1J-M5G\n";
+
+fn run_batch(file_name: &str, contents: &str, format: &str) -> std::process::Output {
+    let (mut cmd, temp) = prepare_command();
+    let batch_path = temp.path().join(file_name);
+    fs::write(&batch_path, contents).expect("write batch file");
+    cmd.arg("--format")
+        .arg(format)
+        .arg("route")
+        .arg("--batch")
+        .arg(&batch_path)
+        .arg("--algorithm")
+        .arg("bfs");
+    cmd.assert().success().get_output().clone()
+}
+
+#[test]
+fn json_batch_reports_each_pair_and_inline_errors() {
+    let output = run_batch("routes.csv", BATCH_CSV, "json");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let entries: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    let entries = entries.as_array().expect("top-level array");
+    assert_eq!(entries.len(), 3);
+
+    assert_eq!(entries[0]["from"], "Nod");
+    assert_eq!(entries[0]["route"]["goal"]["name"], "Brana");
+    assert!(entries[0].get("error").is_none());
+    assert_eq!(entries[1]["route"]["goal"]["name"], "D:2NAS");
+
+    assert_eq!(entries[2]["to"], "E1J-M5G");
+    assert!(entries[2].get("route").is_none());
+    let error = entries[2]["error"].as_str().expect("error message");
+    assert!(error.contains("No route found"), "{error}");
+}
+
+#[test]
+fn json_batch_file_and_text_output() {
+    let batch = r#"[
+        {"from": "Nod", "to": "Brana"},
+        {"from": "J:35IA", "to": "D:2NAS"},
+        {"from": "Nod", "to": "E1J-M5G"}
+    ]"#;
+    let output = run_batch("routes.json", batch, "text");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    // Skip the "Completed in" footer.
+    let lines: Vec<&str> = stdout.lines().filter(|l| l.contains(" -> ")).collect();
+
+    assert_eq!(lines.len(), 3, "{stdout}");
+    assert!(lines[0].starts_with("Nod -> Brana: "), "{stdout}");
+    assert!(lines[1].starts_with("J:35IA -> D:2NAS: "), "{stdout}");
+    assert!(lines[2].starts_with("Nod -> E1J-M5G: error: "), "{stdout}");
+}
+
+#[test]
+fn batch_conflicts_with_explicit_endpoints() {
+    let (mut cmd, temp) = prepare_command();
+    let batch_path = temp.path().join("routes.csv");
+    fs::write(&batch_path, BATCH_CSV).expect("write batch file");
+    cmd.arg("route")
+        .arg("--batch")
+        .arg(&batch_path)
+        .arg("--from")
+        .arg("Nod");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
@@ -66,6 +66,23 @@ fn basic_format_outputs_minimal_path() {
         .stdout(predicate::str::contains("- Brana"));
 }
 
+#[test]
+fn numeric_system_ids_are_accepted_as_endpoints() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("--format")
+        .arg("basic")
+        .arg("route")
+        .arg("--from")
+        .arg("30000191") // Nod
+        .arg("--to")
+        .arg("Brana");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("+ Nod"))
+        .stdout(predicate::str::contains("- Brana"));
+}
+
 #[test]
 fn unknown_system_error_is_friendly() {
     let (mut cmd, _temp) = prepare_command();
@@ -128,6 +145,231 @@ fn json_format_outputs_valid_json() {
     let _: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
 }
 
+#[test]
+fn include_coordinates_adds_step_positions() {
+    let run = |include: bool| {
+        let (mut cmd, _temp) = prepare_command();
+        cmd.arg("--format").arg("json");
+        if include {
+            cmd.arg("--include-coordinates");
+        }
+        cmd.arg("route")
+            .arg("--from")
+            .arg("Nod")
+            .arg("--to")
+            .arg("Brana");
+        let output = cmd.assert().success();
+        let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+        serde_json::from_str::<serde_json::Value>(&stdout).expect("valid JSON")
+    };
+
+    let with = run(true);
+    let steps = with["steps"].as_array().expect("steps array");
+    assert!(!steps.is_empty());
+    for step in steps {
+        for axis in ["x", "y", "z"] {
+            assert!(step["position"][axis].is_f64(), "missing {axis}: {step}");
+        }
+    }
+
+    let without = run(false);
+    let steps = without["steps"].as_array().expect("steps array");
+    assert!(steps.iter().all(|step| step.get("position").is_none()));
+}
+
+#[test]
+fn round_trip_route_ends_at_start() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("--format")
+        .arg("json")
+        .arg("route")
+        .arg("--from")
+        .arg("Nod")
+        .arg("--to")
+        .arg("Brana")
+        .arg("--round-trip");
+    let output = cmd.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+
+    let steps = json["steps"].as_array().expect("steps array");
+    let first = steps.first().expect("first step");
+    let last = steps.last().expect("last step");
+    assert_eq!(first["name"], "Nod");
+    assert_eq!(first["id"], last["id"]);
+    assert!(steps.iter().any(|step| step["name"] == "Brana"));
+    assert_eq!(json["hops"].as_u64(), Some(steps.len() as u64 - 1));
+}
+
+fn route_json_with(extra_args: &[&str]) -> serde_json::Value {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("--format")
+        .arg("json")
+        .arg("route")
+        .arg("--from")
+        .arg("Nod")
+        .arg("--to")
+        .arg("Brana")
+        .args(extra_args);
+    let output = cmd.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    serde_json::from_str(&stdout).expect("valid JSON")
+}
+
+#[test]
+fn fast_preset_selects_a_star() {
+    let json = route_json_with(&["--preset", "fast"]);
+    assert_eq!(json["algorithm"], "a-star");
+}
+
+#[test]
+fn explicit_algorithm_overrides_preset() {
+    let json = route_json_with(&["--preset", "fast", "--algorithm", "bfs"]);
+    assert_eq!(json["algorithm"], "bfs");
+}
+
+#[test]
+fn avoid_temp_range_blocks_systems_inside_the_band() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("route")
+        .arg("--from")
+        .arg("Nod")
+        .arg("--to")
+        .arg("Y:3R7E")
+        .arg("--avoid-gates")
+        .arg("--avoid-temp-range")
+        .arg("4000:5000");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--avoid-temp-range"));
+
+    let json = route_json_with(&["--avoid-gates", "--avoid-temp-range", "4000:5000"]);
+    assert_eq!(
+        json["steps"].as_array().unwrap().last().unwrap()["name"],
+        "Brana"
+    );
+}
+
+#[test]
+fn avoid_temp_range_rejects_reversed_bounds() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("route")
+        .arg("--from")
+        .arg("Nod")
+        .arg("--to")
+        .arg("Brana")
+        .arg("--avoid-temp-range")
+        .arg("5000:4000");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("greater than maximum"));
+}
+
+#[test]
+fn jump_penalty_prefers_gate_route() {
+    let json = route_json_with(&["--jump-penalty", "10"]);
+    assert_eq!(json["gates"], 3);
+    assert_eq!(json["jumps"], 0);
+
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("route")
+        .arg("--from")
+        .arg("Nod")
+        .arg("--to")
+        .arg("Brana")
+        .arg("--gate-penalty")
+        .arg("0");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("greater than 0"));
+}
+
+#[test]
+fn debug_graph_prints_graph_stats_to_stderr() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("route")
+        .arg("--from")
+        .arg("Nod")
+        .arg("--to")
+        .arg("Brana")
+        .arg("--algorithm")
+        .arg("bfs")
+        .arg("--debug-graph");
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("[debug-graph] Gate graph"))
+        .stderr(predicate::str::contains("goal has 2 usable in-edges"));
+}
+
+fn run_request_file(contents: &str) -> assert_cmd::assert::Assert {
+    let (mut cmd, temp) = prepare_command();
+    let request_path = temp.path().join("request.json");
+    fs::write(&request_path, contents).expect("write request file");
+    cmd.arg("--format")
+        .arg("json")
+        .arg("route")
+        .arg("--request-file")
+        .arg(&request_path);
+    cmd.assert()
+}
+
+#[test]
+fn request_file_plans_route() {
+    let output = run_request_file(
+        r#"{"from": "Nod", "to": "Brana", "algorithm": "bfs", "avoid_gates": false}"#,
+    )
+    .success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+
+    assert_eq!(json["start"]["name"], "Nod");
+    assert_eq!(json["goal"]["name"], "Brana");
+    assert_eq!(json["algorithm"], "bfs");
+}
+
+#[test]
+fn request_file_missing_from_reports_field() {
+    run_request_file(r#"{"to": "Brana"}"#)
+        .failure()
+        .stderr(predicate::str::contains("from: missing required field"));
+}
+
+#[test]
+fn request_file_bad_algorithm_reports_field() {
+    run_request_file(r#"{"from": "Nod", "to": "Brana", "algorithm": "fastest"}"#)
+        .failure()
+        .stderr(predicate::str::contains(
+            "algorithm: unknown algorithm `fastest`; expected one of bfs, dijkstra, a-star",
+        ));
+}
+
+#[test]
+fn json_compact_outputs_single_line_with_same_value() {
+    let run = |compact: bool| {
+        let (mut cmd, _temp) = prepare_command();
+        cmd.arg("--format").arg("json");
+        if compact {
+            cmd.arg("--json-compact");
+        }
+        cmd.arg("route")
+            .arg("--from")
+            .arg("Nod")
+            .arg("--to")
+            .arg("Brana");
+        let output = cmd.assert().success();
+        String::from_utf8(output.get_output().stdout.clone()).unwrap()
+    };
+
+    let pretty = run(false);
+    let compact = run(true);
+
+    assert_eq!(compact.trim_end().lines().count(), 1);
+    assert!(pretty.trim_end().lines().count() > 1);
+    let compact_value: serde_json::Value = serde_json::from_str(&compact).expect("valid JSON");
+    let pretty_value: serde_json::Value = serde_json::from_str(&pretty).expect("valid JSON");
+    assert_eq!(compact_value, pretty_value);
+}
+
 #[test]
 fn ingame_format_for_copy_paste() {
     let (mut cmd, _temp) = prepare_command();
@@ -236,6 +478,38 @@ fn multiple_avoid_systems_work() {
     cmd.assert().success();
 }
 
+#[test]
+fn avoid_region_excludes_its_systems() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("route")
+        .arg("--from")
+        .arg("Nod")
+        .arg("--to")
+        .arg("E1J-M5G")
+        .arg("--avoid-region")
+        .arg("451-Y-F1");
+
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "No route found between Nod and E1J-M5G.",
+    ));
+}
+
+#[test]
+fn unknown_avoid_region_is_rejected() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("route")
+        .arg("--from")
+        .arg("Nod")
+        .arg("--to")
+        .arg("Brana")
+        .arg("--avoid-region")
+        .arg("Nowhere");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown region: Nowhere"));
+}
+
 #[test]
 fn invalid_algorithm_shows_error() {
     let (mut cmd, _temp) = prepare_command();
@@ -304,3 +578,177 @@ fn missing_ship_data_with_default_ship_proceeds() {
 
     cmd.assert().success();
 }
+
+fn ship_data_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../docs/fixtures/ship_data.csv")
+        .canonicalize()
+        .expect("fixture ship data present")
+}
+
+#[test]
+fn saved_route_renders_identically_with_route_show() {
+    let saved = tempdir().expect("create temp dir");
+    let route_file = saved.path().join("route.json");
+
+    for (idx, format) in ["text", "enhanced", "json", "note"].iter().enumerate() {
+        let (mut cmd, _temp) = prepare_command();
+        cmd.env("EVEFRONTIER_SHIP_DATA", ship_data_path())
+            .args(["--no-footer", "--format", format, "route"])
+            .args(["--from", "Nod", "--to", "Brana", "--ship", "Reflex"]);
+        // Save once; later formats reuse the same file to prove it is format-agnostic.
+        if idx == 0 {
+            cmd.arg("--save").arg(&route_file);
+        }
+        let computed = cmd.assert().success().get_output().stdout.clone();
+
+        let shown = cli()
+            .args(["--no-logo", "--no-footer", "--format", format, "route-show"])
+            .arg(&route_file)
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone();
+
+        assert_eq!(
+            String::from_utf8_lossy(&shown),
+            String::from_utf8_lossy(&computed),
+            "route-show output differs for format {format}"
+        );
+    }
+}
+
+#[test]
+fn route_show_rejects_invalid_files() {
+    let dir = tempdir().expect("create temp dir");
+    let bogus = dir.path().join("bogus.json");
+    fs::write(&bogus, "{\"not\": \"a route\"}").expect("write bogus file");
+
+    cli()
+        .args(["--no-logo", "route-show"])
+        .arg(&bogus)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is not a saved route summary"));
+}
+
+#[test]
+fn map_format_plots_each_route_system() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("--no-footer")
+        .arg("--format")
+        .arg("map")
+        .arg("route")
+        .arg("--from")
+        .arg("Nod")
+        .arg("--to")
+        .arg("Brana")
+        .arg("--algorithm")
+        .arg("bfs");
+
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let stdout = String::from_utf8(output).unwrap();
+    assert!(stdout.starts_with("Route map: Nod -> Brana"), "{stdout}");
+
+    // BFS over gates: Nod -> H:2L2S -> Y:3R7E -> Brana.
+    let grid: String = stdout
+        .lines()
+        .filter(|l| l.starts_with('|'))
+        .collect::<Vec<_>>()
+        .join("\n");
+    for (initial, name) in [
+        ('N', "Nod"),
+        ('H', "H:2L2S"),
+        ('Y', "Y:3R7E"),
+        ('B', "Brana"),
+    ] {
+        assert!(
+            grid.contains(initial),
+            "{initial} missing from map:\n{stdout}"
+        );
+        assert!(
+            stdout.contains(&format!("  {initial}  {name}")),
+            "{name} missing from legend:\n{stdout}"
+        );
+    }
+}
+
+#[test]
+fn csv_format_prints_header_and_one_row_per_step() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("--format")
+        .arg("csv")
+        .arg("route")
+        .arg("--from")
+        .arg("Nod")
+        .arg("--to")
+        .arg("Brana")
+        .arg("--algorithm")
+        .arg("bfs");
+
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let stdout = String::from_utf8(output).unwrap();
+    let rows: Vec<Vec<&str>> = stdout.lines().map(|l| l.split(',').collect()).collect();
+
+    assert_eq!(
+        rows[0],
+        [
+            "index",
+            "system_id",
+            "name",
+            "method",
+            "distance_ly",
+            "min_temp_k",
+            "hop_fuel",
+            "hop_heat"
+        ]
+    );
+    // BFS over gates: Nod -> H:2L2S -> Y:3R7E -> Brana.
+    let names: Vec<&str> = rows[1..].iter().map(|row| row[2]).collect();
+    assert_eq!(names, ["Nod", "H:2L2S", "Y:3R7E", "Brana"], "{stdout}");
+    assert_eq!(rows[1][0], "0");
+    assert_eq!(rows[2][3], "gate");
+}
+
+#[test]
+fn geojson_format_emits_route_line_and_system_points() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("--format")
+        .arg("geojson")
+        .arg("route")
+        .arg("--from")
+        .arg("Nod")
+        .arg("--to")
+        .arg("Brana")
+        .arg("--algorithm")
+        .arg("bfs");
+
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let geojson: serde_json::Value = serde_json::from_slice(&output).expect("valid GeoJSON");
+
+    assert_eq!(geojson["type"], "FeatureCollection");
+    let features = geojson["features"].as_array().expect("features array");
+    let kinds: Vec<&str> = features
+        .iter()
+        .map(|f| f["geometry"]["type"].as_str().unwrap())
+        .collect();
+    // BFS over gates: Nod -> H:2L2S -> Y:3R7E -> Brana.
+    assert_eq!(kinds, ["LineString", "Point", "Point", "Point", "Point"]);
+    assert_eq!(
+        features[0]["geometry"]["coordinates"]
+            .as_array()
+            .unwrap()
+            .len(),
+        4
+    );
+    assert_eq!(features[1]["properties"]["name"], "Nod");
+    assert_eq!(features[4]["properties"]["name"], "Brana");
+    assert_eq!(
+        features[1]["geometry"]["coordinates"]
+            .as_array()
+            .unwrap()
+            .len(),
+        3
+    );
+}
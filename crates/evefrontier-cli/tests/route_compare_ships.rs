@@ -0,0 +1,142 @@
+//! Integration tests for `route --compare-ships`.
+//!
+//! Tests verify:
+//! - JSON output is an array with one entry per ship, in the requested order
+//! - Each ship's projection matches a single-ship `route --ship` run over the same route
+//! - Text output prints a comparison table, and unknown ships fail before planning
+
+use std::fs;
+use std::path::PathBuf;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use serde_json::Value;
+use tempfile::tempdir;
+
+fn fixture_db() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../docs/fixtures/minimal/static_data.db")
+        .canonicalize()
+        .expect("fixture dataset present")
+}
+
+fn fixture_ship() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../docs/fixtures/ship_data.csv")
+        .canonicalize()
+        .expect("ship data fixture present")
+}
+
+fn prepare_command() -> (Command, tempfile::TempDir) {
+    let temp_dir = tempdir().expect("create temp dir");
+    let cache_dir = temp_dir.path().join("cache");
+    fs::create_dir_all(&cache_dir).expect("create cache dir");
+    let mut cmd = cargo_bin_cmd!("evefrontier-cli");
+    cmd.env("EVEFRONTIER_DATASET_SOURCE", fixture_db())
+        .env("EVEFRONTIER_DATASET_CACHE_DIR", &cache_dir)
+        .env("EVEFRONTIER_SHIP_DATA", fixture_ship())
+        .env("RUST_LOG", "error")
+        .arg("--no-logo")
+        .arg("--data-dir")
+        .arg(temp_dir.path());
+    (cmd, temp_dir)
+}
+
+/// Nod -> J:35IA with gates avoided is a single spatial jump, so every ship burns fuel.
+fn route_json(extra_args: &[&str]) -> Value {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("--format")
+        .arg("json")
+        .arg("route")
+        .arg("--from")
+        .arg("Nod")
+        .arg("--to")
+        .arg("J:35IA")
+        .arg("--avoid-gates")
+        .arg("--no-avoid-critical-state")
+        .args(extra_args);
+    let output = cmd.assert().success().get_output().stdout.clone();
+    serde_json::from_slice(&output).expect("valid JSON")
+}
+
+#[test]
+fn json_comparison_lists_ships_in_requested_order() {
+    let value = route_json(&["--compare-ships", "Reflex,Forager"]);
+    let entries = value.as_array().expect("top-level array");
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0]["ship"], "Reflex");
+    assert_eq!(entries[1]["ship"], "Forager");
+    for entry in entries {
+        assert!(entry["total_fuel"].as_f64().unwrap() > 0.0, "{entry}");
+        assert!(entry["final_heat"].as_f64().is_some(), "{entry}");
+        assert!(entry["total_wait_seconds"].as_f64().is_some(), "{entry}");
+    }
+}
+
+#[test]
+fn comparison_matches_single_ship_projection() {
+    let comparison = route_json(&["--compare-ships", "Reflex"]);
+    let single = route_json(&["--ship", "Reflex"]);
+
+    let entry = &comparison[0];
+    assert_eq!(entry["total_fuel"], single["fuel"]["total"]);
+    assert_eq!(entry["remaining_fuel"], single["fuel"]["remaining"]);
+    assert_eq!(entry["final_heat"], single["heat"]["final_residual_heat"]);
+}
+
+#[test]
+fn text_comparison_prints_table() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("--no-footer")
+        .arg("route")
+        .arg("--from")
+        .arg("Nod")
+        .arg("--to")
+        .arg("J:35IA")
+        .arg("--avoid-gates")
+        .arg("--no-avoid-critical-state")
+        .arg("--compare-ships")
+        .arg("Reflex,Warden");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with("Nod -> J:35IA: 1 hops"))
+        .stdout(predicate::str::contains("Fuel Used"))
+        .stdout(predicate::str::contains("Reflex"))
+        .stdout(predicate::str::contains("Warden"));
+}
+
+#[test]
+fn unknown_ship_is_rejected() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("route")
+        .arg("--from")
+        .arg("Nod")
+        .arg("--to")
+        .arg("Brana")
+        .arg("--compare-ships")
+        .arg("Reflex,Nonexistent");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("ship Nonexistent not found"));
+}
+
+#[test]
+fn compare_ships_conflicts_with_ship() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("route")
+        .arg("--from")
+        .arg("Nod")
+        .arg("--to")
+        .arg("Brana")
+        .arg("--ship")
+        .arg("Reflex")
+        .arg("--compare-ships")
+        .arg("Forager");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
@@ -340,3 +340,131 @@ fn cli_default_calibration_produces_heat() {
         }
     }
 }
+
+#[test]
+fn fuel_reserve_flags_reserve_warning_and_exhaustion() {
+    let (mut cmd, _temp) = prepare_command();
+    // Nod -> J:35IA is a single ~119 ly jump costing ~1190 units for a full Reflex, which
+    // completes on raw fuel (1750) but leaves less than the 1000-unit reserve.
+    cmd.arg("--format")
+        .arg("json")
+        .arg("route")
+        .arg("--from")
+        .arg("Nod")
+        .arg("--to")
+        .arg("J:35IA")
+        .arg("--avoid-gates")
+        .arg("--ship")
+        .arg("Reflex")
+        .arg("--fuel-load")
+        .arg("1750")
+        .arg("--fuel-reserve")
+        .arg("1000")
+        .arg("--no-avoid-critical-state");
+
+    let output = cmd.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    let value: Value = serde_json::from_str(&stdout).expect("valid JSON");
+
+    let steps = value["steps"].as_array().expect("steps array");
+    let last = steps.last().expect("goal step");
+    assert_eq!(last["fuel"]["warning"], "RESERVE");
+    assert!(last["fuel"]["remaining"].as_f64().unwrap() > 0.0);
+    assert_eq!(value["fuel"]["fuel_exhausted"], true);
+}
+
+#[test]
+fn fuel_reserve_rejects_negative_values() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("route")
+        .arg("--from")
+        .arg("Nod")
+        .arg("--to")
+        .arg("Brana")
+        .arg("--fuel-reserve=-5");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("non-negative"));
+}
+
+#[test]
+fn optimize_flag_maps_to_request_optimization() {
+    // Nod and Brana are joined by a gate chain through H:2L2S and Y:3R7E as well as a direct
+    // jump. Without heat limits the jump is shortest, while the gates burn no fuel.
+    let route = |optimize: &str| {
+        let (mut cmd, _temp) = prepare_command();
+        cmd.arg("--format")
+            .arg("json")
+            .arg("route")
+            .arg("--from")
+            .arg("Nod")
+            .arg("--to")
+            .arg("Brana")
+            .arg("--ship")
+            .arg("Reflex")
+            .arg("--no-avoid-critical-state")
+            .arg("--optimize")
+            .arg(optimize);
+        let output = cmd.assert().success().get_output().stdout.clone();
+        serde_json::from_slice::<Value>(&output).expect("valid json")
+    };
+    let step_names = |route: &Value| -> Vec<String> {
+        route["steps"]
+            .as_array()
+            .expect("steps")
+            .iter()
+            .map(|step| step["name"].as_str().expect("step name").to_string())
+            .collect()
+    };
+
+    let distance = route("distance");
+    let fuel = route("fuel");
+
+    assert_eq!(distance["parameters"]["optimization"], "distance");
+    assert_eq!(fuel["parameters"]["optimization"], "fuel");
+    assert_ne!(step_names(&distance), step_names(&fuel));
+
+    let distance_fuel = distance["fuel"]["total"].as_f64().expect("distance fuel");
+    let fuel_fuel = fuel["fuel"]["total"].as_f64().expect("fuel-optimized fuel");
+    assert!(
+        fuel_fuel < distance_fuel,
+        "fuel route {fuel_fuel} should burn less than distance route {distance_fuel}"
+    );
+}
+
+#[test]
+fn optimize_fuel_requires_ship() {
+    for ship_args in [&[][..], &["--ship", "None"][..]] {
+        let (mut cmd, _temp) = prepare_command();
+        cmd.arg("route")
+            .arg("--from")
+            .arg("Nod")
+            .arg("--to")
+            .arg("Brana")
+            .arg("--optimize")
+            .arg("fuel")
+            .args(ship_args);
+
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "--ship is required for --optimize fuel",
+        ));
+    }
+}
+
+#[test]
+fn cheap_preset_requires_ship() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("route")
+        .arg("--from")
+        .arg("Nod")
+        .arg("--to")
+        .arg("Brana")
+        .arg("--preset")
+        .arg("cheap");
+
+    cmd.assert().failure().stderr(
+        predicate::str::contains("--ship is required")
+            .and(predicate::str::contains("--preset cheap")),
+    );
+}
@@ -3,6 +3,8 @@
 //! Tests verify:
 //! - Basic output format shows gate neighbors
 //! - JSON output produces valid, parseable JSON
+//! - `--include-coordinates` adds neighbor positions
+//! - `--depth` widens the search to multi-hop neighbors (1-3)
 //! - Unknown system returns error with fuzzy suggestions
 //! - System with no gate neighbors returns empty result
 
@@ -108,6 +110,78 @@ fn test_scout_gates_json_output() {
     assert!(first_neighbor["id"].as_u64().is_some());
 }
 
+#[test]
+fn test_scout_gates_depth_expands_neighbors() {
+    let run = |depth: &str| {
+        let (mut cmd, _temp) = prepare_command();
+        cmd.arg("--format")
+            .arg("json")
+            .arg("scout")
+            .arg("gates")
+            .arg("Nod")
+            .arg("--depth")
+            .arg(depth);
+        let assert = cmd.assert().success();
+        let stdout = String::from_utf8_lossy(&assert.get_output().stdout).to_string();
+        serde_json::from_str::<serde_json::Value>(&stdout).expect("valid JSON output")
+    };
+
+    let direct = run("1");
+    let wider = run("2");
+    assert_eq!(direct["depth"], 1);
+    assert_eq!(wider["depth"], 2);
+    assert!(wider["count"].as_u64() >= direct["count"].as_u64());
+
+    let direct_neighbors = direct["neighbors"].as_array().expect("neighbors array");
+    assert!(direct_neighbors.iter().all(|n| n["hops"] == 1));
+
+    let hops: Vec<u64> = wider["neighbors"]
+        .as_array()
+        .expect("neighbors array")
+        .iter()
+        .map(|n| n["hops"].as_u64().expect("hops"))
+        .collect();
+    assert!(hops.iter().all(|&h| (1..=2).contains(&h)));
+    assert!(hops.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn test_scout_gates_rejects_out_of_range_depth() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("scout")
+        .arg("gates")
+        .arg("Nod")
+        .arg("--depth")
+        .arg("4");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("depth must be between 1 and 3"));
+}
+
+#[test]
+fn test_scout_gates_include_coordinates() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("--format")
+        .arg("json")
+        .arg("--include-coordinates")
+        .arg("scout")
+        .arg("gates")
+        .arg("Nod");
+
+    let assert = cmd.assert().success();
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout);
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON output");
+
+    let neighbors = json["neighbors"].as_array().expect("neighbors array");
+    assert!(!neighbors.is_empty());
+    for neighbor in neighbors {
+        assert!(neighbor["position"]["x"].is_f64());
+        assert!(neighbor["position"]["y"].is_f64());
+        assert!(neighbor["position"]["z"].is_f64());
+    }
+}
+
 #[test]
 fn test_scout_gates_unknown_system_suggests_matches() {
     let (mut cmd, _temp) = prepare_command();
@@ -559,3 +559,63 @@ fn test_scout_range_no_avoid_critical_state() {
         "--no-avoid-critical-state should override --avoid-critical-state"
     );
 }
+
+fn scout_range_json(extra_args: &[&str]) -> serde_json::Value {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("--format")
+        .arg("json")
+        .arg("scout")
+        .arg("range")
+        .arg("Nod")
+        .arg("--radius")
+        .arg("300")
+        .arg("--ship")
+        .arg("none")
+        .arg("--no-avoid-critical-state")
+        .args(extra_args);
+
+    let assert = cmd.assert().success();
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout).to_string();
+    serde_json::from_str(&stdout).expect("valid JSON output")
+}
+
+fn system_names(json: &serde_json::Value) -> Vec<String> {
+    json["systems"]
+        .as_array()
+        .expect("systems array")
+        .iter()
+        .map(|s| s["name"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn test_scout_range_score_sorts_by_suitability() {
+    let by_distance = scout_range_json(&[]);
+    let scored = scout_range_json(&["--score"]);
+
+    // Without --score, results are in distance order and carry no score.
+    let systems = by_distance["systems"].as_array().unwrap();
+    assert!(systems.iter().all(|s| s.get("score").is_none()));
+
+    let scores: Vec<f64> = scored["systems"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| s["score"].as_f64().expect("score present with --score"))
+        .collect();
+    assert!(
+        scores.windows(2).all(|pair| pair[0] >= pair[1]),
+        "systems should be sorted by score: {scores:?}"
+    );
+
+    // Same systems, different order: H:2L2S (5 planets, 17 moons) outranks the nearer G:3OA0.
+    let mut distance_names = system_names(&by_distance);
+    let scored_names = system_names(&scored);
+    assert_ne!(scored_names, distance_names);
+    let rank = |name: &str| scored_names.iter().position(|n| n == name).unwrap();
+    assert!(rank("H:2L2S") < rank("G:3OA0"), "{scored_names:?}");
+    let mut sorted_scored = scored_names.clone();
+    sorted_scored.sort();
+    distance_names.sort();
+    assert_eq!(sorted_scored, distance_names);
+}
@@ -6,6 +6,7 @@
 //! - Cumulative fuel tracking is correct
 //! - Heat tracking is correct
 //! - Warnings display when thresholds exceeded
+//! - Enhanced output ends with a fuel footer only when a ship is supplied
 
 use std::fs;
 use std::path::PathBuf;
@@ -540,6 +541,38 @@ fn test_scout_range_enhanced_format_with_ship() {
     );
 }
 
+#[test]
+fn test_scout_range_enhanced_footer_with_ship() {
+    let run = |ship: &str| {
+        let (mut cmd, _temp) = prepare_command();
+        cmd.arg("--format")
+            .arg("enhanced")
+            .arg("scout")
+            .arg("range")
+            .arg("Nod")
+            .arg("--limit")
+            .arg("5")
+            .arg("--ship")
+            .arg(ship);
+        let assert = cmd.assert().success();
+        String::from_utf8_lossy(&assert.get_output().stdout).to_string()
+    };
+
+    let with_ship = run("Reflex");
+    for label in ["Total Distance:", "Fuel (Reflex):", "Remaining:"] {
+        assert!(
+            with_ship.contains(label),
+            "footer should include {label}: {with_ship}"
+        );
+    }
+
+    let without_ship = run("None");
+    assert!(
+        !without_ship.contains("Fuel (") && !without_ship.contains("Remaining:"),
+        "no fuel footer without a ship: {without_ship}"
+    );
+}
+
 // =============================================================================
 // T031: Test JSON format includes all fuel/heat fields per contract
 // =============================================================================
@@ -0,0 +1,94 @@
+//! Integration tests for the `system-info` subcommand.
+//!
+//! Tests verify:
+//! - JSON output carries the system's metadata and name-ordered gate neighbors
+//! - Numeric system IDs are accepted
+//! - Unknown names fail with fuzzy suggestions
+
+use std::fs;
+use std::path::PathBuf;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::tempdir;
+
+fn fixture_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../docs/fixtures/minimal/static_data.db")
+        .canonicalize()
+        .expect("fixture dataset present")
+}
+
+fn cli() -> Command {
+    cargo_bin_cmd!("evefrontier-cli")
+}
+
+fn prepare_command() -> (Command, tempfile::TempDir) {
+    let temp_dir = tempdir().expect("create temp dir");
+    let cache_dir = temp_dir.path().join("cache");
+    fs::create_dir_all(&cache_dir).expect("create cache dir");
+    let mut cmd = cli();
+    cmd.env("EVEFRONTIER_DATASET_SOURCE", fixture_path())
+        .env("EVEFRONTIER_DATASET_CACHE_DIR", &cache_dir)
+        .env("RUST_LOG", "error")
+        .arg("--no-logo")
+        .arg("--data-dir")
+        .arg(temp_dir.path());
+    (cmd, temp_dir)
+}
+
+fn info_json(system: &str) -> serde_json::Value {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("--format")
+        .arg("json")
+        .arg("system-info")
+        .arg(system);
+    let output = cmd.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    serde_json::from_str(&stdout).expect("valid JSON")
+}
+
+#[test]
+fn json_lists_metadata_and_gate_neighbors() {
+    let info = info_json("Nod");
+    assert_eq!(info["name"], "Nod");
+    assert_eq!(info["id"], 30000191);
+    assert!(info["star_temp_k"].is_number());
+    assert!(info["position"].is_object());
+
+    let neighbors: Vec<&str> = info["gate_neighbors"]
+        .as_array()
+        .expect("gate_neighbors array")
+        .iter()
+        .map(|n| n["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(neighbors, ["D:2NAS", "H:2L2S", "J:35IA"]);
+}
+
+#[test]
+fn accepts_numeric_system_id() {
+    let info = info_json("30000200");
+    assert_eq!(info["name"], "Brana");
+}
+
+#[test]
+fn text_output_lists_neighbors() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("system-info").arg("Brana");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("System Brana (30000200)"))
+        .stdout(predicate::str::contains(" - G:3OA0"))
+        .stdout(predicate::str::contains(" - Y:3R7E"));
+}
+
+#[test]
+fn unknown_system_suggests_matches() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("system-info").arg("Brama");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown system 'Brama'"))
+        .stderr(predicate::str::contains("Brana"));
+}
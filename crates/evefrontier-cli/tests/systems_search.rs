@@ -0,0 +1,89 @@
+//! Integration tests for the `systems-search` subcommand.
+//!
+//! Tests verify:
+//! - Exact prefix matches are listed before fuzzy matches
+//! - JSON output is an array of `{id, name}` objects
+//! - `--limit` caps the number of results
+
+use std::fs;
+use std::path::PathBuf;
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::tempdir;
+
+fn fixture_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../docs/fixtures/minimal/static_data.db")
+        .canonicalize()
+        .expect("fixture dataset present")
+}
+
+fn cli() -> Command {
+    cargo_bin_cmd!("evefrontier-cli")
+}
+
+fn prepare_command() -> (Command, tempfile::TempDir) {
+    let temp_dir = tempdir().expect("create temp dir");
+    let cache_dir = temp_dir.path().join("cache");
+    fs::create_dir_all(&cache_dir).expect("create cache dir");
+    let mut cmd = cli();
+    cmd.env("EVEFRONTIER_DATASET_SOURCE", fixture_path())
+        .env("EVEFRONTIER_DATASET_CACHE_DIR", &cache_dir)
+        .env("RUST_LOG", "error")
+        .arg("--no-logo")
+        .arg("--data-dir")
+        .arg(temp_dir.path());
+    (cmd, temp_dir)
+}
+
+fn search_json(args: &[&str]) -> Vec<serde_json::Value> {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("--format")
+        .arg("json")
+        .arg("systems-search")
+        .args(args);
+    let output = cmd.assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    value.as_array().expect("JSON array").clone()
+}
+
+#[test]
+fn prefix_matches_come_first() {
+    let matches = search_json(&["no"]);
+    assert_eq!(matches[0]["name"], "Nod");
+    assert_eq!(matches[0]["id"], 30000191);
+}
+
+#[test]
+fn fuzzy_matches_follow_prefix_matches() {
+    let matches = search_json(&["Brama"]);
+    let names: Vec<&str> = matches.iter().filter_map(|m| m["name"].as_str()).collect();
+    assert!(names.contains(&"Brana"), "{names:?}");
+}
+
+#[test]
+fn limit_caps_results() {
+    let matches = search_json(&["Brama", "--limit", "1"]);
+    assert_eq!(matches.len(), 1);
+}
+
+#[test]
+fn text_output_lists_names_and_ids() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("systems-search").arg("Nod");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Nod (30000191)"));
+}
+
+#[test]
+fn rejects_out_of_range_limit() {
+    let (mut cmd, _temp) = prepare_command();
+    cmd.arg("systems-search").arg("Nod").arg("--limit").arg("0");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("limit must be between 1 and 100"));
+}
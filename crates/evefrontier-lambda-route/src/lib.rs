@@ -10,13 +10,13 @@ use serde_json::Value;
 use tracing::{error, info};
 
 use evefrontier_lambda_shared::{
-    from_lib_error, get_runtime, init_runtime, init_tracing, LambdaResponse, ProblemDetails,
-    RouteRequest, Validate,
+    from_lib_error, get_runtime, init_runtime, init_tracing, is_warmup_event, LambdaResponse,
+    ProblemDetails, RouteRequest, Validate, WarmupResponse,
 };
 use evefrontier_lib::output::{RouteOutputKind, RouteSummary};
 use evefrontier_lib::ship::{FuelConfig, ShipCatalog, ShipLoadout};
 use evefrontier_lib::{
-    plan_route, Error as LibError, RouteAlgorithm as LibAlgorithm,
+    explain_route, plan_route, Error as LibError, RouteAlgorithm as LibAlgorithm,
     RouteConstraints as LibConstraints, RouteRequest as LibRequest,
 };
 
@@ -40,7 +40,9 @@ static SHIP_DATA_BYTES: &[u8] = include_bytes!("../../../data/ship_data.csv");
 #[cfg(not(feature = "bundle-ship-data"))]
 static SHIP_DATA_BYTES: &[u8] = &[];
 
-static SHIP_CATALOG: OnceLock<Result<ShipCatalog, LibError>> = OnceLock::new();
+/// Lazily loaded ship catalog. Only successful loads are cached; failures are retried on the
+/// next request so a transient problem does not disable ship support until the next cold start.
+static SHIP_CATALOG: OnceLock<ShipCatalog> = OnceLock::new();
 
 /// Lambda response - either success or RFC 9457 error.
 #[derive(Debug, serde::Serialize)]
@@ -48,6 +50,7 @@ static SHIP_CATALOG: OnceLock<Result<ShipCatalog, LibError>> = OnceLock::new();
 pub enum Response {
     Success(LambdaResponse<RouteResponseDto>),
     Error(ProblemDetails),
+    Warm(WarmupResponse),
 }
 
 /// Entry point used by the Lambda runtime.
@@ -64,6 +67,13 @@ pub async fn run() -> Result<(), Error> {
 pub async fn handler(event: LambdaEvent<Value>) -> Result<Response, Error> {
     let request_id = event.context.request_id.clone();
 
+    if is_warmup_event(&event.payload) {
+        // Touch the runtime so the scheduled ping leaves it initialized for real requests.
+        let _ = get_runtime();
+        info!(request_id = %request_id, "handled warm-up event");
+        return Ok(Response::Warm(WarmupResponse::warm()));
+    }
+
     // Parse the request
     let request: RouteRequest = match serde_json::from_value(event.payload) {
         Ok(req) => req,
@@ -80,7 +90,7 @@ pub async fn handler(event: LambdaEvent<Value>) -> Result<Response, Error> {
         request_id = %request_id,
         from = %request.from,
         to = %request.to,
-        algorithm = ?request.algorithm,
+        algorithm = ?request.algorithm(),
         ship = ?request.ship,
         "handling route request"
     );
@@ -102,18 +112,25 @@ fn handle_route_request(request: &RouteRequest, request_id: &str) -> Response {
     let lib_request = LibRequest {
         start: request.from.clone(),
         goal: request.to.clone(),
-        algorithm: LibAlgorithm::from(request.algorithm),
+        algorithm: LibAlgorithm::from(request.algorithm()),
         constraints: LibConstraints {
             max_jump: request.max_jump,
             avoid_systems: request.avoid.clone(),
+            avoid_regions: Vec::new(),
             avoid_gates: request.avoid_gates,
             max_temperature: request.max_temperature,
+            avoid_temperature_range: None,
             // Expose `avoid_critical_state` via the API; default is handled by Serde
             // to mirror CLI sensible defaults.
             avoid_critical_state: request.avoid_critical_state,
             ship: None,
             loadout: None,
             heat_config: None,
+            round_trip: false,
+            max_hops: None,
+            gate_penalty: 1.0,
+            jump_penalty: 1.0,
+            gate_time_seconds: Default::default(),
         },
         spatial_index: Some(runtime.spatial_index_arc()),
         max_spatial_neighbors: request
@@ -159,7 +176,7 @@ fn handle_route_request(request: &RouteRequest, request_id: &str) -> Response {
         let catalog = match ship_catalog() {
             Ok(cat) => cat,
             Err(err) => {
-                return Response::Error(from_lib_error(err, request_id));
+                return Response::Error(from_lib_error(&err, request_id));
             }
         };
 
@@ -189,6 +206,7 @@ fn handle_route_request(request: &RouteRequest, request_id: &str) -> Response {
         let fuel_config = FuelConfig {
             quality: request.fuel_quality.unwrap_or(10.0),
             dynamic_mass: request.dynamic_mass.unwrap_or(false),
+            reserve: 0.0,
         };
 
         if let Err(err) = summary.attach_fuel(ship, &loadout, &fuel_config) {
@@ -196,8 +214,7 @@ fn handle_route_request(request: &RouteRequest, request_id: &str) -> Response {
         }
         // Attach heat projections mirroring fuel calculations
         let heat_config = evefrontier_lib::ship::HeatConfig {
-            // Fixed calibration constant; API no longer accepts overrides.
-            calibration_constant: 1e-7,
+            calibration_constant: request.heat_calibration(),
             dynamic_mass: request.dynamic_mass.unwrap_or(false),
         };
 
@@ -206,7 +223,13 @@ fn handle_route_request(request: &RouteRequest, request_id: &str) -> Response {
         }
     }
 
-    let response = RouteResponseDto::from_summary(&summary);
+    let mut response = RouteResponseDto::from_summary(&summary);
+    if request.explain {
+        match explain_route(starmap, &lib_request, &plan) {
+            Ok(rationale) => response.rationale = Some(rationale),
+            Err(e) => return Response::Error(from_lib_error(&e, request_id)),
+        }
+    }
 
     info!(
         request_id = %request_id,
@@ -217,10 +240,10 @@ fn handle_route_request(request: &RouteRequest, request_id: &str) -> Response {
         "route computed successfully"
     );
 
-    Response::Success(LambdaResponse::new(response))
+    Response::Success(LambdaResponse::new(response).with_dataset(runtime.dataset_metadata()))
 }
 
-fn ship_catalog() -> Result<&'static ShipCatalog, &'static LibError> {
+fn ship_catalog() -> Result<&'static ShipCatalog, LibError> {
     // Prefer a catalog loaded at runtime (cold-start). This supports Lambda
     // bundling where the shared runtime pre-parsed the CSV into memory.
     if let Ok(runtime) = std::panic::catch_unwind(get_runtime) {
@@ -232,62 +255,69 @@ fn ship_catalog() -> Result<&'static ShipCatalog, &'static LibError> {
     // Otherwise, lazily initialize a per-crate catalog from bundled bytes or
     // the `EVEFRONTIER_SHIP_DATA` env var. This preserves previous behaviour
     // for tests and non-Lambda execution.
-    let result = SHIP_CATALOG.get_or_init(|| {
-        if !SHIP_DATA_BYTES.is_empty() {
-            return ShipCatalog::from_reader(Cursor::new(SHIP_DATA_BYTES));
-        }
+    cached_ship_catalog(&SHIP_CATALOG, || {
+        load_ship_catalog(
+            SHIP_DATA_BYTES,
+            env::var("EVEFRONTIER_SHIP_DATA").ok().as_deref(),
+        )
+    })
+}
 
-        if let Ok(path) = env::var("EVEFRONTIER_SHIP_DATA") {
-            match fs::read(&path) {
-                Ok(bytes) => return ShipCatalog::from_reader(Cursor::new(bytes)),
-                Err(err) => {
-                    return Err(LibError::ShipDataValidation {
-                        message: format!(
-                            "failed to read ship data from EVEFRONTIER_SHIP_DATA ({}): {}",
-                            path, err
-                        ),
-                    });
-                }
-            }
-        }
+/// Return the cached catalog, running `load` until it first succeeds.
+///
+/// Errors are handed back without being cached. Concurrent first calls may each run
+/// `load`; the first successful result wins and later ones are dropped.
+fn cached_ship_catalog(
+    cache: &OnceLock<ShipCatalog>,
+    load: impl FnOnce() -> Result<ShipCatalog, LibError>,
+) -> Result<&ShipCatalog, LibError> {
+    if let Some(catalog) = cache.get() {
+        return Ok(catalog);
+    }
 
-        Err(LibError::ShipDataValidation {
-            message: "ship data not bundled; enable 'bundle-ship-data' feature or set EVEFRONTIER_SHIP_DATA"
-                .to_string(),
-        })
-    });
+    let catalog = load()?;
+    Ok(cache.get_or_init(|| catalog))
+}
+
+/// Load ship data from bundled bytes, falling back to the CSV at `env_path`.
+fn load_ship_catalog(bundled: &[u8], env_path: Option<&str>) -> Result<ShipCatalog, LibError> {
+    if !bundled.is_empty() {
+        return ShipCatalog::from_reader(Cursor::new(bundled));
+    }
 
-    match result {
-        Ok(catalog) => Ok(catalog),
-        Err(err) => Err(err),
+    if let Some(path) = env_path {
+        return match fs::read(path) {
+            Ok(bytes) => ShipCatalog::from_reader(Cursor::new(bytes)),
+            Err(err) => Err(LibError::ShipDataValidation {
+                message: format!(
+                    "failed to read ship data from EVEFRONTIER_SHIP_DATA ({}): {}",
+                    path, err
+                ),
+            }),
+        };
     }
+
+    Err(LibError::ShipDataValidation {
+        message:
+            "ship data not bundled; enable 'bundle-ship-data' feature or set EVEFRONTIER_SHIP_DATA"
+                .to_string(),
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use evefrontier_lambda_shared::test_utils::mock_request_id;
+    use evefrontier_lambda_shared::test_utils::{fixture_lambda_runtime, mock_request_id};
     use evefrontier_lambda_shared::{RouteAlgorithm, RouteOptimization as SharedRouteOptimization};
 
-    fn init_fixture_runtime() {
-        use evefrontier_lambda_shared::test_utils::{
-            fixture_db_bytes, fixture_index_bytes, fixture_ship_bytes,
-        };
-        let _ = init_runtime(
-            fixture_db_bytes(),
-            fixture_index_bytes(),
-            fixture_ship_bytes(),
-        );
-    }
-
     #[test]
     fn parses_and_validates_request() {
-        init_fixture_runtime();
+        fixture_lambda_runtime();
 
         let request = RouteRequest {
             from: "Nod".to_string(),
             to: "Brana".to_string(),
-            algorithm: RouteAlgorithm::AStar,
+            algorithm: Some(RouteAlgorithm::AStar),
             max_jump: None,
             avoid: vec![],
             avoid_gates: false,
@@ -296,16 +326,61 @@ mod tests {
             fuel_quality: None,
             cargo_mass: None,
             fuel_load: None,
+            heat_calibration: None,
             dynamic_mass: None,
             avoid_critical_state: true,
             max_spatial_neighbors: None,
             optimization: None,
+            explain: false,
         };
         let response = handle_route_request(&request, &mock_request_id("test"));
         match response {
             Response::Success(inner) => {
                 assert_eq!(inner.data.summary.hops, 3);
             }
+            Response::Warm(_) => panic!("unexpected warm-up response"),
+            Response::Error(err) => {
+                panic!("unexpected error: {:?}", err);
+            }
+        }
+    }
+
+    #[test]
+    fn response_reports_dataset_checksum() {
+        use evefrontier_lambda_shared::test_utils::fixture_db_path;
+
+        fixture_lambda_runtime();
+
+        let request = RouteRequest {
+            from: "Nod".to_string(),
+            to: "Brana".to_string(),
+            algorithm: Some(RouteAlgorithm::AStar),
+            max_jump: None,
+            avoid: vec![],
+            avoid_gates: false,
+            max_temperature: None,
+            ship: None,
+            fuel_quality: None,
+            cargo_mass: None,
+            fuel_load: None,
+            heat_calibration: None,
+            dynamic_mass: None,
+            avoid_critical_state: true,
+            max_spatial_neighbors: None,
+            optimization: None,
+            explain: false,
+        };
+        let checksum = evefrontier_lib::spatial::compute_dataset_checksum(&fixture_db_path())
+            .expect("fixture checksum");
+        let expected: String = checksum[..8].iter().map(|b| format!("{:02x}", b)).collect();
+
+        match handle_route_request(&request, &mock_request_id("test")) {
+            Response::Success(inner) => {
+                assert_eq!(inner.dataset_checksum.as_deref(), Some(expected.as_str()));
+                let json = serde_json::to_value(&inner).unwrap();
+                assert_eq!(json["dataset_checksum"], expected.as_str());
+            }
+            Response::Warm(_) => panic!("unexpected warm-up response"),
             Response::Error(err) => {
                 panic!("unexpected error: {:?}", err);
             }
@@ -314,12 +389,12 @@ mod tests {
 
     #[test]
     fn response_includes_heat_when_ship_provided() {
-        init_fixture_runtime();
+        fixture_lambda_runtime();
 
         let request = RouteRequest {
             from: "Nod".to_string(),
             to: "Brana".to_string(),
-            algorithm: RouteAlgorithm::AStar,
+            algorithm: Some(RouteAlgorithm::AStar),
             max_jump: None,
             avoid: vec![],
             avoid_gates: false,
@@ -328,16 +403,19 @@ mod tests {
             fuel_quality: None,
             cargo_mass: Some(633_006.0),
             fuel_load: None,
+            heat_calibration: None,
             dynamic_mass: None,
             avoid_critical_state: true,
             max_spatial_neighbors: None,
             optimization: None,
+            explain: false,
         };
         let response = handle_route_request(&request, &mock_request_id("test"));
         match response {
             Response::Success(inner) => {
                 assert!(inner.data.summary.heat.is_some());
             }
+            Response::Warm(_) => panic!("unexpected warm-up response"),
             Response::Error(err) => {
                 panic!("unexpected error: {:?}", err);
             }
@@ -346,12 +424,12 @@ mod tests {
 
     #[test]
     fn respects_optimization_field_when_set() {
-        init_fixture_runtime();
+        fixture_lambda_runtime();
 
         let request = RouteRequest {
             from: "Nod".to_string(),
             to: "Brana".to_string(),
-            algorithm: RouteAlgorithm::AStar,
+            algorithm: Some(RouteAlgorithm::AStar),
             max_jump: None,
             avoid: vec![],
             avoid_gates: false,
@@ -360,10 +438,12 @@ mod tests {
             fuel_quality: None,
             cargo_mass: None,
             fuel_load: None,
+            heat_calibration: None,
             dynamic_mass: None,
             avoid_critical_state: true,
             max_spatial_neighbors: None,
             optimization: Some(SharedRouteOptimization::Fuel),
+            explain: false,
         };
         let _response = handle_route_request(&request, &mock_request_id("test"));
     }
@@ -374,13 +454,44 @@ mod tests {
         assert!(!catalog.ship_names().is_empty());
     }
 
+    #[test]
+    fn failed_ship_catalog_load_is_retried() {
+        use evefrontier_lambda_shared::test_utils::fixture_ship_bytes;
+
+        let path = env::temp_dir().join(format!(
+            "evefrontier-lambda-route-ship-retry-{}.csv",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+        let path_str = path.to_str().expect("utf-8 temp path").to_string();
+        let cache = OnceLock::new();
+
+        // First access fails because the ship data file is missing.
+        let err = cached_ship_catalog(&cache, || load_ship_catalog(&[], Some(&path_str)))
+            .expect_err("missing ship data should fail");
+        assert!(err.to_string().contains("failed to read ship data"));
+        assert!(cache.get().is_none(), "failures must not be cached");
+
+        // Once the file appears, the next access succeeds.
+        fs::write(&path, fixture_ship_bytes()).expect("write ship data");
+        let catalog = cached_ship_catalog(&cache, || load_ship_catalog(&[], Some(&path_str)))
+            .expect("catalog should load after the environment is fixed");
+        assert!(catalog.get("Reflex").is_some());
+
+        // Success is cached: later accesses do not reload even if the file disappears.
+        fs::remove_file(&path).expect("remove ship data");
+        let cached = cached_ship_catalog(&cache, || load_ship_catalog(&[], Some(&path_str)))
+            .expect("cached catalog should be returned");
+        assert!(cached.get("Reflex").is_some());
+    }
+
     #[test]
     fn test_handle_route_request_success() {
-        init_fixture_runtime();
+        fixture_lambda_runtime();
         let request = RouteRequest {
             from: "Nod".to_string(),
             to: "Brana".to_string(),
-            algorithm: RouteAlgorithm::AStar,
+            algorithm: Some(RouteAlgorithm::AStar),
             max_jump: None,
             avoid: vec![],
             avoid_gates: false,
@@ -389,10 +500,12 @@ mod tests {
             fuel_quality: None,
             cargo_mass: None,
             fuel_load: None,
+            heat_calibration: None,
             dynamic_mass: None,
             avoid_critical_state: true,
             max_spatial_neighbors: None,
             optimization: None,
+            explain: false,
         };
         let response = handle_route_request(&request, &mock_request_id("test"));
         match response {
@@ -403,6 +516,7 @@ mod tests {
                 // Fuel is present because a default ship is injected
                 assert!(inner.data.summary.fuel.is_some());
             }
+            Response::Warm(_) => panic!("unexpected warm-up response"),
             Response::Error(err) => {
                 panic!("unexpected error: {:?}", err);
             }
@@ -411,11 +525,11 @@ mod tests {
 
     #[test]
     fn test_handle_route_request_no_route() {
-        init_fixture_runtime();
+        fixture_lambda_runtime();
         let request = RouteRequest {
             from: "Nod".to_string(),
             to: "J:35IA".to_string(), // J:35IA is isolated in minimal fixture
-            algorithm: RouteAlgorithm::Bfs,
+            algorithm: Some(RouteAlgorithm::Bfs),
             max_jump: None,
             avoid: vec![],
             avoid_gates: true,
@@ -424,16 +538,19 @@ mod tests {
             fuel_quality: None,
             cargo_mass: None,
             fuel_load: None,
+            heat_calibration: None,
             dynamic_mass: None,
             avoid_critical_state: true,
             max_spatial_neighbors: None,
             optimization: None,
+            explain: false,
         };
         let response = handle_route_request(&request, &mock_request_id("test"));
         match response {
             Response::Success(inner) => {
                 panic!("unexpected success: {:?}", inner);
             }
+            Response::Warm(_) => panic!("unexpected warm-up response"),
             Response::Error(err) => {
                 assert_eq!(err.status, 404);
                 // ProblemDetails::route_not_found uses "No route exists"
@@ -447,11 +564,11 @@ mod tests {
 
     #[test]
     fn test_handle_route_request_unknown_system() {
-        init_fixture_runtime();
+        fixture_lambda_runtime();
         let request = RouteRequest {
             from: "NonExistentSystem".to_string(),
             to: "Brana".to_string(),
-            algorithm: RouteAlgorithm::AStar,
+            algorithm: Some(RouteAlgorithm::AStar),
             max_jump: None,
             avoid: vec![],
             avoid_gates: false,
@@ -460,16 +577,19 @@ mod tests {
             fuel_quality: None,
             cargo_mass: None,
             fuel_load: None,
+            heat_calibration: None,
             dynamic_mass: None,
             avoid_critical_state: true,
             max_spatial_neighbors: None,
             optimization: None,
+            explain: false,
         };
         let response = handle_route_request(&request, &mock_request_id("test"));
         match response {
             Response::Success(inner) => {
                 panic!("unexpected success: {:?}", inner);
             }
+            Response::Warm(_) => panic!("unexpected warm-up response"),
             Response::Error(err) => {
                 assert_eq!(err.status, 404);
                 // ProblemDetails::unknown_system uses "not found"
@@ -477,4 +597,62 @@ mod tests {
             }
         }
     }
+
+    fn explain_request(explain: bool) -> RouteRequest {
+        RouteRequest {
+            from: "Nod".to_string(),
+            to: "Brana".to_string(),
+            algorithm: Some(RouteAlgorithm::AStar),
+            max_jump: None,
+            avoid: vec![],
+            avoid_gates: false,
+            max_temperature: None,
+            ship: None,
+            fuel_quality: None,
+            cargo_mass: None,
+            fuel_load: None,
+            heat_calibration: None,
+            dynamic_mass: None,
+            avoid_critical_state: false,
+            max_spatial_neighbors: None,
+            optimization: None,
+            explain,
+        }
+    }
+
+    #[test]
+    fn explain_adds_rationale_for_every_hop() {
+        fixture_lambda_runtime();
+
+        match handle_route_request(&explain_request(true), &mock_request_id("test")) {
+            Response::Success(inner) => {
+                let rationale = inner.data.rationale.as_ref().expect("rationale missing");
+                assert_eq!(rationale.len(), inner.data.summary.hops);
+                for (index, hop) in rationale.iter().enumerate() {
+                    assert_eq!(hop.hop, index);
+                    assert_eq!(hop.from, inner.data.steps[index].system);
+                    assert!(!hop.reason.is_empty());
+                }
+                let json = serde_json::to_value(&inner).unwrap();
+                assert!(json["rationale"].is_array());
+            }
+            Response::Warm(_) => panic!("unexpected warm-up response"),
+            Response::Error(err) => panic!("unexpected error: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn rationale_omitted_without_explain() {
+        fixture_lambda_runtime();
+
+        match handle_route_request(&explain_request(false), &mock_request_id("test")) {
+            Response::Success(inner) => {
+                assert!(inner.data.rationale.is_none());
+                let json = serde_json::to_value(&inner).unwrap();
+                assert!(json.get("rationale").is_none());
+            }
+            Response::Warm(_) => panic!("unexpected warm-up response"),
+            Response::Error(err) => panic!("unexpected error: {:?}", err),
+        }
+    }
 }
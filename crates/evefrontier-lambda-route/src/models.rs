@@ -2,6 +2,7 @@ use serde::Serialize;
 
 use evefrontier_lib::output::{RouteStep, RouteSummary};
 use evefrontier_lib::ship::FuelProjection;
+use evefrontier_lib::HopRationale;
 
 /// Fuel projection for a single hop.
 #[derive(Debug, Clone, Serialize, PartialEq)]
@@ -68,6 +69,8 @@ pub struct FuelSummaryDto {
     pub quality: i64,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub warnings: Vec<String>,
+    /// True when a refuel stop is required somewhere along the route.
+    pub fuel_exhausted: bool,
 }
 
 /// A single route step.
@@ -82,6 +85,12 @@ pub struct RouteStepDto {
     pub fuel: Option<FuelProjectionDto>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub heat: Option<HeatProjectionDto>,
+    /// Number of planets in the system (omitted when the dataset has none recorded).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub planet_count: Option<u32>,
+    /// Number of moons in the system (omitted when the dataset has none recorded).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moon_count: Option<u32>,
 }
 
 impl RouteStepDto {
@@ -92,6 +101,8 @@ impl RouteStepDto {
             method: step.method.clone(),
             fuel: step.fuel.as_ref().map(FuelProjectionDto::from),
             heat: step.heat.as_ref().map(HeatProjectionDto::from),
+            planet_count: step.planet_count,
+            moon_count: step.moon_count,
         }
     }
 }
@@ -107,6 +118,8 @@ pub struct RouteSummaryDto {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parameters: Option<RouteParametersDto>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_constraints: Option<EffectiveConstraintsDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub fuel: Option<FuelSummaryDto>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub heat: Option<HeatSummaryDto>,
@@ -119,6 +132,21 @@ pub struct RouteParametersDto {
     pub optimization: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ship_name: Option<String>,
+    /// Requested per-hop jump limit in light-years.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_jump: Option<f64>,
+}
+
+/// DTO for the constraints the planner actually searched with, next to the requested
+/// `parameters`.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct EffectiveConstraintsDto {
+    /// Per-hop jump limit in light-years after any automatic clamping.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_jump: Option<f64>,
+    /// Constraints whose effective value differs from the request (e.g. `max_jump`).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub adjusted: Vec<String>,
 }
 
 /// Complete route response returned by the Lambda handler.
@@ -126,6 +154,9 @@ pub struct RouteParametersDto {
 pub struct RouteResponseDto {
     pub steps: Vec<RouteStepDto>,
     pub summary: RouteSummaryDto,
+    /// Per-hop rationale, present only when the request sets `explain`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rationale: Option<Vec<HopRationale>>,
 }
 
 impl RouteResponseDto {
@@ -138,6 +169,7 @@ impl RouteResponseDto {
             ship_name: f.ship_name.clone(),
             quality: f.quality.round() as i64,
             warnings: f.warnings.clone(),
+            fuel_exhausted: f.fuel_exhausted,
         });
 
         let heat = summary.heat.as_ref().map(|h| HeatSummaryDto {
@@ -154,6 +186,13 @@ impl RouteResponseDto {
                 algorithm: p.algorithm.to_string(),
                 optimization: format!("{:?}", p.optimization),
                 ship_name: p.ship_name.clone(),
+                max_jump: p.max_jump,
+            }),
+            effective_constraints: summary.effective_constraints.as_ref().map(|e| {
+                EffectiveConstraintsDto {
+                    max_jump: e.max_jump,
+                    adjusted: e.adjusted.clone(),
+                }
             }),
             fuel,
             heat,
@@ -162,6 +201,7 @@ impl RouteResponseDto {
         Self {
             steps,
             summary: summary_dto,
+            rationale: None,
         }
     }
 }
@@ -1,18 +1,8 @@
 use evefrontier_lambda_route::Response;
-use evefrontier_lambda_shared::test_utils::{
-    fixture_db_bytes, fixture_index_bytes, fixture_ship_bytes,
-};
-use evefrontier_lambda_shared::{init_runtime, RouteRequest};
+use evefrontier_lambda_shared::test_utils::fixture_lambda_runtime;
+use evefrontier_lambda_shared::RouteRequest;
 use lambda_runtime::{Context, LambdaEvent};
 
-fn init_fixture_runtime() {
-    let _ = init_runtime(
-        fixture_db_bytes(),
-        fixture_index_bytes(),
-        fixture_ship_bytes(),
-    );
-}
-
 async fn invoke(request: RouteRequest) -> Response {
     let context = Context::default();
     let payload = serde_json::to_value(request).expect("serializable payload");
@@ -24,12 +14,12 @@ async fn invoke(request: RouteRequest) -> Response {
 
 #[tokio::test]
 async fn returns_fuel_projection_when_ship_provided() {
-    init_fixture_runtime();
+    fixture_lambda_runtime();
 
     let request = RouteRequest {
         from: "Nod".to_string(),
         to: "Brana".to_string(),
-        algorithm: evefrontier_lambda_shared::RouteAlgorithm::AStar,
+        algorithm: Some(evefrontier_lambda_shared::RouteAlgorithm::AStar),
         max_jump: None,
         avoid: vec![],
         avoid_gates: false,
@@ -38,10 +28,12 @@ async fn returns_fuel_projection_when_ship_provided() {
         fuel_quality: None,
         cargo_mass: Some(1000.0),
         fuel_load: None,
+        heat_calibration: None,
         dynamic_mass: Some(false),
         avoid_critical_state: true,
         max_spatial_neighbors: None,
         optimization: None,
+        explain: false,
     };
 
     let response = invoke(request).await;
@@ -52,18 +44,19 @@ async fn returns_fuel_projection_when_ship_provided() {
             assert!(summary.fuel.is_some(), "fuel summary should be present");
             assert!(success.data.steps.iter().any(|s| s.fuel.is_some()));
         }
+        Response::Warm(_) => panic!("unexpected warm-up response"),
         Response::Error(err) => panic!("unexpected error: {err:?}"),
     }
 }
 
 #[tokio::test]
 async fn omits_fuel_projection_without_ship() {
-    init_fixture_runtime();
+    fixture_lambda_runtime();
 
     let request = RouteRequest {
         from: "Nod".to_string(),
         to: "Brana".to_string(),
-        algorithm: evefrontier_lambda_shared::RouteAlgorithm::AStar,
+        algorithm: Some(evefrontier_lambda_shared::RouteAlgorithm::AStar),
         max_jump: None,
         avoid: vec![],
         avoid_gates: false,
@@ -72,10 +65,12 @@ async fn omits_fuel_projection_without_ship() {
         fuel_quality: None,
         cargo_mass: None,
         fuel_load: None,
+        heat_calibration: None,
         dynamic_mass: None,
         avoid_critical_state: true,
         max_spatial_neighbors: None,
         optimization: None,
+        explain: false,
     };
 
     let response = invoke(request).await;
@@ -87,18 +82,19 @@ async fn omits_fuel_projection_without_ship() {
             assert!(summary.fuel.is_some());
             // Individual steps may or may not have fuel depending on edge type
         }
+        Response::Warm(_) => panic!("unexpected warm-up response"),
         Response::Error(err) => panic!("unexpected error: {err:?}"),
     }
 }
 
 #[tokio::test]
 async fn rejects_unknown_ship_name() {
-    init_fixture_runtime();
+    fixture_lambda_runtime();
 
     let request = RouteRequest {
         from: "Nod".to_string(),
         to: "Brana".to_string(),
-        algorithm: evefrontier_lambda_shared::RouteAlgorithm::AStar,
+        algorithm: Some(evefrontier_lambda_shared::RouteAlgorithm::AStar),
         max_jump: None,
         avoid: vec![],
         avoid_gates: false,
@@ -107,16 +103,86 @@ async fn rejects_unknown_ship_name() {
         fuel_quality: None,
         cargo_mass: None,
         fuel_load: None,
+        heat_calibration: None,
         dynamic_mass: None,
         avoid_critical_state: true,
         max_spatial_neighbors: None,
         optimization: None,
+        explain: false,
     };
 
     let response = invoke(request).await;
 
     match response {
         Response::Success(_) => panic!("expected validation error for unknown ship"),
+        Response::Warm(_) => panic!("unexpected warm-up response"),
         Response::Error(err) => assert_eq!(err.status, 400),
     }
 }
+
+#[tokio::test]
+async fn heat_calibration_changes_projected_heat() {
+    fixture_lambda_runtime();
+
+    let request_with = |heat_calibration| RouteRequest {
+        from: "Nod".to_string(),
+        to: "Brana".to_string(),
+        algorithm: Some(evefrontier_lambda_shared::RouteAlgorithm::AStar),
+        max_jump: None,
+        avoid: vec![],
+        avoid_gates: false,
+        max_temperature: None,
+        ship: Some("Reflex".to_string()),
+        fuel_quality: None,
+        cargo_mass: None,
+        fuel_load: None,
+        heat_calibration,
+        dynamic_mass: None,
+        // Keep planning heat-agnostic so both requests take the same route.
+        avoid_critical_state: false,
+        max_spatial_neighbors: None,
+        optimization: None,
+        explain: false,
+    };
+    let total_hop_heat = |response: Response| match response {
+        Response::Success(success) => success
+            .data
+            .steps
+            .iter()
+            .filter_map(|step| step.heat.as_ref())
+            .map(|heat| heat.hop_heat)
+            .sum::<f64>(),
+        Response::Warm(_) => panic!("unexpected warm-up response"),
+        Response::Error(err) => panic!("unexpected error: {err:?}"),
+    };
+
+    let default_heat = total_hop_heat(invoke(request_with(None)).await);
+    let halved_heat = total_hop_heat(invoke(request_with(Some(2e-7))).await);
+
+    // Jump heat is inversely proportional to the calibration constant.
+    assert!(default_heat > 0.0, "route should include a heated jump");
+    assert!(
+        (2.0 * halved_heat - default_heat).abs() <= 1e-9 * default_heat,
+        "doubling the calibration should halve hop heat ({default_heat} -> {halved_heat})"
+    );
+}
+
+#[tokio::test]
+async fn rejects_non_positive_heat_calibration() {
+    fixture_lambda_runtime();
+
+    let payload = serde_json::json!({"from": "Nod", "to": "Brana", "heat_calibration": 0.0});
+    let event = LambdaEvent::new(payload, Context::default());
+    let response = evefrontier_lambda_route::handler(event)
+        .await
+        .expect("handler should succeed");
+
+    match response {
+        Response::Warm(_) => panic!("unexpected warm-up response"),
+        Response::Error(err) => {
+            assert_eq!(err.status, 400);
+            assert!(err.detail.unwrap_or_default().contains("heat_calibration"));
+        }
+        Response::Success(_) => panic!("zero calibration should be rejected"),
+    }
+}
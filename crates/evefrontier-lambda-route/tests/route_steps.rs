@@ -0,0 +1,97 @@
+use evefrontier_lambda_route::Response;
+use evefrontier_lambda_shared::test_utils::fixture_lambda_runtime;
+use evefrontier_lambda_shared::RouteRequest;
+use lambda_runtime::{Context, LambdaEvent};
+
+async fn invoke(request: RouteRequest) -> Response {
+    let context = Context::default();
+    let payload = serde_json::to_value(request).expect("serializable payload");
+    let event = LambdaEvent::new(payload, context);
+    evefrontier_lambda_route::handler(event)
+        .await
+        .expect("handler should succeed")
+}
+
+#[tokio::test]
+async fn steps_include_planet_and_moon_counts() {
+    fixture_lambda_runtime();
+
+    let request = RouteRequest {
+        from: "Nod".to_string(),
+        to: "Brana".to_string(),
+        algorithm: Some(evefrontier_lambda_shared::RouteAlgorithm::Bfs),
+        max_jump: None,
+        avoid: vec![],
+        avoid_gates: false,
+        max_temperature: None,
+        ship: None,
+        fuel_quality: None,
+        cargo_mass: None,
+        fuel_load: None,
+        heat_calibration: None,
+        dynamic_mass: None,
+        avoid_critical_state: false,
+        max_spatial_neighbors: None,
+        optimization: None,
+        explain: false,
+    };
+
+    match invoke(request).await {
+        Response::Success(success) => {
+            let steps = &success.data.steps;
+            let brana = steps.last().expect("route has steps");
+            assert_eq!(brana.system, "Brana");
+            assert_eq!(brana.planet_count, Some(2));
+            assert_eq!(brana.moon_count, Some(2));
+
+            // Nod has planets but no moons in the fixture, so the moon count is omitted.
+            let nod = steps.first().expect("route has steps");
+            assert_eq!(nod.system, "Nod");
+            assert_eq!(nod.planet_count, Some(2));
+            assert_eq!(nod.moon_count, None);
+        }
+        Response::Warm(_) => panic!("unexpected warm-up response"),
+        Response::Error(err) => panic!("unexpected error: {err:?}"),
+    }
+}
+
+#[tokio::test]
+async fn summary_reports_requested_and_effective_max_jump() {
+    fixture_lambda_runtime();
+
+    let request = RouteRequest {
+        from: "Nod".to_string(),
+        to: "Brana".to_string(),
+        algorithm: Some(evefrontier_lambda_shared::RouteAlgorithm::Dijkstra),
+        max_jump: Some(400.0),
+        avoid: vec![],
+        avoid_gates: false,
+        max_temperature: None,
+        ship: None,
+        fuel_quality: None,
+        cargo_mass: None,
+        fuel_load: None,
+        heat_calibration: None,
+        dynamic_mass: None,
+        avoid_critical_state: false,
+        max_spatial_neighbors: None,
+        optimization: None,
+        explain: false,
+    };
+
+    match invoke(request).await {
+        Response::Success(success) => {
+            let summary = &success.data.summary;
+            let requested = summary.parameters.as_ref().expect("parameters");
+            assert_eq!(requested.max_jump, Some(400.0));
+            let effective = summary
+                .effective_constraints
+                .as_ref()
+                .expect("effective constraints");
+            assert_eq!(effective.max_jump, Some(400.0));
+            assert!(effective.adjusted.is_empty());
+        }
+        Response::Warm(_) => panic!("unexpected warm-up response"),
+        Response::Error(err) => panic!("unexpected error: {err:?}"),
+    }
+}
@@ -0,0 +1,38 @@
+use evefrontier_lambda_route::Response;
+use evefrontier_lambda_shared::test_utils::fixture_lambda_runtime;
+use evefrontier_lambda_shared::WARMUP_STATUS;
+use lambda_runtime::{Context, LambdaEvent};
+use serde_json::{json, Value};
+
+async fn invoke(payload: Value) -> Response {
+    let event = LambdaEvent::new(payload, Context::default());
+    evefrontier_lambda_route::handler(event)
+        .await
+        .expect("handler should succeed")
+}
+
+#[tokio::test]
+async fn warmup_ping_returns_warm_status_without_routing() {
+    fixture_lambda_runtime();
+
+    match invoke(json!({"warmup": true})).await {
+        Response::Warm(warm) => {
+            assert_eq!(warm.status, WARMUP_STATUS);
+            let body = serde_json::to_value(Response::Warm(warm)).unwrap();
+            assert_eq!(body, json!({"status": "warm"}));
+        }
+        Response::Success(success) => panic!("warm-up should not plan a route: {success:?}"),
+        Response::Error(err) => panic!("unexpected error: {err:?}"),
+    }
+}
+
+#[tokio::test]
+async fn warmup_false_is_handled_as_a_route_request() {
+    fixture_lambda_runtime();
+
+    // Without `from`/`to` this is an ordinary, invalid route request.
+    match invoke(json!({"warmup": false})).await {
+        Response::Error(err) => assert_eq!(err.status, 400),
+        other => panic!("expected a validation error, got {other:?}"),
+    }
+}
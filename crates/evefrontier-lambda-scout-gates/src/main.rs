@@ -1,6 +1,7 @@
 //! AWS Lambda function for scouting adjacent gates.
 //!
-//! This Lambda returns the gate-connected neighbours of a system.
+//! This Lambda returns the gate-connected neighbours of a system, optionally
+//! expanded to every system within `depth` gate hops.
 
 use lambda_runtime::{service_fn, Error, LambdaEvent};
 use serde::Serialize;
@@ -8,9 +9,10 @@ use serde_json::Value;
 use tracing::{error, info};
 
 use evefrontier_lambda_shared::{
-    get_runtime, init_runtime, init_tracing, LambdaResponse, ProblemDetails, ScoutGatesRequest,
-    Validate,
+    get_runtime, init_runtime, init_tracing, is_warmup_event, LambdaResponse, ProblemDetails,
+    ScoutGatesRequest, Validate, WarmupResponse,
 };
+use evefrontier_lib::{Starmap, SystemId};
 
 /// Bundled SQLite database (from data/static_data.db).
 /// Bundled spatial index (from data/static_data.db.spatial.bin).
@@ -35,6 +37,8 @@ struct Neighbor {
     name: String,
     /// System ID.
     id: i64,
+    /// Gate hops from the queried system (1 for direct neighbours).
+    hops: usize,
 }
 
 /// Response for scout-gates endpoint.
@@ -44,9 +48,11 @@ struct ScoutGatesResponse {
     system: String,
     /// The queried system ID.
     system_id: i64,
+    /// Gate-hop depth that was searched.
+    depth: usize,
     /// Number of gate-connected neighbors.
     count: usize,
-    /// List of neighboring systems.
+    /// Neighboring systems within `depth` hops, ordered by hop count.
     neighbors: Vec<Neighbor>,
 }
 
@@ -56,6 +62,7 @@ struct ScoutGatesResponse {
 enum Response {
     Success(LambdaResponse<ScoutGatesResponse>),
     Error(ProblemDetails),
+    Warm(WarmupResponse),
 }
 
 #[tokio::main]
@@ -77,6 +84,13 @@ async fn main() -> Result<(), Error> {
 async fn handler(event: LambdaEvent<Value>) -> Result<Response, Error> {
     let request_id = event.context.request_id.clone();
 
+    if is_warmup_event(&event.payload) {
+        // Touch the runtime so the scheduled ping leaves it initialized for real requests.
+        let _ = get_runtime();
+        info!(request_id = %request_id, "handled warm-up event");
+        return Ok(Response::Warm(WarmupResponse::warm()));
+    }
+
     // Parse the request
     let request: ScoutGatesRequest = match serde_json::from_value(event.payload) {
         Ok(req) => req,
@@ -116,25 +130,13 @@ async fn handler(event: LambdaEvent<Value>) -> Result<Response, Error> {
         }
     };
 
-    // Get gate-connected neighbors from adjacency list
-    let neighbors: Vec<Neighbor> = starmap
-        .adjacency
-        .get(&system_id)
-        .map(|ids| {
-            ids.iter()
-                .filter_map(|&id| {
-                    starmap.system_name(id).map(|name| Neighbor {
-                        name: name.to_string(),
-                        id,
-                    })
-                })
-                .collect()
-        })
-        .unwrap_or_default();
+    let depth = request.depth();
+    let neighbors = gate_neighbors(starmap, system_id, depth);
 
     let response = ScoutGatesResponse {
         system: request.system.clone(),
         system_id,
+        depth,
         count: neighbors.len(),
         neighbors,
     };
@@ -146,7 +148,24 @@ async fn handler(event: LambdaEvent<Value>) -> Result<Response, Error> {
         "gate neighbors found"
     );
 
-    Ok(Response::Success(LambdaResponse::new(response)))
+    Ok(Response::Success(
+        LambdaResponse::new(response).with_dataset(runtime.dataset_metadata()),
+    ))
+}
+
+/// Collect gate-connected neighbours within `depth` hops, annotated with hop distance.
+fn gate_neighbors(starmap: &Starmap, system_id: SystemId, depth: usize) -> Vec<Neighbor> {
+    starmap
+        .gate_neighbors_within(system_id, depth)
+        .into_iter()
+        .filter_map(|(id, hops)| {
+            starmap.system_name(id).map(|name| Neighbor {
+                name: name.to_string(),
+                id,
+                hops,
+            })
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -184,6 +203,7 @@ mod tests {
     fn test_validate_valid_request() {
         let request = ScoutGatesRequest {
             system: "Nod".to_string(),
+            depth: None,
         };
         assert!(request.validate("test-req").is_ok());
     }
@@ -192,6 +212,7 @@ mod tests {
     fn test_validate_empty_system() {
         let request = ScoutGatesRequest {
             system: "".to_string(),
+            depth: None,
         };
         let err = request.validate("test-req").unwrap_err();
         assert_eq!(err.status, 400);
@@ -201,6 +222,7 @@ mod tests {
     fn test_validate_whitespace_only_system() {
         let request = ScoutGatesRequest {
             system: "   ".to_string(),
+            depth: None,
         };
         let err = request.validate("test-req").unwrap_err();
         assert_eq!(err.status, 400);
@@ -269,6 +291,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_gate_neighbors_default_depth_is_direct() {
+        let starmap = fixture_starmap();
+        let nod = starmap.system_id_by_name("Nod").expect("Nod exists");
+
+        let neighbors = gate_neighbors(starmap, nod, 1);
+        let direct = starmap.adjacency.get(&nod).expect("Nod has gates");
+        assert_eq!(neighbors.len(), direct.len());
+        assert!(neighbors.iter().all(|n| n.hops == 1));
+    }
+
+    #[test]
+    fn test_gate_neighbors_depth_two_includes_neighbors_of_neighbors() {
+        let starmap = fixture_starmap();
+        let nod = starmap.system_id_by_name("Nod").expect("Nod exists");
+
+        let neighbors = gate_neighbors(starmap, nod, 2);
+        let hops_of = |name: &str| neighbors.iter().find(|n| n.name == name).map(|n| n.hops);
+
+        assert_eq!(hops_of("H:2L2S"), Some(1));
+        assert_eq!(hops_of("J:35IA"), Some(1));
+        assert_eq!(hops_of("D:2NAS"), Some(1));
+        // Y:3R7E is only reachable through H:2L2S.
+        assert_eq!(hops_of("Y:3R7E"), Some(2));
+        // Brana is three hops away and the origin is never listed.
+        assert_eq!(hops_of("Brana"), None);
+        assert_eq!(hops_of("Nod"), None);
+        assert!(neighbors.windows(2).all(|w| w[0].hops <= w[1].hops));
+    }
+
     // ==================== Response Construction Tests ====================
 
     #[test]
@@ -276,15 +328,18 @@ mod tests {
         let response = ScoutGatesResponse {
             system: "Nod".to_string(),
             system_id: 12345,
+            depth: 1,
             count: 2,
             neighbors: vec![
                 Neighbor {
                     name: "Brana".to_string(),
                     id: 54321,
+                    hops: 1,
                 },
                 Neighbor {
                     name: "H:2L2S".to_string(),
                     id: 67890,
+                    hops: 1,
                 },
             ],
         };
@@ -295,6 +350,7 @@ mod tests {
         assert_eq!(json["count"], 2);
         assert!(json["neighbors"].is_array());
         assert_eq!(json["neighbors"].as_array().unwrap().len(), 2);
+        assert_eq!(json["neighbors"][0]["hops"], 1);
     }
 
     #[test]
@@ -302,6 +358,7 @@ mod tests {
         let inner = ScoutGatesResponse {
             system: "Nod".to_string(),
             system_id: 1,
+            depth: 1,
             count: 0,
             neighbors: vec![],
         };
@@ -1,6 +1,7 @@
 //! AWS Lambda function for scouting systems within range.
 //!
-//! This Lambda returns systems within a spatial radius of a given system.
+//! This Lambda returns systems within a spatial radius of a given system. The payload may
+//! also be an array of requests, answered with an array of responses in the same order.
 
 use lambda_runtime::{service_fn, Error, LambdaEvent};
 use serde::Serialize;
@@ -8,10 +9,11 @@ use serde_json::Value;
 use tracing::{error, info};
 
 use evefrontier_lambda_shared::{
-    get_runtime, init_runtime, init_tracing, LambdaResponse, ProblemDetails, ScoutRangeRequest,
-    Validate,
+    get_runtime, init_runtime, init_tracing, is_warmup_event, LambdaResponse, ProblemDetails,
+    ScoutRangeRequest, Validate, WarmupResponse,
 };
 use evefrontier_lib::spatial::NeighbourQuery;
+use evefrontier_lib::{SpatialIndex, Starmap, SystemId};
 
 /// Bundled SQLite database (from data/static_data.db).
 #[cfg(feature = "bundle-data")]
@@ -52,12 +54,18 @@ struct ScoutRangeResponse {
     systems: Vec<NearbySystem>,
 }
 
+/// Largest number of requests accepted in one batch invocation.
+const MAX_BATCH_SIZE: usize = 50;
+
 /// Lambda response - either success or RFC 9457 error.
 #[derive(Debug, Serialize)]
 #[serde(untagged)]
 enum Response {
     Success(LambdaResponse<ScoutRangeResponse>),
     Error(ProblemDetails),
+    Warm(WarmupResponse),
+    /// One response per entry of a batch request, in request order.
+    Batch(Vec<Response>),
 }
 
 #[tokio::main]
@@ -79,15 +87,74 @@ async fn main() -> Result<(), Error> {
 async fn handler(event: LambdaEvent<Value>) -> Result<Response, Error> {
     let request_id = event.context.request_id.clone();
 
+    if is_warmup_event(&event.payload) {
+        // Touch the runtime so the scheduled ping leaves it initialized for real requests.
+        let _ = get_runtime();
+        info!(request_id = %request_id, "handled warm-up event");
+        return Ok(Response::Warm(WarmupResponse::warm()));
+    }
+
+    Ok(handle_payload(event.payload, &request_id))
+}
+
+/// Dispatch a payload holding either one request object or an array of them.
+///
+/// Arrays are answered with an array of responses in the same order; each entry is parsed
+/// and validated on its own, so one bad entry yields a problem in its slot without failing
+/// the rest of the batch.
+fn handle_payload(payload: Value, request_id: &str) -> Response {
+    match payload {
+        Value::Array(entries) => handle_batch(entries, request_id),
+        payload => handle_single(payload, request_id),
+    }
+}
+
+fn handle_batch(entries: Vec<Value>, request_id: &str) -> Response {
+    if entries.is_empty() || entries.len() > MAX_BATCH_SIZE {
+        return Response::Error(ProblemDetails::bad_request(
+            format!(
+                "A batch must contain between 1 and {} requests, got {}",
+                MAX_BATCH_SIZE,
+                entries.len()
+            ),
+            request_id,
+        ));
+    }
+
+    info!(
+        request_id = %request_id,
+        batch_size = entries.len(),
+        "handling scout-range batch"
+    );
+
+    Response::Batch(
+        entries
+            .into_iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                if entry.is_object() {
+                    handle_single(entry, request_id)
+                } else {
+                    Response::Error(ProblemDetails::bad_request(
+                        format!("Batch entry {} must be a JSON object", index),
+                        request_id,
+                    ))
+                }
+            })
+            .collect(),
+    )
+}
+
+fn handle_single(payload: Value, request_id: &str) -> Response {
     // Parse the request
-    let request: ScoutRangeRequest = match serde_json::from_value(event.payload) {
+    let request: ScoutRangeRequest = match serde_json::from_value(payload) {
         Ok(req) => req,
         Err(e) => {
             error!(request_id = %request_id, error = %e, "failed to parse request");
-            return Ok(Response::Error(ProblemDetails::bad_request(
+            return Response::Error(ProblemDetails::bad_request(
                 format!("Invalid request: {}", e),
-                &request_id,
-            )));
+                request_id,
+            ));
         }
     };
 
@@ -101,8 +168,8 @@ async fn handler(event: LambdaEvent<Value>) -> Result<Response, Error> {
     );
 
     // Validate the request
-    if let Err(problem) = request.validate(&request_id) {
-        return Ok(Response::Error(*problem));
+    if let Err(problem) = request.validate(request_id) {
+        return Response::Error(*problem);
     }
 
     let runtime = get_runtime();
@@ -114,44 +181,75 @@ async fn handler(event: LambdaEvent<Value>) -> Result<Response, Error> {
         Some(id) => id,
         None => {
             let suggestions = starmap.fuzzy_system_matches(&request.system, 3);
-            return Ok(Response::Error(ProblemDetails::unknown_system(
+            return Response::Error(ProblemDetails::unknown_system(
                 &request.system,
                 &suggestions,
-                &request_id,
-            )));
+                request_id,
+            ));
         }
     };
 
     // Get the system's position
-    let system = starmap
-        .systems
-        .get(&system_id)
-        .ok_or_else(|| Error::from(format!("System {} found but not in starmap", system_id)))?;
+    let Some(system) = starmap.systems.get(&system_id) else {
+        return Response::Error(ProblemDetails::internal_error(
+            format!("System {} found but not in starmap", system_id),
+            request_id,
+        ));
+    };
 
     let position = match system.position {
         Some(pos) => [pos.x, pos.y, pos.z],
         None => {
-            return Ok(Response::Error(ProblemDetails::bad_request(
+            return Response::Error(ProblemDetails::bad_request(
                 format!("System '{}' has no spatial coordinates", request.system),
-                &request_id,
-            )));
+                request_id,
+            ));
         }
     };
 
-    // Build the query
+    let systems = nearby_systems(starmap, spatial_index, system_id, position, &request);
+
+    let response = ScoutRangeResponse {
+        system: request.system.clone(),
+        system_id,
+        count: systems.len(),
+        systems,
+    };
+
+    info!(
+        request_id = %request_id,
+        system = %request.system,
+        systems_found = response.count,
+        "range query completed"
+    );
+
+    Response::Success(LambdaResponse::new(response).with_dataset(runtime.dataset_metadata()))
+}
+
+/// Find systems near `position`, ordered by distance.
+///
+/// The origin is excluded unless `request.include_origin` is set, in which case it comes first
+/// at distance 0 (even when it would fail the temperature filter) and counts toward the limit.
+fn nearby_systems(
+    starmap: &Starmap,
+    spatial_index: &SpatialIndex,
+    origin: SystemId,
+    position: [f64; 3],
+    request: &ScoutRangeRequest,
+) -> Vec<NearbySystem> {
     let query = NeighbourQuery {
         k: request.limit + 1, // +1 to exclude the origin system
         radius: request.radius,
         max_temperature: request.max_temperature,
     };
 
-    // Find nearby systems
     let results = spatial_index.nearest_filtered(position, &query);
 
-    // Convert to response, excluding the origin system
-    let systems: Vec<NearbySystem> = results
+    request
+        .include_origin
+        .then_some((origin, 0.0))
         .into_iter()
-        .filter(|(id, _)| *id != system_id)
+        .chain(results.into_iter().filter(|(id, _)| *id != origin))
         .take(request.limit)
         .filter_map(|(id, distance)| {
             let name = starmap.system_name(id)?;
@@ -166,23 +264,7 @@ async fn handler(event: LambdaEvent<Value>) -> Result<Response, Error> {
                 min_temp_k,
             })
         })
-        .collect();
-
-    let response = ScoutRangeResponse {
-        system: request.system.clone(),
-        system_id,
-        count: systems.len(),
-        systems,
-    };
-
-    info!(
-        request_id = %request_id,
-        system = %request.system,
-        systems_found = response.count,
-        "range query completed"
-    );
-
-    Ok(Response::Success(LambdaResponse::new(response)))
+        .collect()
 }
 
 #[cfg(test)]
@@ -213,6 +295,7 @@ mod tests {
         assert_eq!(request.limit, 10); // default
         assert!(request.radius.is_none());
         assert!(request.max_temperature.is_none());
+        assert!(!request.include_origin);
     }
 
     #[test]
@@ -246,6 +329,7 @@ mod tests {
             limit: 10,
             radius: None,
             max_temperature: None,
+            include_origin: false,
         };
         assert!(request.validate("test-req").is_ok());
     }
@@ -257,6 +341,7 @@ mod tests {
             limit: 10,
             radius: None,
             max_temperature: None,
+            include_origin: false,
         };
         let err = request.validate("test-req").unwrap_err();
         assert_eq!(err.status, 400);
@@ -269,6 +354,7 @@ mod tests {
             limit: 0,
             radius: None,
             max_temperature: None,
+            include_origin: false,
         };
         let err = request.validate("test-req").unwrap_err();
         assert_eq!(err.status, 400);
@@ -282,6 +368,7 @@ mod tests {
             limit: 101,
             radius: None,
             max_temperature: None,
+            include_origin: false,
         };
         let err = request.validate("test-req").unwrap_err();
         assert_eq!(err.status, 400);
@@ -295,6 +382,7 @@ mod tests {
             limit: 10,
             radius: Some(-50.0),
             max_temperature: None,
+            include_origin: false,
         };
         let err = request.validate("test-req").unwrap_err();
         assert_eq!(err.status, 400);
@@ -308,6 +396,7 @@ mod tests {
             limit: 10,
             radius: None,
             max_temperature: Some(-100.0),
+            include_origin: false,
         };
         let err = request.validate("test-req").unwrap_err();
         assert_eq!(err.status, 400);
@@ -388,6 +477,58 @@ mod tests {
         );
     }
 
+    fn nod_range_request(include_origin: bool) -> ScoutRangeRequest {
+        ScoutRangeRequest {
+            system: "Nod".to_string(),
+            limit: 5,
+            radius: None,
+            max_temperature: None,
+            include_origin,
+        }
+    }
+
+    fn nearby_from_nod(include_origin: bool) -> (SystemId, Vec<NearbySystem>) {
+        let starmap = fixture_starmap();
+        let nod = starmap.system_id_by_name("Nod").expect("Nod exists");
+        let position = starmap.systems[&nod].position.expect("Nod has position");
+        let systems = nearby_systems(
+            starmap,
+            fixture_spatial_index(),
+            nod,
+            [position.x, position.y, position.z],
+            &nod_range_request(include_origin),
+        );
+        (nod, systems)
+    }
+
+    #[test]
+    fn test_origin_excluded_by_default() {
+        let (nod, systems) = nearby_from_nod(false);
+        assert!(!systems.is_empty());
+        assert!(systems.iter().all(|s| s.id != nod));
+        assert!(systems.len() <= 5);
+    }
+
+    #[test]
+    fn test_include_origin_sorts_origin_first_at_zero() {
+        let (nod, systems) = nearby_from_nod(true);
+        assert_eq!(systems[0].id, nod);
+        assert_eq!(systems[0].name, "Nod");
+        assert_eq!(systems[0].distance_ly, 0.0);
+        assert_eq!(systems.iter().filter(|s| s.id == nod).count(), 1);
+        assert!(systems.len() <= 5);
+
+        let (_, without_origin) = nearby_from_nod(false);
+        assert_eq!(systems[1].id, without_origin[0].id);
+    }
+
+    #[test]
+    fn test_parse_include_origin() {
+        let request: ScoutRangeRequest =
+            serde_json::from_value(json!({"system": "Nod", "include_origin": true})).unwrap();
+        assert!(request.include_origin);
+    }
+
     #[test]
     fn test_lookup_unknown_system() {
         let starmap = fixture_starmap();
@@ -462,4 +603,78 @@ mod tests {
         assert_eq!(json["status"], 404);
         assert!(json["title"].as_str().unwrap().contains("Unknown"));
     }
+
+    // ==================== Batch Tests ====================
+
+    #[test]
+    fn test_single_object_payload_is_not_batched() {
+        test_utils::fixture_lambda_runtime();
+
+        let response = handle_payload(json!({"system": "Nod", "limit": 2}), "req-single");
+        match response {
+            Response::Success(inner) => assert_eq!(inner.data.system, "Nod"),
+            other => panic!("expected a single success, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_batch_answers_each_entry_in_order() {
+        test_utils::fixture_lambda_runtime();
+
+        let payload = json!([
+            {"system": "Nod", "limit": 2},
+            {"system": "NonExistentSystem12345"},
+            {"system": "Brana", "limit": 0},
+            {"limit": 3},
+            "Nod",
+            {"system": "Brana", "limit": 1},
+        ]);
+        let Response::Batch(responses) = handle_payload(payload, "req-batch") else {
+            panic!("array payload must produce a batch response");
+        };
+        assert_eq!(responses.len(), 6);
+
+        let status = |response: &Response| match response {
+            Response::Error(problem) => problem.status,
+            Response::Success(_) => 200,
+            other => panic!("unexpected batch entry {:?}", other),
+        };
+        let statuses: Vec<u16> = responses.iter().map(status).collect();
+        assert_eq!(statuses, vec![200, 404, 400, 400, 400, 200]);
+
+        match (&responses[0], &responses[5]) {
+            (Response::Success(nod), Response::Success(brana)) => {
+                assert_eq!(nod.data.system, "Nod");
+                assert_eq!(nod.data.count, 2);
+                assert_eq!(brana.data.system, "Brana");
+                assert_eq!(brana.data.count, 1);
+            }
+            other => panic!("expected successes, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_batch_serializes_as_array() {
+        test_utils::fixture_lambda_runtime();
+
+        let response = handle_payload(
+            json!([{"system": "Nod", "limit": 1}, {"system": ""}]),
+            "req-batch-json",
+        );
+        let json = serde_json::to_value(&response).unwrap();
+        let entries = json.as_array().expect("batch serializes as an array");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0]["system"], "Nod");
+        assert_eq!(entries[1]["status"], 400);
+    }
+
+    #[test]
+    fn test_batch_rejects_empty_and_oversized_arrays() {
+        let empty = handle_payload(json!([]), "req-empty");
+        assert!(matches!(empty, Response::Error(ref p) if p.status == 400));
+
+        let oversized = Value::Array(vec![json!({"system": "Nod"}); MAX_BATCH_SIZE + 1]);
+        let response = handle_payload(oversized, "req-oversized");
+        assert!(matches!(response, Response::Error(ref p) if p.status == 400));
+    }
 }
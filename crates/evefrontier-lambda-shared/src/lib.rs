@@ -7,6 +7,7 @@
 //! - [`ProblemDetails`]: RFC 9457 Problem Details for consistent error responses
 //! - [`LambdaResponse`]: Wrapper for successful responses with content type
 //! - Request types with validation for each Lambda endpoint
+//! - [`is_warmup_event`] / [`WarmupResponse`]: keep-alive pings that skip endpoint logic
 //!
 //! # Testing Support
 //!
@@ -20,16 +21,24 @@ mod requests;
 mod response;
 mod runtime;
 mod tracing_init;
+mod warmup;
 
 #[cfg(any(test, feature = "test-utils"))]
 pub mod test_utils;
 
 pub use problem::{
-    from_lib_error, ProblemDetails, PROBLEM_INTERNAL_ERROR, PROBLEM_INVALID_REQUEST,
-    PROBLEM_ROUTE_NOT_FOUND, PROBLEM_SERVICE_UNAVAILABLE, PROBLEM_UNKNOWN_SYSTEM,
+    from_lib_error, instance_urn, problem_type_for_kind, ProblemDetails, CONTENT_TYPE_PROBLEM_JSON,
+    INSTANCE_URN_PREFIX, PROBLEM_INTERNAL_ERROR, PROBLEM_INVALID_REQUEST, PROBLEM_ROUTE_NOT_FOUND,
+    PROBLEM_SERVICE_UNAVAILABLE, PROBLEM_TYPES, PROBLEM_TYPE_BASE_URL, PROBLEM_UNKNOWN_SYSTEM,
 };
 pub use requests::RouteOptimization;
-pub use requests::{RouteAlgorithm, RouteRequest, ScoutGatesRequest, ScoutRangeRequest, Validate};
+pub use requests::{
+    RouteAlgorithm, RouteRequest, ScoutGatesRequest, ScoutRangeRequest, Validate,
+    MAX_SCOUT_GATES_DEPTH,
+};
 pub use response::LambdaResponse;
+#[cfg(any(test, feature = "test-utils"))]
+pub use runtime::reset_runtime;
 pub use runtime::{get_runtime, init_error_to_problem, init_runtime, InitError, LambdaRuntime};
 pub use tracing_init::init_tracing;
+pub use warmup::{is_warmup_event, WarmupResponse, WARMUP_STATUS};
@@ -30,15 +30,16 @@ pub trait Validate {
 /// Request for computing a route between two systems.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RouteRequest {
-    /// Starting system name.
+    /// Starting system name or numeric system ID.
     pub from: String,
 
-    /// Destination system name.
+    /// Destination system name or numeric system ID.
     pub to: String,
 
-    /// Routing algorithm to use.
-    #[serde(default)]
-    pub algorithm: RouteAlgorithm,
+    /// Routing algorithm to use; omitted means [`RouteAlgorithm::default`] (A*).
+    /// Use [`RouteRequest::algorithm`] to read the effective value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<RouteAlgorithm>,
 
     /// Maximum jump distance in light-years (for spatial routes).
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -73,9 +74,10 @@ pub struct RouteRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fuel_load: Option<f64>,
 
-    /// Optional heat calibration constant to scale heat energy calculations.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    // `heat_calibration` removed: calibration is fixed server-side to 1e-7
+    /// Heat calibration constant scaling jump heat energy. Defaults to the library's
+    /// `HeatConfig` calibration (`1e-7`) when omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub heat_calibration: Option<f64>,
 
     /// Enable per-hop dynamic mass recalculation.
     #[serde(default)]
@@ -92,6 +94,23 @@ pub struct RouteRequest {
     /// Optional optimization objective: distance or fuel.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub optimization: Option<RouteOptimization>,
+
+    /// Include a per-hop `rationale` in the response. Off by default to keep responses small.
+    #[serde(default)]
+    pub explain: bool,
+}
+
+impl RouteRequest {
+    /// Algorithm to plan with, applying the A* default when the request omits one.
+    pub fn algorithm(&self) -> RouteAlgorithm {
+        self.algorithm.unwrap_or_default()
+    }
+
+    /// Heat calibration constant to project with, applying the library default.
+    pub fn heat_calibration(&self) -> f64 {
+        self.heat_calibration
+            .unwrap_or_else(|| evefrontier_lib::ship::HeatConfig::default().calibration_constant)
+    }
 }
 
 fn default_true() -> bool {
@@ -221,17 +240,38 @@ impl Validate for RouteRequest {
             }
         }
 
-        // No validation required; calibration is fixed.
+        if let Some(calibration) = self.heat_calibration {
+            if !calibration.is_finite() || calibration <= 0.0 {
+                return Err(Box::new(ProblemDetails::bad_request(
+                    "The 'heat_calibration' field must be a positive, finite number",
+                    request_id,
+                )));
+            }
+        }
 
         Ok(())
     }
 }
 
+/// Maximum gate-hop depth accepted by [`ScoutGatesRequest`], bounding result size.
+pub const MAX_SCOUT_GATES_DEPTH: usize = 3;
+
 /// Request for finding gate-connected neighbors of a system.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScoutGatesRequest {
     /// System name or ID to find neighbors for.
     pub system: String,
+
+    /// Number of gate hops to explore (default 1, max [`MAX_SCOUT_GATES_DEPTH`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub depth: Option<usize>,
+}
+
+impl ScoutGatesRequest {
+    /// Gate-hop depth to search, applying the default of 1.
+    pub fn depth(&self) -> usize {
+        self.depth.unwrap_or(1)
+    }
 }
 
 impl Validate for ScoutGatesRequest {
@@ -242,6 +282,20 @@ impl Validate for ScoutGatesRequest {
                 request_id,
             )));
         }
+
+        if self.depth == Some(0) {
+            return Err(Box::new(ProblemDetails::bad_request(
+                "The 'depth' field must be at least 1",
+                request_id,
+            )));
+        }
+
+        if self.depth() > MAX_SCOUT_GATES_DEPTH {
+            return Err(Box::new(ProblemDetails::bad_request(
+                format!("The 'depth' field cannot exceed {}", MAX_SCOUT_GATES_DEPTH),
+                request_id,
+            )));
+        }
         Ok(())
     }
 }
@@ -263,6 +317,10 @@ pub struct ScoutRangeRequest {
     /// Maximum star temperature threshold in Kelvin.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_temperature: Option<f64>,
+
+    /// Include the origin system itself as the first result, at distance 0.
+    #[serde(default)]
+    pub include_origin: bool,
 }
 
 fn default_limit() -> usize {
@@ -323,7 +381,7 @@ mod tests {
         let request = RouteRequest {
             from: "Nod".to_string(),
             to: "Brana".to_string(),
-            algorithm: RouteAlgorithm::AStar,
+            algorithm: Some(RouteAlgorithm::AStar),
             max_jump: Some(80.0),
             avoid: vec![],
             avoid_gates: false,
@@ -332,10 +390,12 @@ mod tests {
             fuel_quality: None,
             cargo_mass: None,
             fuel_load: None,
+            heat_calibration: None,
             dynamic_mass: None,
             avoid_critical_state: true,
             max_spatial_neighbors: None,
             optimization: None,
+            explain: false,
         };
         assert!(request.validate("req-123").is_ok());
     }
@@ -345,7 +405,7 @@ mod tests {
         let request = RouteRequest {
             from: "".to_string(),
             to: "Brana".to_string(),
-            algorithm: RouteAlgorithm::AStar,
+            algorithm: Some(RouteAlgorithm::AStar),
             max_jump: None,
             avoid: vec![],
             avoid_gates: false,
@@ -354,10 +414,12 @@ mod tests {
             fuel_quality: None,
             cargo_mass: None,
             fuel_load: None,
+            heat_calibration: None,
             dynamic_mass: None,
             avoid_critical_state: true,
             max_spatial_neighbors: None,
             optimization: None,
+            explain: false,
         };
         let err = request.validate("req-123").unwrap_err();
         assert_eq!(err.status, 400);
@@ -369,7 +431,7 @@ mod tests {
         let request = RouteRequest {
             from: "Nod".to_string(),
             to: "".to_string(),
-            algorithm: RouteAlgorithm::AStar,
+            algorithm: Some(RouteAlgorithm::AStar),
             max_jump: None,
             avoid: vec![],
             avoid_gates: false,
@@ -378,10 +440,12 @@ mod tests {
             fuel_quality: None,
             cargo_mass: None,
             fuel_load: None,
+            heat_calibration: None,
             dynamic_mass: None,
             avoid_critical_state: true,
             max_spatial_neighbors: None,
             optimization: None,
+            explain: false,
         };
         let err = request.validate("req-123").unwrap_err();
         assert_eq!(err.status, 400);
@@ -393,7 +457,7 @@ mod tests {
         let request = RouteRequest {
             from: "Nod".to_string(),
             to: "Brana".to_string(),
-            algorithm: RouteAlgorithm::Dijkstra,
+            algorithm: Some(RouteAlgorithm::Dijkstra),
             max_jump: Some(-10.0),
             avoid: vec![],
             avoid_gates: false,
@@ -402,23 +466,87 @@ mod tests {
             fuel_quality: None,
             cargo_mass: None,
             fuel_load: None,
+            heat_calibration: None,
             dynamic_mass: None,
             avoid_critical_state: true,
             max_spatial_neighbors: None,
             optimization: None,
+            explain: false,
         };
         let err = request.validate("req-123").unwrap_err();
         assert!(err.detail.unwrap().contains("positive number"));
     }
 
+    #[test]
+    fn test_route_request_rejects_invalid_heat_calibration() {
+        for calibration in [0.0, -1e-7, f64::NAN, f64::INFINITY] {
+            let request = RouteRequest {
+                from: "Nod".to_string(),
+                to: "Brana".to_string(),
+                algorithm: Some(RouteAlgorithm::AStar),
+                max_jump: None,
+                avoid: vec![],
+                avoid_gates: false,
+                max_temperature: None,
+                ship: None,
+                fuel_quality: None,
+                cargo_mass: None,
+                fuel_load: None,
+                heat_calibration: Some(calibration),
+                dynamic_mass: None,
+                avoid_critical_state: true,
+                max_spatial_neighbors: None,
+                optimization: None,
+                explain: false,
+            };
+            let err = request.validate("req-123").unwrap_err();
+            assert_eq!(err.status, 400, "{calibration} accepted");
+            assert!(err.detail.unwrap().contains("'heat_calibration' field"));
+        }
+    }
+
+    #[test]
+    fn test_route_request_heat_calibration_defaults() {
+        let mut request: RouteRequest =
+            serde_json::from_value(serde_json::json!({"from": "Nod", "to": "Brana"}))
+                .expect("minimal request parses");
+        assert_eq!(request.heat_calibration(), 1e-7);
+        assert!(request.validate("req-123").is_ok());
+
+        request.heat_calibration = Some(2e-7);
+        assert_eq!(request.heat_calibration(), 2e-7);
+        assert!(request.validate("req-123").is_ok());
+    }
+
     #[test]
     fn test_scout_gates_request_valid() {
         let request = ScoutGatesRequest {
             system: "Nod".to_string(),
+            depth: None,
         };
         assert!(request.validate("req-456").is_ok());
     }
 
+    #[test]
+    fn test_scout_gates_request_depth_bounds() {
+        let json = r#"{"system": "Nod"}"#;
+        let request: ScoutGatesRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.depth(), 1);
+
+        for (depth, ok) in [
+            (0, false),
+            (1, true),
+            (MAX_SCOUT_GATES_DEPTH, true),
+            (4, false),
+        ] {
+            let request = ScoutGatesRequest {
+                system: "Nod".to_string(),
+                depth: Some(depth),
+            };
+            assert_eq!(request.validate("test").is_ok(), ok, "depth {}", depth);
+        }
+    }
+
     #[test]
     fn test_scout_range_request_valid() {
         let request = ScoutRangeRequest {
@@ -426,6 +554,7 @@ mod tests {
             radius: Some(80.0),
             max_temperature: None,
             limit: 10,
+            include_origin: false,
         };
         assert!(request.validate("req-789").is_ok());
     }
@@ -473,7 +602,7 @@ mod tests {
         let req = RouteRequest {
             from: "Nod".to_string(),
             to: "Brana".to_string(),
-            algorithm: RouteAlgorithm::Dijkstra,
+            algorithm: Some(RouteAlgorithm::Dijkstra),
             max_jump: Some(50.0),
             avoid: vec!["System1".to_string(), "System2".to_string()],
             avoid_gates: true,
@@ -482,10 +611,12 @@ mod tests {
             fuel_quality: None,
             cargo_mass: None,
             fuel_load: None,
+            heat_calibration: None,
             dynamic_mass: None,
             avoid_critical_state: true,
             max_spatial_neighbors: None,
             optimization: None,
+            explain: false,
         };
         assert!(req.validate("req-constraints").is_ok());
     }
@@ -495,7 +626,7 @@ mod tests {
         let req = RouteRequest {
             from: "Nod".to_string(),
             to: "Brana".to_string(),
-            algorithm: RouteAlgorithm::AStar,
+            algorithm: Some(RouteAlgorithm::AStar),
             max_jump: None,
             avoid: vec![],
             avoid_gates: false,
@@ -504,10 +635,12 @@ mod tests {
             fuel_quality: None,
             cargo_mass: None,
             fuel_load: None,
+            heat_calibration: None,
             dynamic_mass: None,
             avoid_critical_state: true,
             max_spatial_neighbors: None,
             optimization: None,
+            explain: false,
         };
         let err = req.validate("req-neg-temp").unwrap_err();
         assert!(err.detail.unwrap().contains("max_temperature"));
@@ -518,7 +651,7 @@ mod tests {
         let req = RouteRequest {
             from: "Nod".to_string(),
             to: "Brana".to_string(),
-            algorithm: RouteAlgorithm::AStar,
+            algorithm: Some(RouteAlgorithm::AStar),
             max_jump: None,
             avoid: vec![],
             avoid_gates: false,
@@ -527,10 +660,12 @@ mod tests {
             fuel_quality: Some(10.0),
             cargo_mass: Some(1000.0),
             fuel_load: Some(500.0),
+            heat_calibration: None,
             dynamic_mass: Some(true),
             avoid_critical_state: true,
             max_spatial_neighbors: None,
             optimization: None,
+            explain: false,
         };
         assert!(req.validate("req-ship").is_ok());
     }
@@ -540,7 +675,7 @@ mod tests {
         let req = RouteRequest {
             from: "Nod".to_string(),
             to: "Brana".to_string(),
-            algorithm: RouteAlgorithm::AStar,
+            algorithm: Some(RouteAlgorithm::AStar),
             max_jump: None,
             avoid: vec![],
             avoid_gates: false,
@@ -549,10 +684,12 @@ mod tests {
             fuel_quality: Some(150.0),
             cargo_mass: None,
             fuel_load: None,
+            heat_calibration: None,
             dynamic_mass: None,
             avoid_critical_state: true,
             max_spatial_neighbors: None,
             optimization: None,
+            explain: false,
         };
         let err = req.validate("req-fuel-quality").unwrap_err();
         assert!(err.detail.unwrap().contains("fuel_quality"));
@@ -565,6 +702,7 @@ mod tests {
             limit: 10,
             radius: Some(-100.0),
             max_temperature: None,
+            include_origin: false,
         };
         let err = req.validate("req-neg-radius").unwrap_err();
         assert!(err.detail.unwrap().contains("radius"));
@@ -577,6 +715,7 @@ mod tests {
             limit: 0,
             radius: None,
             max_temperature: None,
+            include_origin: false,
         };
         let err = req.validate("req-zero-limit").unwrap_err();
         assert!(err.detail.unwrap().contains("limit"));
@@ -589,6 +728,7 @@ mod tests {
             radius: Some(80.0),
             max_temperature: None,
             limit: 200,
+            include_origin: false,
         };
         let err = req.validate("req-limit-too-high").unwrap_err();
         assert!(err.detail.unwrap().contains("limit"));
@@ -601,7 +741,16 @@ mod tests {
         assert_eq!(request.from, "Nod");
         assert_eq!(request.to, "Brana");
         assert!(request.avoid_critical_state);
-        assert_eq!(request.algorithm, RouteAlgorithm::AStar);
+        assert_eq!(request.algorithm, None);
+        assert_eq!(request.algorithm(), RouteAlgorithm::AStar);
+
+        // An omitted algorithm is not written back out, so it round-trips as omitted.
+        let json = serde_json::to_value(&request).unwrap();
+        assert!(json.get("algorithm").is_none());
+
+        let request: RouteRequest =
+            serde_json::from_str(r#"{"from": "Nod", "to": "Brana", "algorithm": "bfs"}"#).unwrap();
+        assert_eq!(request.algorithm(), RouteAlgorithm::Bfs);
     }
 
     // =========================================================================
@@ -612,7 +761,7 @@ mod tests {
         RouteRequest {
             from: "Nod".to_string(),
             to: "Brana".to_string(),
-            algorithm: RouteAlgorithm::AStar,
+            algorithm: Some(RouteAlgorithm::AStar),
             max_jump: None,
             avoid: vec![],
             avoid_gates: false,
@@ -621,10 +770,12 @@ mod tests {
             fuel_quality: None,
             cargo_mass: None,
             fuel_load: None,
+            heat_calibration: None,
             dynamic_mass: None,
             avoid_critical_state: true,
             max_spatial_neighbors: None,
             optimization: None,
+            explain: false,
         }
     }
 
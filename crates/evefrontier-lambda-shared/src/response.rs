@@ -1,5 +1,6 @@
 //! Response wrapper for successful Lambda responses.
 
+use evefrontier_lib::spatial::DatasetMetadata;
 use serde::{Deserialize, Serialize};
 
 /// Wrapper for successful Lambda responses with content type metadata.
@@ -30,15 +31,20 @@ pub struct LambdaResponse<T> {
 
     /// Content type for this response.
     pub content_type: String,
+
+    /// First 16 hex characters of the dataset checksum that answered the request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dataset_checksum: Option<String>,
+
+    /// Release tag of the dataset that answered the request, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dataset_release: Option<String>,
 }
 
 impl<T> LambdaResponse<T> {
     /// Create a new successful response with the default content type.
     pub fn new(data: T) -> Self {
-        Self {
-            data,
-            content_type: "application/json".to_string(),
-        }
+        Self::with_content_type(data, "application/json")
     }
 
     /// Create a response with a custom content type.
@@ -46,8 +52,17 @@ impl<T> LambdaResponse<T> {
         Self {
             data,
             content_type: content_type.into(),
+            dataset_checksum: None,
+            dataset_release: None,
         }
     }
+
+    /// Tag the response with the dataset version it was computed from.
+    pub fn with_dataset(mut self, metadata: &DatasetMetadata) -> Self {
+        self.dataset_checksum = Some(metadata.short_checksum());
+        self.dataset_release = metadata.release_tag.clone();
+        self
+    }
 }
 
 impl<T> From<T> for LambdaResponse<T> {
@@ -117,5 +132,27 @@ mod tests {
         let response: LambdaResponse<TestData> = serde_json::from_str(json).unwrap();
         assert_eq!(response.data.value, 42);
         assert_eq!(response.content_type, "application/json");
+        assert!(response.dataset_checksum.is_none());
+    }
+
+    #[test]
+    fn test_response_with_dataset() {
+        let metadata = DatasetMetadata {
+            checksum: [0xab; 32],
+            release_tag: Some("e6c3".to_string()),
+            build_timestamp: 0,
+        };
+        let response = LambdaResponse::new(TestData { value: 1 }).with_dataset(&metadata);
+        let json = serde_json::to_value(&response).unwrap();
+
+        assert_eq!(json["dataset_checksum"], "abababababababab");
+        assert_eq!(json["dataset_release"], "e6c3");
+    }
+
+    #[test]
+    fn test_response_omits_unknown_dataset() {
+        let json = serde_json::to_string(&LambdaResponse::new(TestData { value: 1 })).unwrap();
+        assert!(!json.contains("dataset_checksum"));
+        assert!(!json.contains("dataset_release"));
     }
 }
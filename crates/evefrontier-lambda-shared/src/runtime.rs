@@ -21,8 +21,19 @@
 //! - `db_load_ms`: Time to deserialize SQLite database and load starmap
 //! - `index_load_ms`: Time to decompress and load spatial index
 //! - `total_init_ms`: Total initialization time
-
-use std::sync::{Arc, OnceLock};
+//!
+//! # Dataset Version
+//!
+//! The runtime records [`DatasetMetadata`] for the bundled dataset so handlers can
+//! report it in responses (see `LambdaResponse::with_dataset`). It comes from the
+//! spatial index when the index embeds source metadata, and otherwise from hashing
+//! the bundled database bytes.
+
+use std::sync::Arc;
+#[cfg(not(any(test, feature = "test-utils")))]
+use std::sync::OnceLock;
+#[cfg(any(test, feature = "test-utils"))]
+use std::sync::{PoisonError, RwLock};
 use std::time::Instant;
 
 use rusqlite::Connection;
@@ -30,14 +41,56 @@ use tracing::{error, info};
 
 use evefrontier_lib::db::{load_starmap_from_connection, Starmap};
 use evefrontier_lib::ship::ShipCatalog;
-use evefrontier_lib::spatial::SpatialIndex;
+use evefrontier_lib::spatial::{
+    compute_dataset_checksum_from_bytes, DatasetMetadata, SpatialIndex,
+};
 use evefrontier_lib::temperature::TemperatureMethod;
 use evefrontier_lib::Error as LibError;
 
 use crate::problem::ProblemDetails;
 
+/// Outcome of runtime initialization, cached for the life of the process.
+type RuntimeResult = Result<LambdaRuntime, InitError>;
+
 /// Lazily-initialized Lambda runtime state.
-static RUNTIME: OnceLock<Result<LambdaRuntime, InitError>> = OnceLock::new();
+#[cfg(not(any(test, feature = "test-utils")))]
+static RUNTIME: OnceLock<RuntimeResult> = OnceLock::new();
+
+/// Resettable runtime state for tests; `None` until `init_runtime` runs.
+///
+/// The initialization result is leaked so accessors can hand out `&'static` references
+/// that stay valid across `reset_runtime`, which leaks the previous state.
+#[cfg(any(test, feature = "test-utils"))]
+static RUNTIME: RwLock<Option<&'static RuntimeResult>> = RwLock::new(None);
+
+/// The cached initialization result, if `init_runtime` has run.
+#[cfg(not(any(test, feature = "test-utils")))]
+fn runtime_slot() -> Option<&'static RuntimeResult> {
+    RUNTIME.get()
+}
+
+/// The cached initialization result, running `init` first if there is none yet.
+#[cfg(not(any(test, feature = "test-utils")))]
+fn runtime_slot_or_init(init: impl FnOnce() -> RuntimeResult) -> &'static RuntimeResult {
+    RUNTIME.get_or_init(init)
+}
+
+/// The cached initialization result, if `init_runtime` has run (tolerates a poisoned lock).
+#[cfg(any(test, feature = "test-utils"))]
+fn runtime_slot() -> Option<&'static RuntimeResult> {
+    *RUNTIME.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// The cached initialization result, running `init` first if there is none yet.
+#[cfg(any(test, feature = "test-utils"))]
+fn runtime_slot_or_init(init: impl FnOnce() -> RuntimeResult) -> &'static RuntimeResult {
+    if let Some(result) = runtime_slot() {
+        return result;
+    }
+    // Another caller may initialize while we wait for the write lock; keep theirs.
+    let mut slot = RUNTIME.write().unwrap_or_else(PoisonError::into_inner);
+    *slot.get_or_insert_with(|| &*Box::leak(Box::new(init())))
+}
 
 /// Error during runtime initialization.
 #[derive(Debug, Clone)]
@@ -77,6 +130,7 @@ pub struct LambdaRuntime {
     starmap: Starmap,
     spatial_index: Arc<SpatialIndex>,
     ship_catalog: Option<ShipCatalog>,
+    dataset_metadata: DatasetMetadata,
 }
 
 impl LambdaRuntime {
@@ -99,101 +153,40 @@ impl LambdaRuntime {
     pub fn ship_catalog(&self) -> Option<&ShipCatalog> {
         self.ship_catalog.as_ref()
     }
+
+    /// Metadata identifying the bundled dataset (checksum and release tag).
+    pub fn dataset_metadata(&self) -> &DatasetMetadata {
+        &self.dataset_metadata
+    }
 }
 
 /// Initialize the Lambda runtime from bundled data.
 ///
-/// This function is designed to be called by `include_bytes!` data from each
-/// Lambda handler. It uses rusqlite's serialize feature to load the database
-/// directly from memory.
-///
-/// # Arguments
+/// Loads the database with rusqlite's serialize feature directly from memory,
+/// then the spatial index and optional ship catalog.
 ///
 /// * `db_bytes` - SQLite database bytes (from `include_bytes!`)
 /// * `index_bytes` - Spatial index bytes (from `include_bytes!`)
+/// * `ship_bytes` - Optional ship CSV bytes (from `include_bytes!`), may be empty.
 ///
-/// # Returns
+/// # Idempotency
 ///
-/// Returns a reference to the initialized `LambdaRuntime`, or an `InitError`
-/// if initialization fails.
+/// Initialization is first-wins: the first call loads the data and every later call
+/// returns that same runtime, ignoring its arguments (even if they name a different
+/// fixture). Concurrent first calls are serialized so the data is loaded only once.
+/// Tests that need a different fixture must call `reset_runtime` first (available with
+/// `cfg(test)` or the `test-utils` feature).
 ///
 /// # Panics
 ///
-/// This function will panic if called more than once. Use `get_runtime()` for
-/// subsequent accesses.
-/// Initialize the Lambda runtime from bundled data.
-///
-/// * `db_bytes` - SQLite database bytes (from `include_bytes!`)
-/// * `index_bytes` - Spatial index bytes (from `include_bytes!`)
-/// * `ship_bytes` - Optional ship CSV bytes (from `include_bytes!`), may be empty.
+/// Panics if initialization fails. The failure is cached, so `get_runtime()` keeps
+/// reporting it and `init_error_to_problem()` can surface it in responses.
 pub fn init_runtime(
     db_bytes: &'static [u8],
     index_bytes: &'static [u8],
     ship_bytes: &'static [u8],
 ) -> &'static LambdaRuntime {
-    let result = RUNTIME.get_or_init(|| {
-        let total_start = Instant::now();
-
-        info!(
-            db_size_bytes = db_bytes.len(),
-            index_size_bytes = index_bytes.len(),
-            "initializing Lambda runtime"
-        );
-
-        // Load database from bytes using rusqlite's serialize feature
-        let db_start = Instant::now();
-        let starmap = load_starmap_from_bytes(db_bytes)?;
-        let db_load_ms = db_start.elapsed().as_millis();
-
-        info!(
-            db_load_ms = db_load_ms,
-            system_count = starmap.systems.len(),
-            "starmap loaded from bundled database"
-        );
-
-        // Load spatial index from bytes
-        let index_start = Instant::now();
-        let spatial_index = load_spatial_index_from_bytes(index_bytes)?;
-        let index_load_ms = index_start.elapsed().as_millis();
-
-        info!(
-            index_load_ms = index_load_ms,
-            indexed_systems = spatial_index.len(),
-            "spatial index loaded"
-        );
-
-        let total_init_ms = total_start.elapsed().as_millis();
-        info!(
-            total_init_ms = total_init_ms,
-            db_load_ms = db_load_ms,
-            index_load_ms = index_load_ms,
-            "Lambda runtime initialization complete"
-        );
-
-        // Attempt to load ship catalog if bytes are provided. Failure to parse
-        // ship data should not fail entire runtime; log and continue without it.
-        let ship_catalog = if !ship_bytes.is_empty() {
-            match ShipCatalog::from_reader(std::io::Cursor::new(ship_bytes)) {
-                Ok(c) => {
-                    info!(ship_entries = c.ship_names().len(), "loaded bundled ship_data.csv");
-                    // keep source None since it's bundled bytes
-                    Some(c)
-                }
-                Err(e) => {
-                    info!(error = %e, "failed to parse bundled ship_data.csv; continuing without ship catalog");
-                    None
-                }
-            }
-        } else {
-            None
-        };
-
-        Ok(LambdaRuntime {
-            starmap,
-            spatial_index: Arc::new(spatial_index),
-            ship_catalog,
-        })
-    });
+    let result = runtime_slot_or_init(|| build_runtime(db_bytes, index_bytes, ship_bytes));
 
     match result {
         Ok(runtime) => runtime,
@@ -204,13 +197,108 @@ pub fn init_runtime(
     }
 }
 
+/// Load the starmap, spatial index, and optional ship catalog for a new runtime.
+fn build_runtime(
+    db_bytes: &'static [u8],
+    index_bytes: &'static [u8],
+    ship_bytes: &'static [u8],
+) -> Result<LambdaRuntime, InitError> {
+    let total_start = Instant::now();
+
+    info!(
+        db_size_bytes = db_bytes.len(),
+        index_size_bytes = index_bytes.len(),
+        "initializing Lambda runtime"
+    );
+
+    // Load database from bytes using rusqlite's serialize feature
+    let db_start = Instant::now();
+    let starmap = load_starmap_from_bytes(db_bytes)?;
+    let db_load_ms = db_start.elapsed().as_millis();
+
+    info!(
+        db_load_ms = db_load_ms,
+        system_count = starmap.systems.len(),
+        "starmap loaded from bundled database"
+    );
+
+    // Load spatial index from bytes
+    let index_start = Instant::now();
+    let spatial_index = load_spatial_index_from_bytes(index_bytes)?;
+    let index_load_ms = index_start.elapsed().as_millis();
+
+    info!(
+        index_load_ms = index_load_ms,
+        indexed_systems = spatial_index.len(),
+        "spatial index loaded"
+    );
+
+    // Prefer the metadata embedded in the index; hash the bundled database otherwise.
+    let dataset_metadata =
+        spatial_index
+            .source_metadata()
+            .cloned()
+            .unwrap_or_else(|| DatasetMetadata {
+                checksum: compute_dataset_checksum_from_bytes(db_bytes),
+                release_tag: None,
+                build_timestamp: 0,
+            });
+
+    let total_init_ms = total_start.elapsed().as_millis();
+    info!(
+        total_init_ms = total_init_ms,
+        db_load_ms = db_load_ms,
+        index_load_ms = index_load_ms,
+        dataset_checksum = %dataset_metadata.short_checksum(),
+        "Lambda runtime initialization complete"
+    );
+
+    // Attempt to load ship catalog if bytes are provided. Failure to parse
+    // ship data should not fail entire runtime; log and continue without it.
+    let ship_catalog = if !ship_bytes.is_empty() {
+        match ShipCatalog::from_reader(std::io::Cursor::new(ship_bytes)) {
+            Ok(c) => {
+                info!(
+                    ship_entries = c.ship_names().len(),
+                    "loaded bundled ship_data.csv"
+                );
+                // keep source None since it's bundled bytes
+                Some(c)
+            }
+            Err(e) => {
+                info!(error = %e, "failed to parse bundled ship_data.csv; continuing without ship catalog");
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    Ok(LambdaRuntime {
+        starmap,
+        spatial_index: Arc::new(spatial_index),
+        ship_catalog,
+        dataset_metadata,
+    })
+}
+
+/// Discard the current runtime so the next `init_runtime` call loads fresh data.
+///
+/// Test-only: previously returned references stay valid (their state is leaked), but
+/// tests running concurrently may observe the runtime as uninitialized, so callers
+/// should serialize tests that reset.
+#[cfg(any(test, feature = "test-utils"))]
+pub fn reset_runtime() {
+    *RUNTIME.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
 /// Get the initialized runtime.
 ///
 /// # Panics
 ///
 /// Panics if `init_runtime` has not been called or if initialization failed.
 pub fn get_runtime() -> &'static LambdaRuntime {
-    match RUNTIME.get() {
+    match runtime_slot() {
         Some(Ok(runtime)) => runtime,
         Some(Err(e)) => panic!("Lambda runtime initialization failed: {}", e),
         None => panic!("Lambda runtime not initialized. Call init_runtime() first."),
@@ -222,7 +310,7 @@ pub fn get_runtime() -> &'static LambdaRuntime {
 /// Use this when the handler fails during cold start to return a proper
 /// RFC 9457 error response.
 pub fn init_error_to_problem(request_id: &str) -> ProblemDetails {
-    match RUNTIME.get() {
+    match runtime_slot() {
         Some(Err(e)) => ProblemDetails::internal_error(e.message.clone(), request_id),
         _ => ProblemDetails::internal_error("Runtime initialization failed", request_id),
     }
@@ -257,7 +345,7 @@ fn load_starmap_from_bytes(db_bytes: &'static [u8]) -> Result<Starmap, InitError
 ///
 /// The index format includes a header, zstd-compressed data, and checksum.
 fn load_spatial_index_from_bytes(index_bytes: &[u8]) -> Result<SpatialIndex, InitError> {
-    SpatialIndex::load_from_bytes(index_bytes).map_err(|e| InitError {
+    SpatialIndex::from_bytes(index_bytes).map_err(|e| InitError {
         message: format!("Failed to load spatial index: {}", e),
     })
 }
@@ -265,6 +353,7 @@ fn load_spatial_index_from_bytes(index_bytes: &[u8]) -> Result<SpatialIndex, Ini
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::lock_runtime;
 
     // Note: These tests require the fixture database, which won't be available
     // in the Lambda build environment. Tests use conditional compilation.
@@ -286,6 +375,7 @@ mod tests {
 
     #[test]
     fn test_init_runtime_loads_ship_catalog() {
+        let _guard = lock_runtime();
         let runtime = init_runtime(
             crate::test_utils::fixture_db_bytes(),
             crate::test_utils::fixture_index_bytes(),
@@ -303,4 +393,63 @@ mod tests {
             "expected Reflex in ship catalog"
         );
     }
+
+    #[test]
+    fn test_init_runtime_records_dataset_checksum() {
+        let _guard = lock_runtime();
+        let runtime = init_runtime(
+            crate::test_utils::fixture_db_bytes(),
+            crate::test_utils::fixture_index_bytes(),
+            crate::test_utils::fixture_ship_bytes(),
+        );
+
+        // The fixture index carries no source metadata, so the database bytes are hashed.
+        let expected = evefrontier_lib::spatial::compute_dataset_checksum(
+            &crate::test_utils::fixture_db_path(),
+        )
+        .expect("fixture checksum");
+        assert_eq!(runtime.dataset_metadata().checksum, expected);
+        assert_eq!(runtime.dataset_metadata().release_tag, None);
+    }
+
+    #[test]
+    fn test_init_runtime_is_first_wins() {
+        let _guard = lock_runtime();
+        let first = init_runtime(
+            crate::test_utils::fixture_db_bytes(),
+            crate::test_utils::fixture_index_bytes(),
+            crate::test_utils::fixture_ship_bytes(),
+        );
+        // Different arguments are ignored once initialized.
+        let second = init_runtime(
+            crate::test_utils::fixture_db_bytes(),
+            crate::test_utils::fixture_index_bytes(),
+            &[],
+        );
+        assert!(std::ptr::eq(first, second));
+        assert!(std::ptr::eq(first, get_runtime()));
+    }
+
+    #[test]
+    fn test_reset_runtime_loads_new_fixture() {
+        let _guard = lock_runtime();
+
+        reset_runtime();
+        let without_ships = init_runtime(
+            crate::test_utils::fixture_db_bytes(),
+            crate::test_utils::fixture_index_bytes(),
+            &[],
+        );
+        assert!(without_ships.ship_catalog().is_none());
+
+        reset_runtime();
+        let with_ships = init_runtime(
+            crate::test_utils::fixture_db_bytes(),
+            crate::test_utils::fixture_index_bytes(),
+            crate::test_utils::fixture_ship_bytes(),
+        );
+        assert!(!std::ptr::eq(without_ships, with_ships));
+        assert!(with_ships.ship_catalog().is_some());
+        assert!(std::ptr::eq(with_ships, get_runtime()));
+    }
 }
@@ -9,7 +9,7 @@
 //!
 //! ```ignore
 //! use evefrontier_lambda_shared::test_utils::{
-//!     fixture_db_bytes, fixture_spatial_index, mock_request_id,
+//!     fixture_db_bytes, fixture_lambda_runtime, fixture_spatial_index, mock_request_id,
 //! };
 //!
 //! #[test]
@@ -28,10 +28,12 @@ use evefrontier_lib::db::Starmap;
 use evefrontier_lib::load_starmap;
 use evefrontier_lib::spatial::SpatialIndex;
 
+use crate::runtime::{init_runtime, LambdaRuntime};
+
 /// Path to the minimal test fixture database.
 ///
 /// Contains 8 systems: Nod, Brana, D:2NAS, G:3OA0, H:2L2S, J:35IA, Y:3R7E, E1J-M5G.
-fn fixture_db_path() -> PathBuf {
+pub fn fixture_db_path() -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../docs/fixtures/minimal/static_data.db")
 }
 
@@ -70,18 +72,13 @@ pub fn fixture_spatial_index() -> &'static SpatialIndex {
 /// Serialize the fixture spatial index for runtime initialization tests.
 ///
 /// Returns the serialized bytes of the spatial index, suitable for testing
-/// `SpatialIndex::load_from_bytes()`.
+/// `SpatialIndex::from_bytes()`.
 pub fn fixture_index_bytes() -> &'static [u8] {
     static BYTES: OnceLock<Vec<u8>> = OnceLock::new();
     BYTES.get_or_init(|| {
-        let index = fixture_spatial_index();
-        // Use tempfile crate for RAII-based cleanup that guarantees file removal
-        // when the NamedTempFile is dropped, even in failure scenarios.
-        let temp_file = tempfile::NamedTempFile::new().expect("failed to create temporary file");
-        let temp_path = temp_file.path();
-        index.save(temp_path).expect("index save should succeed");
-        // Read bytes before temp_file is dropped (which deletes the file)
-        std::fs::read(temp_path).expect("index read should succeed")
+        fixture_spatial_index()
+            .to_bytes()
+            .expect("index serialization should succeed")
     })
 }
 
@@ -95,6 +92,28 @@ pub fn fixture_ship_bytes() -> &'static [u8] {
     })
 }
 
+/// Initialize the process-wide Lambda runtime from the fixture data.
+///
+/// Passes the fixture database, the fixture spatial index, and the fixture ship data
+/// through [`init_runtime`], so Lambda tests share the cold-start path the handlers use.
+/// Later calls return the runtime that is already initialized.
+pub fn fixture_lambda_runtime() -> &'static LambdaRuntime {
+    init_runtime(
+        fixture_db_bytes(),
+        fixture_index_bytes(),
+        fixture_ship_bytes(),
+    )
+}
+
+/// Serializes tests in this crate that initialize or reset the process-wide runtime.
+#[cfg(test)]
+pub(crate) fn lock_runtime() -> std::sync::MutexGuard<'static, ()> {
+    static RUNTIME_TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+    RUNTIME_TEST_LOCK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
 /// Create a mock request ID for testing.
 ///
 /// Since `lambda_runtime::Context` is non-exhaustive and cannot be directly
@@ -185,6 +204,38 @@ mod tests {
         assert!(bytes.starts_with(b"EFSI"), "should have EFSI magic header");
     }
 
+    #[test]
+    fn fixture_spatial_index_builds_deterministically() {
+        assert_eq!(
+            fixture_spatial_index(),
+            &SpatialIndex::build(fixture_starmap())
+        );
+    }
+
+    #[test]
+    fn fixture_lambda_runtime_loads_fixture() {
+        let _guard = lock_runtime();
+        let runtime = fixture_lambda_runtime();
+
+        let starmap = runtime.starmap();
+        assert_eq!(starmap.systems.len(), 8);
+        let positioned = starmap
+            .systems
+            .values()
+            .filter(|system| system.position.is_some())
+            .count();
+        assert_eq!(runtime.spatial_index().len(), positioned);
+
+        let nod = starmap.system_id_by_name(systems::NOD).expect("Nod exists");
+        let position = runtime
+            .spatial_index()
+            .position(nod)
+            .expect("Nod is indexed")
+            .map(f64::from);
+        let nearest = runtime.spatial_index().nearest(position, 2);
+        assert_eq!(nearest.first().map(|(id, _)| *id), Some(nod));
+    }
+
     #[test]
     fn mock_request_id_formats_correctly() {
         let id = mock_request_id("123");
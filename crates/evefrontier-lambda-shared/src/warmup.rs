@@ -0,0 +1,56 @@
+//! Warm-up (keep-alive) events for Lambda handlers.
+//!
+//! A scheduled event can invoke a function with `{"warmup": true}` to keep an
+//! execution environment hot. Handlers answer with a [`WarmupResponse`] without
+//! running any endpoint logic.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Status reported by every warm-up response.
+pub const WARMUP_STATUS: &str = "warm";
+
+/// Returns `true` when `payload` is a warm-up ping (`{"warmup": true}`).
+///
+/// Any other value of `warmup`, including `false` or a string, is treated as a
+/// regular request so it goes through normal parsing and validation.
+pub fn is_warmup_event(payload: &Value) -> bool {
+    payload.get("warmup").and_then(Value::as_bool) == Some(true)
+}
+
+/// Minimal success body returned for warm-up pings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WarmupResponse {
+    /// Always [`WARMUP_STATUS`].
+    pub status: String,
+}
+
+impl WarmupResponse {
+    /// Create the `{"status": "warm"}` response.
+    pub fn warm() -> Self {
+        Self {
+            status: WARMUP_STATUS.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn detects_warmup_payload() {
+        assert!(is_warmup_event(&json!({"warmup": true})));
+        assert!(!is_warmup_event(&json!({"warmup": false})));
+        assert!(!is_warmup_event(&json!({"warmup": "true"})));
+        assert!(!is_warmup_event(&json!({"from": "Nod", "to": "Brana"})));
+        assert!(!is_warmup_event(&json!(null)));
+    }
+
+    #[test]
+    fn warm_response_serializes_status_only() {
+        let json = serde_json::to_value(WarmupResponse::warm()).unwrap();
+        assert_eq!(json, json!({"status": "warm"}));
+    }
+}
@@ -0,0 +1,15 @@
+//! Fixture loading shared by the benchmark harnesses.
+
+use std::path::PathBuf;
+
+use evefrontier_lib::{load_starmap, Starmap};
+
+/// Path to the minimal fixture database checked into `docs/fixtures`.
+pub fn fixture_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../docs/fixtures/minimal/static_data.db")
+}
+
+/// Load the fixture starmap, panicking if the fixture is missing or unreadable.
+pub fn fixture_starmap() -> Starmap {
+    load_starmap(&fixture_path(), None).expect("fixture loads")
+}
@@ -0,0 +1,59 @@
+//! Starmap and spatial lookups on the hot path of the scout services.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use evefrontier_lib::{NeighbourQuery, SpatialIndex, Starmap, SystemId};
+use once_cell::sync::Lazy;
+use std::hint::black_box;
+
+mod common;
+
+static STARMAP: Lazy<Starmap> = Lazy::new(common::fixture_starmap);
+static INDEX: Lazy<SpatialIndex> = Lazy::new(|| SpatialIndex::build(&STARMAP));
+
+fn benchmark_lookups(c: &mut Criterion) {
+    let starmap = &*STARMAP;
+    let index = &*INDEX;
+
+    let mut names: Vec<&str> = starmap.name_to_id.keys().map(String::as_str).collect();
+    names.sort_unstable();
+    let mut ids: Vec<SystemId> = starmap.systems.keys().copied().collect();
+    ids.sort_unstable();
+    let points: Vec<[f64; 3]> = ids
+        .iter()
+        .filter_map(|id| starmap.systems[id].position)
+        .map(|p| [p.x, p.y, p.z])
+        .collect();
+
+    c.bench_function("system_id_by_name", |b| {
+        b.iter(|| {
+            for name in &names {
+                black_box(starmap.system_id_by_name(black_box(name)));
+            }
+        });
+    });
+
+    c.bench_function("fuzzy_system_matches", |b| {
+        // A near miss of a real name, so scoring runs over every candidate.
+        b.iter(|| black_box(starmap.fuzzy_system_matches(black_box("Brnaa"), 5)));
+    });
+
+    c.bench_function("adjacency_lookup", |b| {
+        b.iter(|| {
+            for id in &ids {
+                black_box(starmap.adjacency.get(black_box(id)).map(Vec::len));
+            }
+        });
+    });
+
+    let query = NeighbourQuery::within_radius(5, 100.0);
+    c.bench_function("nearest_filtered", |b| {
+        b.iter(|| {
+            for &point in &points {
+                black_box(index.nearest_filtered(black_box(point), &query));
+            }
+        });
+    });
+}
+
+criterion_group!(benches, benchmark_lookups);
+criterion_main!(benches);
@@ -1,17 +1,11 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use evefrontier_lib::{
-    load_starmap, plan_route, RouteAlgorithm, RouteConstraints, RouteRequest, Starmap,
-};
+use evefrontier_lib::{plan_route, RouteAlgorithm, RouteConstraints, RouteRequest, Starmap};
 use once_cell::sync::Lazy;
 use std::hint::black_box;
-use std::path::PathBuf;
 
-fn fixture_path() -> PathBuf {
-    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../docs/fixtures/minimal/static_data.db")
-}
+mod common;
 
-static STARMAP: Lazy<Starmap> =
-    Lazy::new(|| load_starmap(&fixture_path(), None).expect("fixture loads"));
+static STARMAP: Lazy<Starmap> = Lazy::new(common::fixture_starmap);
 static BFS_REQUEST: Lazy<RouteRequest> = Lazy::new(|| RouteRequest::bfs("Nod", "Brana"));
 static DIJKSTRA_REQUEST: Lazy<RouteRequest> = Lazy::new(|| RouteRequest {
     start: "Nod".to_string(),
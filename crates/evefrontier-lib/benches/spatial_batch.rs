@@ -0,0 +1,56 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use evefrontier_lib::{NeighbourQuery, SpatialIndex};
+use once_cell::sync::Lazy;
+use std::hint::black_box;
+
+/// Number of origin points per iteration; the fixture positions are repeated to reach it.
+const POINT_COUNT: usize = 1_000;
+
+mod common;
+
+static INDEX: Lazy<SpatialIndex> = Lazy::new(|| {
+    let starmap = common::fixture_starmap();
+    SpatialIndex::build(&starmap)
+});
+
+static POINTS: Lazy<Vec<[f64; 3]>> = Lazy::new(|| {
+    let starmap = common::fixture_starmap();
+    let positions: Vec<[f64; 3]> = starmap
+        .systems
+        .values()
+        .filter_map(|system| system.position)
+        .map(|p| [p.x, p.y, p.z])
+        .collect();
+    positions
+        .iter()
+        .copied()
+        .cycle()
+        .take(POINT_COUNT)
+        .collect()
+});
+
+fn benchmark_spatial_batch(c: &mut Criterion) {
+    let index = &*INDEX;
+    let points = &*POINTS;
+    let query = NeighbourQuery::within_radius(5, 100.0);
+
+    c.bench_function("nearest_filtered_repeated", |b| {
+        b.iter(|| {
+            let results: Vec<_> = points
+                .iter()
+                .map(|&point| index.nearest_filtered(point, &query))
+                .collect();
+            black_box(results.len())
+        });
+    });
+
+    c.bench_function("nearest_filtered_batch", |b| {
+        b.iter(|| {
+            let results = index.nearest_filtered_batch(points, &query);
+            black_box(results.len())
+        });
+    });
+}
+
+criterion_group!(benches, benchmark_spatial_batch);
+criterion_main!(benches);
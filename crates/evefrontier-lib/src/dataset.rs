@@ -60,6 +60,17 @@ impl DatasetPaths {
     }
 }
 
+/// Optional behaviour for [`ensure_dataset_with_options`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DatasetOptions {
+    /// After downloading a dataset, also install its spatial index.
+    ///
+    /// The release's prebuilt `.spatial.bin` is used when it matches the downloaded database
+    /// (per [`crate::verify_freshness`]); otherwise, or when the release publishes none, the
+    /// index is built locally. Has no effect when a fresh cached dataset is reused.
+    pub fetch_spatial_index: bool,
+}
+
 /// Absolute path to the checked-in minimal fixture database, when available.
 static PROTECTED_FIXTURE_DATASET: Lazy<Option<PathBuf>> = Lazy::new(|| {
     let path =
@@ -166,18 +177,27 @@ fn normalize_windows_data_dir(path: &Path) -> PathBuf {
 ///
 /// Returns [`DatasetPaths`] containing the database path and optional spatial index path.
 pub fn ensure_dataset(target: Option<&Path>, release: DatasetRelease) -> Result<DatasetPaths> {
+    ensure_dataset_with_options(target, release, DatasetOptions::default())
+}
+
+/// Like [`ensure_dataset`], with [`DatasetOptions`] controlling what else is fetched.
+pub fn ensure_dataset_with_options(
+    target: Option<&Path>,
+    release: DatasetRelease,
+    options: DatasetOptions,
+) -> Result<DatasetPaths> {
     if let Some(explicit) = target {
         let resolved = canonical_dataset_path(explicit);
-        return ensure_or_download(&resolved, &release);
+        return ensure_or_download(&resolved, &release, options);
     }
 
     if let Some(env_path) = env::var_os("EVEFRONTIER_DATA_DIR") {
         let resolved = canonical_dataset_path(Path::new(&env_path));
-        return ensure_or_download(&resolved, &release);
+        return ensure_or_download(&resolved, &release, options);
     }
 
     let default = default_dataset_path()?;
-    ensure_or_download(&default, &release)
+    ensure_or_download(&default, &release, options)
 }
 
 /// Ensure the Era 6 Cycle 3 dataset is available locally and return paths to the files.
@@ -185,7 +205,11 @@ pub fn ensure_e6c3_dataset(target: Option<&Path>) -> Result<DatasetPaths> {
     ensure_dataset(target, DatasetRelease::tag("e6c3"))
 }
 
-fn ensure_or_download(path: &Path, release: &DatasetRelease) -> Result<DatasetPaths> {
+fn ensure_or_download(
+    path: &Path,
+    release: &DatasetRelease,
+    options: DatasetOptions,
+) -> Result<DatasetPaths> {
     guard_protected_dataset(path)?;
 
     if path.exists() {
@@ -237,7 +261,8 @@ fn ensure_or_download(path: &Path, release: &DatasetRelease) -> Result<DatasetPa
         "attempting to download dataset to {}",
         path.display()
     );
-    let (resolved_tag, ship_data) = download_dataset_with_tag(path, release.clone())?;
+    let (resolved_tag, ship_data) =
+        download_dataset_with_tag(path, release.clone(), options.fetch_spatial_index)?;
     write_release_marker(path, release, &resolved_tag)?;
     Ok(DatasetPaths::for_database_with_ship(
         path.to_path_buf(),
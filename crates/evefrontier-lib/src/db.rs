@@ -4,6 +4,7 @@ use std::path::Path;
 use std::sync::Arc;
 
 use rusqlite::{Connection, Row};
+use serde::{Deserialize, Serialize};
 use tracing::{debug, warn};
 
 use crate::error::{Error, Result};
@@ -15,8 +16,8 @@ pub type SystemId = i64;
 /// 1 light-year ≈ 9.4607304725808 × 10^15 meters
 const METERS_TO_LIGHT_YEARS: f64 = 1.0 / 9.4607304725808e15;
 
-/// Cartesian coordinates for a solar system.
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// Cartesian coordinates for a solar system, in light-years.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct SystemPosition {
     pub x: f64,
     pub y: f64,
@@ -84,12 +85,28 @@ pub struct System {
     pub position: Option<SystemPosition>,
 }
 
+/// Gate connection from a system to one of its neighbours.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GateLink {
+    /// System on the other side of the gate.
+    pub target: SystemId,
+    /// Dataset `jumpType` code, when the dataset records one.
+    pub jump_type: Option<i64>,
+    /// Straight-line distance in light-years, when both systems have positions.
+    pub distance: Option<f64>,
+}
+
 /// In-memory representation of the starmap graph.
 #[derive(Debug, Clone, Default)]
 pub struct Starmap {
     pub systems: HashMap<SystemId, System>,
     pub name_to_id: HashMap<String, SystemId>,
+    /// Gate neighbours of each system, sorted by ID.
     pub adjacency: Arc<HashMap<SystemId, Vec<SystemId>>>,
+    /// Gate links with per-gate metadata, in the same order as `adjacency`.
+    ///
+    /// Filled in by [`load_starmap`]; starmaps assembled by hand may leave it empty.
+    pub gate_links: Arc<HashMap<SystemId, Vec<GateLink>>>,
 }
 
 impl Starmap {
@@ -98,6 +115,46 @@ impl Starmap {
         self.name_to_id.get(name).copied()
     }
 
+    /// Resolve a system given either its case-sensitive name or its numeric ID.
+    ///
+    /// Names take precedence; a string that is not a known name is parsed as a
+    /// [`SystemId`] and accepted only if that system exists in the starmap.
+    pub fn resolve_system_id(&self, name_or_id: &str) -> Option<SystemId> {
+        self.system_id_by_name(name_or_id).or_else(|| {
+            name_or_id
+                .trim()
+                .parse::<SystemId>()
+                .ok()
+                .filter(|id| self.systems.contains_key(id))
+        })
+    }
+
+    /// Systems in a region given by name (case-insensitive) or numeric region ID.
+    ///
+    /// Regions are known only through system metadata, so the result is empty for an
+    /// unknown region or a dataset without region data.
+    pub fn systems_in_region(&self, region: &str) -> Vec<SystemId> {
+        let region = region.trim();
+        let id = region.parse::<i64>().ok();
+        self.systems
+            .values()
+            .filter(|system| {
+                let metadata = &system.metadata;
+                id.is_some_and(|id| metadata.region_id == Some(id))
+                    || metadata
+                        .region_name
+                        .as_deref()
+                        .is_some_and(|name| name.eq_ignore_ascii_case(region))
+            })
+            .map(|system| system.id)
+            .collect()
+    }
+
+    /// Gate links leaving `id`, or an empty slice for unknown or gateless systems.
+    pub fn gate_links(&self, id: SystemId) -> &[GateLink] {
+        self.gate_links.get(&id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
     /// Lookup a system name by identifier.
     pub fn system_name(&self, id: SystemId) -> Option<&str> {
         self.systems.get(&id).map(|sys| sys.name.as_str())
@@ -132,6 +189,41 @@ impl Starmap {
             .map(|(_, name)| name)
             .collect()
     }
+
+    /// Systems reachable from `start` within `max_hops` gate jumps.
+    ///
+    /// Performs a breadth-first search over gate adjacency and returns each
+    /// system once, paired with its minimum hop count, ordered by hop count and
+    /// then by system ID.
+    /// The start system itself is excluded; `max_hops == 0` yields nothing.
+    pub fn gate_neighbors_within(
+        &self,
+        start: SystemId,
+        max_hops: usize,
+    ) -> Vec<(SystemId, usize)> {
+        let mut visited = HashSet::from([start]);
+        let mut frontier = vec![start];
+        let mut found = Vec::new();
+
+        for hops in 1..=max_hops {
+            let mut next = Vec::new();
+            for system in &frontier {
+                for &neighbor in self.adjacency.get(system).into_iter().flatten() {
+                    if visited.insert(neighbor) {
+                        found.push((neighbor, hops));
+                        next.push(neighbor);
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+
+        found.sort_unstable_by_key(|&(system, hops)| (hops, system));
+        found
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -167,6 +259,7 @@ struct SchemaDefinition {
     jumps_table: &'static str,
     jump_from_column: &'static str,
     jump_to_column: &'static str,
+    jump_type_column: Option<&'static str>,
     constellation_join: Option<MetadataJoin>,
     region_join: Option<MetadataJoin>,
     security_column: Option<&'static str>,
@@ -191,6 +284,7 @@ impl SchemaVariant {
                 jumps_table: "Jumps",
                 jump_from_column: "fromSystemId",
                 jump_to_column: "toSystemId",
+                jump_type_column: Some("jumpType"),
                 constellation_join: Some(MetadataJoin {
                     fk_column: "constellationId",
                     table: "Constellations",
@@ -218,6 +312,7 @@ impl SchemaVariant {
                 jumps_table: "mapSolarSystemJumps",
                 jump_from_column: "fromSolarSystemID",
                 jump_to_column: "toSolarSystemID",
+                jump_type_column: None,
                 constellation_join: None,
                 region_join: None,
                 security_column: None,
@@ -278,7 +373,13 @@ pub fn load_starmap_from_connection(
     debug!(schema = %schema.variant, "loading starmap from connection");
 
     let mut systems = load_systems(connection, &schema)?;
-    let adjacency = Arc::new(load_adjacency(connection, &schema, &systems)?);
+    let gate_links = load_gate_links(connection, &schema, &systems)?;
+    let adjacency = Arc::new(
+        gate_links
+            .iter()
+            .map(|(&id, links)| (id, links.iter().map(|link| link.target).collect()))
+            .collect(),
+    );
 
     // Calculate minimum external temperatures for systems (if celestial data available)
     calculate_min_external_temps(connection, &mut systems, temperature_method)?;
@@ -295,6 +396,7 @@ pub fn load_starmap_from_connection(
         systems,
         name_to_id,
         adjacency,
+        gate_links: Arc::new(gate_links),
     })
 }
 
@@ -434,32 +536,40 @@ fn load_legacy_systems(
     Ok(systems)
 }
 
-/// Load jump connections into adjacency lists.
+/// Load jump connections into per-system gate link lists.
 ///
-/// The loader inserts edges in both directions for every row, assuming that
+/// The loader inserts links in both directions for every row, assuming that
 /// jumps are bidirectional gate connections. One-way travel (such as wormholes)
 /// is not currently modeled and would require schema changes or additional
-/// metadata to represent directionality accurately.
-fn load_adjacency(
+/// metadata to represent directionality accurately. When a pair is listed more
+/// than once, the first non-null `jumpType` recorded for it is kept.
+fn load_gate_links(
     connection: &Connection,
     schema: &SchemaDefinition,
     systems: &HashMap<SystemId, System>,
-) -> Result<HashMap<SystemId, Vec<SystemId>>> {
+) -> Result<HashMap<SystemId, Vec<GateLink>>> {
     let sql = format!(
-        "SELECT {from}, {to} FROM {table}",
+        "SELECT {from}, {to}, {jump_type} FROM {table}",
         from = schema.jump_from_column,
         to = schema.jump_to_column,
+        jump_type = schema.jump_type_column.unwrap_or("NULL"),
         table = schema.jumps_table
     );
 
     let mut stmt = connection.prepare(&sql)?;
-    let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+    let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?;
+
+    let gate_distance = |from: SystemId, to: SystemId| {
+        let from = systems.get(&from)?.position?;
+        let to = systems.get(&to)?.position?;
+        Some(from.distance_to(&to))
+    };
 
-    let mut adjacency: HashMap<SystemId, Vec<SystemId>> = HashMap::new();
+    let mut links: HashMap<SystemId, Vec<GateLink>> = HashMap::new();
     let mut skipped_edges = 0usize;
     let mut invalid_system_ids: HashSet<SystemId> = HashSet::new();
     for row in rows {
-        let (from, to): (SystemId, SystemId) = row?;
+        let (from, to, jump_type): (SystemId, SystemId, Option<i64>) = row?;
         // Skip edges referencing systems not in the dataset (may occur due to schema
         // mismatches or incomplete data exports)
         if !systems.contains_key(&from) || !systems.contains_key(&to) {
@@ -475,13 +585,29 @@ fn load_adjacency(
             }
             continue;
         }
-        adjacency.entry(from).or_default().push(to);
-        adjacency.entry(to).or_default().push(from);
+        let distance = gate_distance(from, to);
+        links.entry(from).or_default().push(GateLink {
+            target: to,
+            jump_type,
+            distance,
+        });
+        links.entry(to).or_default().push(GateLink {
+            target: from,
+            jump_type,
+            distance,
+        });
     }
 
-    for neighbours in adjacency.values_mut() {
-        neighbours.sort_unstable();
-        neighbours.dedup();
+    for neighbours in links.values_mut() {
+        // Stable sort keeps rows in dataset order within each target.
+        neighbours.sort_by_key(|link| link.target);
+        neighbours.dedup_by(|duplicate, kept| {
+            if duplicate.target != kept.target {
+                return false;
+            }
+            kept.jump_type = kept.jump_type.or(duplicate.jump_type);
+            true
+        });
     }
 
     if skipped_edges > 0 {
@@ -492,7 +618,7 @@ fn load_adjacency(
         );
     }
 
-    Ok(adjacency)
+    Ok(links)
 }
 
 /// Calculate minimum external temperatures for all systems.
@@ -805,6 +931,12 @@ fn detect_static_schema(connection: &Connection) -> Result<Option<SchemaDefiniti
         }
     }
 
+    if let Some(column) = schema.jump_type_column {
+        if !table_has_columns(connection, schema.jumps_table, &[column])? {
+            schema.jump_type_column = None;
+        }
+    }
+
     let position_candidates = [
         PositionColumns {
             x: "centerX",
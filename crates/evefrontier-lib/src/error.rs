@@ -47,6 +47,10 @@ pub enum Error {
         suggestions: Vec<String>,
     },
 
+    /// Raised when an avoided region matches no system in the dataset.
+    #[error("unknown region: {name}")]
+    UnknownRegion { name: String },
+
     /// Raised when no route could be found between two systems.
     #[error("no route found between {start} and {goal}")]
     RouteNotFound { start: String, goal: String },
@@ -55,10 +59,33 @@ pub enum Error {
     #[error("route plan was empty")]
     EmptyRoutePlan,
 
+    /// Raised when a route step's system has no name in the dataset and
+    /// [`MissingNamePolicy::Fail`](crate::output::MissingNamePolicy::Fail) is in effect.
+    #[error(
+        "route step {position} (system {system_id}) has no name in the dataset; it may be incomplete or corrupt"
+    )]
+    UnnamedRouteSystem { system_id: i64, position: usize },
+
+    /// Raised when a reachability budget is invalid or cannot be priced.
+    #[error("invalid reachability budget: {message}")]
+    InvalidReachBudget { message: String },
+
     /// Raised when a requested routing option is not yet supported.
     #[error("route option {option} is not supported yet")]
     UnsupportedRouteOption { option: String },
 
+    /// Raised when an avoided temperature range is not finite or its bounds are reversed.
+    #[error("invalid temperature range {min}:{max}; expected finite bounds with min <= max")]
+    InvalidTemperatureRange { min: f64, max: f64 },
+
+    /// Raised when a gate or jump edge penalty is not a finite, positive multiplier.
+    #[error("invalid {kind} penalty {value}; expected a finite value greater than 0")]
+    InvalidEdgePenalty { kind: String, value: f64 },
+
+    /// Raised when a gate or jump travel time estimate is negative or not finite.
+    #[error("invalid {kind} time {value}; expected a finite, non-negative number of seconds")]
+    InvalidTravelTime { kind: String, value: f64 },
+
     /// Raised when temperature calculation fails due to invalid parameters.
     #[error("temperature calculation failed: {0}")]
     TemperatureCalculation(String),
@@ -95,10 +122,21 @@ pub enum Error {
     #[error("failed to load spatial index from {path}: {message}")]
     SpatialIndexLoad { path: PathBuf, message: String },
 
+    /// Raised when a spatial index uses a format version this build cannot read,
+    /// typically one written by a newer release.
+    #[error(
+        "unsupported spatial index format version {version} (this build reads versions up to {max_supported})"
+    )]
+    SpatialIndexUnsupportedVersion { version: u8, max_supported: u8 },
+
     /// Raised when deserializing a spatial index from bytes fails.
     #[error("failed to deserialize spatial index: {message}")]
     SpatialIndexDeserialize { message: String },
 
+    /// Raised when querying a spatial index that contains no systems.
+    #[error("spatial index is empty; it may not have been built for this dataset")]
+    SpatialIndexEmpty,
+
     /// Database deserialization failed (used with rusqlite serialize feature).
     #[error("failed to deserialize database: {message}")]
     DatabaseDeserialize { message: String },
@@ -160,6 +198,113 @@ pub enum Error {
     FmapInvalidSystemId { system_id: u32, base_id: u32 },
 }
 
+/// Stable, message-independent category of an [`Error`].
+///
+/// Callers that branch on failures (HTTP status mapping, metric labels) should match on
+/// the kind rather than on [`Error`]'s `Display` output, which is free to change wording.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// A system name did not resolve to a system in the dataset.
+    UnknownSystem,
+    /// No route exists between the requested systems under the given constraints.
+    RouteNotFound,
+    /// A route plan was produced without any systems.
+    EmptyRoute,
+    /// A request option or budget was invalid or unsupported.
+    InvalidRequest,
+    /// A temperature calculation received invalid parameters.
+    Temperature,
+    /// Ship catalog data failed validation.
+    ShipData,
+    /// The dataset could not be located, downloaded, or cached.
+    DatasetUnavailable,
+    /// The dataset was found but could not be read or used.
+    DatasetInvalid,
+    /// Building, loading, or querying the spatial index failed.
+    SpatialIndex,
+    /// Encoding or decoding an fmap token failed.
+    Fmap,
+    /// A filesystem or other I/O operation failed.
+    Io,
+    /// An HTTP request failed.
+    Http,
+}
+
+impl ErrorKind {
+    /// Snake-case label for this kind, suitable for metrics and logs.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorKind::UnknownSystem => "unknown_system",
+            ErrorKind::RouteNotFound => "no_path",
+            ErrorKind::EmptyRoute => "empty_route",
+            ErrorKind::InvalidRequest => "invalid_request",
+            ErrorKind::Temperature => "temperature",
+            ErrorKind::ShipData => "ship_data",
+            ErrorKind::DatasetUnavailable => "dataset_unavailable",
+            ErrorKind::DatasetInvalid => "dataset_invalid",
+            ErrorKind::SpatialIndex => "spatial_index",
+            ErrorKind::Fmap => "fmap",
+            ErrorKind::Io => "io",
+            ErrorKind::Http => "http",
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Error {
+    /// Category of this error, stable across changes to its message.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::UnknownSystem { .. } => ErrorKind::UnknownSystem,
+            Error::RouteNotFound { .. } => ErrorKind::RouteNotFound,
+            Error::EmptyRoutePlan => ErrorKind::EmptyRoute,
+            Error::InvalidReachBudget { .. }
+            | Error::UnknownRegion { .. }
+            | Error::UnsupportedRouteOption { .. }
+            | Error::InvalidTemperatureRange { .. }
+            | Error::InvalidEdgePenalty { .. }
+            | Error::InvalidTravelTime { .. }
+            | Error::ProtectedFixturePath { .. } => ErrorKind::InvalidRequest,
+            Error::TemperatureCalculation(_) => ErrorKind::Temperature,
+            Error::ShipDataValidation { .. } | Error::DuplicateShipName { .. } => {
+                ErrorKind::ShipData
+            }
+            Error::DatasetNotFound { .. }
+            | Error::ProjectDirsUnavailable
+            | Error::CacheDirsUnavailable
+            | Error::DatasetAssetMissing { .. }
+            | Error::DatasetReleaseNotFound { .. }
+            | Error::ArchiveMissingDatabase { .. } => ErrorKind::DatasetUnavailable,
+            Error::UnsupportedSchema
+            | Error::UnnamedRouteSystem { .. }
+            | Error::DatabaseDeserialize { .. }
+            | Error::Sqlite(_)
+            | Error::Zip(_) => ErrorKind::DatasetInvalid,
+            Error::SpatialIndexSerialize { .. }
+            | Error::SpatialIndexLoad { .. }
+            | Error::SpatialIndexUnsupportedVersion { .. }
+            | Error::SpatialIndexDeserialize { .. }
+            | Error::SpatialIndexEmpty => ErrorKind::SpatialIndex,
+            Error::FmapBase64DecodeError { .. }
+            | Error::FmapDecompressionError { .. }
+            | Error::FmapCompressionError { .. }
+            | Error::FmapUnsupportedVersion { .. }
+            | Error::FmapInvalidBitWidth { .. }
+            | Error::FmapTooManyWaypoints { .. }
+            | Error::FmapInvalidWaypointType { .. }
+            | Error::FmapTruncatedData { .. }
+            | Error::FmapInvalidSystemId { .. } => ErrorKind::Fmap,
+            Error::Io(_) => ErrorKind::Io,
+            Error::Http(_) => ErrorKind::Http,
+        }
+    }
+}
+
 fn format_suggestions(suggestions: &[String]) -> String {
     if suggestions.is_empty() {
         String::new()
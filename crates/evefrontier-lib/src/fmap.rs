@@ -35,6 +35,12 @@ pub const MAX_BIT_WIDTH: u8 = 30;
 /// Bits used to encode waypoint type (5 types: 0-4 requires 3 bits).
 pub const WAYPOINT_TYPE_BITS: u8 = 3;
 
+/// Largest decompressed token the decoder accepts: a full header plus `u16::MAX`
+/// waypoints at the maximum bit width. Anything bigger cannot be a valid token,
+/// so decompression stops there instead of inflating arbitrarily large input.
+const MAX_RAW_SIZE: usize = FMAP_HEADER_SIZE
+    + (u16::MAX as usize * (MAX_BIT_WIDTH + WAYPOINT_TYPE_BITS) as usize).div_ceil(8);
+
 /// Waypoint type for fmap route encoding.
 ///
 /// Values correspond to the frontier-reapers/starmap specification.
@@ -142,8 +148,6 @@ impl BitWriter {
 }
 
 /// Helper for reading bits at a time from a byte buffer.
-/// Used in Phase 4 (decoding).
-#[allow(dead_code)]
 struct BitReader<'a> {
     buf: &'a [u8],
     idx: usize,       // byte index
@@ -151,7 +155,6 @@ struct BitReader<'a> {
     bits_left: usize, // bits remaining in cur (0..8)
 }
 
-#[allow(dead_code)]
 impl<'a> BitReader<'a> {
     fn new(buf: &'a [u8]) -> Self {
         BitReader {
@@ -249,8 +252,6 @@ fn to_base64url(bytes: &[u8]) -> String {
 }
 
 /// Convert base64url string to raw bytes.
-/// Used in Phase 4 (decoding).
-#[allow(dead_code)]
 fn from_base64url(s: &str) -> Result<Vec<u8>, Error> {
     use base64::Engine;
     let engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
@@ -304,8 +305,18 @@ pub fn encode_fmap_token(waypoints: &[Waypoint]) -> Result<FmapToken, Error> {
     })
 }
 
+/// Total raw size (header plus bitpacked payload) needed for `count` waypoints.
+fn required_raw_size(count: usize, bit_width: u8) -> usize {
+    let bits = count * (bit_width + WAYPOINT_TYPE_BITS) as usize;
+    FMAP_HEADER_SIZE + bits.div_ceil(8)
+}
+
 /// Decode an fmap token string back to waypoints.
 ///
+/// Malformed input never panics: invalid base64, corrupt or oversized gzip data,
+/// short headers, unsupported versions, and waypoint counts the payload cannot
+/// hold are all reported as [`Error`]s. Surrounding whitespace is ignored.
+///
 /// # Arguments
 /// * `token_str` - Base64url-encoded gzipped token
 ///
@@ -314,16 +325,24 @@ pub fn encode_fmap_token(waypoints: &[Waypoint]) -> Result<FmapToken, Error> {
 /// * `Err(Error)` - Decoding error with details
 pub fn decode_fmap_token(token_str: &str) -> Result<DecodedFmapToken, Error> {
     // Decode from base64url
-    let compressed = from_base64url(token_str)?;
+    let compressed = from_base64url(token_str.trim())?;
 
-    // Decompress gzip
+    // Decompress gzip, reading at most one byte past the largest valid token
     use flate2::read::GzDecoder;
     use std::io::Read;
-    let mut decoder = GzDecoder::new(&compressed[..]);
+    let mut decoder = GzDecoder::new(&compressed[..]).take(MAX_RAW_SIZE as u64 + 1);
     let mut raw = Vec::new();
     decoder
         .read_to_end(&mut raw)
         .map_err(|e| Error::FmapDecompressionError { source: e })?;
+    if raw.len() > MAX_RAW_SIZE {
+        return Err(Error::FmapDecompressionError {
+            source: std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("decompressed fmap data exceeds {MAX_RAW_SIZE} bytes"),
+            ),
+        });
+    }
 
     // Parse header (4 bytes minimum)
     if raw.len() < FMAP_HEADER_SIZE {
@@ -350,6 +369,15 @@ pub fn decode_fmap_token(token_str: &str) -> Result<DecodedFmapToken, Error> {
         return Err(Error::FmapInvalidBitWidth { k: bit_width });
     }
 
+    // Ensure the payload can hold every waypoint the header claims
+    let required = required_raw_size(count, bit_width);
+    if raw.len() < required {
+        return Err(Error::FmapTruncatedData {
+            expected: required,
+            actual: raw.len(),
+        });
+    }
+
     // Parse payload
     let payload = &raw[FMAP_HEADER_SIZE..];
     let mut reader = BitReader::new(payload);
@@ -17,6 +17,7 @@ use tracing::{debug, info, warn};
 use zip::ZipArchive;
 
 use crate::error::{Error, Result};
+use crate::spatial::{install_spatial_index, spatial_index_path};
 
 const RELEASES_API_BASE: &str =
     "https://api.github.com/repos/Scetrov/evefrontier_datasets/releases";
@@ -24,38 +25,83 @@ const CACHE_DIR_NAME: &str = "evefrontier_datasets";
 const CACHE_DIR_ENV: &str = "EVEFRONTIER_DATASET_CACHE_DIR";
 const DATASET_SOURCE_ENV: &str = "EVEFRONTIER_DATASET_SOURCE";
 const LATEST_TAG_OVERRIDE_ENV: &str = "EVEFRONTIER_DATASET_LATEST_TAG";
+const DOWNLOAD_RETRIES_ENV: &str = "EVEFRONTIER_DOWNLOAD_RETRIES";
+const DATASET_ASSET_PATTERN_ENV: &str = "EVEFRONTIER_DATASET_ASSET_PATTERN";
+const DEFAULT_DATASET_ASSET_PATTERN: &str = "static_data.db";
 
 // Retry/backoff policy for transient failures (timeouts, connection resets, 5xx).
 // Blocking implementation (std::thread::sleep) because we use the blocking reqwest client.
 // If migrating to async, swap for `tokio::time::sleep` and async reqwest.
 //
 // Semantics:
-// - HTTP_MAX_RETRIES counts *additional* retries after the initial attempt.
-// - Total attempts = 1 + HTTP_MAX_RETRIES.
+// - The retry count (EVEFRONTIER_DOWNLOAD_RETRIES, default HTTP_MAX_RETRIES) counts *additional*
+//   retries after the initial attempt, so total attempts = 1 + retries. `0` disables retries.
 // - Backoff sequence (per failure before next attempt): 300ms → 600ms → 1200ms (capped growth).
 //   For 3 retries this is 300 + 600 + 1200 = 2100ms maximum added delay.
 // - Combined with 30s client timeout worst-case wall time ≈ 32.1s for a single request context.
 const HTTP_MAX_RETRIES: usize = 3; // initial + 3 retries = 4 attempts total
 const HTTP_INITIAL_BACKOFF_MS: u64 = 300;
 
-// Generic retry loop helper. Attempts the operation up to (1 + HTTP_MAX_RETRIES) times.
+// Retry count from EVEFRONTIER_DOWNLOAD_RETRIES, falling back to HTTP_MAX_RETRIES.
+fn http_max_retries() -> usize {
+    parse_max_retries(env::var(DOWNLOAD_RETRIES_ENV).ok().as_deref())
+}
+
+fn parse_max_retries(raw: Option<&str>) -> usize {
+    match raw.map(str::trim) {
+        None | Some("") => HTTP_MAX_RETRIES,
+        Some(value) => value.parse().unwrap_or_else(|_| {
+            warn!(
+                "ignoring invalid {}={:?}; using {} retries",
+                DOWNLOAD_RETRIES_ENV, value, HTTP_MAX_RETRIES
+            );
+            HTTP_MAX_RETRIES
+        }),
+    }
+}
+
+// Generic retry loop helper. Attempts the operation up to (1 + http_max_retries()) times.
 // `should_retry` returns true if the error is transient and the loop should continue.
-fn retry_loop<T, E, Op, ShouldRetry>(
+fn retry_loop<T, E, Op, ShouldRetry>(op: Op, should_retry: ShouldRetry) -> std::result::Result<T, E>
+where
+    E: fmt::Display,
+    Op: FnMut() -> std::result::Result<T, E>,
+    ShouldRetry: FnMut(&E) -> bool,
+{
+    retry_loop_with(
+        http_max_retries(),
+        HTTP_INITIAL_BACKOFF_MS,
+        op,
+        should_retry,
+    )
+}
+
+fn retry_loop_with<T, E, Op, ShouldRetry>(
+    max_retries: usize,
+    initial_backoff_ms: u64,
     mut op: Op,
     mut should_retry: ShouldRetry,
 ) -> std::result::Result<T, E>
 where
+    E: fmt::Display,
     Op: FnMut() -> std::result::Result<T, E>,
     ShouldRetry: FnMut(&E) -> bool,
 {
-    let mut backoff = HTTP_INITIAL_BACKOFF_MS;
-    for attempt in 0..=HTTP_MAX_RETRIES {
+    let mut backoff = initial_backoff_ms;
+    for attempt in 0..=max_retries {
         match op() {
             Ok(val) => return Ok(val),
             Err(err) => {
-                if attempt == HTTP_MAX_RETRIES || !should_retry(&err) {
+                if attempt == max_retries || !should_retry(&err) {
                     return Err(err);
                 }
+                warn!(
+                    "attempt {}/{} failed: {}; retrying in {}ms",
+                    attempt + 1,
+                    max_retries + 1,
+                    err,
+                    backoff
+                );
                 std::thread::sleep(Duration::from_millis(backoff));
                 backoff = (backoff.saturating_mul(2)).min(5_000);
             }
@@ -100,7 +146,7 @@ impl fmt::Display for DatasetRelease {
 /// `EVEFRONTIER_DATASET_SOURCE` environment variable, which accepts either a
 /// path to a `.db` file or a `.zip` archive containing the database.
 pub fn download_latest_dataset(target_path: &Path) -> Result<()> {
-    download_dataset_with_tag(target_path, DatasetRelease::Latest).map(|_| ())
+    download_dataset_with_tag(target_path, DatasetRelease::Latest, false).map(|_| ())
 }
 
 /// Download the specified dataset release from GitHub into `target_path`.
@@ -109,7 +155,7 @@ pub fn download_latest_dataset(target_path: &Path) -> Result<()> {
 /// metadata through the GitHub API, caches the resulting asset, and copies the
 /// database into the requested `target_path`.
 pub fn download_dataset(target_path: &Path, release: DatasetRelease) -> Result<()> {
-    download_dataset_with_tag(target_path, release).map(|_| ())
+    download_dataset_with_tag(target_path, release, false).map(|_| ())
 }
 
 /// Test helper: download the latest dataset but use `source` as a local override
@@ -204,6 +250,7 @@ pub fn download_from_source_with_cache(
 pub(crate) fn download_dataset_with_tag(
     target_path: &Path,
     release: DatasetRelease,
+    fetch_spatial_index: bool,
 ) -> Result<(String, Option<PathBuf>)> {
     if let Some(source) = env::var_os(DATASET_SOURCE_ENV) {
         let override_path = PathBuf::from(source);
@@ -213,6 +260,10 @@ pub(crate) fn download_dataset_with_tag(
             "using local dataset override"
         );
         copy_from_override(&override_path, target_path)?;
+        if fetch_spatial_index {
+            // Local overrides never publish an index; build one to honour the request.
+            crate::spatial::build_spatial_index_for(target_path, &spatial_index_path(target_path))?;
+        }
 
         // When using a local override we still want to record the resolved
         // release tag. This honors the `EVEFRONTIER_DATASET_LATEST_TAG`
@@ -251,10 +302,10 @@ pub(crate) fn download_dataset_with_tag(
             AssetKind::Archive => {
                 download_archive_asset(&client, &asset.download_url, &cached_dataset)?
             }
-            AssetKind::ShipCsv => {
-                // Should not be selected for the main dataset; ship CSVs will be handled
-                // separately by the ship-data downloader tasks.
-                unreachable!("select_dataset_asset should not return ShipCsv");
+            AssetKind::ShipCsv | AssetKind::SpatialIndex => {
+                // Should not be selected for the main dataset; ship CSVs and spatial indexes
+                // are handled separately below.
+                unreachable!("select_dataset_asset only returns database or archive assets");
             }
         }
     } else {
@@ -317,9 +368,48 @@ pub(crate) fn download_dataset_with_tag(
     }
 
     copy_cached_to_target(&cached_dataset, target_path)?;
+
+    if fetch_spatial_index {
+        install_release_spatial_index(&client, &release_response, &cache_dir, target_path)?;
+    }
+
     Ok((release_response.tag_name, ship_cached))
 }
 
+// Install the release's prebuilt spatial index next to `target_path`, falling back to a local
+// build when the release has none or the download fails. A downloaded index that doesn't match
+// the dataset is rebuilt by `install_spatial_index`.
+fn install_release_spatial_index(
+    client: &Client,
+    release: &ReleaseResponse,
+    cache_dir: &Path,
+    target_path: &Path,
+) -> Result<()> {
+    let index_path = spatial_index_path(target_path);
+    let Some(asset) = select_spatial_index_asset(release) else {
+        info!(tag = %release.tag_name, "release has no spatial index; building locally");
+        return crate::spatial::build_spatial_index_for(target_path, &index_path);
+    };
+
+    let cached_index = cache_dir.join(cache_file_name(&asset, release, target_path));
+    if !cached_index.exists() {
+        info!(
+            tag = %release.tag_name,
+            asset = %asset.name,
+            path = %cached_index.display(),
+            "caching spatial index asset"
+        );
+        if let Err(error) = download_database_asset(client, &asset.download_url, &cached_index) {
+            warn!(%error, "failed to download spatial index; building locally");
+            return crate::spatial::build_spatial_index_for(target_path, &index_path);
+        }
+    }
+
+    let source = install_spatial_index(&cached_index, target_path)?;
+    debug!(?source, path = %index_path.display(), "spatial index ready");
+    Ok(())
+}
+
 pub(crate) fn resolve_release_tag(release: &DatasetRelease) -> Result<String> {
     match release {
         DatasetRelease::Latest => {
@@ -613,6 +703,8 @@ enum AssetKind {
     Archive,
     /// A CSV file containing ship data (e.g., `ship_data.csv`).
     ShipCsv,
+    /// A prebuilt spatial index (e.g., `static_data.db.spatial.bin`).
+    SpatialIndex,
 }
 
 #[derive(Debug)]
@@ -623,6 +715,45 @@ struct AssetInfo {
 }
 
 fn select_dataset_asset(release: &ReleaseResponse) -> Option<AssetInfo> {
+    let pattern = env::var(DATASET_ASSET_PATTERN_ENV)
+        .ok()
+        .filter(|value| !value.trim().is_empty());
+    select_dataset_asset_matching(release, pattern.as_deref())
+}
+
+// Pick the dataset asset from a release.
+//
+// An explicit `pattern` (from EVEFRONTIER_DATASET_ASSET_PATTERN) must match: if no database or
+// archive asset matches it, nothing is selected. Without one, an asset named like
+// DEFAULT_DATASET_ASSET_PATTERN wins, falling back to the first database (then archive) asset so
+// older releases with differently named assets keep working.
+fn select_dataset_asset_matching(
+    release: &ReleaseResponse,
+    pattern: Option<&str>,
+) -> Option<AssetInfo> {
+    let matching = |pattern: &str| {
+        release.assets.iter().find_map(|asset| {
+            let kind = classify_asset(asset)?;
+            let is_dataset = matches!(kind, AssetKind::Database | AssetKind::Archive);
+            if !is_dataset || !glob_matches(pattern, &asset.name) {
+                return None;
+            }
+            Some(AssetInfo {
+                name: asset.name.clone(),
+                download_url: asset.browser_download_url.clone(),
+                kind,
+            })
+        })
+    };
+
+    match pattern {
+        Some(pattern) => matching(pattern),
+        None => matching(DEFAULT_DATASET_ASSET_PATTERN)
+            .or_else(|| select_dataset_asset_by_kind(release)),
+    }
+}
+
+fn select_dataset_asset_by_kind(release: &ReleaseResponse) -> Option<AssetInfo> {
     let mut archive_candidate: Option<AssetInfo> = None;
     for asset in &release.assets {
         let kind = classify_asset(asset);
@@ -641,8 +772,8 @@ fn select_dataset_asset(release: &ReleaseResponse) -> Option<AssetInfo> {
                     kind: AssetKind::Archive,
                 });
             }
-            Some(AssetKind::ShipCsv) => {
-                // Ship CSVs are not dataset assets; skip and allow DB/archive selection.
+            Some(AssetKind::ShipCsv | AssetKind::SpatialIndex) => {
+                // Ship CSVs and indexes are not dataset assets; skip and allow DB/archive selection.
                 continue;
             }
             None => continue,
@@ -652,8 +783,43 @@ fn select_dataset_asset(release: &ReleaseResponse) -> Option<AssetInfo> {
     archive_candidate
 }
 
+// Case-insensitive glob match supporting `*` (any run of characters) and `?` (one character).
+fn glob_matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let name: Vec<char> = name.to_lowercase().chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // Position of the last `*` in the pattern and the name index it was tried against.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 fn classify_asset(asset: &ReleaseAsset) -> Option<AssetKind> {
     let name = asset.name.to_ascii_lowercase();
+    if name.ends_with(".spatial.bin") {
+        return Some(AssetKind::SpatialIndex);
+    }
     if name.ends_with(".db") || name.ends_with(".sqlite") {
         return Some(AssetKind::Database);
     }
@@ -675,6 +841,16 @@ fn classify_asset(asset: &ReleaseAsset) -> Option<AssetKind> {
     None
 }
 
+fn select_spatial_index_asset(release: &ReleaseResponse) -> Option<AssetInfo> {
+    release.assets.iter().find_map(|asset| {
+        (classify_asset(asset)? == AssetKind::SpatialIndex).then(|| AssetInfo {
+            name: asset.name.clone(),
+            download_url: asset.browser_download_url.clone(),
+            kind: AssetKind::SpatialIndex,
+        })
+    })
+}
+
 #[allow(dead_code)]
 fn select_ship_asset(release: &ReleaseResponse) -> Option<AssetInfo> {
     for asset in &release.assets {
@@ -696,6 +872,9 @@ fn cache_file_name(asset: &AssetInfo, release: &ReleaseResponse, target: &Path)
         AssetKind::Database => format!("{}-{}", tag, sanitize_component(&asset.name)),
         AssetKind::Archive => format!("{}-{}", tag, target_dataset_filename(target)),
         AssetKind::ShipCsv => format!("{}-{}", tag, sanitize_component(&asset.name)),
+        AssetKind::SpatialIndex => {
+            format!("{}-{}.spatial.bin", tag, target_dataset_filename(target))
+        }
     }
 }
 
@@ -1007,8 +1186,106 @@ fn copy_file_atomic(source: &Path, destination: &Path) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::io::Read;
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
     use tempfile::tempdir;
 
+    /// Serve one scripted HTTP response per connection and count the connections.
+    ///
+    /// Each entry is `(status line, body)`; the listener stays open after the script runs out
+    /// so an unexpected retry would still be counted rather than refused.
+    fn scripted_server(script: Vec<(&'static str, &'static str)>) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind mock server");
+        let url = format!("http://{}/asset", listener.local_addr().unwrap());
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&hits);
+        std::thread::spawn(move || {
+            let mut script = script.into_iter();
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    match stream.read(&mut buf) {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => request.extend_from_slice(&buf[..n]),
+                    }
+                }
+                let (status, body) = script.next().unwrap_or(("500 Internal Server Error", ""));
+                let response = format!(
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        (url, hits)
+    }
+
+    fn test_client() -> Client {
+        Client::builder().no_proxy().build().expect("build client")
+    }
+
+    #[test]
+    fn download_retries_transient_failures_then_succeeds() {
+        let (url, hits) = scripted_server(vec![
+            ("503 Service Unavailable", ""),
+            ("502 Bad Gateway", ""),
+            ("200 OK", "dataset"),
+        ]);
+        let mut file = tempfile::tempfile().expect("temp file");
+
+        download_to_file(&test_client(), &url, &mut file).expect("third attempt succeeds");
+
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+        let mut contents = String::new();
+        file.seek(SeekFrom::Start(0)).unwrap();
+        file.read_to_string(&mut contents).unwrap();
+        assert_eq!(contents, "dataset");
+    }
+
+    #[test]
+    fn download_does_not_retry_not_found() {
+        let (url, hits) = scripted_server(vec![("404 Not Found", ""), ("200 OK", "dataset")]);
+        let mut file = tempfile::tempfile().expect("temp file");
+
+        let err = download_to_file(&test_client(), &url, &mut file).expect_err("404 is terminal");
+
+        assert!(matches!(err, Error::Http(ref e) if e.status() == Some(StatusCode::NOT_FOUND)));
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn retry_loop_stops_after_max_retries() {
+        let mut attempts = 0;
+        let result: std::result::Result<(), String> = retry_loop_with(
+            2,
+            0,
+            || {
+                attempts += 1;
+                Err("timeout".to_string())
+            },
+            |_| true,
+        );
+
+        assert!(result.is_err());
+        assert_eq!(attempts, 3);
+    }
+
+    #[test]
+    fn max_retries_env_value_is_parsed() {
+        assert_eq!(parse_max_retries(None), HTTP_MAX_RETRIES);
+        assert_eq!(parse_max_retries(Some("")), HTTP_MAX_RETRIES);
+        assert_eq!(parse_max_retries(Some("0")), 0);
+        assert_eq!(parse_max_retries(Some(" 5 ")), 5);
+        assert_eq!(parse_max_retries(Some("many")), HTTP_MAX_RETRIES);
+    }
+
     #[test]
     fn classify_detects_ship_data_by_name() {
         let asset = ReleaseAsset {
@@ -1031,6 +1308,88 @@ mod tests {
         assert_eq!(classify_asset(&asset), Some(AssetKind::ShipCsv));
     }
 
+    fn asset(name: &str) -> ReleaseAsset {
+        ReleaseAsset {
+            name: name.to_string(),
+            browser_download_url: format!("https://example.com/{}", name),
+            content_type: None,
+        }
+    }
+
+    fn multi_asset_release() -> ReleaseResponse {
+        ReleaseResponse {
+            tag_name: "e6c4".to_string(),
+            assets: vec![
+                asset("ship_data.csv"),
+                asset("legacy_static_data.db"),
+                asset("static_data.db.sha256"),
+                asset("static_data.db"),
+                asset("static_data.db.spatial.bin"),
+                asset("static_data.zip"),
+            ],
+        }
+    }
+
+    #[test]
+    fn select_dataset_asset_prefers_default_name() {
+        let selected = select_dataset_asset_matching(&multi_asset_release(), None)
+            .expect("dataset asset should be found");
+        assert_eq!(selected.name, "static_data.db");
+        assert_eq!(selected.kind, AssetKind::Database);
+    }
+
+    #[test]
+    fn select_dataset_asset_honors_pattern_override() {
+        let release = multi_asset_release();
+
+        let selected =
+            select_dataset_asset_matching(&release, Some("legacy_*.db")).expect("legacy db");
+        assert_eq!(selected.name, "legacy_static_data.db");
+
+        let selected = select_dataset_asset_matching(&release, Some("*.ZIP")).expect("archive");
+        assert_eq!(selected.name, "static_data.zip");
+        assert_eq!(selected.kind, AssetKind::Archive);
+
+        // Patterns never select ship data or unrecognised assets.
+        assert!(select_dataset_asset_matching(&release, Some("ship_data.csv")).is_none());
+        assert!(select_dataset_asset_matching(&release, Some("*.spatial.bin")).is_none());
+    }
+
+    #[test]
+    fn select_spatial_index_asset_picks_index() {
+        let release = multi_asset_release();
+        let selected = select_spatial_index_asset(&release).expect("index asset");
+        assert_eq!(selected.name, "static_data.db.spatial.bin");
+        assert_eq!(selected.kind, AssetKind::SpatialIndex);
+
+        let without_index = ReleaseResponse {
+            tag_name: "e6c3".to_string(),
+            assets: vec![asset("static_data.db")],
+        };
+        assert!(select_spatial_index_asset(&without_index).is_none());
+    }
+
+    #[test]
+    fn select_dataset_asset_falls_back_without_default_name() {
+        let release = ReleaseResponse {
+            tag_name: "e6c3".to_string(),
+            assets: vec![asset("ship_data.csv"), asset("frontier.sqlite")],
+        };
+        let selected = select_dataset_asset_matching(&release, None).expect("fallback asset");
+        assert_eq!(selected.name, "frontier.sqlite");
+    }
+
+    #[test]
+    fn glob_matches_wildcards() {
+        assert!(glob_matches("static_data.db", "Static_Data.DB"));
+        assert!(glob_matches("*.db", "static_data.db"));
+        assert!(glob_matches("static_?ata.*", "static_data.zip"));
+        assert!(glob_matches("*data*", "static_data.db"));
+        assert!(!glob_matches("*.db", "static_data.db.sha256"));
+        assert!(!glob_matches("static_data.db", "static_data.db.bak"));
+        assert!(!glob_matches("?", ""));
+    }
+
     #[test]
     fn select_ship_asset_picks_ship_csv() {
         let release = ReleaseResponse {
@@ -1,7 +1,9 @@
 use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use serde::{Deserialize, Serialize};
 use tracing::warn;
 
 use crate::db::{Starmap, SystemId, SystemPosition};
@@ -34,7 +36,7 @@ pub enum GraphMode {
 }
 
 /// Classification for the edge used in the routing graph.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum EdgeKind {
     Gate,
     Spatial,
@@ -48,11 +50,56 @@ pub struct Edge {
     pub distance: f64,
 }
 
+/// Default seconds to activate a gate and complete the transit.
+pub const DEFAULT_GATE_ACTIVATION_SECS: f64 = 10.0;
+
+/// Default seconds to spool the jump drive before a spatial jump.
+pub const DEFAULT_JUMP_SPOOL_SECS: f64 = 60.0;
+
+/// Per-hop travel time estimates used by the time optimization objective.
+///
+/// Every gate takes `gate_activation_secs` and every spatial jump takes `jump_spool_secs` plus
+/// `jump_secs_per_ly` for each light-year covered, so the fastest route trades long gate chains
+/// against fewer, slower jumps. Individual gates can be given their own time through
+/// [`RouteConstraints::gate_time_seconds`](crate::RouteConstraints::gate_time_seconds).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TravelTimeConfig {
+    /// Seconds to activate a gate and arrive at the connected system.
+    pub gate_activation_secs: f64,
+    /// Seconds to spool the jump drive for a spatial jump.
+    pub jump_spool_secs: f64,
+    /// Additional seconds per light-year of a spatial jump (`0.0` makes jump time flat).
+    #[serde(default)]
+    pub jump_secs_per_ly: f64,
+}
+
+impl Default for TravelTimeConfig {
+    fn default() -> Self {
+        Self {
+            gate_activation_secs: DEFAULT_GATE_ACTIVATION_SECS,
+            jump_spool_secs: DEFAULT_JUMP_SPOOL_SECS,
+            jump_secs_per_ly: 0.0,
+        }
+    }
+}
+
+impl TravelTimeConfig {
+    /// Estimated seconds to traverse `edge`, ignoring any per-gate override.
+    pub fn edge_seconds(&self, edge: &Edge) -> f64 {
+        match edge.kind {
+            EdgeKind::Gate => self.gate_activation_secs,
+            EdgeKind::Spatial => self.jump_spool_secs + self.jump_secs_per_ly * edge.distance,
+        }
+    }
+}
+
 /// Graph structure used by pathfinding algorithms.
 #[derive(Debug, Clone)]
 pub struct Graph {
     mode: GraphMode,
     adjacency: Arc<HashMap<SystemId, Vec<Edge>>>,
+    gate_penalty: f64,
+    jump_penalty: f64,
 }
 
 impl Graph {
@@ -68,6 +115,30 @@ impl Graph {
             .map(Vec::as_slice)
             .unwrap_or(&[])
     }
+
+    /// Search cost of `edge`: its distance multiplied by the gate or jump penalty the graph
+    /// was built with. Penalties are `1.0` unless set through [`GraphBuildOptions`] on a hybrid
+    /// graph, so the weight usually equals the distance.
+    pub fn edge_weight(&self, edge: &Edge) -> f64 {
+        let penalty = match edge.kind {
+            EdgeKind::Gate => self.gate_penalty,
+            EdgeKind::Spatial => self.jump_penalty,
+        };
+        edge.distance * penalty
+    }
+
+    /// Smallest penalty applied to any edge, used to keep distance heuristics admissible when
+    /// a penalty discounts edges below their physical length.
+    pub(crate) fn min_penalty(&self) -> f64 {
+        self.gate_penalty.min(self.jump_penalty)
+    }
+
+    /// Iterate over every directed edge as `(source, edge)` pairs.
+    pub(crate) fn edges(&self) -> impl Iterator<Item = (SystemId, &Edge)> + '_ {
+        self.adjacency
+            .iter()
+            .flat_map(|(&source, edges)| edges.iter().map(move |edge| (source, edge)))
+    }
 }
 
 impl Default for Graph {
@@ -75,6 +146,8 @@ impl Default for Graph {
         Self {
             mode: GraphMode::Gate,
             adjacency: Arc::new(HashMap::new()),
+            gate_penalty: 1.0,
+            jump_penalty: 1.0,
         }
     }
 }
@@ -91,6 +164,19 @@ impl Graph {
         Self {
             mode,
             adjacency: Arc::new(adjacency),
+            gate_penalty: 1.0,
+            jump_penalty: 1.0,
+        }
+    }
+
+    /// Copy of this graph with its adjacency replaced, keeping the mode and edge penalties.
+    pub(crate) fn with_adjacency(
+        &self,
+        adjacency: std::collections::HashMap<SystemId, Vec<Edge>>,
+    ) -> Self {
+        Self {
+            adjacency: Arc::new(adjacency),
+            ..self.clone()
         }
     }
 }
@@ -102,10 +188,7 @@ pub fn build_graph(starmap: &Starmap) -> Graph {
 
 /// Build a routing graph that only considers gate edges.
 pub fn build_gate_graph(starmap: &Starmap) -> Graph {
-    Graph {
-        mode: GraphMode::Gate,
-        adjacency: Arc::new(build_gate_adjacency(starmap)),
-    }
+    Graph::from_parts(GraphMode::Gate, build_gate_adjacency(starmap))
 }
 
 /// Build a routing graph that only considers spatial jumps.
@@ -119,10 +202,7 @@ pub fn build_hybrid_graph(starmap: &Starmap) -> Graph {
     let spatial = build_spatial_adjacency(starmap);
     let adjacency = merge_adjacency(starmap, gate, spatial);
 
-    Graph {
-        mode: GraphMode::Hybrid,
-        adjacency: Arc::new(adjacency),
-    }
+    Graph::from_parts(GraphMode::Hybrid, adjacency)
 }
 
 fn build_gate_adjacency(starmap: &Starmap) -> HashMap<SystemId, Vec<Edge>> {
@@ -162,6 +242,7 @@ fn build_gate_adjacency(starmap: &Starmap) -> HashMap<SystemId, Vec<Edge>> {
                     })
                     .collect()
             })
+            .map(dedup_edges)
             .unwrap_or_default();
         adjacency.insert(system_id, edges);
     }
@@ -214,8 +295,8 @@ fn build_spatial_adjacency(starmap: &Starmap) -> HashMap<SystemId, Vec<Edge>> {
 ///
 /// This function preserves both gate and spatial edges to the same target system,
 /// as they represent different routing options. A "duplicate" is only an edge with
-/// both the same target AND the same kind (e.g., two spatial edges to the same system).
-/// When duplicates exist, we keep the edge with the shorter distance.
+/// both the same target AND the same kind (e.g., two spatial edges to the same system);
+/// [`dedup_edges`] keeps the shorter one.
 fn merge_adjacency(
     starmap: &Starmap,
     mut gate: HashMap<SystemId, Vec<Edge>>,
@@ -223,23 +304,12 @@ fn merge_adjacency(
 ) -> HashMap<SystemId, Vec<Edge>> {
     for (system_id, spatial_edges) in spatial {
         let entry = gate.entry(system_id).or_default();
-        for edge in spatial_edges {
-            // Check for duplicate: same target AND same kind
-            // Note: A spatial edge to X is NOT considered duplicate of a gate edge to X
-            if let Some(existing) = entry
-                .iter_mut()
-                .find(|existing| existing.target == edge.target && existing.kind == edge.kind)
-            {
-                if edge.distance < existing.distance {
-                    *existing = edge.clone();
-                }
-                continue;
-            }
-            entry.push(edge);
-        }
-        entry.sort_by(|a, b| {
+        entry.extend(spatial_edges);
+        let mut merged = dedup_edges(std::mem::take(entry));
+        merged.sort_by(|a, b| {
             compare_distance(a.distance, b.distance).then_with(|| a.kind.cmp(&b.kind))
         });
+        *entry = merged;
     }
 
     for &system_id in starmap.systems.keys() {
@@ -249,6 +319,31 @@ fn merge_adjacency(
     gate
 }
 
+/// Collapse edges sharing a `(target, kind)` pair into the one with the shortest distance.
+///
+/// Datasets can list the same gate twice, and a gate and a spatial jump to the same system
+/// are deliberately kept apart. Surviving edges keep the position of the first occurrence so
+/// tie-breaking in the search is unchanged.
+fn dedup_edges(edges: Vec<Edge>) -> Vec<Edge> {
+    let mut positions: HashMap<(SystemId, EdgeKind), usize> = HashMap::with_capacity(edges.len());
+    let mut kept: Vec<Edge> = Vec::with_capacity(edges.len());
+    for edge in edges {
+        match positions.entry((edge.target, edge.kind)) {
+            Entry::Occupied(slot) => {
+                let existing = &mut kept[*slot.get()];
+                if edge.distance < existing.distance {
+                    *existing = edge;
+                }
+            }
+            Entry::Vacant(slot) => {
+                slot.insert(kept.len());
+                kept.push(edge);
+            }
+        }
+    }
+    kept
+}
+
 fn compare_distance(a: f64, b: f64) -> Ordering {
     a.partial_cmp(&b).unwrap_or(Ordering::Greater)
 }
@@ -266,6 +361,12 @@ pub struct GraphBuildOptions {
     pub max_temperature: Option<f64>,
     /// Maximum number of nearest neighbours to include for spatial edges.
     pub max_spatial_neighbors: usize,
+    /// Multiplier applied to gate edge weights in hybrid graphs. Values above `1.0` steer the
+    /// search away from gates; reported distances are unaffected.
+    pub gate_penalty: f64,
+    /// Multiplier applied to spatial jump edge weights in hybrid graphs. Values above `1.0`
+    /// favour gate-heavy routes; reported distances are unaffected.
+    pub jump_penalty: f64,
 }
 
 impl Default for GraphBuildOptions {
@@ -275,6 +376,8 @@ impl Default for GraphBuildOptions {
             max_jump: None,
             max_temperature: None,
             max_spatial_neighbors: DEFAULT_MAX_SPATIAL_NEIGHBORS,
+            gate_penalty: 1.0,
+            jump_penalty: 1.0,
         }
     }
 }
@@ -286,15 +389,14 @@ pub fn build_spatial_graph_indexed(starmap: &Starmap, options: &GraphBuildOption
     let (index, _diagnostics) = get_or_build_index(starmap, options);
     let adjacency = build_spatial_adjacency_indexed(starmap, &index, options);
 
-    Graph {
-        mode: GraphMode::Spatial,
-        adjacency: Arc::new(adjacency),
-    }
+    Graph::from_parts(GraphMode::Spatial, adjacency)
 }
 
 /// Build a routing graph that combines gate and spatial edges, using a spatial index.
 ///
 /// If no index is provided, builds one automatically (with a warning for large datasets).
+/// The options' `gate_penalty` and `jump_penalty` scale the search weight of each edge kind
+/// (see [`Graph::edge_weight`]).
 pub fn build_hybrid_graph_indexed(starmap: &Starmap, options: &GraphBuildOptions) -> Graph {
     let (index, _diagnostics) = get_or_build_index(starmap, options);
     let gate = build_gate_adjacency(starmap);
@@ -302,8 +404,9 @@ pub fn build_hybrid_graph_indexed(starmap: &Starmap, options: &GraphBuildOptions
     let adjacency = merge_adjacency(starmap, gate, spatial);
 
     Graph {
-        mode: GraphMode::Hybrid,
-        adjacency: Arc::new(adjacency),
+        gate_penalty: options.gate_penalty,
+        jump_penalty: options.jump_penalty,
+        ..Graph::from_parts(GraphMode::Hybrid, adjacency)
     }
 }
 
@@ -416,7 +519,7 @@ fn build_spatial_adjacency_indexed(
                 .collect()
         };
 
-        adjacency.insert(system.id, edges);
+        adjacency.insert(system.id, dedup_edges(edges));
     }
 
     // Ensure all systems have an entry
@@ -488,6 +591,7 @@ mod tests {
             systems,
             name_to_id,
             adjacency: std::sync::Arc::new(adj),
+            gate_links: Default::default(),
         };
 
         let gate_adj = build_gate_adjacency(&starmap);
@@ -575,6 +679,7 @@ mod tests {
             systems,
             name_to_id,
             adjacency: std::sync::Arc::new(adj),
+            gate_links: Default::default(),
         };
 
         // Build hybrid graph which includes spatial A->C edge
@@ -90,16 +90,20 @@ pub mod path;
 pub mod routing;
 pub mod ship;
 pub mod spatial;
+pub mod suitability;
 pub mod temperature;
 #[cfg(test)]
 pub mod test_helpers;
 
-pub use dataset::{default_dataset_path, ensure_dataset, ensure_e6c3_dataset, DatasetPaths};
+pub use dataset::{
+    default_dataset_path, ensure_dataset, ensure_dataset_with_options, ensure_e6c3_dataset,
+    DatasetOptions, DatasetPaths,
+};
 pub use db::{
-    load_starmap, load_starmap_from_connection, Starmap, System, SystemId, SystemMetadata,
-    SystemPosition,
+    load_starmap, load_starmap_from_connection, GateLink, Starmap, System, SystemId,
+    SystemMetadata, SystemPosition,
 };
-pub use error::{Error, Result};
+pub use error::{Error, ErrorKind, Result};
 pub use fmap::{
     decode_fmap_token, encode_fmap_token, DecodedFmapToken, FmapToken, Waypoint, WaypointType,
 };
@@ -107,27 +111,40 @@ pub use github::DatasetRelease;
 pub use graph::{
     build_gate_graph, build_graph, build_hybrid_graph, build_hybrid_graph_indexed,
     build_spatial_graph, build_spatial_graph_indexed, Edge, EdgeKind, Graph, GraphBuildOptions,
-    GraphMode, SAFE_MAX_SPATIAL_NEIGHBORS,
+    GraphMode, TravelTimeConfig, DEFAULT_GATE_ACTIVATION_SECS, DEFAULT_JUMP_SPOOL_SECS,
+    SAFE_MAX_SPATIAL_NEIGHBORS,
 };
 pub use output::{
-    FuelSummary, RouteEndpoint, RouteOutputKind, RouteRenderMode, RouteStep, RouteSummary,
+    FuelSummary, MissingNamePolicy, RouteEndpoint, RouteOutputKind, RouteRenderMode, RouteStep,
+    RouteSummary,
 };
 pub use path::{
-    find_route, find_route_a_star, find_route_bfs, find_route_dijkstra, PathConstraints,
+    find_reachable, find_route, find_route_a_star, find_route_bfs, find_route_dijkstra,
+    find_route_dijkstra_time, find_route_k, route_hash, HopConstraint, PathConstraints,
+    TravelTimeCost,
 };
 pub use routing::{
-    plan_route, select_planner, AStarPlanner, BfsPlanner, DijkstraPlanner, RouteAlgorithm,
-    RouteConstraints, RouteDiagnostic, RouteOptimization, RoutePlan, RoutePlanner, RouteRequest,
+    explain_route, plan_reachable, plan_route, plan_route_with_debug, select_planner, AStarPlanner,
+    BfsPlanner, DijkstraPlanner, GraphDebug, HopRationale, LandmarkData, ReachBudget,
+    ReachableRequest, RouteAlgorithm, RouteConstraints, RouteDiagnostic, RouteOptimization,
+    RoutePlan, RoutePlanner, RoutePreset, RouteRequest, MAX_REACHABLE_RESULTS,
+    SAFE_PRESET_MAX_TEMPERATURE,
 };
 pub use ship::{
     calculate_cooling_time, calculate_jump_fuel_cost, calculate_route_fuel,
-    compute_cooling_constant, project_fuel_for_hop, FuelConfig, FuelProjection,
-    HeatProjectionParams, ShipAttributes, ShipCatalog, ShipLoadout, FUEL_MASS_PER_UNIT_KG,
-    HEAT_CRITICAL, HEAT_NOMINAL, HEAT_OVERHEATED,
+    compute_cooling_constant, plan_cooling_stops, plan_refuel_stops, project_fuel_for_hop,
+    project_fuel_for_hop_with_reserve, FuelConfig, FuelProjection, HeatProjectionParams,
+    ShipAttributes, ShipCatalog, ShipLoadout, FUEL_MASS_PER_UNIT_KG, HEAT_CRITICAL, HEAT_NOMINAL,
+    HEAT_OVERHEATED,
 };
 pub use ship::{calculate_jump_heat, HeatConfig};
 pub use spatial::{
-    compute_dataset_checksum, read_release_tag, spatial_index_path, try_load_spatial_index,
-    verify_freshness, DatasetMetadata, FreshnessResult, IndexNode, NeighbourQuery, SpatialIndex,
-    VerifyDiagnostics, VerifyOutput, FLAG_HAS_METADATA, INDEX_VERSION_V2,
+    compute_dataset_checksum, compute_dataset_checksum_from_bytes,
+    compute_dataset_checksum_with_progress, install_spatial_index, read_release_tag,
+    spatial_index_path, try_load_spatial_index, verify_freshness, DatasetMetadata, FreshnessResult,
+    IndexNode, NeighbourQuery, SpatialIndex, SpatialIndexSource, VerifyDiagnostics, VerifyOutput,
+    FLAG_HAS_METADATA, INDEX_VERSION_V2,
+};
+pub use suitability::{
+    rank_by_suitability, ScoredNeighbour, SuitabilityScorer, SuitabilityWeights,
 };
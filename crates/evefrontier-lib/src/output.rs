@@ -1,8 +1,9 @@
 use std::fmt::Write;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
 
-use crate::db::{Starmap, SystemId};
+use crate::db::{Starmap, SystemId, SystemPosition};
 use crate::error::{Error, Result};
 use crate::routing::RoutePlan;
 use crate::ship::{
@@ -12,7 +13,7 @@ use crate::ship::{
 use crate::RouteAlgorithm;
 
 /// Classifies the high-level command that produced a route summary.
-#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum RouteOutputKind {
     Route,
@@ -26,8 +27,21 @@ pub enum RouteRenderMode {
     InGameNote,
 }
 
+/// How [`RouteSummary::from_plan_with`] treats route steps whose system has no name.
+///
+/// A nameless step usually means a partial or corrupt dataset. The step is never dropped,
+/// since that would misstate hop distances; the policy only decides whether to continue.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MissingNamePolicy {
+    /// Log a warning and keep the step with `name: None` (rendered as `<unknown>`).
+    #[default]
+    Warn,
+    /// Fail with [`Error::UnnamedRouteSystem`] naming the system ID and route position.
+    Fail,
+}
+
 /// Endpoint within a planned route.
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct RouteEndpoint {
     pub id: SystemId,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -41,7 +55,7 @@ impl RouteEndpoint {
 }
 
 /// Step taken during traversal of a planned route.
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct RouteStep {
     pub index: usize,
     pub id: SystemId,
@@ -68,6 +82,9 @@ pub struct RouteStep {
     /// Heat projection for this hop (present when ship data is supplied).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub heat: Option<crate::ship::HeatProjection>,
+    /// System coordinates in light-years (present after `attach_positions()`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<SystemPosition>,
 }
 
 impl RouteStep {
@@ -77,7 +94,10 @@ impl RouteStep {
 }
 
 /// Structured representation of a planned route that higher-level consumers can serialise.
-#[derive(Debug, Clone, Serialize, PartialEq)]
+///
+/// Summaries round-trip through JSON, so a saved route can be re-rendered without
+/// recomputing it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct RouteSummary {
     pub kind: RouteOutputKind,
     pub algorithm: RouteAlgorithm,
@@ -88,25 +108,43 @@ pub struct RouteSummary {
     pub total_distance: f64,
     /// Distance covered by jump drive (light-years).
     pub jump_distance: f64,
+    /// Mean length of the route's jump-drive hops (light-years); `None` without jumps.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_jump_distance: Option<f64>,
+    /// Longest jump-drive hop on the route (light-years); `None` without jumps.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_jump_distance: Option<f64>,
     pub start: RouteEndpoint,
     pub goal: RouteEndpoint,
     pub steps: Vec<RouteStep>,
     /// Aggregated fuel projection when ship data is provided.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fuel: Option<FuelSummary>,
+    /// Indices into `steps` where the ship must refill its tank, as planned by
+    /// [`plan_refuel_stops`](crate::ship::plan_refuel_stops); empty without fuel projections.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub refuel_at: Vec<usize>,
     /// Aggregated heat summary when ship data is provided.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub heat: Option<crate::ship::HeatSummary>,
+    /// Pauses needed to cool the hull before onward jumps, as planned by
+    /// [`plan_cooling_stops`](crate::ship::plan_cooling_stops); empty without heat projections.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cooling_stops: Vec<crate::ship::CoolingStop>,
     /// fmap URL token for sharing/bookmarking the route.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fmap_url: Option<String>,
     /// Optional summary of the effective routing parameters used to compute this plan.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parameters: Option<RouteParametersSummary>,
+    /// Constraints the planner actually searched with, reported next to the requested values in
+    /// `parameters` so clients can spot automatic adjustments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effective_constraints: Option<EffectiveConstraintsSummary>,
 }
 
 /// Summary of the request-level parameters that were applied by the planner.
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct RouteParametersSummary {
     pub algorithm: crate::routing::RouteAlgorithm,
     pub optimization: crate::routing::RouteOptimization,
@@ -120,11 +158,53 @@ pub struct RouteParametersSummary {
     pub max_jump: Option<f64>,
 }
 
+/// Constraint values the planner searched with after automatic adjustments.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EffectiveConstraintsSummary {
+    /// Per-hop jump limit (light-years), clamped by the ship's heat limit when
+    /// `avoid_critical_state` is on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_jump: Option<f64>,
+    /// Names of the constraints whose effective value differs from the request
+    /// (e.g. `["max_jump"]`); empty when the request was applied as given.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub adjusted: Vec<String>,
+}
+
+impl EffectiveConstraintsSummary {
+    /// Compare the plan's effective constraints against the request that produced it.
+    pub fn from_plan(plan: &RoutePlan, request: &crate::routing::RouteRequest) -> Self {
+        let mut adjusted = Vec::new();
+        if plan.effective_max_jump != request.constraints.max_jump {
+            adjusted.push("max_jump".to_string());
+        }
+        Self {
+            max_jump: plan.effective_max_jump,
+            adjusted,
+        }
+    }
+}
+
 impl RouteSummary {
+    /// Attach each step's coordinates from the starmap.
+    ///
+    /// Positions are opt-in so default summaries (and the service/Lambda payloads built
+    /// from them) stay compact; steps whose system has no position are left as `None`.
+    pub fn attach_positions(&mut self, starmap: &Starmap) {
+        for step in &mut self.steps {
+            step.position = starmap.systems.get(&step.id).and_then(|s| s.position);
+        }
+    }
+
     /// Attach heat projections to each hop using the supplied ship/loadout/config.
     ///
     /// Mirrors `attach_fuel()` behavior: gate steps have zero heat, jumps compute heat using
     /// `calculate_jump_heat()` and the cumulative heat is tracked in the summary.
+    ///
+    /// A step's cumulative heat is its `residual_heat`: the hull temperature on arrival after
+    /// any required cooldown. Each jump starts from the previous system's ambient temperature,
+    /// so heat is not summed across hops. Call `attach_fuel()` first when using dynamic mass
+    /// so each hop departs with the fuel the previous step left in the tank.
     pub fn attach_heat(
         &mut self,
         ship: &ShipAttributes,
@@ -137,10 +217,9 @@ impl RouteSummary {
 
         // Note: we do not track a global generated heat total in the summary; per-step residuals
         // and warnings are used instead.
-        let remaining_fuel = loadout.fuel_load;
-
         let mut warnings = Vec::new();
         let mut total_wait_time_seconds = 0.0;
+        let mut cooldown_stops = 0usize;
         let mut last_residual = crate::ship::HEAT_NOMINAL;
 
         for idx in 1..self.steps.len() {
@@ -176,21 +255,7 @@ impl RouteSummary {
                 });
             }
 
-            let effective_fuel = if config.dynamic_mass {
-                remaining_fuel
-            } else {
-                loadout.fuel_load
-            };
-
-            let mass = ship.base_mass_kg
-                + loadout.cargo_mass_kg
-                + (effective_fuel * FUEL_MASS_PER_UNIT_KG);
-
-            if !mass.is_finite() || mass <= 0.0 {
-                return Err(Error::ShipDataValidation {
-                    message: format!("computed mass must be finite and positive, got {}", mass),
-                });
-            }
+            let mass = self.hop_mass(idx, ship, loadout, config)?;
 
             // Use shared helper for DRY heat projection
             let prev_ambient_opt = self.steps[idx - 1].min_external_temp;
@@ -217,6 +282,9 @@ impl RouteSummary {
             }
             if let Some(wait) = projection.wait_time_seconds {
                 total_wait_time_seconds += wait;
+                if wait > crate::ship::COOLDOWN_STOP_THRESHOLD_SECONDS {
+                    cooldown_stops += 1;
+                }
             }
 
             if let Some(step) = self.steps.get_mut(idx) {
@@ -228,15 +296,50 @@ impl RouteSummary {
         self.heat = Some(crate::ship::HeatSummary {
             total_wait_time_seconds,
             final_residual_heat: last_residual,
+            cooldown_stops,
             warnings,
         });
+        self.cooling_stops = crate::ship::plan_cooling_stops(self, ship, loadout, config)?;
 
         Ok(())
     }
+
+    /// Ship mass in kilograms while making the hop into step `idx`.
+    ///
+    /// In dynamic mode the ship departs with whatever the previous step left in the tank (as
+    /// projected by `attach_fuel()`), matching the scout range projection.
+    pub(crate) fn hop_mass(
+        &self,
+        idx: usize,
+        ship: &ShipAttributes,
+        loadout: &ShipLoadout,
+        config: &crate::ship::HeatConfig,
+    ) -> Result<f64> {
+        let effective_fuel = if config.dynamic_mass {
+            self.steps[idx - 1]
+                .fuel
+                .as_ref()
+                .and_then(|f| f.remaining)
+                .unwrap_or(loadout.fuel_load)
+        } else {
+            loadout.fuel_load
+        };
+
+        let mass =
+            ship.base_mass_kg + loadout.cargo_mass_kg + (effective_fuel * FUEL_MASS_PER_UNIT_KG);
+
+        if !mass.is_finite() || mass <= 0.0 {
+            return Err(Error::ShipDataValidation {
+                message: format!("computed mass must be finite and positive, got {}", mass),
+            });
+        }
+
+        Ok(mass)
+    }
 }
 
 /// Fuel summary aggregated across all route steps.
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct FuelSummary {
     pub total: f64,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -247,15 +350,32 @@ pub struct FuelSummary {
     pub quality: f64,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub warnings: Vec<String>,
+    /// True when the tank ran dry on at least one hop (a `REFUEL` stop is required) or the
+    /// route dipped below the configured fuel reserve.
+    pub fuel_exhausted: bool,
 }
 
 impl RouteSummary {
     /// Convert a [`RoutePlan`] into a structured summary with resolved system names.
+    ///
+    /// Steps whose system has no name are kept with a warning; see
+    /// [`from_plan_with`](Self::from_plan_with) to reject them instead.
     pub fn from_plan(
         kind: RouteOutputKind,
         starmap: &Starmap,
         plan: &RoutePlan,
         request: Option<&crate::routing::RouteRequest>,
+    ) -> Result<Self> {
+        Self::from_plan_with(kind, starmap, plan, request, MissingNamePolicy::default())
+    }
+
+    /// Like [`from_plan`](Self::from_plan), with an explicit policy for nameless systems.
+    pub fn from_plan_with(
+        kind: RouteOutputKind,
+        starmap: &Starmap,
+        plan: &RoutePlan,
+        request: Option<&crate::routing::RouteRequest>,
+        missing_names: MissingNamePolicy,
     ) -> Result<Self> {
         if plan.steps.is_empty() {
             return Err(Error::EmptyRoutePlan);
@@ -265,6 +385,8 @@ impl RouteSummary {
         let mut steps = Vec::with_capacity(plan.steps.len());
         let mut total_distance = 0.0;
         let mut jump_distance = 0.0;
+        let mut jump_count = 0usize;
+        let mut max_jump_distance: Option<f64> = None;
 
         for (index, &system_id) in plan.steps.iter().enumerate() {
             let (distance, method) = if index == 0 {
@@ -278,6 +400,8 @@ impl RouteSummary {
                     total_distance += d;
                     if edge_method.as_deref() == Some("jump") {
                         jump_distance += d;
+                        jump_count += 1;
+                        max_jump_distance = Some(max_jump_distance.map_or(d, |m: f64| m.max(d)));
                     }
                 }
 
@@ -299,10 +423,30 @@ impl RouteSummary {
                 .get(&system_id)
                 .and_then(|s| s.metadata.moon_count);
 
+            let name = starmap
+                .system_name(system_id)
+                .filter(|name| !name.trim().is_empty())
+                .map(|name| name.to_string());
+            if name.is_none() {
+                match missing_names {
+                    MissingNamePolicy::Fail => {
+                        return Err(Error::UnnamedRouteSystem {
+                            system_id,
+                            position: index,
+                        })
+                    }
+                    MissingNamePolicy::Warn => warn!(
+                        system_id,
+                        position = index,
+                        "route step has no system name; the dataset may be incomplete"
+                    ),
+                }
+            }
+
             steps.push(RouteStep {
                 index,
                 id: system_id,
-                name: starmap.system_name(system_id).map(|name| name.to_string()),
+                name,
                 distance,
                 method,
                 min_external_temp,
@@ -310,6 +454,7 @@ impl RouteSummary {
                 moon_count,
                 fuel: None,
                 heat: None,
+                position: None,
             });
         }
 
@@ -336,11 +481,15 @@ impl RouteSummary {
             jumps: plan.jumps,
             total_distance,
             jump_distance,
+            average_jump_distance: (jump_count > 0).then(|| jump_distance / jump_count as f64),
+            max_jump_distance,
             start,
             goal,
             steps,
             fuel: None,
+            refuel_at: Vec::new(),
             heat: None,
+            cooling_stops: Vec::new(),
             fmap_url: None,
             parameters: request.map(|r| RouteParametersSummary {
                 algorithm: r.algorithm,
@@ -352,6 +501,7 @@ impl RouteSummary {
                 avoid_gates: r.constraints.avoid_gates,
                 max_jump: r.constraints.max_jump,
             }),
+            effective_constraints: request.map(|r| EffectiveConstraintsSummary::from_plan(plan, r)),
         })
     }
 
@@ -359,6 +509,7 @@ impl RouteSummary {
     ///
     /// Distance-driven hops receive per-hop fuel data; the first step (origin)
     /// carries no fuel information. The summary's `fuel` field aggregates totals.
+    /// `refuel_at` lists the steps where the tank must be refilled.
     ///
     /// Note: This method inlines gate step handling (zero fuel cost) rather than delegating to
     /// `calculate_route_fuel()` to avoid invoking the full route fuel calculator for each step.
@@ -379,6 +530,7 @@ impl RouteSummary {
         let mut cumulative = 0.0;
         let mut remaining_fuel = loadout.fuel_load;
         let mut refueled = false;
+        let mut reserve_breached = false;
 
         for idx in 1..self.steps.len() {
             let method = self.steps[idx].method.as_deref();
@@ -437,15 +589,18 @@ impl RouteSummary {
             cumulative += hop_cost;
 
             // Use shared helper to detect refuel and build projection
-            let (projection, new_remaining) = crate::ship::project_fuel_for_hop(
+            let (projection, new_remaining) = crate::ship::project_fuel_for_hop_with_reserve(
                 hop_cost,
                 cumulative,
                 remaining_fuel,
                 loadout.fuel_load,
+                fuel_config.reserve,
             );
 
-            if projection.warning.is_some() {
+            if projection.warning.as_deref() == Some("REFUEL") {
                 refueled = true;
+            } else if new_remaining < fuel_config.reserve {
+                reserve_breached = true;
             }
             remaining_fuel = new_remaining;
 
@@ -466,7 +621,9 @@ impl RouteSummary {
             ship_name: Some(ship.name.clone()),
             quality: fuel_config.quality,
             warnings: Vec::new(),
+            fuel_exhausted: refueled || reserve_breached,
         });
+        self.refuel_at = crate::ship::plan_refuel_stops(self, loadout.fuel_load);
 
         Ok(())
     }
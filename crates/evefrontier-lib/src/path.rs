@@ -1,9 +1,16 @@
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use serde::{Deserialize, Serialize};
 
 use crate::db::{Starmap, SystemId};
-use crate::graph::{Edge, EdgeKind, Graph};
-use crate::ship::{calculate_jump_heat, HeatConfig, ShipAttributes, ShipLoadout, HEAT_CRITICAL};
+use crate::graph::{Edge, EdgeKind, Graph, TravelTimeConfig};
+use crate::ship::{
+    calculate_jump_heat, project_heat_for_jump, HeatConfig, HeatProjectionParams, ShipAttributes,
+    ShipLoadout, HEAT_CRITICAL,
+};
 
 // =============================================================================
 // Edge Predicates - composable functions for edge filtering
@@ -32,6 +39,13 @@ fn system_meets_avoidance(target: SystemId, avoided: &HashSet<SystemId>) -> bool
     !avoided.contains(&target)
 }
 
+/// Star temperature of `target`, if the starmap knows it.
+fn star_temperature(starmap: Option<&Starmap>, target: SystemId) -> Option<f64> {
+    starmap
+        .and_then(|m| m.systems.get(&target))
+        .and_then(|s| s.metadata.star_temperature)
+}
+
 /// Check if a system meets the temperature constraint.
 /// Only applies to spatial jumps; non-spatial always passes.
 fn system_meets_temperature(
@@ -46,10 +60,24 @@ fn system_meets_temperature(
     let Some(limit) = max_temperature else {
         return true;
     };
-    let temp = starmap
-        .and_then(|m| m.systems.get(&target))
-        .and_then(|s| s.metadata.star_temperature);
-    temp.is_none_or(|t| t <= limit)
+    star_temperature(starmap, target).is_none_or(|t| t <= limit)
+}
+
+/// Check if a system lies outside the avoided temperature range (inclusive bounds).
+/// Only applies to spatial jumps; non-spatial always passes.
+fn system_outside_temperature_range(
+    edge: &Edge,
+    starmap: Option<&Starmap>,
+    target: SystemId,
+    range: Option<(f64, f64)>,
+) -> bool {
+    if edge.kind != EdgeKind::Spatial {
+        return true;
+    }
+    let Some((min, max)) = range else {
+        return true;
+    };
+    star_temperature(starmap, target).is_none_or(|t| t < min || t > max)
 }
 
 // =============================================================================
@@ -163,6 +191,46 @@ fn check_heat_safety(
 // PathConstraints
 // =============================================================================
 
+/// The constraint that rules out an edge, as reported by [`PathConstraints::violation`].
+///
+/// Names match the route request fields that set each constraint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HopConstraint {
+    /// The spatial jump is longer than `max_jump`.
+    MaxJump,
+    /// Gates are excluded by `avoid_gates`.
+    AvoidGates,
+    /// The target is one of the avoided systems.
+    AvoidSystems,
+    /// The target star is hotter than `max_temperature`.
+    MaxTemperature,
+    /// The target star lies inside `avoid_temperature_range`.
+    AvoidTemperatureRange,
+    /// The jump would push the engine into critical heat (`avoid_critical_state`).
+    AvoidCriticalState,
+}
+
+impl HopConstraint {
+    /// The request field name for this constraint.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            HopConstraint::MaxJump => "max_jump",
+            HopConstraint::AvoidGates => "avoid_gates",
+            HopConstraint::AvoidSystems => "avoid_systems",
+            HopConstraint::MaxTemperature => "max_temperature",
+            HopConstraint::AvoidTemperatureRange => "avoid_temperature_range",
+            HopConstraint::AvoidCriticalState => "avoid_critical_state",
+        }
+    }
+}
+
+impl fmt::Display for HopConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Constraints applied during pathfinding.
 #[derive(Debug, Clone)]
 pub struct PathConstraints {
@@ -174,6 +242,9 @@ pub struct PathConstraints {
     pub avoided_systems: HashSet<SystemId>,
     /// Maximum allowed stellar surface temperature in Kelvin (only enforced for spatial jumps).
     pub max_temperature: Option<f64>,
+    /// Inclusive `(min, max)` band of stellar surface temperatures in Kelvin to avoid (only
+    /// enforced for spatial jumps). Checked alongside `max_temperature`: a target must pass both.
+    pub avoid_temperature_range: Option<(f64, f64)>,
     /// Avoid hops that would cause the engine to become critical (requires ship/loadout).
     /// Defaults to true - heat-aware routing is enabled by default.
     pub avoid_critical_state: bool,
@@ -183,6 +254,8 @@ pub struct PathConstraints {
     pub loadout: Option<ShipLoadout>,
     /// Optional heat configuration (calibration constant etc.); required when `avoid_critical_state` is `true`.
     pub heat_config: Option<HeatConfig>,
+    /// Maximum number of hops in the resulting path; longer paths are pruned even when cheaper.
+    pub max_hops: Option<usize>,
 }
 
 impl Default for PathConstraints {
@@ -192,48 +265,64 @@ impl Default for PathConstraints {
             avoid_gates: false,
             avoided_systems: HashSet::new(),
             max_temperature: None,
+            avoid_temperature_range: None,
             avoid_critical_state: true, // Heat-aware routing enabled by default
             ship: None,
             loadout: None,
             heat_config: None,
+            max_hops: None,
         }
     }
 }
 
 impl PathConstraints {
     /// Check if an edge to a target system is allowed under these constraints.
+    pub(crate) fn allows(&self, starmap: Option<&Starmap>, edge: &Edge, target: SystemId) -> bool {
+        self.violation(starmap, edge, target).is_none()
+    }
+
+    /// The first constraint that rules out an edge to `target`, or `None` if it is allowed.
     ///
-    /// This method composes multiple predicate checks:
+    /// This method composes multiple predicate checks, in order:
     /// 1. Edge distance limit (for spatial jumps)
     /// 2. Gate avoidance policy
     /// 3. System avoidance list
     /// 4. Temperature constraints
     /// 5. Heat safety (avoid critical engine state)
-    pub(crate) fn allows(&self, starmap: Option<&Starmap>, edge: &Edge, target: SystemId) -> bool {
+    pub fn violation(
+        &self,
+        starmap: Option<&Starmap>,
+        edge: &Edge,
+        target: SystemId,
+    ) -> Option<HopConstraint> {
         // Check edge predicates
         if !edge_meets_distance_limit(edge, self.max_jump) {
-            return false;
+            return Some(HopConstraint::MaxJump);
         }
 
         if !edge_meets_gate_policy(edge, self.avoid_gates) {
-            return false;
+            return Some(HopConstraint::AvoidGates);
         }
 
         // Check system predicates
         if !system_meets_avoidance(target, &self.avoided_systems) {
-            return false;
+            return Some(HopConstraint::AvoidSystems);
         }
 
         if !system_meets_temperature(edge, starmap, target, self.max_temperature) {
-            return false;
+            return Some(HopConstraint::MaxTemperature);
+        }
+
+        if !system_outside_temperature_range(edge, starmap, target, self.avoid_temperature_range) {
+            return Some(HopConstraint::AvoidTemperatureRange);
         }
 
         // Check heat safety
         if !check_heat_safety(edge, target, self, starmap) {
-            return false;
+            return Some(HopConstraint::AvoidCriticalState);
         }
 
-        true
+        None
     }
 }
 
@@ -260,9 +349,14 @@ pub fn find_route_bfs(
     let mut queue = VecDeque::new();
 
     parents.insert(start, None);
-    queue.push_back(start);
+    queue.push_back((start, 0usize));
 
-    while let Some(current) = queue.pop_front() {
+    while let Some((current, hops)) = queue.pop_front() {
+        // BFS reaches every system with the fewest hops first, so nothing beyond the
+        // budget can still lead to an in-budget path.
+        if constraints.max_hops.is_some_and(|max| hops >= max) {
+            continue;
+        }
         for edge in graph.neighbours(current) {
             let next = edge.target;
             if parents.contains_key(&next) {
@@ -276,7 +370,7 @@ pub fn find_route_bfs(
             if next == goal {
                 return Some(reconstruct_path(&parents, start, goal));
             }
-            queue.push_back(next);
+            queue.push_back((next, hops + 1));
         }
     }
 
@@ -296,6 +390,144 @@ pub fn find_route_dijkstra(
         return Some(vec![start]);
     }
 
+    dijkstra_excluding(graph, starmap, start, goal, constraints, &HashSet::new())
+        .map(|(path, _)| path)
+}
+
+/// Hash an ordered sequence of systems, for spotting identical routes.
+///
+/// Equal sequences always hash equal; the order of the systems matters. The value is only
+/// stable within one build of the library, so it should not be persisted.
+pub fn route_hash(steps: &[SystemId]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    steps.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Find up to `k` loopless paths from `start` to `goal`, ordered by total distance.
+///
+/// Uses Yen's algorithm on top of the Dijkstra planner, so every candidate honours
+/// `constraints` exactly as [`find_route_dijkstra`] does and the first entry is the path it
+/// would return. Paths are distinct as system sequences; parallel gate and spatial edges
+/// between the same pair of systems do not produce separate entries. Fewer than `k` paths
+/// (possibly none) are returned when the graph does not contain that many.
+pub fn find_route_k(
+    graph: &Graph,
+    starmap: Option<&Starmap>,
+    start: SystemId,
+    goal: SystemId,
+    constraints: &PathConstraints,
+    k: usize,
+) -> Vec<Vec<SystemId>> {
+    if k == 0 {
+        return Vec::new();
+    }
+    if start == goal {
+        return vec![vec![start]];
+    }
+
+    let Some(first) = dijkstra_excluding(graph, starmap, start, goal, constraints, &HashSet::new())
+    else {
+        return Vec::new();
+    };
+
+    let mut accepted: Vec<(Vec<SystemId>, f64)> = vec![first];
+    let mut candidates: Vec<(Vec<SystemId>, f64)> = Vec::new();
+    // Keyed by route hash; each bucket holds the full sequences so a hash collision between
+    // different paths cannot drop a candidate.
+    let mut seen: HashMap<u64, Vec<Vec<SystemId>>> = HashMap::new();
+    seen.entry(route_hash(&accepted[0].0))
+        .or_default()
+        .push(accepted[0].0.clone());
+
+    while accepted.len() < k {
+        let (previous, _) = accepted.last().expect("at least one accepted path").clone();
+
+        for (spur_index, &spur_node) in previous.iter().enumerate().take(previous.len() - 1) {
+            let root = &previous[..=spur_index];
+
+            // Block the next hop of every accepted path that shares this root so the spur
+            // search has to deviate here.
+            let removed_edges: HashSet<(SystemId, SystemId)> = accepted
+                .iter()
+                .filter(|(path, _)| path.len() > spur_index + 1 && path[..=spur_index] == *root)
+                .map(|(path, _)| (spur_node, path[spur_index + 1]))
+                .collect();
+
+            // Root systems other than the spur node may not be revisited, and the root's
+            // hops count against any hop budget.
+            let mut spur_constraints = constraints.clone();
+            spur_constraints
+                .avoided_systems
+                .extend(root[..spur_index].iter().copied());
+            if let Some(max_hops) = constraints.max_hops {
+                let Some(remaining) = max_hops.checked_sub(spur_index) else {
+                    continue;
+                };
+                spur_constraints.max_hops = Some(remaining);
+            }
+
+            let Some((spur_path, spur_cost)) = dijkstra_excluding(
+                graph,
+                starmap,
+                spur_node,
+                goal,
+                &spur_constraints,
+                &removed_edges,
+            ) else {
+                continue;
+            };
+            let Some(root_cost) = path_distance(graph, starmap, root, constraints) else {
+                continue;
+            };
+
+            let mut candidate = root[..spur_index].to_vec();
+            candidate.extend(spur_path);
+            let bucket = seen.entry(route_hash(&candidate)).or_default();
+            if !bucket.contains(&candidate) {
+                bucket.push(candidate.clone());
+                candidates.push((candidate, root_cost + spur_cost));
+            }
+        }
+
+        let Some(best) = candidates
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(&b.0)))
+            .map(|(index, _)| index)
+        else {
+            break;
+        };
+        accepted.push(candidates.swap_remove(best));
+    }
+
+    accepted.into_iter().map(|(path, _)| path).collect()
+}
+
+/// Dijkstra search that additionally skips the directed `(from, to)` hops in `removed_edges`.
+/// Returns the path together with its total distance.
+fn dijkstra_excluding(
+    graph: &Graph,
+    starmap: Option<&Starmap>,
+    start: SystemId,
+    goal: SystemId,
+    constraints: &PathConstraints,
+    removed_edges: &HashSet<(SystemId, SystemId)>,
+) -> Option<(Vec<SystemId>, f64)> {
+    if constraints.max_hops.is_some() {
+        return find_route_hop_bounded(
+            graph,
+            starmap,
+            start,
+            goal,
+            constraints,
+            |edge, from| {
+                (!removed_edges.contains(&(from, edge.target))).then(|| graph.edge_weight(edge))
+            },
+            |_| 0.0,
+        );
+    }
+
     let mut distances: HashMap<SystemId, f64> = HashMap::new();
     let mut parents: HashMap<SystemId, Option<SystemId>> = HashMap::new();
     let mut queue = BinaryHeap::new();
@@ -314,16 +546,19 @@ pub fn find_route_dijkstra(
         }
 
         if entry.node == goal {
-            return Some(reconstruct_path(&parents, start, goal));
+            return Some((reconstruct_path(&parents, start, goal), current_distance));
         }
 
         for edge in graph.neighbours(entry.node) {
             let next = edge.target;
+            if removed_edges.contains(&(entry.node, next)) {
+                continue;
+            }
             if !constraints.allows(starmap, edge, next) {
                 continue;
             }
 
-            let next_cost = current_distance + edge.distance;
+            let next_cost = current_distance + graph.edge_weight(edge);
             if next_cost < *distances.get(&next).unwrap_or(&f64::INFINITY) {
                 distances.insert(next, next_cost);
                 parents.insert(next, Some(entry.node));
@@ -335,6 +570,24 @@ pub fn find_route_dijkstra(
     None
 }
 
+/// Total search cost of `path`, taking the cheapest allowed edge for each hop.
+fn path_distance(
+    graph: &Graph,
+    starmap: Option<&Starmap>,
+    path: &[SystemId],
+    constraints: &PathConstraints,
+) -> Option<f64> {
+    path.windows(2).try_fold(0.0, |total, hop| {
+        graph
+            .neighbours(hop[0])
+            .iter()
+            .filter(|edge| edge.target == hop[1] && constraints.allows(starmap, edge, hop[1]))
+            .map(|edge| graph.edge_weight(edge))
+            .min_by(f64::total_cmp)
+            .map(|weight| total + weight)
+    })
+}
+
 /// Run Dijkstra's algorithm where edge costs are measured in fuel units instead
 /// of distance. Gate traversals have zero fuel cost; spatial hops compute fuel
 /// using `calculate_jump_fuel_cost` with a static total mass approximation.
@@ -351,6 +604,20 @@ pub fn find_route_dijkstra_fuel(
         return Some(vec![start]);
     }
 
+    if constraints.max_hops.is_some() {
+        let fuel_cost = |edge: &Edge, _from: SystemId| match edge.kind {
+            EdgeKind::Gate => Some(0.0),
+            EdgeKind::Spatial => {
+                crate::ship::calculate_jump_fuel_cost(total_mass_kg, edge.distance, fuel_config)
+                    .ok()
+            }
+        };
+        return find_route_hop_bounded(graph, starmap, start, goal, constraints, fuel_cost, |_| {
+            0.0
+        })
+        .map(|(path, _)| path);
+    }
+
     let mut distances: HashMap<SystemId, f64> = HashMap::new();
     let mut parents: HashMap<SystemId, Option<SystemId>> = HashMap::new();
     let mut queue = BinaryHeap::new();
@@ -405,6 +672,304 @@ pub fn find_route_dijkstra_fuel(
     None
 }
 
+/// Parameters for the weighted fuel/heat edge cost used by
+/// [`find_route_dijkstra_weighted`].
+///
+/// A spatial hop costs `fuel_weight * fuel + heat_weight * cooldown`, with fuel in
+/// fuel units and cooldown in seconds. The weights therefore double as the exchange
+/// rate between the two units: `fuel_weight = 1.0, heat_weight = 1.0` treats one fuel
+/// unit and one second of cooldown as equally expensive.
+#[derive(Debug, Clone)]
+pub struct WeightedCost {
+    /// Ship attributes used for heat (specific heat, hull mass).
+    pub ship: ShipAttributes,
+    /// Static total mass approximation used for both fuel and heat.
+    pub total_mass_kg: f64,
+    /// Fuel configuration (quality) used for per-hop fuel cost.
+    pub fuel_config: crate::ship::FuelConfig,
+    /// Heat configuration (calibration constant) used for per-hop heat.
+    pub heat_config: HeatConfig,
+    /// Cost per fuel unit burned.
+    pub fuel_weight: f64,
+    /// Cost per second of cooldown required after a hop.
+    pub heat_weight: f64,
+}
+
+impl WeightedCost {
+    /// Weighted cost of traversing `edge` from `from`, or `None` when the hop cannot be
+    /// evaluated (the edge is then rejected, mirroring the fuel planner).
+    ///
+    /// Cooldown follows [`project_heat_for_jump`]: the hop starts at the origin's ambient
+    /// temperature and cools toward nominal at the destination. Arriving at `goal`
+    /// requires no cooldown; otherwise the next hop is assumed to be a jump. Hops the
+    /// ship cannot cool down from are rejected only when `avoid_critical_state` is set;
+    /// otherwise they cost their fuel alone.
+    fn edge_cost(
+        &self,
+        starmap: Option<&Starmap>,
+        edge: &Edge,
+        from: SystemId,
+        goal: SystemId,
+        constraints: &PathConstraints,
+    ) -> Option<f64> {
+        if edge.kind == EdgeKind::Gate {
+            return Some(0.0);
+        }
+
+        let fuel = crate::ship::calculate_jump_fuel_cost(
+            self.total_mass_kg,
+            edge.distance,
+            &self.fuel_config,
+        )
+        .ok()?;
+
+        let ambient = |id: SystemId| {
+            starmap
+                .and_then(|m| m.systems.get(&id))
+                .and_then(|s| s.metadata.min_external_temp)
+        };
+        let projection = project_heat_for_jump(HeatProjectionParams {
+            mass: self.total_mass_kg,
+            specific_heat: self.ship.specific_heat,
+            distance_ly: edge.distance,
+            hull_mass_kg: self.ship.base_mass_kg,
+            calibration_constant: self.heat_config.calibration_constant,
+            prev_ambient: ambient(from),
+            current_min_external_temp: ambient(edge.target),
+            is_goal: edge.target == goal,
+            next_is_gate: false,
+        })
+        .ok()?;
+        if !projection.can_proceed && constraints.avoid_critical_state {
+            return None;
+        }
+        let cooldown = projection.wait_time_seconds.unwrap_or(0.0);
+
+        Some(self.fuel_weight * fuel + self.heat_weight * cooldown)
+    }
+}
+
+/// Run Dijkstra's algorithm where edge costs are a weighted sum of fuel units and
+/// heat-induced cooldown seconds (see [`WeightedCost`]). Gate traversals are free.
+pub fn find_route_dijkstra_weighted(
+    graph: &Graph,
+    starmap: Option<&Starmap>,
+    start: SystemId,
+    goal: SystemId,
+    constraints: &PathConstraints,
+    cost: &WeightedCost,
+) -> Option<Vec<SystemId>> {
+    if start == goal {
+        return Some(vec![start]);
+    }
+
+    if constraints.max_hops.is_some() {
+        return find_route_hop_bounded(
+            graph,
+            starmap,
+            start,
+            goal,
+            constraints,
+            |edge, from| cost.edge_cost(starmap, edge, from, goal, constraints),
+            |_| 0.0,
+        )
+        .map(|(path, _)| path);
+    }
+
+    let mut distances: HashMap<SystemId, f64> = HashMap::new();
+    let mut parents: HashMap<SystemId, Option<SystemId>> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+
+    distances.insert(start, 0.0);
+    parents.insert(start, None);
+    queue.push(QueueEntry::new(start, 0.0));
+
+    while let Some(entry) = queue.pop() {
+        let Some(&current_cost) = distances.get(&entry.node) else {
+            continue;
+        };
+        if current_cost < entry.cost.0 {
+            continue;
+        }
+
+        if entry.node == goal {
+            return Some(reconstruct_path(&parents, start, goal));
+        }
+
+        for edge in graph.neighbours(entry.node) {
+            let next = edge.target;
+            if !constraints.allows(starmap, edge, next) {
+                continue;
+            }
+
+            // Conservative: if the hop cannot be costed, reject the edge
+            let Some(edge_cost) = cost.edge_cost(starmap, edge, entry.node, goal, constraints)
+            else {
+                continue;
+            };
+
+            let next_cost = current_cost + edge_cost;
+            if next_cost < *distances.get(&next).unwrap_or(&f64::INFINITY) {
+                distances.insert(next, next_cost);
+                parents.insert(next, Some(entry.node));
+                queue.push(QueueEntry::new(next, next_cost));
+            }
+        }
+    }
+
+    None
+}
+
+/// Travel time pricing for [`find_route_dijkstra_time`]: per-hop estimates from a
+/// [`TravelTimeConfig`], overridden for individual gates by `gate_times`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TravelTimeCost {
+    pub config: TravelTimeConfig,
+    /// Seconds to traverse specific gates, keyed by their endpoint systems in either order.
+    pub gate_times: HashMap<(SystemId, SystemId), f64>,
+}
+
+impl TravelTimeCost {
+    /// Seconds to traverse `edge` out of `from`.
+    pub fn edge_seconds(&self, edge: &Edge, from: SystemId) -> f64 {
+        if edge.kind == EdgeKind::Gate {
+            let gate_time = self
+                .gate_times
+                .get(&(from, edge.target))
+                .or_else(|| self.gate_times.get(&(edge.target, from)));
+            if let Some(&seconds) = gate_time {
+                return seconds;
+            }
+        }
+        self.config.edge_seconds(edge)
+    }
+}
+
+/// Run Dijkstra's algorithm minimizing total travel time, pricing each hop with
+/// [`TravelTimeCost::edge_seconds`].
+pub fn find_route_dijkstra_time(
+    graph: &Graph,
+    starmap: Option<&Starmap>,
+    start: SystemId,
+    goal: SystemId,
+    constraints: &PathConstraints,
+    cost: &TravelTimeCost,
+) -> Option<Vec<SystemId>> {
+    if start == goal {
+        return Some(vec![start]);
+    }
+
+    if constraints.max_hops.is_some() {
+        return find_route_hop_bounded(
+            graph,
+            starmap,
+            start,
+            goal,
+            constraints,
+            |edge, from| Some(cost.edge_seconds(edge, from)),
+            |_| 0.0,
+        )
+        .map(|(path, _)| path);
+    }
+
+    let mut times: HashMap<SystemId, f64> = HashMap::new();
+    let mut parents: HashMap<SystemId, Option<SystemId>> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+
+    times.insert(start, 0.0);
+    parents.insert(start, None);
+    queue.push(QueueEntry::new(start, 0.0));
+
+    while let Some(entry) = queue.pop() {
+        let Some(&current_time) = times.get(&entry.node) else {
+            continue;
+        };
+        if current_time < entry.cost.0 {
+            continue;
+        }
+
+        if entry.node == goal {
+            return Some(reconstruct_path(&parents, start, goal));
+        }
+
+        for edge in graph.neighbours(entry.node) {
+            let next = edge.target;
+            if !constraints.allows(starmap, edge, next) {
+                continue;
+            }
+
+            let next_time = current_time + cost.edge_seconds(edge, entry.node);
+            if next_time < *times.get(&next).unwrap_or(&f64::INFINITY) {
+                times.insert(next, next_time);
+                parents.insert(next, Some(entry.node));
+                queue.push(QueueEntry::new(next, next_time));
+            }
+        }
+    }
+
+    None
+}
+
+/// Flood-fill outward from `start` with Dijkstra's algorithm, returning every
+/// system whose cheapest cost stays within `budget`, ordered by ascending cost.
+///
+/// `edge_cost` prices each permitted edge; returning `None` rejects the edge. The
+/// start system is not included. The search stops once `limit` systems have been
+/// settled, so a capped result always holds the cheapest systems.
+pub fn find_reachable<F>(
+    graph: &Graph,
+    starmap: Option<&Starmap>,
+    start: SystemId,
+    constraints: &PathConstraints,
+    budget: f64,
+    limit: usize,
+    edge_cost: F,
+) -> Vec<(SystemId, f64)>
+where
+    F: Fn(&Edge) -> Option<f64>,
+{
+    let mut distances: HashMap<SystemId, f64> = HashMap::new();
+    let mut settled: HashSet<SystemId> = HashSet::new();
+    let mut reachable = Vec::new();
+    let mut queue = BinaryHeap::new();
+
+    distances.insert(start, 0.0);
+    queue.push(QueueEntry::new(start, 0.0));
+
+    while let Some(entry) = queue.pop() {
+        if !settled.insert(entry.node) {
+            continue;
+        }
+
+        let current_cost = entry.cost.0;
+        if entry.node != start {
+            if reachable.len() >= limit {
+                break;
+            }
+            reachable.push((entry.node, current_cost));
+        }
+
+        for edge in graph.neighbours(entry.node) {
+            let next = edge.target;
+            if settled.contains(&next) || !constraints.allows(starmap, edge, next) {
+                continue;
+            }
+
+            let Some(hop_cost) = edge_cost(edge) else {
+                continue;
+            };
+
+            let next_cost = current_cost + hop_cost;
+            if next_cost <= budget && next_cost < *distances.get(&next).unwrap_or(&f64::INFINITY) {
+                distances.insert(next, next_cost);
+                queue.push(QueueEntry::new(next, next_cost));
+            }
+        }
+    }
+
+    reachable
+}
+
 /// Run A* search with an admissible heuristic derived from system positions
 /// when available.
 pub fn find_route_a_star(
@@ -414,18 +979,80 @@ pub fn find_route_a_star(
     goal: SystemId,
     constraints: &PathConstraints,
 ) -> Option<Vec<SystemId>> {
+    find_route_a_star_bounded(graph, starmap, start, goal, constraints, |_| 0.0)
+}
+
+/// A* search whose heuristic is the larger of the straight-line distance and `lower_bound`.
+///
+/// `lower_bound(node)` must never exceed the cheapest remaining cost from `node` to `goal`
+/// in `graph` (for example a landmark triangle-inequality bound); with `|_| 0.0` this is
+/// exactly [`find_route_a_star`].
+pub(crate) fn find_route_a_star_bounded<B>(
+    graph: &Graph,
+    starmap: Option<&Starmap>,
+    start: SystemId,
+    goal: SystemId,
+    constraints: &PathConstraints,
+    lower_bound: B,
+) -> Option<Vec<SystemId>>
+where
+    B: Fn(SystemId) -> f64,
+{
     if start == goal {
         return Some(vec![start]);
     }
 
+    if constraints.max_hops.is_some() {
+        // Edge penalties below 1.0 make an edge cheaper than the straight-line distance, so
+        // scale the heuristic down to keep it admissible.
+        let heuristic_scale = graph.min_penalty().min(1.0);
+        return find_route_hop_bounded(
+            graph,
+            starmap,
+            start,
+            goal,
+            constraints,
+            |edge, _| Some(graph.edge_weight(edge)),
+            |node| heuristic_distance(starmap, node, goal).max(lower_bound(node)) * heuristic_scale,
+        )
+        .map(|(path, _)| path);
+    }
+
+    a_star_search(graph, starmap, start, goal, constraints, lower_bound).0
+}
+
+/// Unbounded A* search behind [`find_route_a_star_bounded`], also returning how many
+/// systems were expanded (popped and relaxed) before the goal was reached.
+pub(crate) fn a_star_search<B>(
+    graph: &Graph,
+    starmap: Option<&Starmap>,
+    start: SystemId,
+    goal: SystemId,
+    constraints: &PathConstraints,
+    lower_bound: B,
+) -> (Option<Vec<SystemId>>, usize)
+where
+    B: Fn(SystemId) -> f64,
+{
+    if start == goal {
+        return (Some(vec![start]), 0);
+    }
+
+    // Edge penalties below 1.0 make an edge cheaper than the straight-line distance, so scale
+    // the heuristic down to keep it admissible.
+    let heuristic_scale = graph.min_penalty().min(1.0);
+    let heuristic = |node: SystemId| {
+        heuristic_distance(starmap, node, goal).max(lower_bound(node)) * heuristic_scale
+    };
+
     let mut g_score: HashMap<SystemId, f64> = HashMap::new();
     let mut parents: HashMap<SystemId, Option<SystemId>> = HashMap::new();
     let mut queue = BinaryHeap::new();
+    let mut expanded = 0;
 
     g_score.insert(start, 0.0);
     parents.insert(start, None);
-    let start_estimate = heuristic_distance(starmap, start, goal);
-    queue.push(AStarEntry::new(start, 0.0, start_estimate));
+    queue.push(AStarEntry::new(start, 0.0, heuristic(start)));
 
     while let Some(entry) = queue.pop() {
         // Check if this is the best-known cost for the current node.
@@ -445,8 +1072,9 @@ pub fn find_route_a_star(
         };
 
         if entry.node == goal {
-            return Some(reconstruct_path(&parents, start, goal));
+            return (Some(reconstruct_path(&parents, start, goal)), expanded);
         }
+        expanded += 1;
 
         for edge in graph.neighbours(entry.node) {
             let next = edge.target;
@@ -454,12 +1082,88 @@ pub fn find_route_a_star(
                 continue;
             }
 
-            let tentative_g = current_score + edge.distance;
+            let tentative_g = current_score + graph.edge_weight(edge);
             if tentative_g < *g_score.get(&next).unwrap_or(&f64::INFINITY) {
                 g_score.insert(next, tentative_g);
                 parents.insert(next, Some(entry.node));
-                let heuristic = heuristic_distance(starmap, next, goal);
-                queue.push(AStarEntry::new(next, tentative_g, heuristic));
+                queue.push(AStarEntry::new(next, tentative_g, heuristic(next)));
+            }
+        }
+    }
+
+    (None, expanded)
+}
+
+/// Best-first search over `(system, hops taken)` states for `constraints.max_hops`.
+///
+/// Tracking the hop count per state lets a pricier path with fewer hops survive when a cheaper
+/// one would exceed the budget, which per-system best costs alone cannot express. `edge_cost`
+/// prices a permitted edge leaving `from` (`None` rejects it) and `heuristic` must be admissible;
+/// pass `|_| 0.0` for plain Dijkstra. Returns the path and its total cost.
+fn find_route_hop_bounded<F, H>(
+    graph: &Graph,
+    starmap: Option<&Starmap>,
+    start: SystemId,
+    goal: SystemId,
+    constraints: &PathConstraints,
+    edge_cost: F,
+    heuristic: H,
+) -> Option<(Vec<SystemId>, f64)>
+where
+    F: Fn(&Edge, SystemId) -> Option<f64>,
+    H: Fn(SystemId) -> f64,
+{
+    type State = (SystemId, usize);
+    let max_hops = constraints.max_hops.unwrap_or(usize::MAX);
+
+    let mut costs: HashMap<State, f64> = HashMap::new();
+    let mut parents: HashMap<State, State> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+
+    costs.insert((start, 0), 0.0);
+    queue.push(HopEntry::new(start, 0, 0.0, heuristic(start)));
+
+    while let Some(entry) = queue.pop() {
+        let state = (entry.node, entry.hops);
+        if costs.get(&state).is_some_and(|&best| best < entry.cost.0) {
+            continue;
+        }
+
+        if entry.node == goal {
+            let mut path = vec![goal];
+            let mut current = state;
+            while let Some(&parent) = parents.get(&current) {
+                path.push(parent.0);
+                current = parent;
+            }
+            path.reverse();
+            return Some((path, entry.cost.0));
+        }
+
+        if entry.hops >= max_hops {
+            continue;
+        }
+
+        for edge in graph.neighbours(entry.node) {
+            let next = edge.target;
+            if !constraints.allows(starmap, edge, next) {
+                continue;
+            }
+            let Some(hop_cost) = edge_cost(edge, entry.node) else {
+                continue;
+            };
+
+            let next_state = (next, entry.hops + 1);
+            let next_cost = entry.cost.0 + hop_cost;
+            if next_cost < *costs.get(&next_state).unwrap_or(&f64::INFINITY) {
+                costs.insert(next_state, next_cost);
+                parents.insert(next_state, state);
+                queue.push(HopEntry::new(
+                    next,
+                    entry.hops + 1,
+                    next_cost,
+                    heuristic(next),
+                ));
             }
         }
     }
@@ -593,6 +1297,42 @@ impl PartialOrd for AStarEntry {
     }
 }
 
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct HopEntry {
+    node: SystemId,
+    hops: usize,
+    cost: FloatOrd,
+    estimate: FloatOrd,
+}
+
+impl HopEntry {
+    fn new(node: SystemId, hops: usize, cost: f64, heuristic: f64) -> Self {
+        Self {
+            node,
+            hops,
+            cost: FloatOrd(cost),
+            estimate: FloatOrd(cost + heuristic),
+        }
+    }
+}
+
+impl Ord for HopEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Min-heap by estimate; fewer hops first on ties so goal states surface early.
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| other.hops.cmp(&self.hops))
+            .then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+impl PartialOrd for HopEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -746,6 +1486,7 @@ mod tests {
             systems,
             name_to_id: std::collections::HashMap::new(),
             adjacency: std::sync::Arc::new(std::collections::HashMap::new()),
+            gate_links: Default::default(),
         };
 
         // Create constraints that request avoid_critical_state and provide a ship with an
@@ -855,6 +1596,7 @@ mod tests {
             systems,
             name_to_id,
             adjacency: std::sync::Arc::new(adj),
+            gate_links: Default::default(),
         };
 
         let graph = crate::graph::build_hybrid_graph(&starmap);
@@ -891,4 +1633,416 @@ mod tests {
         .expect("fuel route found");
         assert_eq!(route_fuel, vec![a.id, b.id, c.id]);
     }
+
+    /// Gate graph from 1 to 4 with three candidate routes:
+    /// - 1 -> 5 -> 4: fewest hops, but slow gates (100 s each) and ~41 ly long
+    /// - 1 -> 2 -> 3 -> 4: shortest at ~10 ly, with 50 s gates
+    /// - 1 -> 6 -> 7 -> 8 -> 4: 30 ly over four default-speed gates
+    fn varied_gate_times() -> (Graph, TravelTimeCost) {
+        let mut adjacency: HashMap<SystemId, Vec<Edge>> = HashMap::new();
+        let mut gate_times = HashMap::new();
+        let mut link = |a: SystemId, b: SystemId, distance: f64, seconds: Option<f64>| {
+            for (from, to) in [(a, b), (b, a)] {
+                adjacency.entry(from).or_default().push(Edge {
+                    target: to,
+                    kind: EdgeKind::Gate,
+                    distance,
+                });
+            }
+            if let Some(seconds) = seconds {
+                gate_times.insert((a, b), seconds);
+            }
+        };
+        link(1, 5, 20.6, Some(100.0));
+        link(5, 4, 20.6, Some(100.0));
+        link(1, 2, 3.2, Some(50.0));
+        link(2, 3, 4.0, Some(50.0));
+        link(3, 4, 3.2, Some(50.0));
+        link(1, 6, 10.0, None);
+        link(6, 7, 5.0, None);
+        link(7, 8, 5.0, None);
+        link(8, 4, 10.0, None);
+
+        let cost = TravelTimeCost {
+            config: TravelTimeConfig::default(),
+            gate_times,
+        };
+        (
+            Graph::from_parts(crate::graph::GraphMode::Gate, adjacency),
+            cost,
+        )
+    }
+
+    #[test]
+    fn time_optimal_route_differs_from_distance_and_hop_optimal() {
+        let (graph, cost) = varied_gate_times();
+        let constraints = unconstrained();
+
+        let hops = find_route_bfs(&graph, None, 1, 4, &constraints);
+        let distance = find_route_dijkstra(&graph, None, 1, 4, &constraints);
+        let time = find_route_dijkstra_time(&graph, None, 1, 4, &constraints, &cost);
+
+        assert_eq!(hops, Some(vec![1, 5, 4]));
+        assert_eq!(distance, Some(vec![1, 2, 3, 4]));
+        assert_eq!(time, Some(vec![1, 6, 7, 8, 4]));
+
+        // Overrides apply in either direction of the gate.
+        let back = find_route_dijkstra_time(&graph, None, 4, 1, &constraints, &cost);
+        assert_eq!(back, Some(vec![4, 8, 7, 6, 1]));
+
+        // Under a tight hop budget the fastest route that fits is chosen.
+        let bounded = PathConstraints {
+            max_hops: Some(3),
+            ..unconstrained()
+        };
+        let time = find_route_dijkstra_time(&graph, None, 1, 4, &bounded, &cost);
+        assert_eq!(time, Some(vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn travel_time_scales_spatial_jumps_by_distance() {
+        let cost = TravelTimeCost {
+            config: TravelTimeConfig {
+                jump_secs_per_ly: 2.0,
+                ..TravelTimeConfig::default()
+            },
+            gate_times: HashMap::from([((1, 2), 5.0)]),
+        };
+        let jump = Edge {
+            target: 2,
+            kind: EdgeKind::Spatial,
+            distance: 10.0,
+        };
+        let gate = Edge {
+            kind: EdgeKind::Gate,
+            ..jump
+        };
+
+        assert_eq!(cost.edge_seconds(&jump, 1), 80.0);
+        assert_eq!(cost.edge_seconds(&gate, 1), 5.0);
+        assert_eq!(cost.edge_seconds(&gate, 3), 10.0);
+    }
+
+    /// Graph with a short two-hop route through a hot hop (1 -> 2 -> 3, 15 ly each) and a
+    /// longer four-hop route whose hops stay below nominal temperature
+    /// (1 -> 4 -> 5 -> 6 -> 3, 9 ly each).
+    fn hot_and_cool_graph() -> Graph {
+        let spatial = |target, distance| Edge {
+            target,
+            kind: EdgeKind::Spatial,
+            distance,
+        };
+        let mut adjacency = HashMap::new();
+        adjacency.insert(1, vec![spatial(2, 15.0), spatial(4, 9.0)]);
+        adjacency.insert(2, vec![spatial(3, 15.0)]);
+        adjacency.insert(4, vec![spatial(5, 9.0)]);
+        adjacency.insert(5, vec![spatial(6, 9.0)]);
+        adjacency.insert(6, vec![spatial(3, 9.0)]);
+        Graph::from_parts(crate::graph::GraphMode::Spatial, adjacency)
+    }
+
+    fn weighted_cost(fuel_weight: f64, heat_weight: f64) -> WeightedCost {
+        // Hull mass * calibration = 1 and specific heat = 1, so a hop heats by 3 K/ly
+        // and burns 10 fuel/ly at the default quality.
+        WeightedCost {
+            ship: ShipAttributes {
+                name: "TestShip".to_string(),
+                base_mass_kg: 1e7,
+                specific_heat: 1.0,
+                fuel_capacity: 1000.0,
+                cargo_capacity: 1000.0,
+            },
+            total_mass_kg: 1e7,
+            fuel_config: crate::ship::FuelConfig::default(),
+            heat_config: HeatConfig::default(),
+            fuel_weight,
+            heat_weight,
+        }
+    }
+
+    #[test]
+    fn dijkstra_weighted_shifts_between_hot_and_cool_routes() {
+        let graph = hot_and_cool_graph();
+        let constraints = PathConstraints {
+            avoid_critical_state: false,
+            ..Default::default()
+        };
+
+        // Fuel only: the shorter hot route burns 300 fuel versus 360 for the cool route.
+        let fuel_only = find_route_dijkstra_weighted(
+            &graph,
+            None,
+            1,
+            3,
+            &constraints,
+            &weighted_cost(1.0, 0.0),
+        )
+        .expect("route found");
+        assert_eq!(fuel_only, vec![1, 2, 3]);
+
+        // The 15 ly hop reaches 45 K and needs ~40 s to cool before the next jump, so
+        // pricing cooldown heavily makes the cool route cheaper.
+        let heat_heavy = find_route_dijkstra_weighted(
+            &graph,
+            None,
+            1,
+            3,
+            &constraints,
+            &weighted_cost(1.0, 10.0),
+        )
+        .expect("route found");
+        assert_eq!(heat_heavy, vec![1, 4, 5, 6, 3]);
+    }
+
+    #[test]
+    fn weighted_cost_ignores_cooldown_on_arrival_and_gates() {
+        let cost = weighted_cost(0.0, 1.0);
+        let constraints = PathConstraints::default();
+        let hot = Edge {
+            target: 3,
+            kind: EdgeKind::Spatial,
+            distance: 15.0,
+        };
+        let into_goal = cost
+            .edge_cost(None, &hot, 2, 3, &constraints)
+            .expect("costed");
+        assert_eq!(into_goal, 0.0);
+
+        let mid_route = cost
+            .edge_cost(None, &hot, 2, 99, &constraints)
+            .expect("costed");
+        assert!(mid_route > 0.0);
+
+        let gate = Edge {
+            target: 3,
+            kind: EdgeKind::Gate,
+            distance: 0.0,
+        };
+        assert_eq!(cost.edge_cost(None, &gate, 2, 99, &constraints), Some(0.0));
+    }
+
+    #[test]
+    fn weighted_cost_rejects_uncoolable_hops_only_when_avoiding_critical_state() {
+        // Departing a 100 K system leaves the hull above nominal, and a thermal mass too
+        // large to represent gives a cooling constant of zero: the ship cannot cool down.
+        let mut systems = HashMap::new();
+        systems.insert(
+            1,
+            System {
+                id: 1,
+                name: "Hot".to_string(),
+                metadata: SystemMetadata {
+                    constellation_id: None,
+                    constellation_name: None,
+                    region_id: None,
+                    region_name: None,
+                    security_status: None,
+                    star_temperature: None,
+                    star_luminosity: None,
+                    min_external_temp: Some(100.0),
+                    planet_count: None,
+                    moon_count: None,
+                },
+                position: None,
+            },
+        );
+        let starmap = Starmap {
+            systems,
+            name_to_id: HashMap::new(),
+            adjacency: std::sync::Arc::new(HashMap::new()),
+            gate_links: Default::default(),
+        };
+        let mut cost = weighted_cost(1.0, 1.0);
+        cost.ship.base_mass_kg = 1e200;
+        cost.ship.specific_heat = 1e200;
+        cost.total_mass_kg = 1e200;
+        let hop = Edge {
+            target: 2,
+            kind: EdgeKind::Spatial,
+            distance: 10.0,
+        };
+
+        let avoiding = PathConstraints::default();
+        assert!(avoiding.avoid_critical_state);
+        assert_eq!(cost.edge_cost(Some(&starmap), &hop, 1, 3, &avoiding), None);
+
+        let graph = Graph::from_parts(
+            crate::graph::GraphMode::Spatial,
+            HashMap::from([
+                (1, vec![hop.clone()]),
+                (
+                    2,
+                    vec![Edge {
+                        target: 3,
+                        kind: EdgeKind::Spatial,
+                        distance: 10.0,
+                    }],
+                ),
+            ]),
+        );
+        let heat_blind = PathConstraints {
+            avoid_critical_state: false,
+            ..Default::default()
+        };
+        let fuel_only = cost
+            .edge_cost(Some(&starmap), &hop, 1, 3, &heat_blind)
+            .expect("costed without heat avoidance");
+        assert!(fuel_only > 0.0);
+        assert_eq!(
+            find_route_dijkstra_weighted(&graph, Some(&starmap), 1, 3, &heat_blind, &cost),
+            Some(vec![1, 2, 3])
+        );
+    }
+
+    /// Diamond with a tail: 1 -> 2 -> 4 (2 + 2), 1 -> 3 -> 4 (3 + 3), 1 -> 4 (10) and a
+    /// detour 2 -> 3 (0.5).
+    fn diamond_graph() -> Graph {
+        let spatial = |target, distance| Edge {
+            target,
+            kind: EdgeKind::Spatial,
+            distance,
+        };
+        let mut adjacency = HashMap::new();
+        adjacency.insert(1, vec![spatial(2, 2.0), spatial(3, 3.0), spatial(4, 10.0)]);
+        adjacency.insert(2, vec![spatial(4, 2.0), spatial(3, 0.5)]);
+        adjacency.insert(3, vec![spatial(4, 3.0)]);
+        Graph::from_parts(crate::graph::GraphMode::Spatial, adjacency)
+    }
+
+    fn unconstrained() -> PathConstraints {
+        PathConstraints {
+            avoid_critical_state: false,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn find_route_k_orders_distinct_paths_by_cost() {
+        let graph = diamond_graph();
+        let routes = find_route_k(&graph, None, 1, 4, &unconstrained(), 10);
+
+        assert_eq!(
+            routes,
+            vec![vec![1, 2, 4], vec![1, 2, 3, 4], vec![1, 3, 4], vec![1, 4],]
+        );
+        assert_eq!(
+            routes[0],
+            find_route_dijkstra(&graph, None, 1, 4, &unconstrained()).unwrap()
+        );
+        assert_eq!(
+            find_route_k(&graph, None, 1, 4, &unconstrained(), 2).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn find_route_k_applies_constraints_to_every_candidate() {
+        let graph = diamond_graph();
+        let mut constraints = unconstrained();
+        constraints.avoided_systems.insert(2);
+        constraints.max_jump = Some(5.0);
+
+        let routes = find_route_k(&graph, None, 1, 4, &constraints, 5);
+        assert_eq!(routes, vec![vec![1, 3, 4]]);
+    }
+
+    #[test]
+    fn find_route_k_returns_empty_without_route() {
+        let graph = diamond_graph();
+        assert!(find_route_k(&graph, None, 4, 1, &unconstrained(), 3).is_empty());
+        assert!(find_route_k(&graph, None, 1, 4, &unconstrained(), 0).is_empty());
+        assert_eq!(
+            find_route_k(&graph, None, 1, 1, &unconstrained(), 3),
+            vec![vec![1]]
+        );
+    }
+
+    /// A cheap four-hop chain 1 -> 2 -> 3 -> 4 -> 5 (1 ly per hop) alongside an expensive
+    /// shortcut 1 -> 4 (10 ly), so system 4 is cheapest to reach with the most hops.
+    fn hop_budget_graph() -> Graph {
+        let spatial = |target, distance| Edge {
+            target,
+            kind: EdgeKind::Spatial,
+            distance,
+        };
+        let mut adjacency = HashMap::new();
+        adjacency.insert(1, vec![spatial(2, 1.0), spatial(4, 10.0)]);
+        adjacency.insert(2, vec![spatial(3, 1.0)]);
+        adjacency.insert(3, vec![spatial(4, 1.0)]);
+        adjacency.insert(4, vec![spatial(5, 1.0)]);
+        Graph::from_parts(crate::graph::GraphMode::Spatial, adjacency)
+    }
+
+    fn with_max_hops(max_hops: Option<usize>) -> PathConstraints {
+        PathConstraints {
+            max_hops,
+            ..unconstrained()
+        }
+    }
+
+    #[test]
+    fn max_hops_prefers_short_expensive_route_when_tight() {
+        let graph = hop_budget_graph();
+        let cheap = vec![1, 2, 3, 4, 5];
+        let short = vec![1, 4, 5];
+
+        for (max_hops, expected) in [(None, &cheap), (Some(4), &cheap), (Some(2), &short)] {
+            let constraints = with_max_hops(max_hops);
+            assert_eq!(
+                find_route_dijkstra(&graph, None, 1, 5, &constraints).as_ref(),
+                Some(expected),
+                "dijkstra with max_hops {max_hops:?}"
+            );
+            assert_eq!(
+                find_route_a_star(&graph, None, 1, 5, &constraints).as_ref(),
+                Some(expected),
+                "a* with max_hops {max_hops:?}"
+            );
+            assert_eq!(
+                find_route_dijkstra_fuel(
+                    &graph,
+                    None,
+                    1,
+                    5,
+                    &constraints,
+                    1e7,
+                    &crate::ship::FuelConfig::default()
+                )
+                .as_ref(),
+                Some(expected),
+                "fuel dijkstra with max_hops {max_hops:?}"
+            );
+        }
+
+        assert_eq!(
+            find_route_k(&graph, None, 1, 5, &with_max_hops(Some(2)), 5),
+            vec![short]
+        );
+    }
+
+    #[test]
+    fn max_hops_prunes_every_planner_when_no_route_fits() {
+        let graph = hop_budget_graph();
+        let constraints = with_max_hops(Some(1));
+
+        assert_eq!(find_route_bfs(&graph, None, 1, 5, &constraints), None);
+        assert_eq!(find_route_dijkstra(&graph, None, 1, 5, &constraints), None);
+        assert_eq!(find_route_a_star(&graph, None, 1, 5, &constraints), None);
+        assert_eq!(
+            find_route_dijkstra_weighted(
+                &graph,
+                None,
+                1,
+                5,
+                &constraints,
+                &weighted_cost(1.0, 0.0)
+            ),
+            None
+        );
+        assert!(find_route_k(&graph, None, 1, 5, &constraints, 3).is_empty());
+        assert_eq!(
+            find_route_bfs(&graph, None, 1, 5, &with_max_hops(Some(2))),
+            Some(vec![1, 4, 5])
+        );
+    }
 }
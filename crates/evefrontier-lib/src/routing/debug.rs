@@ -0,0 +1,111 @@
+//! Graph statistics for diagnosing route planning failures.
+//!
+//! When [`plan_route`](super::plan_route) reports `RouteNotFound` it is often
+//! because the constraints left the goal with no usable way in. [`plan_route_with_debug`]
+//! rebuilds the graph the planner searched and summarises it so that case is visible.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::db::{Starmap, SystemId};
+use crate::error::Result;
+use crate::graph::{EdgeKind, Graph, GraphMode};
+use crate::path::PathConstraints as SearchConstraints;
+
+use super::{
+    compute_effective_constraints, plan_route, resolve_avoided_systems, resolve_system,
+    select_graph, RoutePlan, RouteRequest,
+};
+
+/// Summary of the graph constructed for a route request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphDebug {
+    /// Graph variant selected for the request's algorithm and constraints.
+    pub mode: GraphMode,
+    /// Distinct systems appearing in the graph, as an edge source or target.
+    pub node_count: usize,
+    /// Directed gate edges in the graph as built, before per-hop constraint checks.
+    pub gate_edges: usize,
+    /// Directed spatial edges in the graph as built, before per-hop constraint checks.
+    pub spatial_edges: usize,
+    /// Edges into the goal that pass the request's constraints. Zero means the
+    /// constraints disconnected the goal.
+    pub goal_in_edges: usize,
+}
+
+impl GraphDebug {
+    fn from_graph(
+        graph: &Graph,
+        starmap: &Starmap,
+        goal: SystemId,
+        constraints: &SearchConstraints,
+    ) -> Self {
+        let mut nodes = HashSet::new();
+        let (mut gate_edges, mut spatial_edges, mut goal_in_edges) = (0, 0, 0);
+        for (source, edge) in graph.edges() {
+            nodes.insert(source);
+            nodes.insert(edge.target);
+            match edge.kind {
+                EdgeKind::Gate => gate_edges += 1,
+                EdgeKind::Spatial => spatial_edges += 1,
+            }
+            if edge.target == goal && constraints.allows(Some(starmap), edge, goal) {
+                goal_in_edges += 1;
+            }
+        }
+
+        Self {
+            mode: graph.mode(),
+            node_count: nodes.len(),
+            gate_edges,
+            spatial_edges,
+            goal_in_edges,
+        }
+    }
+}
+
+impl fmt::Display for GraphDebug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} graph: {} systems, {} gate edges, {} spatial edges; goal has {} usable in-edges",
+            self.mode, self.node_count, self.gate_edges, self.spatial_edges, self.goal_in_edges
+        )
+    }
+}
+
+/// Plan a route like [`plan_route`] and also describe the graph it searched.
+///
+/// The graph statistics cover the `start → goal` leg (the outbound leg of a round
+/// trip) and are `None` when planning fails before a graph is built, for example
+/// on an unknown system name. The graph is rebuilt for the statistics, so this is
+/// slower than [`plan_route`] and meant for troubleshooting only.
+pub fn plan_route_with_debug(
+    starmap: &Starmap,
+    request: &RouteRequest,
+) -> (Result<RoutePlan>, Option<GraphDebug>) {
+    (
+        plan_route(starmap, request),
+        graph_debug(starmap, request).ok(),
+    )
+}
+
+fn graph_debug(starmap: &Starmap, request: &RouteRequest) -> Result<GraphDebug> {
+    let start_id = resolve_system(starmap, &request.start)?;
+    let goal_id = resolve_system(starmap, &request.goal)?;
+    let avoided = resolve_avoided_systems(starmap, &request.constraints)?;
+    let base_constraints = request.constraints.to_search_constraints(avoided);
+    let effective =
+        compute_effective_constraints(starmap, &request.constraints, start_id, &base_constraints);
+
+    let (graph, _diagnostics) = select_graph(
+        starmap,
+        request.algorithm,
+        &effective,
+        request.spatial_index.as_ref().cloned(),
+        request.max_spatial_neighbors,
+        request.constraints.edge_penalties(),
+    );
+
+    Ok(GraphDebug::from_graph(&graph, starmap, goal_id, &effective))
+}
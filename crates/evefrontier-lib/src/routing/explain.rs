@@ -0,0 +1,155 @@
+//! Per-hop rationale for a planned route.
+//!
+//! [`explain_route`] re-checks each hop of a [`RoutePlan`] against the constraints the
+//! planner searched with. It reports how the hop was made and which constraint, if any,
+//! ruled out the other way of making it. For example, a gate can be taken because the
+//! direct jump is longer than `max_jump`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::db::{Starmap, SystemId};
+use crate::error::Result;
+use crate::graph::{Edge, EdgeKind};
+use crate::path::{HopConstraint, PathConstraints as SearchConstraints};
+
+use super::{resolve_avoided_systems, RouteAlgorithm, RoutePlan, RouteRequest};
+
+/// Why one hop of a route was made the way it was.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HopRationale {
+    /// Zero-based hop index; hop `i` runs from step `i` to step `i + 1`.
+    pub hop: usize,
+    /// Departure system name (or ID when unnamed).
+    pub from: String,
+    /// Arrival system name (or ID when unnamed).
+    pub to: String,
+    /// How the hop was made: `"gate"` or `"jump"`.
+    pub method: String,
+    /// Straight-line distance in light-years, when both systems have positions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f64>,
+    /// Constraint that ruled out the alternative way of making this hop, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constraint: Option<HopConstraint>,
+    /// Human-readable explanation.
+    pub reason: String,
+}
+
+/// Explain each hop of `plan`, which must have been planned from `request`.
+///
+/// Hops are classified the same way as [`RouteSummary`](crate::output::RouteSummary)
+/// steps, except that a gate-connected hop counts as a jump when `avoid_gates` rules the
+/// gate out. BFS without `avoid_gates` searches the gate network only, so its hops never
+/// name a constraint. The constraints are rebuilt from the request with the plan's effective
+/// `max_jump`, so a heat-clamped limit is reported as it was searched.
+pub fn explain_route(
+    starmap: &Starmap,
+    request: &RouteRequest,
+    plan: &RoutePlan,
+) -> Result<Vec<HopRationale>> {
+    let avoided = resolve_avoided_systems(starmap, &request.constraints)?;
+    let mut constraints = request.constraints.to_search_constraints(avoided);
+    constraints.max_jump = plan.effective_max_jump;
+    let gates_only = request.algorithm == RouteAlgorithm::Bfs && !constraints.avoid_gates;
+
+    Ok(plan
+        .steps
+        .windows(2)
+        .enumerate()
+        .map(|(hop, pair)| explain_hop(starmap, &constraints, gates_only, hop, pair[0], pair[1]))
+        .collect())
+}
+
+fn explain_hop(
+    starmap: &Starmap,
+    constraints: &SearchConstraints,
+    gates_only: bool,
+    hop: usize,
+    from: SystemId,
+    to: SystemId,
+) -> HopRationale {
+    let distance = straight_line_distance(starmap, from, to);
+    let has_gate = starmap
+        .adjacency
+        .get(&from)
+        .is_some_and(|neighbours| neighbours.contains(&to));
+    let edge = |kind| Edge {
+        target: to,
+        kind,
+        distance: distance.unwrap_or(0.0),
+    };
+
+    let (method, constraint, reason) = if has_gate {
+        match constraints.violation(Some(starmap), &edge(EdgeKind::Gate), to) {
+            Some(constraint) => (
+                "jump",
+                Some(constraint),
+                format!("jumped because the gate is ruled out by {constraint}"),
+            ),
+            None if gates_only => (
+                "gate",
+                None,
+                "took the gate connection; bfs searches the gate network only".to_string(),
+            ),
+            None => {
+                let jump_blocked = distance.and_then(|_| {
+                    constraints.violation(Some(starmap), &edge(EdgeKind::Spatial), to)
+                });
+                match jump_blocked {
+                    Some(constraint) => (
+                        "gate",
+                        Some(constraint),
+                        format!(
+                            "took the gate because a direct jump is ruled out by {}",
+                            describe(constraint, constraints)
+                        ),
+                    ),
+                    None => ("gate", None, "took the gate connection".to_string()),
+                }
+            }
+        }
+    } else {
+        (
+            "jump",
+            None,
+            "jumped because no gate connects these systems".to_string(),
+        )
+    };
+
+    HopRationale {
+        hop,
+        from: system_label(starmap, from),
+        to: system_label(starmap, to),
+        method: method.to_string(),
+        distance,
+        constraint,
+        reason,
+    }
+}
+
+/// Constraint name, with the limit it enforces where that helps the reader.
+fn describe(constraint: HopConstraint, constraints: &SearchConstraints) -> String {
+    match (
+        constraint,
+        constraints.max_jump,
+        constraints.max_temperature,
+    ) {
+        (HopConstraint::MaxJump, Some(limit), _) => format!("max_jump ({limit:.2} ly)"),
+        (HopConstraint::MaxTemperature, _, Some(limit)) => {
+            format!("max_temperature ({limit:.0} K)")
+        }
+        _ => constraint.to_string(),
+    }
+}
+
+fn straight_line_distance(starmap: &Starmap, from: SystemId, to: SystemId) -> Option<f64> {
+    let from = starmap.systems.get(&from)?.position?;
+    let to = starmap.systems.get(&to)?.position?;
+    Some(from.distance_to(&to))
+}
+
+fn system_label(starmap: &Starmap, id: SystemId) -> String {
+    starmap
+        .system_name(id)
+        .map_or_else(|| id.to_string(), str::to_string)
+}
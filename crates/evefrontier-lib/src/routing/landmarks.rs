@@ -0,0 +1,249 @@
+//! Landmark distances for the ALT (A*, Landmarks, Triangle inequality) heuristic.
+//!
+//! For any landmark `L`, the triangle inequality gives `d(v, t) >= |d(L, t) - d(L, v)|`, which
+//! is usually much tighter than the straight-line distance on a sparse gate network. Landmarks
+//! are chosen by farthest-point selection so they sit on the periphery of the map, where the
+//! bound is most informative.
+
+use std::collections::HashMap;
+
+use crate::db::{Starmap, SystemId};
+use crate::graph::{build_gate_graph, Graph, GraphMode};
+use crate::path::{find_reachable, PathConstraints};
+
+/// Precomputed gate-network distances from a set of landmark systems.
+///
+/// Distances are measured over the gate graph, so the bound is only admissible for searches
+/// whose graph cannot be shorter than it: A* applies it to [`GraphMode::Gate`] graphs and
+/// ignores it otherwise. Gates are bidirectional, so one distance table per landmark serves
+/// both directions.
+#[derive(Debug, Clone, Default)]
+pub struct LandmarkData {
+    landmarks: Vec<SystemId>,
+    distances: Vec<HashMap<SystemId, f64>>,
+}
+
+impl LandmarkData {
+    /// Pick up to `count` spread-out landmarks and record their distance to every system.
+    ///
+    /// The first landmark is the system farthest from the lowest system ID; each further one
+    /// maximises its distance to the nearest landmark already chosen, with systems no landmark
+    /// can reach taking priority so every gate component gets covered. Ties go to the lowest ID,
+    /// keeping the selection deterministic.
+    pub fn build(starmap: &Starmap, count: usize) -> Self {
+        let graph = build_gate_graph(starmap);
+        let mut ids: Vec<SystemId> = starmap.systems.keys().copied().collect();
+        ids.sort_unstable();
+
+        let mut data = Self::default();
+        let Some(&seed) = ids.first() else {
+            return data;
+        };
+        if count == 0 {
+            return data;
+        }
+
+        let seed_distances = distances_from(&graph, seed);
+        let mut nearest: HashMap<SystemId, f64> = HashMap::new();
+        let mut next = farthest(&ids, |id| seed_distances.get(&id).copied());
+
+        while let Some(landmark) = next {
+            let distances = distances_from(&graph, landmark);
+            for (&id, &distance) in &distances {
+                let entry = nearest.entry(id).or_insert(f64::INFINITY);
+                *entry = entry.min(distance);
+            }
+            data.landmarks.push(landmark);
+            data.distances.push(distances);
+
+            if data.landmarks.len() >= count {
+                break;
+            }
+            next = farthest(&ids, |id| {
+                if data.landmarks.contains(&id) {
+                    None
+                } else {
+                    Some(nearest.get(&id).copied().unwrap_or(f64::INFINITY))
+                }
+            });
+        }
+
+        data
+    }
+
+    /// Landmark system IDs in selection order.
+    pub fn landmarks(&self) -> &[SystemId] {
+        &self.landmarks
+    }
+
+    /// Number of landmarks.
+    pub fn len(&self) -> usize {
+        self.landmarks.len()
+    }
+
+    /// Whether no landmarks were selected (e.g. an empty starmap).
+    pub fn is_empty(&self) -> bool {
+        self.landmarks.is_empty()
+    }
+
+    /// Largest triangle-inequality lower bound on the gate distance from `from` to `to`.
+    ///
+    /// Landmarks that cannot reach both systems contribute nothing, so the result is `0.0`
+    /// when no landmark covers the pair.
+    pub fn lower_bound(&self, from: SystemId, to: SystemId) -> f64 {
+        self.distances
+            .iter()
+            .filter_map(|distances| Some((distances.get(&from)?, distances.get(&to)?)))
+            .map(|(from, to)| (to - from).abs())
+            .fold(0.0, f64::max)
+    }
+
+    /// Whether the bound is admissible for a search over `graph`.
+    pub(crate) fn applies_to(&self, graph: &Graph) -> bool {
+        !self.is_empty() && graph.mode() == GraphMode::Gate
+    }
+}
+
+/// Gate distance from `origin` to every system it can reach, including itself at `0.0`.
+fn distances_from(graph: &Graph, origin: SystemId) -> HashMap<SystemId, f64> {
+    let constraints = PathConstraints {
+        avoid_critical_state: false,
+        ..PathConstraints::default()
+    };
+    let mut distances: HashMap<SystemId, f64> = find_reachable(
+        graph,
+        None,
+        origin,
+        &constraints,
+        f64::INFINITY,
+        usize::MAX,
+        |edge| Some(graph.edge_weight(edge)),
+    )
+    .into_iter()
+    .collect();
+    distances.insert(origin, 0.0);
+    distances
+}
+
+/// The ID with the greatest score, lowest ID first on ties; `None` scores are skipped.
+fn farthest<F>(ids: &[SystemId], score: F) -> Option<SystemId>
+where
+    F: Fn(SystemId) -> Option<f64>,
+{
+    let mut best: Option<(SystemId, f64)> = None;
+    for &id in ids {
+        let Some(value) = score(id) else {
+            continue;
+        };
+        if best.is_none_or(|(_, best_value)| value > best_value) {
+            best = Some((id, value));
+        }
+    }
+    best.map(|(id, _)| id)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::db::{System, SystemMetadata, SystemPosition};
+    use crate::path::{a_star_search, find_route_a_star};
+
+    fn system(id: SystemId, x: f64, y: f64) -> System {
+        System {
+            id,
+            name: format!("S{id}"),
+            metadata: SystemMetadata {
+                constellation_id: None,
+                constellation_name: None,
+                region_id: None,
+                region_name: None,
+                security_status: None,
+                star_temperature: None,
+                star_luminosity: None,
+                min_external_temp: None,
+                planet_count: None,
+                moon_count: None,
+            },
+            position: SystemPosition::new(x, y, 0.0),
+        }
+    }
+
+    /// Start (1) and goal (4) sit 1 ly apart, but the only gate path detours through 2 and 3.
+    /// A dead-end spur (5, 6) heads straight at the goal and lures the Euclidean heuristic.
+    fn detour_starmap() -> Starmap {
+        let systems = [
+            system(1, 0.0, 0.0),
+            system(2, 0.0, 10.0),
+            system(3, 1.0, 10.0),
+            system(4, 1.0, 0.0),
+            system(5, 0.5, 0.0),
+            system(6, 0.9, 0.0),
+        ];
+        let links = [(1, 2), (2, 3), (3, 4), (1, 5), (5, 6)];
+
+        let mut adjacency: HashMap<SystemId, Vec<SystemId>> = HashMap::new();
+        for (a, b) in links {
+            adjacency.entry(a).or_default().push(b);
+            adjacency.entry(b).or_default().push(a);
+        }
+        Starmap {
+            name_to_id: systems.iter().map(|s| (s.name.clone(), s.id)).collect(),
+            systems: systems.into_iter().map(|s| (s.id, s)).collect(),
+            adjacency: Arc::new(adjacency),
+            gate_links: Default::default(),
+        }
+    }
+
+    #[test]
+    fn build_selects_distinct_spread_out_landmarks() {
+        let starmap = detour_starmap();
+        let data = LandmarkData::build(&starmap, 2);
+
+        // System 1 seeds the search; 4 is farthest from it, then 6 is farthest from 4.
+        assert_eq!(data.landmarks(), &[4, 6]);
+        assert_eq!(LandmarkData::build(&starmap, 50).len(), 6);
+        assert!(LandmarkData::build(&starmap, 0).is_empty());
+        assert!(LandmarkData::build(&Starmap::default(), 3).is_empty());
+    }
+
+    #[test]
+    fn lower_bound_never_exceeds_gate_distance() {
+        let starmap = detour_starmap();
+        let data = LandmarkData::build(&starmap, 2);
+        let graph = build_gate_graph(&starmap);
+
+        for &from in starmap.systems.keys() {
+            let exact = distances_from(&graph, from);
+            for (&to, &distance) in &exact {
+                assert!(data.lower_bound(from, to) <= distance + 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn alt_matches_plain_a_star_with_fewer_expansions() {
+        let starmap = detour_starmap();
+        let graph = build_gate_graph(&starmap);
+        let data = LandmarkData::build(&starmap, 2);
+        let constraints = PathConstraints::default();
+
+        let (plain, plain_expanded) =
+            a_star_search(&graph, Some(&starmap), 1, 4, &constraints, |_| 0.0);
+        let (alt, alt_expanded) = a_star_search(&graph, Some(&starmap), 1, 4, &constraints, |n| {
+            data.lower_bound(n, 4)
+        });
+
+        assert_eq!(plain, Some(vec![1, 2, 3, 4]));
+        assert_eq!(alt, plain);
+        assert_eq!(
+            find_route_a_star(&graph, Some(&starmap), 1, 4, &constraints),
+            plain
+        );
+        assert!(
+            alt_expanded < plain_expanded,
+            "ALT expanded {alt_expanded}, plain A* {plain_expanded}"
+        );
+    }
+}
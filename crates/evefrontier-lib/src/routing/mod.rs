@@ -6,6 +6,7 @@
 //! - [`RouteRequest`] - High-level route planning request
 //! - [`RoutePlan`] - Planned route result
 //! - [`plan_route`] - Main entry point for computing routes
+//! - [`plan_reachable`] - Flood-fill of every system within a distance or fuel budget
 //!
 //! # Strategy Pattern
 //!
@@ -24,27 +25,36 @@
 //! println!("Route: {} hops", plan.hop_count());
 //! ```
 
+mod debug;
+mod explain;
+mod landmarks;
 mod planner;
+mod reachable;
 
+pub use debug::{plan_route_with_debug, GraphDebug};
+pub use explain::{explain_route, HopRationale};
+pub use landmarks::LandmarkData;
 pub use planner::{select_planner, AStarPlanner, BfsPlanner, DijkstraPlanner, RoutePlanner};
+pub use reachable::{plan_reachable, ReachBudget, ReachableRequest, MAX_REACHABLE_RESULTS};
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
+use std::str::FromStr;
 use std::sync::Arc;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::db::{Starmap, SystemId};
 use crate::error::{Error, Result};
 use crate::graph::{
     build_gate_graph, build_hybrid_graph_indexed, build_spatial_graph_indexed, EdgeKind, Graph,
-    GraphBuildOptions, GraphMode,
+    GraphBuildOptions, GraphMode, TravelTimeConfig,
 };
 use crate::path::PathConstraints as SearchConstraints;
 use crate::spatial::SpatialIndex;
 
 /// Supported routing algorithms.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum RouteAlgorithm {
     /// Breadth-first search (unweighted graph).
@@ -58,7 +68,7 @@ pub enum RouteAlgorithm {
 }
 
 /// Optimization objective for route planning.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum RouteOptimization {
     /// Optimize for shortest distance (default behavior).
@@ -66,6 +76,68 @@ pub enum RouteOptimization {
     /// Optimize for minimal fuel consumption (requires ship + loadout).
     #[default]
     Fuel,
+    /// Optimize for a weighted sum of fuel consumption and heat-induced cooldown time
+    /// (requires ship + loadout; uses the request's heat config or its default).
+    ///
+    /// Each spatial hop costs `fuel_weight * fuel + heat_weight * cooldown`, where `fuel`
+    /// is measured in fuel units and `cooldown` is the wait in seconds needed to return
+    /// to nominal temperature after the hop. The weights carry the unit conversion: with
+    /// both set to `1.0`, one fuel unit is worth one second of cooldown. Gate hops are
+    /// free under both terms.
+    Weighted { fuel_weight: f64, heat_weight: f64 },
+    /// Optimize for minimal travel time, charging seconds per gate activation and per
+    /// jump-drive spool plus a per-light-year jump time (see [`TravelTimeConfig`]), with
+    /// individual gates priced by [`RouteConstraints::gate_time_seconds`] where listed.
+    /// Does not require ship data.
+    Time(TravelTimeConfig),
+}
+
+impl RouteOptimization {
+    /// Validate the optimization parameters.
+    ///
+    /// Weighted objectives require finite, non-negative weights with at least one of
+    /// them positive; time objectives require finite, non-negative times.
+    pub fn validate(&self) -> Result<()> {
+        if let RouteOptimization::Time(config) = self {
+            for (kind, value) in [
+                ("gate activation", config.gate_activation_secs),
+                ("jump spool", config.jump_spool_secs),
+                ("jump per-ly", config.jump_secs_per_ly),
+            ] {
+                if !value.is_finite() || value < 0.0 {
+                    return Err(Error::InvalidTravelTime {
+                        kind: kind.to_string(),
+                        value,
+                    });
+                }
+            }
+            return Ok(());
+        }
+
+        let RouteOptimization::Weighted {
+            fuel_weight,
+            heat_weight,
+        } = *self
+        else {
+            return Ok(());
+        };
+
+        for (name, weight) in [("fuel_weight", fuel_weight), ("heat_weight", heat_weight)] {
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(Error::ShipDataValidation {
+                    message: format!("{name} must be finite and non-negative, got {weight}"),
+                });
+            }
+        }
+
+        if fuel_weight == 0.0 && heat_weight == 0.0 {
+            return Err(Error::ShipDataValidation {
+                message: "fuel_weight and heat_weight cannot both be zero".to_string(),
+            });
+        }
+
+        Ok(())
+    }
 }
 
 impl fmt::Display for RouteAlgorithm {
@@ -80,18 +152,91 @@ impl fmt::Display for RouteAlgorithm {
 }
 
 /// Constraints applied during route planning.
-#[derive(Debug, Clone)]
+///
+/// Serializes symmetrically so a client can save the constraints a route was planned with and
+/// send them back to replay it. The ship, loadout, and heat configuration are not part of the
+/// serialized form; missing fields take their [`Default`] values.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct RouteConstraints {
     pub max_jump: Option<f64>,
     pub avoid_systems: Vec<String>,
+    /// Regions, by name (case-insensitive) or numeric ID, whose member systems are avoided
+    /// as if each were listed in `avoid_systems`.
+    pub avoid_regions: Vec<String>,
     pub avoid_gates: bool,
     pub max_temperature: Option<f64>,
+    /// Inclusive `(min, max)` band of star temperatures in Kelvin that spatial jumps must not
+    /// enter, for avoiding a dangerous thermal zone while allowing cooler and hotter systems.
+    /// When `max_temperature` is also set a system must satisfy both, so the effective limit is
+    /// the union of the two exclusions.
+    pub avoid_temperature_range: Option<(f64, f64)>,
     /// Avoid hops that would result in the engine becoming critical (requires ship/loadout).
     pub avoid_critical_state: bool,
     /// Optional ship information used when evaluating heat-based constraints.
+    #[serde(skip)]
     pub ship: Option<crate::ship::ShipAttributes>,
+    #[serde(skip)]
     pub loadout: Option<crate::ship::ShipLoadout>,
+    #[serde(skip)]
     pub heat_config: Option<crate::ship::HeatConfig>,
+    /// Plan the return leg as well: `plan_route` yields start → goal → start as one closed loop.
+    /// Ignored by reachability queries.
+    pub round_trip: bool,
+    /// Maximum number of hops per route; `plan_route` reports `RouteNotFound` when no route
+    /// fits. With `round_trip` the budget applies to each leg.
+    pub max_hops: Option<usize>,
+    /// Multiplier on gate edge weights when planning over the hybrid graph (`1.0` = plain
+    /// distance). Raise it to prefer spatial jumps.
+    pub gate_penalty: f64,
+    /// Multiplier on spatial jump edge weights when planning over the hybrid graph (`1.0` =
+    /// plain distance). Raise it to prefer gates.
+    pub jump_penalty: f64,
+    /// Seconds to traverse specific gates, keyed by their endpoint system IDs in either order.
+    /// Only used by [`RouteOptimization::Time`]; unlisted gates take the objective's
+    /// `gate_activation_secs`.
+    ///
+    /// Serialized as a list of `{"from", "to", "seconds"}` entries ordered by endpoints.
+    #[serde(with = "gate_time_list")]
+    pub gate_time_seconds: HashMap<(SystemId, SystemId), f64>,
+}
+
+/// Serde form of [`RouteConstraints::gate_time_seconds`]; JSON object keys cannot be tuples.
+mod gate_time_list {
+    use std::collections::HashMap;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::db::SystemId;
+
+    #[derive(Serialize, Deserialize)]
+    struct GateTime {
+        from: SystemId,
+        to: SystemId,
+        seconds: f64,
+    }
+
+    pub fn serialize<S: Serializer>(
+        times: &HashMap<(SystemId, SystemId), f64>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut entries: Vec<GateTime> = times
+            .iter()
+            .map(|(&(from, to), &seconds)| GateTime { from, to, seconds })
+            .collect();
+        entries.sort_by_key(|entry| (entry.from, entry.to));
+        entries.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<(SystemId, SystemId), f64>, D::Error> {
+        let entries = Vec::<GateTime>::deserialize(deserializer)?;
+        Ok(entries
+            .into_iter()
+            .map(|entry| ((entry.from, entry.to), entry.seconds))
+            .collect())
+    }
 }
 
 impl Default for RouteConstraints {
@@ -99,28 +244,159 @@ impl Default for RouteConstraints {
         Self {
             max_jump: None,
             avoid_systems: Vec::new(),
+            avoid_regions: Vec::new(),
             avoid_gates: false,
             max_temperature: None,
+            avoid_temperature_range: None,
             // Sensible default: avoid critical state unless the caller disables it
             avoid_critical_state: true,
             ship: None,
             loadout: None,
             heat_config: None,
+            round_trip: false,
+            max_hops: None,
+            gate_penalty: 1.0,
+            jump_penalty: 1.0,
+            gate_time_seconds: HashMap::new(),
         }
     }
 }
 
 impl RouteConstraints {
+    /// Constraints seeded from a named preset; fields the preset does not cover keep their
+    /// defaults.
+    ///
+    /// The preset's algorithm and optimization are exposed separately through
+    /// [`RoutePreset::algorithm`] and [`RoutePreset::optimization`].
+    pub fn preset(preset: RoutePreset) -> Self {
+        let defaults = Self::default();
+        match preset {
+            RoutePreset::Fast => Self {
+                max_temperature: None,
+                avoid_critical_state: false,
+                ..defaults
+            },
+            RoutePreset::Safe => Self {
+                max_temperature: Some(SAFE_PRESET_MAX_TEMPERATURE),
+                avoid_critical_state: true,
+                ..defaults
+            },
+            RoutePreset::Cheap => Self {
+                avoid_gates: false,
+                ..defaults
+            },
+        }
+    }
+
+    /// Validate constraint values the type system cannot check.
+    ///
+    /// An `avoid_temperature_range` needs finite bounds with `min <= max`, the gate and
+    /// jump penalties must be finite and positive, and per-gate times finite and non-negative.
+    pub fn validate(&self) -> Result<()> {
+        if let Some((min, max)) = self.avoid_temperature_range {
+            if !min.is_finite() || !max.is_finite() || min > max {
+                return Err(Error::InvalidTemperatureRange { min, max });
+            }
+        }
+        for (name, value) in [("gate", self.gate_penalty), ("jump", self.jump_penalty)] {
+            if !value.is_finite() || value <= 0.0 {
+                return Err(Error::InvalidEdgePenalty {
+                    kind: name.to_string(),
+                    value,
+                });
+            }
+        }
+        for (&(from, to), &value) in &self.gate_time_seconds {
+            if !value.is_finite() || value < 0.0 {
+                return Err(Error::InvalidTravelTime {
+                    kind: format!("gate {from}-{to}"),
+                    value,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// The `(gate, jump)` penalties passed to hybrid graph construction.
+    fn edge_penalties(&self) -> (f64, f64) {
+        (self.gate_penalty, self.jump_penalty)
+    }
+
     fn to_search_constraints(&self, avoided: HashSet<SystemId>) -> SearchConstraints {
         SearchConstraints {
             max_jump: self.max_jump,
             avoid_gates: self.avoid_gates,
             avoided_systems: avoided,
             max_temperature: self.max_temperature,
+            avoid_temperature_range: self.avoid_temperature_range,
             avoid_critical_state: self.avoid_critical_state,
             ship: self.ship.clone(),
             loadout: self.loadout,
             heat_config: self.heat_config,
+            max_hops: self.max_hops,
+        }
+    }
+}
+
+/// Maximum star temperature (Kelvin) allowed for spatial jumps by the `safe` preset.
+pub const SAFE_PRESET_MAX_TEMPERATURE: f64 = 5000.0;
+
+/// Named bundles of route settings.
+///
+/// A preset only seeds the request: any constraint the caller sets explicitly replaces the
+/// preset's value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutePreset {
+    /// A* search with no temperature limit.
+    Fast,
+    /// Avoid critical engine state and spatial jumps to stars hotter than
+    /// [`SAFE_PRESET_MAX_TEMPERATURE`].
+    Safe,
+    /// Fuel optimization, which favours gates because gate hops burn no fuel.
+    Cheap,
+}
+
+impl RoutePreset {
+    /// Algorithm implied by the preset, if it picks one.
+    pub fn algorithm(self) -> Option<RouteAlgorithm> {
+        match self {
+            RoutePreset::Fast => Some(RouteAlgorithm::AStar),
+            RoutePreset::Safe | RoutePreset::Cheap => None,
+        }
+    }
+
+    /// Optimization objective implied by the preset, if it picks one.
+    pub fn optimization(self) -> Option<RouteOptimization> {
+        match self {
+            RoutePreset::Cheap => Some(RouteOptimization::Fuel),
+            RoutePreset::Fast | RoutePreset::Safe => None,
+        }
+    }
+}
+
+impl fmt::Display for RoutePreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            RoutePreset::Fast => "fast",
+            RoutePreset::Safe => "safe",
+            RoutePreset::Cheap => "cheap",
+        };
+        f.write_str(value)
+    }
+}
+
+impl FromStr for RoutePreset {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "fast" => Ok(RoutePreset::Fast),
+            "safe" => Ok(RoutePreset::Safe),
+            "cheap" => Ok(RoutePreset::Cheap),
+            _ => Err(Error::UnsupportedRouteOption {
+                option: format!("preset '{value}'"),
+            }),
         }
     }
 }
@@ -137,7 +413,7 @@ pub struct RouteRequest {
     pub spatial_index: Option<Arc<SpatialIndex>>,
     /// Maximum spatial neighbours to consider when building the spatial/hybrid graph.
     pub max_spatial_neighbors: usize,
-    /// Optimization objective used by the planner (distance or fuel).
+    /// Optimization objective used by the planner (distance, fuel, or weighted).
     pub optimization: RouteOptimization,
     /// Fuel configuration used when optimizing for fuel (quality/dynamic_mass).
     pub fuel_config: crate::ship::FuelConfig,
@@ -178,7 +454,7 @@ pub enum RouteDiagnostic {
 }
 
 /// Planned route with optional diagnostic messages.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RoutePlan {
     pub algorithm: RouteAlgorithm,
     pub start: SystemId,
@@ -187,6 +463,10 @@ pub struct RoutePlan {
     pub gates: usize,
     pub jumps: usize,
     pub diagnostics: Vec<RouteDiagnostic>,
+    /// Per-hop jump limit (light-years) the search actually used: the requested `max_jump`,
+    /// tightened by the ship's heat limit when `avoid_critical_state` is on. Differs from the
+    /// request when the planner clamped it.
+    pub effective_max_jump: Option<f64>,
 }
 
 impl RoutePlan {
@@ -194,15 +474,23 @@ impl RoutePlan {
     pub fn hop_count(&self) -> usize {
         self.steps.len().saturating_sub(1)
     }
+
+    /// Hash of the ordered `steps`, for detecting identical routes.
+    ///
+    /// Plans that visit the same systems in the same order hash equal regardless of the
+    /// algorithm or how each hop was made. See [`route_hash`](crate::path::route_hash).
+    pub fn route_hash(&self) -> u64 {
+        crate::path::route_hash(&self.steps)
+    }
 }
 
 // =============================================================================
 // Helper Functions
 // =============================================================================
 
-/// Resolve system names to IDs, returning an error for unknown systems.
+/// Resolve system names or numeric IDs, returning an error for unknown systems.
 fn resolve_system(starmap: &Starmap, name: &str) -> Result<SystemId> {
-    starmap.system_id_by_name(name).ok_or_else(|| {
+    starmap.resolve_system_id(name).ok_or_else(|| {
         let suggestions = starmap.fuzzy_system_matches(name, 3);
         Error::UnknownSystem {
             name: name.to_string(),
@@ -211,48 +499,72 @@ fn resolve_system(starmap: &Starmap, name: &str) -> Result<SystemId> {
     })
 }
 
-/// Resolve a list of avoided system names to their IDs.
-fn resolve_avoided_systems(starmap: &Starmap, avoided: &[String]) -> Result<HashSet<SystemId>> {
+/// Resolve the avoided systems and every member of the avoided regions to system IDs.
+fn resolve_avoided_systems(
+    starmap: &Starmap,
+    constraints: &RouteConstraints,
+) -> Result<HashSet<SystemId>> {
     let mut resolved = HashSet::new();
-    for name in avoided {
+    for name in &constraints.avoid_systems {
         let id = resolve_system(starmap, name)?;
         resolved.insert(id);
     }
+    for region in &constraints.avoid_regions {
+        resolved.extend(resolve_region(starmap, region)?);
+    }
     Ok(resolved)
 }
 
-/// Check if a system meets temperature constraints.
-fn system_meets_temperature(starmap: &Starmap, system: SystemId, limit: Option<f64>) -> bool {
-    let Some(limit) = limit else {
-        return true;
-    };
-    starmap
+/// Member systems of an avoided region, or [`Error::UnknownRegion`] if it has none.
+fn resolve_region(starmap: &Starmap, region: &str) -> Result<Vec<SystemId>> {
+    let members = starmap.systems_in_region(region);
+    if members.is_empty() {
+        return Err(Error::UnknownRegion {
+            name: region.to_string(),
+        });
+    }
+    Ok(members)
+}
+
+/// Check if a system meets the maximum temperature and lies outside any avoided range.
+fn system_meets_temperature(
+    starmap: &Starmap,
+    system: SystemId,
+    constraints: &SearchConstraints,
+) -> bool {
+    let Some(temperature) = starmap
         .systems
         .get(&system)
         .and_then(|sys| sys.metadata.star_temperature)
-        .map(|temperature| temperature <= limit)
-        .unwrap_or(true)
+    else {
+        return true;
+    };
+    constraints
+        .max_temperature
+        .is_none_or(|limit| temperature <= limit)
+        && constraints
+            .avoid_temperature_range
+            .is_none_or(|(min, max)| temperature < min || temperature > max)
 }
 
 /// Compute effective constraints including ship-based limits.
 fn compute_effective_constraints(
     starmap: &Starmap,
-    request: &RouteRequest,
+    constraints: &RouteConstraints,
     start_id: SystemId,
     base_constraints: &SearchConstraints,
 ) -> SearchConstraints {
     let mut effective = base_constraints.clone();
 
-    if let (Some(ship), Some(_loadout)) = (&request.constraints.ship, &request.constraints.loadout)
-    {
+    if let (Some(ship), Some(_loadout)) = (&constraints.ship, &constraints.loadout) {
         // Heat-based maximum distance only applies when avoiding critical engine state
-        if request.constraints.avoid_critical_state {
+        if constraints.avoid_critical_state {
             let ambient = starmap
                 .systems
                 .get(&start_id)
                 .and_then(|s| s.metadata.min_external_temp)
                 .unwrap_or(0.0);
-            let heat_cfg = request.constraints.heat_config.unwrap_or_default();
+            let heat_cfg = constraints.heat_config.unwrap_or_default();
 
             let allowed_delta = crate::ship::HEAT_CRITICAL - ambient;
             if allowed_delta > 0.0 && heat_cfg.calibration_constant > 0.0 {
@@ -279,20 +591,26 @@ fn compute_effective_constraints(
 
 /// Select the appropriate graph for the given algorithm and constraints.
 /// Returns the graph and any diagnostic messages generated during construction.
+///
+/// `edge_penalties` are the `(gate, jump)` weight multipliers applied to the hybrid graph.
 fn select_graph(
     starmap: &Starmap,
     algorithm: RouteAlgorithm,
     constraints: &SearchConstraints,
     spatial_index: Option<Arc<SpatialIndex>>,
     max_spatial_neighbors: usize,
+    edge_penalties: (f64, f64),
 ) -> (Graph, Vec<RouteDiagnostic>) {
     let mut diagnostics = Vec::new();
 
+    let (gate_penalty, jump_penalty) = edge_penalties;
     let options = GraphBuildOptions {
         spatial_index: spatial_index.clone(),
         max_jump: constraints.max_jump,
         max_temperature: constraints.max_temperature,
         max_spatial_neighbors,
+        gate_penalty,
+        jump_penalty,
     };
 
     // If spatial index not provided and we're building spatial/hybrid graph, emit diagnostic
@@ -357,7 +675,7 @@ fn classify_edges(graph: &Graph, steps: &[SystemId]) -> (usize, usize) {
                     .neighbours(u)
                     .iter()
                     .filter(|e| e.target == v)
-                    .min_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap());
+                    .min_by(|a, b| graph.edge_weight(a).total_cmp(&graph.edge_weight(b)));
 
                 match chosen.map(|e| e.kind) {
                     Some(EdgeKind::Gate) => gates += 1,
@@ -389,7 +707,7 @@ fn validate_route_edges(
             .neighbours(u)
             .iter()
             .filter(|e| e.target == v)
-            .min_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap());
+            .min_by(|a, b| graph.edge_weight(a).total_cmp(&graph.edge_weight(b)));
 
         let Some(edge) = chosen else {
             return Err(Error::RouteNotFound {
@@ -451,7 +769,7 @@ fn try_alternative_route(
     goal_id: SystemId,
 ) -> Result<Option<Vec<SystemId>>> {
     let filtered_adj = build_filtered_adjacency(graph, starmap, constraints);
-    let filtered_graph = crate::graph::Graph::from_parts(graph.mode(), filtered_adj);
+    let filtered_graph = graph.with_adjacency(filtered_adj);
 
     let planner = select_planner(request);
     let alt_route = planner.find_path(
@@ -479,25 +797,32 @@ fn try_alternative_route(
 /// Compute a route using the requested algorithm and constraints.
 ///
 /// This is the main entry point for route planning. It:
-/// 1. Resolves system names to IDs
+/// 1. Resolves system names (or numeric ID strings) to IDs
 /// 2. Validates start/goal against constraints
 /// 3. Selects the appropriate planner strategy
 /// 4. Builds the graph and executes pathfinding
 /// 5. Validates the route for safety (heat constraints)
 pub fn plan_route(starmap: &Starmap, request: &RouteRequest) -> Result<RoutePlan> {
+    request.optimization.validate()?;
+    request.constraints.validate()?;
+
+    if request.constraints.round_trip {
+        return plan_round_trip(starmap, request);
+    }
+
     // Step 1: Resolve system names
     let start_id = resolve_system(starmap, &request.start)?;
     let goal_id = resolve_system(starmap, &request.goal)?;
 
     // Step 2: Resolve avoided systems and build base constraints
-    let avoided = resolve_avoided_systems(starmap, &request.constraints.avoid_systems)?;
+    let avoided = resolve_avoided_systems(starmap, &request.constraints)?;
     let base_constraints = request.constraints.to_search_constraints(avoided.clone());
 
     // Step 3: Validate start/goal against constraints
     if base_constraints.avoided_systems.contains(&start_id)
         || base_constraints.avoided_systems.contains(&goal_id)
-        || !system_meets_temperature(starmap, start_id, base_constraints.max_temperature)
-        || !system_meets_temperature(starmap, goal_id, base_constraints.max_temperature)
+        || !system_meets_temperature(starmap, start_id, &base_constraints)
+        || !system_meets_temperature(starmap, goal_id, &base_constraints)
     {
         return Err(Error::RouteNotFound {
             start: request.start.clone(),
@@ -507,7 +832,7 @@ pub fn plan_route(starmap: &Starmap, request: &RouteRequest) -> Result<RoutePlan
 
     // Step 4: Compute effective constraints with ship-based limits
     let effective_constraints =
-        compute_effective_constraints(starmap, request, start_id, &base_constraints);
+        compute_effective_constraints(starmap, &request.constraints, start_id, &base_constraints);
 
     // Step 5: Build graph and select planner
     let (graph, diagnostics) = select_graph(
@@ -516,6 +841,7 @@ pub fn plan_route(starmap: &Starmap, request: &RouteRequest) -> Result<RoutePlan
         &effective_constraints,
         request.spatial_index.as_ref().cloned(),
         request.max_spatial_neighbors,
+        request.constraints.edge_penalties(),
     );
 
     let planner = select_planner(request);
@@ -553,6 +879,8 @@ pub fn plan_route(starmap: &Starmap, request: &RouteRequest) -> Result<RoutePlan
             gates,
             jumps,
             diagnostics,
+            // The fallback search runs with the unclamped base constraints.
+            effective_max_jump: base_constraints.max_jump,
         });
     }
 
@@ -567,6 +895,45 @@ pub fn plan_route(starmap: &Starmap, request: &RouteRequest) -> Result<RoutePlan
         gates,
         jumps,
         diagnostics,
+        effective_max_jump: effective_constraints.max_jump,
+    })
+}
+
+/// Plan start → goal and goal → start with the same request and join them into one loop.
+///
+/// The combined plan keeps `goal` as the turnaround system while `steps` end back at `start`;
+/// gate and jump counts cover both legs. Diagnostics from the return leg are only added when
+/// they differ from the outbound ones (e.g. an in-memory index is reported once), and the
+/// effective `max_jump` is the tighter of the two legs' limits.
+fn plan_round_trip(starmap: &Starmap, request: &RouteRequest) -> Result<RoutePlan> {
+    let mut leg = request.clone();
+    leg.constraints.round_trip = false;
+    let outbound = plan_route(starmap, &leg)?;
+
+    std::mem::swap(&mut leg.start, &mut leg.goal);
+    let inbound = plan_route(starmap, &leg)?;
+
+    let mut steps = outbound.steps;
+    steps.extend(inbound.steps.into_iter().skip(1));
+    let mut diagnostics = outbound.diagnostics;
+    for diagnostic in inbound.diagnostics {
+        if !diagnostics.contains(&diagnostic) {
+            diagnostics.push(diagnostic);
+        }
+    }
+
+    Ok(RoutePlan {
+        algorithm: outbound.algorithm,
+        start: outbound.start,
+        goal: outbound.goal,
+        steps,
+        gates: outbound.gates + inbound.gates,
+        jumps: outbound.jumps + inbound.jumps,
+        diagnostics,
+        effective_max_jump: match (outbound.effective_max_jump, inbound.effective_max_jump) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        },
     })
 }
 
@@ -583,6 +950,111 @@ mod tests {
         assert!(c.heat_config.is_none());
     }
 
+    fn plan(algorithm: RouteAlgorithm, steps: Vec<SystemId>, gates: usize) -> RoutePlan {
+        RoutePlan {
+            algorithm,
+            start: steps[0],
+            goal: *steps.last().unwrap(),
+            jumps: steps.len() - 1 - gates,
+            steps,
+            gates,
+            diagnostics: Vec::new(),
+            effective_max_jump: None,
+        }
+    }
+
+    #[test]
+    fn route_hash_matches_for_identical_steps() {
+        let bfs = plan(RouteAlgorithm::Bfs, vec![1, 2, 3, 4], 3);
+        let a_star = plan(RouteAlgorithm::AStar, vec![1, 2, 3, 4], 1);
+
+        assert_eq!(bfs.route_hash(), a_star.route_hash());
+        assert_eq!(bfs.route_hash(), crate::path::route_hash(&[1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn route_hash_differs_for_different_steps() {
+        let route = plan(RouteAlgorithm::Dijkstra, vec![1, 2, 3, 4], 0);
+        let detour = plan(RouteAlgorithm::Dijkstra, vec![1, 5, 3, 4], 0);
+        let reordered = plan(RouteAlgorithm::Dijkstra, vec![1, 3, 2, 4], 0);
+
+        assert_ne!(route.route_hash(), detour.route_hash());
+        assert_ne!(route.route_hash(), reordered.route_hash());
+    }
+
+    #[test]
+    fn route_constraints_round_trip_through_json() {
+        let constraints = RouteConstraints {
+            max_jump: Some(80.0),
+            avoid_systems: vec!["Brana".to_string()],
+            avoid_regions: vec!["10000001".to_string()],
+            avoid_gates: true,
+            max_temperature: Some(5000.0),
+            avoid_temperature_range: Some((100.0, 200.0)),
+            avoid_critical_state: false,
+            round_trip: true,
+            max_hops: Some(6),
+            gate_penalty: 2.0,
+            jump_penalty: 0.5,
+            gate_time_seconds: HashMap::from([((2, 1), 12.0), ((1, 3), 8.0)]),
+            ..RouteConstraints::default()
+        };
+
+        let json = serde_json::to_value(&constraints).unwrap();
+        assert_eq!(
+            json["gate_time_seconds"],
+            serde_json::json!([
+                { "from": 1, "to": 3, "seconds": 8.0 },
+                { "from": 2, "to": 1, "seconds": 12.0 }
+            ])
+        );
+        assert!(json.get("ship").is_none());
+
+        let back: RouteConstraints = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&back).unwrap(), json);
+        assert_eq!(back.gate_time_seconds, constraints.gate_time_seconds);
+        assert_eq!(back.avoid_temperature_range, Some((100.0, 200.0)));
+    }
+
+    #[test]
+    fn route_constraints_missing_fields_take_defaults() {
+        let constraints: RouteConstraints =
+            serde_json::from_value(serde_json::json!({ "max_jump": 40.0 })).unwrap();
+        assert_eq!(constraints.max_jump, Some(40.0));
+        assert!(constraints.avoid_critical_state);
+        assert_eq!(constraints.gate_penalty, 1.0);
+        assert!(constraints.gate_time_seconds.is_empty());
+    }
+
+    #[test]
+    fn presets_set_documented_fields() {
+        let fast = RouteConstraints::preset(RoutePreset::Fast);
+        assert_eq!(fast.max_temperature, None);
+        assert!(!fast.avoid_critical_state);
+        assert_eq!(RoutePreset::Fast.algorithm(), Some(RouteAlgorithm::AStar));
+
+        let safe = RouteConstraints::preset(RoutePreset::Safe);
+        assert!(safe.avoid_critical_state);
+        assert_eq!(safe.max_temperature, Some(SAFE_PRESET_MAX_TEMPERATURE));
+        assert_eq!(RoutePreset::Safe.algorithm(), None);
+
+        let cheap = RouteConstraints::preset(RoutePreset::Cheap);
+        assert!(!cheap.avoid_gates);
+        assert_eq!(
+            RoutePreset::Cheap.optimization(),
+            Some(RouteOptimization::Fuel)
+        );
+    }
+
+    #[test]
+    fn preset_names_round_trip() {
+        for preset in [RoutePreset::Fast, RoutePreset::Safe, RoutePreset::Cheap] {
+            assert_eq!(preset.to_string().parse::<RoutePreset>().unwrap(), preset);
+        }
+        let err = "scenic".parse::<RoutePreset>().unwrap_err();
+        assert!(err.to_string().contains("preset 'scenic'"), "{err}");
+    }
+
     #[test]
     fn route_plan_hop_count() {
         let plan = RoutePlan {
@@ -593,6 +1065,7 @@ mod tests {
             gates: 2,
             jumps: 0,
             diagnostics: vec![],
+            effective_max_jump: None,
         };
         assert_eq!(plan.hop_count(), 2);
     }
@@ -607,7 +1080,69 @@ mod tests {
             gates: 0,
             jumps: 0,
             diagnostics: vec![],
+            effective_max_jump: None,
         };
         assert_eq!(plan.hop_count(), 0);
     }
+
+    #[test]
+    fn weighted_optimization_validates_weights() {
+        let ok = RouteOptimization::Weighted {
+            fuel_weight: 1.0,
+            heat_weight: 0.0,
+        };
+        assert!(ok.validate().is_ok());
+        assert!(RouteOptimization::Fuel.validate().is_ok());
+
+        for (fuel_weight, heat_weight) in [(-1.0, 1.0), (1.0, f64::NAN), (0.0, 0.0)] {
+            let invalid = RouteOptimization::Weighted {
+                fuel_weight,
+                heat_weight,
+            };
+            assert!(
+                matches!(invalid.validate(), Err(Error::ShipDataValidation { .. })),
+                "expected ({fuel_weight}, {heat_weight}) to be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn time_optimization_validates_times() {
+        assert!(RouteOptimization::Time(TravelTimeConfig::default())
+            .validate()
+            .is_ok());
+
+        for (gate_activation_secs, jump_spool_secs) in
+            [(-1.0, 60.0), (10.0, -1.0), (f64::NAN, 60.0)]
+        {
+            let invalid = RouteOptimization::Time(TravelTimeConfig {
+                gate_activation_secs,
+                jump_spool_secs,
+                ..TravelTimeConfig::default()
+            });
+            assert!(
+                matches!(invalid.validate(), Err(Error::InvalidTravelTime { .. })),
+                "expected ({gate_activation_secs}, {jump_spool_secs}) to be rejected"
+            );
+        }
+
+        let negative_per_ly = RouteOptimization::Time(TravelTimeConfig {
+            jump_secs_per_ly: -0.5,
+            ..TravelTimeConfig::default()
+        });
+        assert!(negative_per_ly.validate().is_err());
+    }
+
+    #[test]
+    fn gate_time_overrides_must_be_non_negative() {
+        let mut constraints = RouteConstraints::default();
+        constraints.gate_time_seconds.insert((1, 2), 0.0);
+        assert!(constraints.validate().is_ok());
+
+        constraints.gate_time_seconds.insert((2, 3), f64::INFINITY);
+        assert!(matches!(
+            constraints.validate(),
+            Err(Error::InvalidTravelTime { ref kind, .. }) if kind == "gate 2-3"
+        ));
+    }
 }
@@ -4,14 +4,18 @@
 //! different routing algorithms (BFS, Dijkstra, A*). The strategy pattern
 //! allows adding new algorithms without modifying the `plan_route` orchestrator.
 
+use std::sync::Arc;
+
 use crate::db::{Starmap, SystemId};
 use crate::graph::Graph;
 use crate::path::{
-    find_route_a_star, find_route_bfs, find_route_dijkstra, PathConstraints as SearchConstraints,
+    find_route_a_star, find_route_a_star_bounded, find_route_bfs, find_route_dijkstra,
+    find_route_dijkstra_time, find_route_dijkstra_weighted, PathConstraints as SearchConstraints,
+    TravelTimeCost, WeightedCost,
 };
 use crate::ship::FuelConfig;
 
-use super::{RouteAlgorithm, RouteOptimization, RouteRequest};
+use super::{LandmarkData, RouteAlgorithm, RouteOptimization, RouteRequest};
 
 /// Trait for route planning strategies.
 ///
@@ -65,12 +69,14 @@ impl RoutePlanner for BfsPlanner {
 
 /// Dijkstra's algorithm planner for weighted graph traversal.
 ///
-/// Can optimize for either distance or fuel consumption.
+/// Can optimize for distance, fuel consumption, a weighted fuel/heat objective, or travel time.
 #[derive(Debug, Clone)]
 pub struct DijkstraPlanner {
     optimization: RouteOptimization,
     fuel_config: FuelConfig,
     ship_mass: Option<f64>,
+    weighted: Option<WeightedCost>,
+    travel_time: Option<TravelTimeCost>,
 }
 
 impl DijkstraPlanner {
@@ -80,6 +86,8 @@ impl DijkstraPlanner {
             optimization: RouteOptimization::Distance,
             fuel_config: FuelConfig::default(),
             ship_mass: None,
+            weighted: None,
+            travel_time: None,
         }
     }
 
@@ -89,6 +97,8 @@ impl DijkstraPlanner {
             optimization: RouteOptimization::Fuel,
             fuel_config,
             ship_mass: Some(ship_mass),
+            weighted: None,
+            travel_time: None,
         }
     }
 
@@ -105,6 +115,8 @@ impl DijkstraPlanner {
             optimization: request.optimization,
             fuel_config: request.fuel_config,
             ship_mass,
+            weighted: weighted_cost_from_request(request),
+            travel_time: travel_time_cost_from_request(request),
         }
     }
 }
@@ -122,6 +134,21 @@ impl RoutePlanner for DijkstraPlanner {
         goal: SystemId,
         constraints: &SearchConstraints,
     ) -> Option<Vec<SystemId>> {
+        if matches!(self.optimization, RouteOptimization::Weighted { .. }) {
+            if let Some(cost) = &self.weighted {
+                return find_route_dijkstra_weighted(
+                    graph,
+                    starmap,
+                    start,
+                    goal,
+                    constraints,
+                    cost,
+                );
+            }
+            tracing::warn!(
+                "weighted optimization requested but missing ship/loadout; falling back to distance"
+            );
+        }
         if self.optimization == RouteOptimization::Fuel {
             if let Some(mass) = self.ship_mass {
                 return crate::path::find_route_dijkstra_fuel(
@@ -138,6 +165,9 @@ impl RoutePlanner for DijkstraPlanner {
                 "fuel optimization requested but missing ship/loadout; falling back to distance"
             );
         }
+        if let Some(cost) = &self.travel_time {
+            return find_route_dijkstra_time(graph, starmap, start, goal, constraints, cost);
+        }
         find_route_dijkstra(graph, starmap, start, goal, constraints)
     }
 
@@ -148,13 +178,17 @@ impl RoutePlanner for DijkstraPlanner {
 
 /// A* algorithm planner for heuristic-guided traversal.
 ///
-/// Uses Euclidean distance as the heuristic. Can fall back to
-/// fuel-based Dijkstra when optimizing for fuel consumption.
+/// Uses Euclidean distance as the heuristic, tightened by landmark (ALT) bounds on gate
+/// graphs when [`LandmarkData`] is attached. Falls back to fuel-based, weighted, or time-based
+/// Dijkstra when optimizing for fuel, fuel/heat, or travel time.
 #[derive(Debug, Clone)]
 pub struct AStarPlanner {
     optimization: RouteOptimization,
     fuel_config: FuelConfig,
     ship_mass: Option<f64>,
+    weighted: Option<WeightedCost>,
+    travel_time: Option<TravelTimeCost>,
+    landmarks: Option<Arc<LandmarkData>>,
 }
 
 impl AStarPlanner {
@@ -164,6 +198,9 @@ impl AStarPlanner {
             optimization: RouteOptimization::Distance,
             fuel_config: FuelConfig::default(),
             ship_mass: None,
+            weighted: None,
+            travel_time: None,
+            landmarks: None,
         }
     }
 
@@ -176,9 +213,21 @@ impl AStarPlanner {
             optimization: RouteOptimization::Fuel,
             fuel_config,
             ship_mass: Some(ship_mass),
+            weighted: None,
+            travel_time: None,
+            landmarks: None,
         }
     }
 
+    /// Attach precomputed landmark distances for the ALT heuristic.
+    ///
+    /// The bound is only used when searching a gate graph; other graphs keep the plain
+    /// Euclidean heuristic.
+    pub fn with_landmarks(mut self, landmarks: Arc<LandmarkData>) -> Self {
+        self.landmarks = Some(landmarks);
+        self
+    }
+
     /// Create a planner from a route request.
     pub fn from_request(request: &RouteRequest) -> Self {
         let ship_mass = request
@@ -192,6 +241,9 @@ impl AStarPlanner {
             optimization: request.optimization,
             fuel_config: request.fuel_config,
             ship_mass,
+            weighted: weighted_cost_from_request(request),
+            travel_time: travel_time_cost_from_request(request),
+            landmarks: None,
         }
     }
 }
@@ -209,8 +261,23 @@ impl RoutePlanner for AStarPlanner {
         goal: SystemId,
         constraints: &SearchConstraints,
     ) -> Option<Vec<SystemId>> {
-        // A* with fuel optimization is approximated by running Dijkstra with fuel costs
-        // to keep heuristic admissibility simple.
+        // A* with fuel, weighted, or time optimization is approximated by running Dijkstra with
+        // the corresponding costs to keep heuristic admissibility simple.
+        if matches!(self.optimization, RouteOptimization::Weighted { .. }) {
+            if let Some(cost) = &self.weighted {
+                return find_route_dijkstra_weighted(
+                    graph,
+                    starmap,
+                    start,
+                    goal,
+                    constraints,
+                    cost,
+                );
+            }
+            tracing::warn!(
+                "weighted optimization requested but missing ship/loadout; falling back to distance A*"
+            );
+        }
         if self.optimization == RouteOptimization::Fuel {
             if let Some(mass) = self.ship_mass {
                 return crate::path::find_route_dijkstra_fuel(
@@ -227,7 +294,17 @@ impl RoutePlanner for AStarPlanner {
                 "fuel optimization requested but missing ship/loadout; falling back to distance A*"
             );
         }
-        find_route_a_star(graph, starmap, start, goal, constraints)
+        if let Some(cost) = &self.travel_time {
+            return find_route_dijkstra_time(graph, starmap, start, goal, constraints, cost);
+        }
+        match &self.landmarks {
+            Some(landmarks) if landmarks.applies_to(graph) => {
+                find_route_a_star_bounded(graph, starmap, start, goal, constraints, |node| {
+                    landmarks.lower_bound(node, goal)
+                })
+            }
+            _ => find_route_a_star(graph, starmap, start, goal, constraints),
+        }
     }
 
     fn requires_spatial_index(&self) -> bool {
@@ -235,6 +312,40 @@ impl RoutePlanner for AStarPlanner {
     }
 }
 
+/// Build the weighted fuel/heat cost parameters for a request, if it asks for a
+/// weighted objective and carries the ship and loadout needed to evaluate it.
+fn weighted_cost_from_request(request: &RouteRequest) -> Option<WeightedCost> {
+    let RouteOptimization::Weighted {
+        fuel_weight,
+        heat_weight,
+    } = request.optimization
+    else {
+        return None;
+    };
+    let ship = request.constraints.ship.as_ref()?;
+    let loadout = request.constraints.loadout.as_ref()?;
+
+    Some(WeightedCost {
+        ship: ship.clone(),
+        total_mass_kg: loadout.total_mass_kg(ship),
+        fuel_config: request.fuel_config,
+        heat_config: request.constraints.heat_config.unwrap_or_default(),
+        fuel_weight,
+        heat_weight,
+    })
+}
+
+/// Build the travel time pricing for a request, if it asks for the time objective.
+fn travel_time_cost_from_request(request: &RouteRequest) -> Option<TravelTimeCost> {
+    let RouteOptimization::Time(config) = request.optimization else {
+        return None;
+    };
+    Some(TravelTimeCost {
+        config,
+        gate_times: request.constraints.gate_time_seconds.clone(),
+    })
+}
+
 /// Select the appropriate planner for a given request.
 pub fn select_planner(request: &RouteRequest) -> Box<dyn RoutePlanner> {
     match request.algorithm {
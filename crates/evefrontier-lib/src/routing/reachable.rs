@@ -0,0 +1,183 @@
+//! Reachability planning: every system within a distance or fuel budget.
+//!
+//! Where [`plan_route`](super::plan_route) answers "how do I get from A to B",
+//! [`plan_reachable`] answers "where can I get to from A". It runs a Dijkstra
+//! flood-fill over the same hybrid (or spatial-only) graph used for routing and
+//! returns each reachable system together with its cheapest cost.
+
+use std::sync::Arc;
+
+use crate::db::{Starmap, SystemId};
+use crate::error::{Error, Result};
+use crate::graph::{EdgeKind, GraphBuildOptions};
+use crate::path::find_reachable;
+use crate::ship::{calculate_jump_fuel_cost, FuelConfig};
+use crate::spatial::SpatialIndex;
+
+use super::{
+    compute_effective_constraints, resolve_avoided_systems, resolve_system, select_graph,
+    RouteAlgorithm, RouteConstraints,
+};
+
+/// Hard upper bound on the number of systems returned by [`plan_reachable`].
+///
+/// Large budgets can cover most of the starmap; callers asking for more than this
+/// are clamped so responses stay a manageable size.
+pub const MAX_REACHABLE_RESULTS: usize = 1_000;
+
+/// Budget spent while flooding outward from the start system.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReachBudget {
+    /// Total light-years travelled, counting gate and spatial hops alike.
+    Distance(f64),
+    /// Total fuel units burned (requires ship + loadout). Gate hops are free.
+    Fuel(f64),
+}
+
+impl ReachBudget {
+    /// Numeric budget regardless of unit.
+    pub fn value(&self) -> f64 {
+        match *self {
+            ReachBudget::Distance(value) | ReachBudget::Fuel(value) => value,
+        }
+    }
+
+    /// Validate that the budget is finite and non-negative.
+    pub fn validate(&self) -> Result<()> {
+        let value = self.value();
+        if !value.is_finite() || value < 0.0 {
+            return Err(Error::InvalidReachBudget {
+                message: format!("budget must be finite and non-negative, got {value}"),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Reachability request: a start system, a budget, and the usual route constraints.
+#[derive(Debug, Clone)]
+pub struct ReachableRequest {
+    pub start: String,
+    pub budget: ReachBudget,
+    pub constraints: RouteConstraints,
+    /// Pre-loaded spatial index for faster graph construction.
+    pub spatial_index: Option<Arc<SpatialIndex>>,
+    /// Maximum spatial neighbours to consider when building the graph.
+    pub max_spatial_neighbors: usize,
+    /// Fuel configuration used to price spatial hops under a fuel budget.
+    pub fuel_config: FuelConfig,
+    /// Maximum number of systems to return (clamped to [`MAX_REACHABLE_RESULTS`]).
+    pub limit: usize,
+}
+
+impl ReachableRequest {
+    /// Convenience constructor for a distance budget without extra constraints.
+    pub fn distance(start: impl Into<String>, budget_ly: f64) -> Self {
+        Self {
+            start: start.into(),
+            budget: ReachBudget::Distance(budget_ly),
+            constraints: RouteConstraints::default(),
+            spatial_index: None,
+            max_spatial_neighbors: GraphBuildOptions::default().max_spatial_neighbors,
+            fuel_config: FuelConfig::default(),
+            limit: MAX_REACHABLE_RESULTS,
+        }
+    }
+
+    /// Attach a pre-loaded spatial index to the request.
+    pub fn with_spatial_index(mut self, index: Arc<SpatialIndex>) -> Self {
+        self.spatial_index = Some(index);
+        self
+    }
+}
+
+/// Find every system reachable from the request's start within its budget.
+///
+/// Returns `(system, cost)` pairs ordered by ascending cost, where cost is in the
+/// budget's unit (light-years or fuel units). The start system itself is omitted.
+/// When more systems fit the budget than the (clamped) limit allows, the cheapest
+/// ones are kept.
+pub fn plan_reachable(
+    starmap: &Starmap,
+    request: &ReachableRequest,
+) -> Result<Vec<(SystemId, f64)>> {
+    request.budget.validate()?;
+    request.constraints.validate()?;
+
+    let start_id = resolve_system(starmap, &request.start)?;
+    let avoided = resolve_avoided_systems(starmap, &request.constraints)?;
+    let base_constraints = request.constraints.to_search_constraints(avoided);
+    let mut effective =
+        compute_effective_constraints(starmap, &request.constraints, start_id, &base_constraints);
+
+    // A single hop can never cost more than the whole distance budget, so there is
+    // no point materialising longer spatial edges.
+    if let ReachBudget::Distance(budget) = request.budget {
+        effective.max_jump = Some(effective.max_jump.map_or(budget, |jump| jump.min(budget)));
+    }
+
+    let ship_mass = match request.budget {
+        ReachBudget::Distance(_) => None,
+        ReachBudget::Fuel(_) => {
+            let (Some(ship), Some(loadout)) =
+                (&request.constraints.ship, &request.constraints.loadout)
+            else {
+                return Err(Error::InvalidReachBudget {
+                    message: "fuel budget requires a ship and loadout".to_string(),
+                });
+            };
+            Some(loadout.total_mass_kg(ship))
+        }
+    };
+
+    let (graph, _diagnostics) = select_graph(
+        starmap,
+        RouteAlgorithm::Dijkstra,
+        &effective,
+        request.spatial_index.as_ref().cloned(),
+        request.max_spatial_neighbors,
+        request.constraints.edge_penalties(),
+    );
+
+    let limit = request.limit.min(MAX_REACHABLE_RESULTS);
+    let reachable = find_reachable(
+        &graph,
+        Some(starmap),
+        start_id,
+        &effective,
+        request.budget.value(),
+        limit,
+        |edge| match (ship_mass, edge.kind) {
+            (None, _) => Some(edge.distance),
+            (Some(_), EdgeKind::Gate) => Some(0.0),
+            // Conservative: if fuel calc fails, reject the edge
+            (Some(mass), EdgeKind::Spatial) => {
+                calculate_jump_fuel_cost(mass, edge.distance, &request.fuel_config).ok()
+            }
+        },
+    );
+
+    Ok(reachable)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget_validation_rejects_negative_and_non_finite() {
+        assert!(ReachBudget::Distance(0.0).validate().is_ok());
+        assert!(ReachBudget::Fuel(12.5).validate().is_ok());
+
+        for budget in [
+            ReachBudget::Distance(-1.0),
+            ReachBudget::Distance(f64::INFINITY),
+            ReachBudget::Fuel(f64::NAN),
+        ] {
+            assert!(
+                matches!(budget.validate(), Err(Error::InvalidReachBudget { .. })),
+                "expected {budget:?} to be rejected"
+            );
+        }
+    }
+}
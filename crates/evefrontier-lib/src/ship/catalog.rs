@@ -1,7 +1,7 @@
 //! Ship catalog loading and management.
 //!
-//! This module handles loading ship data from CSV files and provides
-//! catalog lookup functionality.
+//! This module handles loading ship data from CSV files (or custom JSON
+//! definitions) and provides catalog lookup functionality.
 
 use std::collections::HashMap;
 use std::fs;
@@ -9,6 +9,7 @@ use std::io::Read;
 use std::path::{Path, PathBuf};
 
 use csv::{ReaderBuilder, Trim};
+use serde_json::Value;
 
 use crate::error::{Error, Result};
 
@@ -229,6 +230,80 @@ impl ShipCatalog {
         })
     }
 
+    /// Load custom ship definitions from a JSON array.
+    ///
+    /// Each element is an object with the CSV column names as keys (`name`, `base_mass_kg`,
+    /// `specific_heat`, `fuel_capacity`, `cargo_capacity`); other keys are ignored. Use
+    /// [`ShipCatalog::merge`] to layer the result over a CSV-loaded catalog.
+    pub fn from_json_reader<R: Read>(reader: R) -> Result<Self> {
+        let entries: Vec<Value> =
+            serde_json::from_reader(reader).map_err(|e| Error::ShipDataValidation {
+                message: format!("ship JSON must be an array of ship objects: {e}"),
+            })?;
+
+        let mut ships = HashMap::new();
+        for (index, entry) in entries.iter().enumerate() {
+            let entry_num = index + 1;
+            let object = entry.as_object().ok_or_else(|| Error::ShipDataValidation {
+                message: format!("ship entry {entry_num} is not a JSON object"),
+            })?;
+
+            let name = object
+                .get("name")
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .ok_or_else(|| Error::ShipDataValidation {
+                    message: format!("ship entry {entry_num} is missing a string `name`"),
+                })?;
+
+            let number = |field: &str| -> Result<f64> {
+                match object.get(field) {
+                    None | Some(Value::Null) => Err(Error::ShipDataValidation {
+                        message: format!("missing {field} for ship '{name}'"),
+                    }),
+                    Some(value) => value.as_f64().ok_or_else(|| Error::ShipDataValidation {
+                        message: format!(
+                            "invalid {field} for ship '{name}': expected a number, found {value}"
+                        ),
+                    }),
+                }
+            };
+
+            let ship = ShipAttributes {
+                name: name.to_string(),
+                base_mass_kg: number("base_mass_kg")?,
+                specific_heat: number("specific_heat")?,
+                fuel_capacity: number("fuel_capacity")?,
+                cargo_capacity: number("cargo_capacity")?,
+            };
+
+            ship.validate().map_err(|e| match e {
+                Error::ShipDataValidation { message } => Error::ShipDataValidation {
+                    message: format!("invalid ship '{name}': {message}"),
+                },
+                other => other,
+            })?;
+
+            let key = normalize_name(&ship.name);
+            if ships.contains_key(&key) {
+                return Err(Error::DuplicateShipName { name: key });
+            }
+            ships.insert(key, ship);
+        }
+
+        Ok(Self {
+            ships,
+            source: None,
+        })
+    }
+
+    /// Add the ships from `overrides`, replacing any existing ship with the same name
+    /// (case-insensitive). The source path of `self` is kept.
+    pub fn merge(&mut self, overrides: ShipCatalog) {
+        self.ships.extend(overrides.ships);
+    }
+
     /// Get a ship by name (case-insensitive).
     pub fn get(&self, name: &str) -> Option<&ShipAttributes> {
         self.ships.get(&normalize_name(name))
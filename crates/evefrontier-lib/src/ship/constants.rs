@@ -24,3 +24,10 @@ pub const BASE_COOLING_POWER: f64 = 1e6;
 /// computed wait durations). This value was chosen as a pragmatic balance between
 /// numerical robustness and not materially inflating cooling waits.
 pub const COOLING_EPSILON: f64 = 0.01;
+
+/// Minimum cooldown (seconds) for a hop to count as a forced cooldown stop.
+///
+/// Sub-second waits are rounding noise from the cooling model rather than a stop a
+/// pilot would notice, so they are excluded from `HeatSummary::cooldown_stops` and
+/// from per-hop cooldown display.
+pub const COOLDOWN_STOP_THRESHOLD_SECONDS: f64 = 0.5;
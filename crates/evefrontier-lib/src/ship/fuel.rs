@@ -6,6 +6,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::error::{Error, Result};
+use crate::output::RouteSummary;
 
 use super::attributes::{ShipAttributes, ShipLoadout};
 use super::constants::FUEL_MASS_PER_UNIT_KG;
@@ -17,6 +18,8 @@ pub struct FuelConfig {
     pub quality: f64,
     /// Enable per-hop dynamic mass recalculation as fuel is consumed.
     pub dynamic_mass: bool,
+    /// Emergency reserve in fuel units; hops that dip below it raise a `RESERVE` warning.
+    pub reserve: f64,
 }
 
 impl Default for FuelConfig {
@@ -24,6 +27,7 @@ impl Default for FuelConfig {
         Self {
             quality: 10.0,
             dynamic_mass: false,
+            reserve: 0.0,
         }
     }
 }
@@ -46,6 +50,15 @@ impl FuelConfig {
             });
         }
 
+        if !self.reserve.is_finite() || self.reserve < 0.0 {
+            return Err(Error::ShipDataValidation {
+                message: format!(
+                    "fuel_reserve must be finite and non-negative, got {}",
+                    self.reserve
+                ),
+            });
+        }
+
         Ok(())
     }
 
@@ -69,7 +82,9 @@ impl FuelConfig {
 pub struct FuelProjection {
     /// Fuel units consumed for this hop.
     pub hop_cost: f64,
-    /// Cumulative fuel consumed up to and including this hop.
+    /// Total fuel burned from the origin up to and including this hop.
+    ///
+    /// Monotonically increasing: refuel stops reset `remaining` but never `cumulative`.
     pub cumulative: f64,
     /// Fuel remaining after this hop.
     pub remaining: Option<f64>,
@@ -122,6 +137,36 @@ pub fn project_fuel_for_hop(
     cumulative_fuel: f64,
     remaining_fuel: f64,
     fuel_capacity: f64,
+) -> (FuelProjection, f64) {
+    project_fuel_for_hop_with_reserve(
+        hop_cost,
+        cumulative_fuel,
+        remaining_fuel,
+        fuel_capacity,
+        0.0,
+    )
+}
+
+/// Reserve-aware variant of [`project_fuel_for_hop`].
+///
+/// Behaves identically when `reserve` is zero. Otherwise, a hop that can be flown on raw fuel
+/// but takes the tank from at-or-above `reserve` to below it is tagged with a `RESERVE`
+/// warning (distinct from `REFUEL`, which still takes precedence when the tank runs dry).
+///
+/// # Examples
+/// ```
+/// use evefrontier_lib::ship::project_fuel_for_hop_with_reserve;
+///
+/// let (projection, remaining) = project_fuel_for_hop_with_reserve(50.0, 50.0, 120.0, 1000.0, 100.0);
+/// assert_eq!(projection.warning, Some("RESERVE".to_string()));
+/// assert_eq!(remaining, 70.0);
+/// ```
+pub fn project_fuel_for_hop_with_reserve(
+    hop_cost: f64,
+    cumulative_fuel: f64,
+    remaining_fuel: f64,
+    fuel_capacity: f64,
+    reserve: f64,
 ) -> (FuelProjection, f64) {
     if hop_cost > remaining_fuel {
         // Insufficient fuel: REFUEL warning and reset to capacity
@@ -137,18 +182,48 @@ pub fn project_fuel_for_hop(
     } else {
         // Sufficient fuel: consume fuel for this hop
         let new_remaining = (remaining_fuel - hop_cost).max(0.0);
+        let warning = if remaining_fuel >= reserve && new_remaining < reserve {
+            Some("RESERVE".to_string())
+        } else {
+            None
+        };
         (
             FuelProjection {
                 hop_cost,
                 cumulative: cumulative_fuel,
                 remaining: Some(new_remaining),
-                warning: None,
+                warning,
             },
             new_remaining,
         )
     }
 }
 
+/// Plan the refuel stops for a route whose steps carry fuel projections.
+///
+/// Walks the steps of `summary` (as filled in by
+/// [`RouteSummary::attach_fuel`](crate::output::RouteSummary::attach_fuel)) starting with a
+/// `fuel_capacity` tank, and returns the index into `summary.steps` of every step whose hop
+/// costs more than the fuel left since the last refill. A full refill at that step resets the
+/// remaining fuel to `fuel_capacity`, matching the `REFUEL` warnings on the step projections.
+/// Steps without a projection, such as the origin, cost nothing.
+pub fn plan_refuel_stops(summary: &RouteSummary, fuel_capacity: f64) -> Vec<usize> {
+    let mut remaining = fuel_capacity;
+    let mut stops = Vec::new();
+    for (idx, step) in summary.steps.iter().enumerate() {
+        let Some(hop_cost) = step.fuel.as_ref().map(|fuel| fuel.hop_cost) else {
+            continue;
+        };
+        if hop_cost > remaining {
+            stops.push(idx);
+            remaining = fuel_capacity;
+        } else {
+            remaining -= hop_cost;
+        }
+    }
+    stops
+}
+
 /// Calculate fuel units required for a single jump.
 ///
 /// Formula: hop_cost = (total_mass_kg / 100_000) × (fuel_quality / 100) × distance_ly
@@ -239,6 +314,11 @@ pub fn calculate_maximum_distance(
 /// true, remaining fuel is decremented per hop and used to recompute mass; otherwise static
 /// mode uses the initial fuel load for all hops.
 ///
+/// The first hop whose cost exceeds the fuel left in the tank is tagged with a `REFUEL`
+/// warning; `remaining` is clamped at zero from that hop onwards. When `fuel_config.reserve`
+/// is set, the hop that first takes the tank below the reserve (without running dry) is
+/// tagged with a `RESERVE` warning instead.
+///
 /// # Returns
 /// A vector of `FuelProjection` containing `hop_cost`, `cumulative`, `remaining` and
 /// optional `warning` fields.
@@ -253,6 +333,8 @@ pub fn calculate_route_fuel(
     let mut projections = Vec::with_capacity(distances_ly.len());
     let mut cumulative = 0.0;
     let mut dynamic_fuel_load = loadout.fuel_load;
+    let mut exhausted = false;
+    let mut in_reserve = loadout.fuel_load < fuel_config.reserve;
 
     for &distance in distances_ly {
         if !distance.is_finite() || distance <= 0.0 {
@@ -279,18 +361,33 @@ pub fn calculate_route_fuel(
         let hop_cost = calculate_jump_fuel_cost(mass, distance, fuel_config)?;
         cumulative += hop_cost;
 
-        let remaining = if fuel_config.dynamic_mass {
-            dynamic_fuel_load = (dynamic_fuel_load - hop_cost).max(0.0);
-            Some(dynamic_fuel_load)
+        let unclamped = if fuel_config.dynamic_mass {
+            dynamic_fuel_load - hop_cost
+        } else {
+            loadout.fuel_load - cumulative
+        };
+        let remaining = unclamped.max(0.0);
+        if fuel_config.dynamic_mass {
+            dynamic_fuel_load = remaining;
+        }
+
+        // Only the hop where the tank first runs dry (or first dips into the reserve)
+        // carries the warning.
+        let warning = if unclamped < 0.0 && !exhausted {
+            exhausted = true;
+            Some("REFUEL".to_string())
+        } else if unclamped >= 0.0 && unclamped < fuel_config.reserve && !in_reserve {
+            Some("RESERVE".to_string())
         } else {
-            Some((loadout.fuel_load - cumulative).max(0.0))
+            None
         };
+        in_reserve = unclamped < fuel_config.reserve;
 
         projections.push(FuelProjection {
             hop_cost,
             cumulative,
-            remaining,
-            warning: None,
+            remaining: Some(remaining),
+            warning,
         });
     }
 
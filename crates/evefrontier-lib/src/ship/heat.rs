@@ -31,9 +31,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::error::{Error, Result};
+use crate::output::RouteSummary;
 
+use super::attributes::{ShipAttributes, ShipLoadout};
 use super::constants::{
-    BASE_COOLING_POWER, COOLING_EPSILON, HEAT_CRITICAL, HEAT_NOMINAL, HEAT_OVERHEATED,
+    BASE_COOLING_POWER, COOLDOWN_STOP_THRESHOLD_SECONDS, COOLING_EPSILON, HEAT_CRITICAL,
+    HEAT_NOMINAL, HEAT_OVERHEATED,
 };
 
 /// Configuration for heat calculations.
@@ -67,6 +70,9 @@ pub struct HeatProjection {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub wait_time_seconds: Option<f64>,
     /// Residual temperature at arrival after any optional cooldown.
+    ///
+    /// Route and scout outputs report this as the step's cumulative heat. Each jump starts from
+    /// the departure system's ambient temperature, so heat is never summed across hops.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub residual_heat: Option<f64>,
     /// Whether the ship can safely proceed to the next hop.
@@ -80,11 +86,29 @@ pub struct HeatSummary {
     pub total_wait_time_seconds: f64,
     /// Final residual heat at the destination after any cooling at the point of arrival.
     pub final_residual_heat: f64,
+    /// Number of hops that force a cooldown stop (wait above
+    /// `COOLDOWN_STOP_THRESHOLD_SECONDS`) before the next jump.
+    #[serde(default)]
+    pub cooldown_stops: usize,
     /// Warnings collected across all steps of the route.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub warnings: Vec<String>,
 }
 
+/// A pause on a route where the hull must cool before the next jump.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CoolingStop {
+    /// Index into the route's `steps` of the system where the ship waits.
+    pub step_index: usize,
+    /// Time spent cooling at this system (seconds).
+    pub wait_seconds: f64,
+    /// Hull temperature on arrival, before cooling: the heat carried out of the previous hop
+    /// (or the departure system's ambient temperature, if warmer) plus this hop's heat.
+    pub peak_heat: f64,
+    /// Hull temperature carried into the next hop once the wait is over.
+    pub residual_heat: f64,
+}
+
 /// Parameters for heat projection calculation.
 ///
 /// Groups related parameters to avoid exceeding clippy's `too_many_arguments` threshold.
@@ -367,6 +391,72 @@ pub fn project_heat_for_jump(params: HeatProjectionParams) -> Result<HeatProject
     })
 }
 
+/// Find the steps of a route where the ship must pause to cool before jumping on.
+///
+/// Walks the hop heat that
+/// [`RouteSummary::attach_heat`](crate::output::RouteSummary::attach_heat) attached to each
+/// step, carrying the hull temperature from one hop to the next: a hop departs at the residual
+/// heat left by the previous hop (or the departure system's ambient temperature, if warmer),
+/// and gates pass that heat through unchanged. Cooldown follows the same policy as
+/// [`project_heat_for_jump`]: above nominal, the ship cools toward `HEAT_NOMINAL` unless it has
+/// arrived at the goal or is about to take a gate. Heat left over when no pause is taken is
+/// what makes a later stop necessary even when that hop alone would stay below nominal.
+///
+/// Waits at or below `COOLDOWN_STOP_THRESHOLD_SECONDS` are rounding noise and do not count as
+/// stops, matching `HeatSummary::cooldown_stops`.
+pub fn plan_cooling_stops(
+    summary: &RouteSummary,
+    ship: &ShipAttributes,
+    loadout: &ShipLoadout,
+    config: &HeatConfig,
+) -> Result<Vec<CoolingStop>> {
+    let mut stops = Vec::new();
+    let mut carried: Option<f64> = None;
+    let last = summary.steps.len().saturating_sub(1);
+
+    for idx in 1..summary.steps.len() {
+        let Some(heat) = summary.steps[idx].heat.as_ref() else {
+            continue;
+        };
+        if summary.steps[idx].method.as_deref() == Some("gate") {
+            continue;
+        }
+
+        let departure = summary.steps[idx - 1]
+            .min_external_temp
+            .unwrap_or(0.0)
+            .max(0.0);
+        let peak_heat = carried.map_or(departure, |c| c.max(departure)) + heat.hop_heat;
+
+        let is_goal = idx == last;
+        let next_is_gate = !is_goal && summary.steps[idx + 1].method.as_deref() == Some("gate");
+        let mut residual_heat = peak_heat;
+
+        if peak_heat > HEAT_NOMINAL && !is_goal && !next_is_gate {
+            let arrival = summary.steps[idx].min_external_temp;
+            let mass = summary.hop_mass(idx, ship, loadout, config)?;
+            let k = compute_cooling_constant(mass, ship.specific_heat, arrival);
+            let env_temp = arrival.unwrap_or(0.0);
+            let wait_seconds = calculate_cooling_time(peak_heat, HEAT_NOMINAL, env_temp, k);
+            if wait_seconds > 0.0 {
+                residual_heat = HEAT_NOMINAL.max(env_temp + COOLING_EPSILON);
+            }
+            if wait_seconds > COOLDOWN_STOP_THRESHOLD_SECONDS {
+                stops.push(CoolingStop {
+                    step_index: idx,
+                    wait_seconds,
+                    peak_heat,
+                    residual_heat,
+                });
+            }
+        }
+
+        carried = Some(residual_heat);
+    }
+
+    Ok(stops)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -39,15 +39,15 @@ pub mod heat;
 pub use attributes::{ShipAttributes, ShipLoadout};
 pub use catalog::ShipCatalog;
 pub use constants::{
-    BASE_COOLING_POWER, COOLING_EPSILON, FUEL_MASS_PER_UNIT_KG, HEAT_CRITICAL, HEAT_NOMINAL,
-    HEAT_OVERHEATED,
+    BASE_COOLING_POWER, COOLDOWN_STOP_THRESHOLD_SECONDS, COOLING_EPSILON, FUEL_MASS_PER_UNIT_KG,
+    HEAT_CRITICAL, HEAT_NOMINAL, HEAT_OVERHEATED,
 };
 pub use fuel::{
-    calculate_jump_fuel_cost, calculate_maximum_distance, calculate_route_fuel,
-    project_fuel_for_hop, FuelConfig, FuelProjection,
+    calculate_jump_fuel_cost, calculate_maximum_distance, calculate_route_fuel, plan_refuel_stops,
+    project_fuel_for_hop, project_fuel_for_hop_with_reserve, FuelConfig, FuelProjection,
 };
 pub use heat::{
     calculate_cooling_time, calculate_jump_heat, compute_cooling_constant,
-    compute_dissipation_per_sec, compute_zone_factor, project_heat_for_jump, HeatConfig,
-    HeatProjection, HeatProjectionParams, HeatSummary,
+    compute_dissipation_per_sec, compute_zone_factor, plan_cooling_stops, project_heat_for_jump,
+    CoolingStop, HeatConfig, HeatProjection, HeatProjectionParams, HeatSummary,
 };
@@ -144,6 +144,15 @@ pub struct DatasetMetadata {
     pub build_timestamp: i64,
 }
 
+impl DatasetMetadata {
+    /// Short dataset identifier: the first 16 hex characters of the checksum.
+    ///
+    /// Used by the HTTP services and Lambdas to report which dataset answered a request.
+    pub fn short_checksum(&self) -> String {
+        hex_encode(&self.checksum[..8])
+    }
+}
+
 /// Result of verifying spatial index freshness against the current dataset.
 ///
 /// This enum represents all possible outcomes of comparing a spatial index's
@@ -334,6 +343,36 @@ impl NeighbourQuery {
 ///
 /// Returns an error if the file cannot be opened or read.
 pub fn compute_dataset_checksum(path: &Path) -> Result<[u8; 32]> {
+    compute_dataset_checksum_with_progress(path, None)
+}
+
+/// Compute the SHA-256 checksum of a dataset file, reporting progress as it goes.
+///
+/// Same as [`compute_dataset_checksum`], but calls `progress(bytes_hashed, total_bytes)` once
+/// before reading and after every chunk, so callers can show how far along a large file is.
+/// The last call reports `bytes_hashed == total_bytes` (unless the file changes while it is
+/// being read).
+///
+/// # Example
+///
+/// ```no_run
+/// use evefrontier_lib::spatial::compute_dataset_checksum_with_progress;
+/// use std::path::Path;
+///
+/// let mut report = |hashed: u64, total: u64| eprint!("\r{hashed}/{total} bytes");
+/// let path = Path::new("static_data.db");
+/// let checksum = compute_dataset_checksum_with_progress(path, Some(&mut report))?;
+/// println!("\nChecksum: {:02x?}", checksum);
+/// # Ok::<(), evefrontier_lib::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be opened or read.
+pub fn compute_dataset_checksum_with_progress(
+    path: &Path,
+    mut progress: Option<&mut dyn FnMut(u64, u64)>,
+) -> Result<[u8; 32]> {
     let file = File::open(path).map_err(|e| {
         if e.kind() == std::io::ErrorKind::NotFound {
             Error::DatasetNotFound {
@@ -343,10 +382,16 @@ pub fn compute_dataset_checksum(path: &Path) -> Result<[u8; 32]> {
             Error::Io(e)
         }
     })?;
+    let total = file.metadata()?.len();
 
     let mut reader = BufReader::with_capacity(64 * 1024, file);
     let mut hasher = Sha256::new();
     let mut buf = [0u8; 64 * 1024];
+    let mut hashed = 0u64;
+
+    if let Some(progress) = progress.as_mut() {
+        progress(hashed, total);
+    }
 
     loop {
         let bytes_read = reader.read(&mut buf)?;
@@ -354,11 +399,23 @@ pub fn compute_dataset_checksum(path: &Path) -> Result<[u8; 32]> {
             break;
         }
         hasher.update(&buf[..bytes_read]);
+        hashed += bytes_read as u64;
+        if let Some(progress) = progress.as_mut() {
+            progress(hashed, total);
+        }
     }
 
     Ok(hasher.finalize().into())
 }
 
+/// Compute the SHA-256 checksum of an in-memory dataset.
+///
+/// Produces the same value as [`compute_dataset_checksum`] for the file the bytes
+/// were read from; used when the dataset is bundled with `include_bytes!`.
+pub fn compute_dataset_checksum_from_bytes(bytes: &[u8]) -> [u8; 32] {
+    Sha256::digest(bytes).into()
+}
+
 /// Read the release tag from a `.db.release` marker file.
 ///
 /// The marker file is created by the downloader and contains lines like:
@@ -529,13 +586,17 @@ impl SpatialIndex {
     ///
     /// Only systems with valid 3D positions are indexed. The `min_external_temp`
     /// from each system's metadata is embedded in the index for temperature-aware
-    /// queries.
+    /// queries. Systems are inserted in ascending ID order, so building twice from
+    /// the same starmap saves byte-identical files.
     pub fn build(starmap: &Starmap) -> Self {
         let mut nodes = Vec::new();
         let mut temp_lookup = HashMap::new();
         let mut id_to_index = HashMap::new();
 
-        for system in starmap.systems.values() {
+        let mut systems: Vec<_> = starmap.systems.values().collect();
+        systems.sort_by_key(|system| system.id);
+
+        for system in systems {
             let Some(position) = system.position else {
                 continue;
             };
@@ -640,9 +701,49 @@ impl SpatialIndex {
             .map(|&idx| self.nodes[idx].coords)
     }
 
+    /// SHA-256 hash of the indexed content, independent of node insertion order.
+    ///
+    /// Covers every node (system ID, coordinates, temperature) in system ID order plus the
+    /// dataset checksum and release tag from the source metadata. The metadata's
+    /// `build_timestamp` is excluded, so two builds from the same data hash equally; a
+    /// mismatch means the inputs differed or the build is not deterministic.
+    pub fn content_hash(&self) -> [u8; 32] {
+        let mut nodes: Vec<&IndexNode> = self.nodes.iter().collect();
+        nodes.sort_by_key(|node| node.system_id);
+
+        let mut hasher = Sha256::new();
+        hasher.update((nodes.len() as u64).to_le_bytes());
+        for node in nodes {
+            hasher.update(node.system_id.to_le_bytes());
+            for coord in node.coords {
+                hasher.update(coord.to_bits().to_le_bytes());
+            }
+            match node.min_external_temp {
+                Some(temp) => {
+                    hasher.update([1]);
+                    hasher.update(temp.to_bits().to_le_bytes());
+                }
+                None => hasher.update([0]),
+            }
+        }
+
+        match &self.metadata {
+            Some(meta) => {
+                hasher.update([1]);
+                hasher.update(meta.checksum);
+                let tag = meta.release_tag.as_deref().unwrap_or("");
+                hasher.update((tag.len() as u64).to_le_bytes());
+                hasher.update(tag.as_bytes());
+            }
+            None => hasher.update([0]),
+        }
+
+        hasher.finalize().into()
+    }
+
     /// Find k nearest neighbours to a point.
     ///
-    /// Returns (SystemId, distance) pairs sorted by distance.
+    /// Returns (SystemId, distance) pairs sorted by distance, then by system ID.
     pub fn nearest(&self, point: [f64; 3], k: usize) -> Vec<(SystemId, f64)> {
         if k == 0 || self.nodes.is_empty() {
             return Vec::new();
@@ -651,19 +752,22 @@ impl SpatialIndex {
         let query_point = [point[0] as f32, point[1] as f32, point[2] as f32];
         let results = self.tree.nearest_n::<SquaredEuclidean>(&query_point, k);
 
-        results
+        let mut neighbors: Vec<(SystemId, f64)> = results
             .into_iter()
             .map(|neighbor| {
                 let node = &self.nodes[neighbor.item];
                 let distance = (neighbor.distance as f64).sqrt();
                 (node.system_id, distance)
             })
-            .collect()
+            .collect();
+
+        neighbors.sort_by(by_distance_then_id);
+        neighbors
     }
 
     /// Find all systems within a radius of a point.
     ///
-    /// Returns (SystemId, distance) pairs sorted by distance.
+    /// Returns (SystemId, distance) pairs sorted by distance, then by system ID.
     pub fn within_radius(&self, point: [f64; 3], radius: f64) -> Vec<(SystemId, f64)> {
         if radius <= 0.0 || self.nodes.is_empty() {
             return Vec::new();
@@ -684,14 +788,14 @@ impl SpatialIndex {
             })
             .collect();
 
-        neighbors.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+        neighbors.sort_by(by_distance_then_id);
         neighbors
     }
 
     /// Find nearest neighbours with filtering.
     ///
-    /// Applies temperature and radius constraints, returning up to k results.
-    /// Over-fetches by 2x to account for filtered results.
+    /// Applies temperature and radius constraints, returning up to k results sorted by
+    /// distance, then by system ID. Over-fetches by 2x to account for filtered results.
     ///
     /// # Temperature Filtering (per ADR 0009)
     ///
@@ -722,7 +826,7 @@ impl SpatialIndex {
             .tree
             .nearest_n::<SquaredEuclidean>(&query_point, fetch_count);
 
-        let mut results = Vec::with_capacity(k);
+        let mut results = Vec::with_capacity(fetch_count);
 
         for neighbor in candidates {
             let node = &self.nodes[neighbor.item];
@@ -746,16 +850,61 @@ impl SpatialIndex {
             }
 
             results.push((node.system_id, distance));
-
-            if results.len() >= k {
-                break;
-            }
         }
 
+        // The tree returns equidistant candidates in arbitrary order; sort before
+        // truncating so ties are broken by system ID, including at the cutoff.
+        results.sort_by(by_distance_then_id);
+        results.truncate(k);
         results
     }
 
+    /// Run [`nearest_filtered`](Self::nearest_filtered) for many origin points against this index.
+    ///
+    /// Results are in input order and each entry is exactly what a single `nearest_filtered`
+    /// call for that point returns. With the `parallel` feature, points are spread across the
+    /// rayon thread pool.
+    pub fn nearest_filtered_batch(
+        &self,
+        points: &[[f64; 3]],
+        query: &NeighbourQuery,
+    ) -> Vec<Vec<(SystemId, f64)>> {
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            points
+                .par_iter()
+                .map(|&point| self.nearest_filtered(point, query))
+                .collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            points
+                .iter()
+                .map(|&point| self.nearest_filtered(point, query))
+                .collect()
+        }
+    }
+
+    /// Fallible variant of [`nearest_filtered`](Self::nearest_filtered).
+    ///
+    /// Returns [`Error::SpatialIndexEmpty`] when the index holds no systems, so callers can
+    /// tell an unbuilt index apart from a query that legitimately matched nothing (which,
+    /// like `k == 0`, yields `Ok` with an empty vector).
+    pub fn try_nearest_filtered(
+        &self,
+        point: [f64; 3],
+        query: &NeighbourQuery,
+    ) -> Result<Vec<(SystemId, f64)>> {
+        if self.nodes.is_empty() {
+            return Err(Error::SpatialIndexEmpty);
+        }
+        Ok(self.nearest_filtered(point, query))
+    }
+
     /// Find all systems within a radius, filtered by temperature.
+    ///
+    /// Returns (SystemId, distance) pairs sorted by distance, then by system ID.
     pub fn within_radius_filtered(
         &self,
         point: [f64; 3],
@@ -791,31 +940,97 @@ impl SpatialIndex {
             })
             .collect();
 
-        results.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+        results.sort_by(by_distance_then_id);
+        results
+    }
+
+    /// Find all systems inside an axis-aligned bounding box.
+    ///
+    /// Bounds are inclusive. Returns (SystemId, distance from the box center) pairs sorted by
+    /// distance, then by system ID. Returns an empty list when any axis has `min >= max` or a non-finite bound.
+    pub fn within_bbox(&self, min: [f64; 3], max: [f64; 3]) -> Vec<(SystemId, f64)> {
+        let valid = (0..3)
+            .all(|axis| min[axis].is_finite() && max[axis].is_finite() && min[axis] < max[axis]);
+        if !valid {
+            debug!(?min, ?max, "ignoring degenerate bounding box");
+            return Vec::new();
+        }
+        if self.nodes.is_empty() {
+            return Vec::new();
+        }
+
+        let center: [f64; 3] = std::array::from_fn(|axis| (min[axis] + max[axis]) / 2.0);
+        let squared_half_diagonal: f64 = (0..3)
+            .map(|axis| ((max[axis] - min[axis]) / 2.0).powi(2))
+            .sum();
+
+        // The box's circumscribed sphere narrows the candidates; the slack keeps corner
+        // systems from being lost to f32 rounding before the exact per-axis check.
+        let query_point = [center[0] as f32, center[1] as f32, center[2] as f32];
+        let squared_radius = (squared_half_diagonal * (1.0 + 1e-5)) as f32;
+        let candidates = self
+            .tree
+            .within::<SquaredEuclidean>(&query_point, squared_radius);
+
+        let mut results: Vec<(SystemId, f64)> = candidates
+            .into_iter()
+            .filter_map(|neighbor| {
+                let node = &self.nodes[neighbor.item];
+                let coords = node.coords.map(f64::from);
+                let inside = (0..3).all(|axis| (min[axis]..=max[axis]).contains(&coords[axis]));
+                if !inside {
+                    return None;
+                }
+
+                let distance = (0..3)
+                    .map(|axis| (coords[axis] - center[axis]).powi(2))
+                    .sum::<f64>()
+                    .sqrt();
+                Some((node.system_id, distance))
+            })
+            .collect();
+
+        results.sort_by(by_distance_then_id);
         results
     }
 
     /// Serialize the index to a file.
     ///
+    /// Writes the bytes produced by [`SpatialIndex::to_bytes`].
+    pub fn save(&self, path: &Path) -> Result<()> {
+        info!(
+            path = %path.display(),
+            nodes = self.nodes.len(),
+            "saving spatial index"
+        );
+
+        let bytes = self.to_bytes()?;
+
+        let file = File::create(path)?;
+        let mut writer = BufWriter::new(file);
+        writer.write_all(&bytes)?;
+        writer.flush()?;
+
+        info!(file_size = bytes.len(), "spatial index saved");
+
+        Ok(())
+    }
+
+    /// Serialize the index to the on-disk format without touching the filesystem.
+    ///
     /// Uses postcard for compact binary encoding and zstd for compression.
     /// Writes a versioned header and SHA-256 checksum for integrity verification.
     ///
     /// If the index was built with `build_with_metadata()`, writes v2 format with
     /// embedded source metadata. Otherwise writes v1 format for backward compatibility.
-    pub fn save(&self, path: &Path) -> Result<()> {
+    /// [`SpatialIndex::from_bytes`] reads the result back.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
         let version = if self.metadata.is_some() {
             INDEX_VERSION_V2
         } else {
             INDEX_VERSION
         };
 
-        info!(
-            path = %path.display(),
-            nodes = self.nodes.len(),
-            version = version,
-            "saving spatial index"
-        );
-
         // Serialize nodes with postcard
         let serialized =
             postcard::to_allocvec(&self.nodes).map_err(|e| Error::SpatialIndexSerialize {
@@ -870,28 +1085,23 @@ impl SpatialIndex {
         // Compute checksum over compressed data only (consistent with v1)
         let checksum = Sha256::digest(&compressed);
 
-        // Write file
-        let file = File::create(path)?;
-        let mut writer = BufWriter::new(file);
-
-        writer.write_all(&header)?;
-        if !metadata_section.is_empty() {
-            writer.write_all(&metadata_section)?;
-        }
-        writer.write_all(&compressed)?;
-        writer.write_all(&checksum)?;
-        writer.flush()?;
+        let mut bytes = Vec::with_capacity(
+            HEADER_SIZE + metadata_section.len() + compressed.len() + CHECKSUM_SIZE,
+        );
+        bytes.extend_from_slice(&header);
+        bytes.extend_from_slice(&metadata_section);
+        bytes.extend_from_slice(&compressed);
+        bytes.extend_from_slice(&checksum);
 
-        let file_size = HEADER_SIZE + metadata_section.len() + compressed.len() + CHECKSUM_SIZE;
-        info!(
-            file_size = file_size,
+        debug!(
+            size = bytes.len(),
             compressed_size = compressed.len(),
             version = version,
             has_metadata = self.metadata.is_some(),
-            "spatial index saved"
+            "serialized spatial index"
         );
 
-        Ok(())
+        Ok(bytes)
     }
 
     /// Load a spatial index from a file.
@@ -925,12 +1135,9 @@ impl SpatialIndex {
 
         let version = header[4];
         if version != INDEX_VERSION && version != INDEX_VERSION_V2 {
-            return Err(Error::SpatialIndexLoad {
-                path: path.to_path_buf(),
-                message: format!(
-                    "unsupported version {} (expected {} or {})",
-                    version, INDEX_VERSION, INDEX_VERSION_V2
-                ),
+            return Err(Error::SpatialIndexUnsupportedVersion {
+                version,
+                max_supported: INDEX_VERSION_V2,
             });
         }
 
@@ -1103,21 +1310,69 @@ impl SpatialIndex {
         })
     }
 
-    /// Load a spatial index from a byte slice.
+    /// Deserialize an index from bytes produced by [`SpatialIndex::to_bytes`] or read from
+    /// an index file.
     ///
     /// This is useful for loading from bundled data (e.g., `include_bytes!` in Lambda).
     /// The byte slice must contain the complete index with header, compressed data,
-    /// and checksum.
+    /// and checksum; the body is verified and decompressed in place without copying.
     ///
     /// # Example
     ///
     /// ```text
     /// static INDEX_BYTES: &[u8] = include_bytes!("../data/spatial.bin");
-    /// let index = SpatialIndex::load_from_bytes(INDEX_BYTES)?;
+    /// let index = SpatialIndex::from_bytes(INDEX_BYTES)?;
     /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut cursor = std::io::Cursor::new(bytes);
+        let (version, node_count, metadata) = Self::read_prelude(&mut cursor)?;
+        let body = &bytes[cursor.position() as usize..];
+
+        Self::from_body(body, version, node_count, metadata)
+    }
+
+    /// Load a spatial index from a byte slice.
+    ///
+    /// Equivalent to [`SpatialIndex::from_bytes`].
     pub fn load_from_bytes(bytes: &[u8]) -> Result<Self> {
-        use std::io::Cursor;
-        Self::load_from_reader(Cursor::new(bytes))
+        Self::from_bytes(bytes)
+    }
+
+    /// Load a spatial index by memory-mapping the file.
+    ///
+    /// The header, metadata, and checksum are validated directly over the mapped bytes, and the
+    /// compressed body is decompressed from the mapping without first copying it into memory.
+    /// Falls back to [`SpatialIndex::load`] when the file cannot be mapped (e.g. on platforms or
+    /// filesystems without mmap support). The resulting index is identical to one from `load`.
+    pub fn load_mmap(path: &Path) -> Result<Self> {
+        debug!(path = %path.display(), "memory-mapping spatial index");
+
+        let file = File::open(path).map_err(|e| Error::SpatialIndexLoad {
+            path: path.to_path_buf(),
+            message: format!("failed to open file: {}", e),
+        })?;
+
+        // SAFETY: the mapping is read-only and only lives for the duration of this call; the
+        // checksum is verified over the mapped bytes before they are decompressed.
+        let mmap = match unsafe { memmap2::Mmap::map(&file) } {
+            Ok(mmap) => mmap,
+            Err(e) => {
+                warn!(
+                    path = %path.display(),
+                    error = %e,
+                    "memory-mapping spatial index failed; falling back to buffered load"
+                );
+                return Self::load(path);
+            }
+        };
+
+        Self::from_bytes(&mmap).map_err(|e| match e {
+            Error::SpatialIndexDeserialize { message } => Error::SpatialIndexLoad {
+                path: path.to_path_buf(),
+                message,
+            },
+            other => other,
+        })
     }
 
     /// Load a spatial index from any `Read + Seek` source.
@@ -1125,6 +1380,25 @@ impl SpatialIndex {
     /// This is the underlying implementation shared by `load` (file) and
     /// `load_from_bytes` (bundled data). Supports both v1 and v2 formats.
     pub fn load_from_reader<R: std::io::Read>(mut reader: R) -> Result<Self> {
+        let (version, node_count, metadata) = Self::read_prelude(&mut reader)?;
+
+        // Read remaining data (compressed + checksum)
+        let mut remaining = Vec::new();
+        reader
+            .read_to_end(&mut remaining)
+            .map_err(|e| Error::SpatialIndexDeserialize {
+                message: format!("failed to read data: {}", e),
+            })?;
+
+        Self::from_body(&remaining, version, node_count, metadata)
+    }
+
+    /// Read and validate the header and optional v2 metadata section.
+    ///
+    /// Returns the format version, declared node count, and metadata.
+    fn read_prelude<R: std::io::Read>(
+        reader: &mut R,
+    ) -> Result<(u8, u32, Option<DatasetMetadata>)> {
         // Read and validate header
         let mut header = [0u8; HEADER_SIZE];
         reader
@@ -1141,11 +1415,9 @@ impl SpatialIndex {
 
         let version = header[4];
         if version != INDEX_VERSION && version != INDEX_VERSION_V2 {
-            return Err(Error::SpatialIndexDeserialize {
-                message: format!(
-                    "unsupported version {} (expected {} or {})",
-                    version, INDEX_VERSION, INDEX_VERSION_V2
-                ),
+            return Err(Error::SpatialIndexUnsupportedVersion {
+                version,
+                max_supported: INDEX_VERSION_V2,
             });
         }
 
@@ -1211,23 +1483,26 @@ impl SpatialIndex {
             None
         };
 
-        // Read remaining data (compressed + checksum)
-        let mut remaining = Vec::new();
-        reader
-            .read_to_end(&mut remaining)
-            .map_err(|e| Error::SpatialIndexDeserialize {
-                message: format!("failed to read data: {}", e),
-            })?;
+        Ok((version, node_count, metadata))
+    }
 
-        if remaining.len() < CHECKSUM_SIZE {
+    /// Verify the checksum over `body` (compressed data followed by checksum), then decompress
+    /// and rebuild the tree.
+    fn from_body(
+        body: &[u8],
+        version: u8,
+        node_count: u32,
+        metadata: Option<DatasetMetadata>,
+    ) -> Result<Self> {
+        if body.len() < CHECKSUM_SIZE {
             return Err(Error::SpatialIndexDeserialize {
                 message: "data too short for checksum".to_string(),
             });
         }
 
-        let checksum_start = remaining.len() - CHECKSUM_SIZE;
-        let compressed = &remaining[..checksum_start];
-        let stored_checksum = &remaining[checksum_start..];
+        let checksum_start = body.len() - CHECKSUM_SIZE;
+        let compressed = &body[..checksum_start];
+        let stored_checksum = &body[checksum_start..];
 
         // Verify checksum
         let computed_checksum = Sha256::digest(compressed);
@@ -1298,6 +1573,22 @@ impl std::fmt::Debug for SpatialIndex {
     }
 }
 
+/// Indexes compare by [`SpatialIndex::content_hash`], so equal content in a different node
+/// order is equal. Each comparison hashes both indexes; cache the hash when comparing often.
+impl PartialEq for SpatialIndex {
+    fn eq(&self, other: &Self) -> bool {
+        self.content_hash() == other.content_hash()
+    }
+}
+
+impl Eq for SpatialIndex {}
+
+impl std::hash::Hash for SpatialIndex {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.content_hash().hash(state);
+    }
+}
+
 /// Derive the spatial index path from a database path.
 ///
 /// The index file is stored alongside the database with a `.spatial.bin` extension.
@@ -1328,6 +1619,62 @@ pub fn try_load_spatial_index(db_path: &Path) -> Option<SpatialIndex> {
     }
 }
 
+/// How [`install_spatial_index`] provided the index for a dataset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpatialIndexSource {
+    /// The prebuilt index matched the dataset and was installed as-is.
+    Prebuilt,
+    /// The prebuilt index was unusable (stale, legacy, or unreadable) and a new one was built.
+    Rebuilt,
+}
+
+/// Install a prebuilt spatial index alongside `db_path`, rebuilding locally if it doesn't match.
+///
+/// The candidate is checked with [`verify_freshness`] before it is copied to
+/// [`spatial_index_path`]; anything other than [`FreshnessResult::Fresh`] falls back to building
+/// a v2 index (with embedded metadata) from the dataset itself.
+pub fn install_spatial_index(candidate: &Path, db_path: &Path) -> Result<SpatialIndexSource> {
+    let index_path = spatial_index_path(db_path);
+    match verify_freshness(candidate, db_path) {
+        FreshnessResult::Fresh { .. } => {
+            std::fs::copy(candidate, &index_path)?;
+            info!(path = %index_path.display(), "installed prebuilt spatial index");
+            Ok(SpatialIndexSource::Prebuilt)
+        }
+        other => {
+            warn!(
+                candidate = %candidate.display(),
+                result = ?other,
+                "prebuilt spatial index does not match dataset; rebuilding locally"
+            );
+            build_spatial_index_for(db_path, &index_path)?;
+            Ok(SpatialIndexSource::Rebuilt)
+        }
+    }
+}
+
+/// Build a v2 spatial index for `db_path` and save it to `index_path`.
+pub(crate) fn build_spatial_index_for(db_path: &Path, index_path: &Path) -> Result<()> {
+    let starmap = crate::db::load_starmap(db_path, None)?;
+    let metadata = DatasetMetadata {
+        checksum: compute_dataset_checksum(db_path)?,
+        release_tag: read_release_tag(db_path),
+        build_timestamp: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0),
+    };
+    SpatialIndex::build_with_metadata(&starmap, metadata).save(index_path)
+}
+
+/// Order query results by distance, breaking ties by system ID so equidistant systems
+/// come back in the same order regardless of tree layout.
+fn by_distance_then_id(a: &(SystemId, f64), b: &(SystemId, f64)) -> std::cmp::Ordering {
+    a.1.partial_cmp(&b.1)
+        .unwrap_or(std::cmp::Ordering::Equal)
+        .then_with(|| a.0.cmp(&b.0))
+}
+
 fn position_to_coords(pos: &SystemPosition) -> [f32; 3] {
     [pos.x as f32, pos.y as f32, pos.z as f32]
 }
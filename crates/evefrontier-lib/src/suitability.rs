@@ -0,0 +1,142 @@
+//! Suitability scoring for spatial neighbour results.
+//!
+//! A [`NeighbourQuery`](crate::NeighbourQuery) returns systems ordered by distance. Explorers
+//! often care more about what is in a system than how far away it is, so
+//! [`rank_by_suitability`] re-orders those results by a composite score instead.
+//!
+//! Scoring goes through the [`SuitabilityScorer`] trait. [`SuitabilityWeights`] is the
+//! default scorer; any `Fn(&System, f64) -> f64` closure can be used in its place.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use evefrontier_lib::{
+//!     load_starmap, rank_by_suitability, SpatialIndex, SuitabilityWeights, System,
+//! };
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let starmap = load_starmap(std::path::Path::new("static_data.db"), None)?;
+//! let index = SpatialIndex::build(&starmap);
+//! let neighbours = index.nearest([0.0, 0.0, 0.0], 10);
+//!
+//! // Default weights: planets and moons add, cold systems get a bonus, distance subtracts.
+//! let ranked = rank_by_suitability(&starmap, &neighbours, &SuitabilityWeights::default());
+//!
+//! // Or rank by a custom closure, e.g. moons only.
+//! let by_moons = |system: &System, _distance_ly: f64| {
+//!     f64::from(system.metadata.moon_count.unwrap_or(0))
+//! };
+//! let ranked_by_moons = rank_by_suitability(&starmap, &neighbours, &by_moons);
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::db::{Starmap, System, SystemId};
+use crate::ship::HEAT_CRITICAL;
+
+/// Computes a suitability score for a candidate system; higher is better.
+pub trait SuitabilityScorer {
+    /// Score `system`, which lies `distance_ly` light-years from the query origin.
+    fn score(&self, system: &System, distance_ly: f64) -> f64;
+}
+
+impl<F> SuitabilityScorer for F
+where
+    F: Fn(&System, f64) -> f64,
+{
+    fn score(&self, system: &System, distance_ly: f64) -> f64 {
+        self(system, distance_ly)
+    }
+}
+
+/// Weights for the default linear suitability score.
+///
+/// ```text
+/// score = planet   × planet_count
+///       + moon     × moon_count
+///       + cold     × (1 − min_external_temp / HEAT_CRITICAL), clamped to [0, 1]
+///       − distance × distance_ly
+/// ```
+///
+/// Missing planet or moon counts count as zero, and a system without temperature data gets no
+/// cold bonus.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SuitabilityWeights {
+    /// Points per planet.
+    pub planet: f64,
+    /// Points per moon.
+    pub moon: f64,
+    /// Bonus for a system at 0 K, shrinking to nothing at [`HEAT_CRITICAL`].
+    pub cold: f64,
+    /// Penalty per light-year from the query origin.
+    pub distance: f64,
+}
+
+impl Default for SuitabilityWeights {
+    fn default() -> Self {
+        Self {
+            planet: 1.0,
+            moon: 0.25,
+            cold: 1.0,
+            distance: 0.01,
+        }
+    }
+}
+
+impl SuitabilityScorer for SuitabilityWeights {
+    fn score(&self, system: &System, distance_ly: f64) -> f64 {
+        let metadata = &system.metadata;
+        let planets = f64::from(metadata.planet_count.unwrap_or(0));
+        let moons = f64::from(metadata.moon_count.unwrap_or(0));
+        let coldness = metadata
+            .min_external_temp
+            .map_or(0.0, |temp| (1.0 - temp / HEAT_CRITICAL).clamp(0.0, 1.0));
+
+        self.planet * planets + self.moon * moons + self.cold * coldness
+            - self.distance * distance_ly
+    }
+}
+
+/// A neighbour result with its suitability score.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ScoredNeighbour {
+    /// System identifier.
+    pub system_id: SystemId,
+    /// Distance from the query origin in light-years.
+    pub distance_ly: f64,
+    /// Suitability score; higher is better.
+    pub score: f64,
+}
+
+/// Score `neighbours` (as returned by the spatial index) and sort them best first.
+///
+/// Ties are broken by distance, then by system ID, so the order is deterministic. Neighbours
+/// that are not in `starmap` are dropped.
+pub fn rank_by_suitability(
+    starmap: &Starmap,
+    neighbours: &[(SystemId, f64)],
+    scorer: &impl SuitabilityScorer,
+) -> Vec<ScoredNeighbour> {
+    let mut ranked: Vec<ScoredNeighbour> = neighbours
+        .iter()
+        .filter_map(|&(system_id, distance_ly)| {
+            let system = starmap.systems.get(&system_id)?;
+            Some(ScoredNeighbour {
+                system_id,
+                distance_ly,
+                score: scorer.score(system, distance_ly),
+            })
+        })
+        .collect();
+
+    ranked.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then(a.distance_ly.total_cmp(&b.distance_ly))
+            .then(a.system_id.cmp(&b.system_id))
+    });
+    ranked
+}
@@ -24,6 +24,7 @@ impl RouteStepBuilder {
                 moon_count: None,
                 fuel: None,
                 heat: None,
+                position: None,
             },
         }
     }
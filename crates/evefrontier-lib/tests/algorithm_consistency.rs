@@ -0,0 +1,178 @@
+//! Property-based consistency checks between the BFS, Dijkstra, and A* planners.
+//!
+//! Random start/goal pairs and constraints are drawn over the fixture and each
+//! algorithm is run on the same graph, so any disagreement points at a planner bug
+//! rather than at different graph construction.
+
+use std::collections::HashSet;
+
+use evefrontier_lib::{
+    build_gate_graph, build_hybrid_graph, build_spatial_graph, find_route_a_star, find_route_bfs,
+    find_route_dijkstra, load_starmap, EdgeKind, Graph, GraphMode, PathConstraints, Starmap,
+    SystemId,
+};
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+
+mod common;
+use common::{fixture_db_path, fixture_system_pairs, seeded_runner};
+
+/// Relative tolerance when comparing summed edge distances.
+const DISTANCE_EPSILON: f64 = 1e-9;
+
+#[derive(Debug, Clone)]
+struct Case {
+    start: SystemId,
+    goal: SystemId,
+    mode: GraphMode,
+    max_jump: Option<f64>,
+    avoid_gates: bool,
+    max_temperature: Option<f64>,
+    avoided: Vec<SystemId>,
+}
+
+fn case_strategy(starmap: &Starmap) -> impl Strategy<Value = Case> {
+    let pairs = fixture_system_pairs(starmap);
+    let mut ids: Vec<SystemId> = starmap.systems.keys().copied().collect();
+    ids.sort_unstable();
+
+    (
+        prop::sample::select(pairs),
+        prop::sample::select(vec![GraphMode::Gate, GraphMode::Spatial, GraphMode::Hybrid]),
+        prop::option::of(1.0f64..400.0),
+        any::<bool>(),
+        prop::option::of(100.0f64..10_000.0),
+        prop::sample::subsequence(ids.clone(), 0..=2.min(ids.len())),
+    )
+        .prop_map(
+            |((start, goal), mode, max_jump, avoid_gates, max_temperature, avoided)| Case {
+                start,
+                goal,
+                mode,
+                max_jump,
+                avoid_gates,
+                max_temperature,
+                avoided: avoided
+                    .into_iter()
+                    .filter(|&id| id != start && id != goal)
+                    .collect(),
+            },
+        )
+}
+
+fn graph_for(starmap: &Starmap, mode: GraphMode) -> Graph {
+    match mode {
+        GraphMode::Gate => build_gate_graph(starmap),
+        GraphMode::Spatial => build_spatial_graph(starmap),
+        GraphMode::Hybrid => build_hybrid_graph(starmap),
+    }
+}
+
+fn constraints_for(case: &Case) -> PathConstraints {
+    PathConstraints {
+        max_jump: case.max_jump,
+        avoid_gates: case.avoid_gates,
+        avoided_systems: case.avoided.iter().copied().collect(),
+        max_temperature: case.max_temperature,
+        ..PathConstraints::default()
+    }
+}
+
+/// Check that `path` runs from `start` to `goal` over existing, non-repeating graph edges
+/// that avoid the case's excluded systems, returning its total distance.
+fn validate_path(
+    graph: &Graph,
+    case: &Case,
+    constraints: &PathConstraints,
+    path: &[SystemId],
+) -> Result<f64, TestCaseError> {
+    prop_assert_eq!(path.first(), Some(&case.start));
+    prop_assert_eq!(path.last(), Some(&case.goal));
+
+    let unique: HashSet<SystemId> = path.iter().copied().collect();
+    prop_assert_eq!(
+        unique.len(),
+        path.len(),
+        "path revisits a system: {:?}",
+        path
+    );
+    for avoided in &constraints.avoided_systems {
+        prop_assert!(!unique.contains(avoided), "path enters avoided {}", avoided);
+    }
+
+    let mut distance = 0.0;
+    for hop in path.windows(2) {
+        let edge = graph
+            .neighbours(hop[0])
+            .iter()
+            .filter(|edge| edge.target == hop[1])
+            .filter(|edge| constraints.max_jump.is_none_or(|max| edge.distance <= max))
+            .filter(|edge| !constraints.avoid_gates || edge.kind != EdgeKind::Gate)
+            .map(|edge| edge.distance)
+            .min_by(f64::total_cmp);
+        let Some(edge_distance) = edge else {
+            return Err(TestCaseError::fail(format!(
+                "no permitted edge {} -> {} in {:?}",
+                hop[0], hop[1], path
+            )));
+        };
+        distance += edge_distance;
+    }
+    Ok(distance)
+}
+
+#[test]
+fn planners_agree_on_random_fixture_routes() {
+    let starmap = load_starmap(&fixture_db_path(), None).expect("fixture loads");
+    let (mut runner, seed) = seeded_runner(256);
+
+    let result = runner.run(&case_strategy(&starmap), |case| {
+        let graph = graph_for(&starmap, case.mode);
+        let constraints = constraints_for(&case);
+        let map = Some(&starmap);
+
+        let bfs = find_route_bfs(&graph, map, case.start, case.goal, &constraints);
+        let dijkstra = find_route_dijkstra(&graph, map, case.start, case.goal, &constraints);
+        let a_star = find_route_a_star(&graph, map, case.start, case.goal, &constraints);
+
+        // Reachability does not depend on the search order.
+        prop_assert_eq!(bfs.is_some(), dijkstra.is_some());
+        prop_assert_eq!(dijkstra.is_some(), a_star.is_some());
+        let (Some(bfs), Some(dijkstra), Some(a_star)) = (bfs, dijkstra, a_star) else {
+            return Ok(());
+        };
+
+        validate_path(&graph, &case, &constraints, &bfs)?;
+        let dijkstra_distance = validate_path(&graph, &case, &constraints, &dijkstra)?;
+        let a_star_distance = validate_path(&graph, &case, &constraints, &a_star)?;
+
+        // BFS minimises hops, so no weighted search may use fewer.
+        prop_assert!(bfs.len() <= dijkstra.len());
+        prop_assert!(bfs.len() <= a_star.len());
+
+        // A* with an admissible heuristic is as optimal as Dijkstra.
+        let tolerance = DISTANCE_EPSILON * dijkstra_distance.max(1.0);
+        prop_assert!(
+            (a_star_distance - dijkstra_distance).abs() <= tolerance,
+            "A* {} ly vs Dijkstra {} ly",
+            a_star_distance,
+            dijkstra_distance
+        );
+        Ok(())
+    });
+
+    if let Err(error) = result {
+        panic!("{error} (EVEFRONTIER_PROPTEST_SEED={seed})");
+    }
+}
+
+#[test]
+fn fixture_system_pairs_cover_every_ordered_pair() {
+    let starmap = load_starmap(&fixture_db_path(), None).expect("fixture loads");
+    let pairs = fixture_system_pairs(&starmap);
+    let n = starmap.systems.len();
+
+    assert_eq!(pairs.len(), n * (n - 1));
+    assert!(pairs.iter().all(|(start, goal)| start != goal));
+    assert!(pairs.windows(2).all(|w| w[0] < w[1]));
+}
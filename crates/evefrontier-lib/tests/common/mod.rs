@@ -9,6 +9,8 @@ use std::path::PathBuf;
 
 use evefrontier_lib::output;
 use evefrontier_lib::ship::{ShipAttributes, ShipCatalog};
+use evefrontier_lib::{Starmap, SystemId};
+use proptest::test_runner::{Config, RngAlgorithm, TestRng, TestRunner};
 use tempfile::TempDir;
 
 /// Path to fixtures directory used by tests (ship data, minimal DB, etc.)
@@ -33,6 +35,51 @@ pub fn fixture_db_path() -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../docs/fixtures/minimal/static_data.db")
 }
 
+/// Every ordered `(start, goal)` pair of distinct fixture systems, sorted by ID.
+///
+/// Property tests sample from this list so generated cases are always resolvable.
+#[allow(dead_code)]
+pub fn fixture_system_pairs(starmap: &Starmap) -> Vec<(SystemId, SystemId)> {
+    let mut ids: Vec<SystemId> = starmap.systems.keys().copied().collect();
+    ids.sort_unstable();
+    ids.iter()
+        .flat_map(|&start| {
+            ids.iter()
+                .filter(move |&&goal| goal != start)
+                .map(move |&goal| (start, goal))
+        })
+        .collect()
+}
+
+/// Seed used by [`seeded_runner`] when `EVEFRONTIER_PROPTEST_SEED` is unset.
+#[allow(dead_code)]
+pub const DEFAULT_PROPTEST_SEED: u64 = 0x00E6_C3E6_C3E6_C3E6;
+
+/// Proptest runner with a fixed seed so failures reproduce across runs and machines.
+///
+/// Set `EVEFRONTIER_PROPTEST_SEED` to a `u64` to explore a different sequence; the seed in
+/// use is part of every failure message. Failure persistence is disabled because the seed
+/// already identifies the run.
+#[allow(dead_code)]
+pub fn seeded_runner(cases: u32) -> (TestRunner, u64) {
+    let seed = std::env::var("EVEFRONTIER_PROPTEST_SEED")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(DEFAULT_PROPTEST_SEED);
+
+    let mut bytes = [0u8; 32];
+    for chunk in bytes.chunks_mut(8) {
+        chunk.copy_from_slice(&seed.to_le_bytes());
+    }
+    let config = Config {
+        cases,
+        failure_persistence: None,
+        ..Config::default()
+    };
+    let rng = TestRng::from_seed(RngAlgorithm::ChaCha, &bytes);
+    (TestRunner::new_with_rng(config, rng), seed)
+}
+
 /// Test environment with temporary files for spatial index testing.
 ///
 /// Provides a clean directory with a copy of the fixture database,
@@ -152,6 +199,7 @@ impl RouteStepBuilder {
                 moon_count: None,
                 fuel: None,
                 heat: None,
+                position: None,
             },
         }
     }
@@ -0,0 +1,205 @@
+use std::path::PathBuf;
+
+use evefrontier_lib::{Error, ErrorKind};
+
+fn io_error() -> std::io::Error {
+    std::io::Error::other("boom")
+}
+
+#[test]
+fn every_variant_maps_to_expected_kind() {
+    let path = PathBuf::from("/tmp/static_data.db");
+    let http_error = reqwest::blocking::Client::new()
+        .get("not a url")
+        .build()
+        .expect_err("relative URL is rejected");
+
+    let cases = vec![
+        (
+            Error::DatasetNotFound { path: path.clone() },
+            ErrorKind::DatasetUnavailable,
+        ),
+        (
+            Error::ProtectedFixturePath { path: path.clone() },
+            ErrorKind::InvalidRequest,
+        ),
+        (Error::ProjectDirsUnavailable, ErrorKind::DatasetUnavailable),
+        (Error::CacheDirsUnavailable, ErrorKind::DatasetUnavailable),
+        (
+            Error::DatasetAssetMissing { tag: "e6c3".into() },
+            ErrorKind::DatasetUnavailable,
+        ),
+        (
+            Error::DatasetReleaseNotFound { tag: "e6c3".into() },
+            ErrorKind::DatasetUnavailable,
+        ),
+        (
+            Error::ArchiveMissingDatabase {
+                archive: path.clone(),
+            },
+            ErrorKind::DatasetUnavailable,
+        ),
+        (Error::UnsupportedSchema, ErrorKind::DatasetInvalid),
+        (
+            Error::UnknownSystem {
+                name: "Nodd".into(),
+                suggestions: vec!["Nod".into()],
+            },
+            ErrorKind::UnknownSystem,
+        ),
+        (
+            Error::RouteNotFound {
+                start: "Nod".into(),
+                goal: "Brana".into(),
+            },
+            ErrorKind::RouteNotFound,
+        ),
+        (Error::EmptyRoutePlan, ErrorKind::EmptyRoute),
+        (
+            Error::UnnamedRouteSystem {
+                system_id: 30000191,
+                position: 1,
+            },
+            ErrorKind::DatasetInvalid,
+        ),
+        (
+            Error::InvalidReachBudget {
+                message: "negative".into(),
+            },
+            ErrorKind::InvalidRequest,
+        ),
+        (
+            Error::UnsupportedRouteOption {
+                option: "fuel".into(),
+            },
+            ErrorKind::InvalidRequest,
+        ),
+        (
+            Error::InvalidTemperatureRange {
+                min: 4_000.0,
+                max: 3_000.0,
+            },
+            ErrorKind::InvalidRequest,
+        ),
+        (
+            Error::TemperatureCalculation("bad".into()),
+            ErrorKind::Temperature,
+        ),
+        (
+            Error::ShipDataValidation {
+                message: "bad".into(),
+            },
+            ErrorKind::ShipData,
+        ),
+        (
+            Error::DuplicateShipName {
+                name: "Reflex".into(),
+            },
+            ErrorKind::ShipData,
+        ),
+        (
+            Error::Sqlite(rusqlite::Error::QueryReturnedNoRows),
+            ErrorKind::DatasetInvalid,
+        ),
+        (Error::Io(io_error()), ErrorKind::Io),
+        (Error::Http(http_error), ErrorKind::Http),
+        (
+            Error::Zip(zip::result::ZipError::FileNotFound),
+            ErrorKind::DatasetInvalid,
+        ),
+        (
+            Error::SpatialIndexSerialize {
+                message: "bad".into(),
+            },
+            ErrorKind::SpatialIndex,
+        ),
+        (
+            Error::SpatialIndexLoad {
+                path: path.clone(),
+                message: "bad".into(),
+            },
+            ErrorKind::SpatialIndex,
+        ),
+        (
+            Error::SpatialIndexDeserialize {
+                message: "bad".into(),
+            },
+            ErrorKind::SpatialIndex,
+        ),
+        (
+            Error::SpatialIndexUnsupportedVersion {
+                version: 3,
+                max_supported: 2,
+            },
+            ErrorKind::SpatialIndex,
+        ),
+        (Error::SpatialIndexEmpty, ErrorKind::SpatialIndex),
+        (
+            Error::DatabaseDeserialize {
+                message: "bad".into(),
+            },
+            ErrorKind::DatasetInvalid,
+        ),
+        (
+            Error::FmapBase64DecodeError {
+                source: base64::DecodeError::InvalidLength(3),
+            },
+            ErrorKind::Fmap,
+        ),
+        (
+            Error::FmapDecompressionError { source: io_error() },
+            ErrorKind::Fmap,
+        ),
+        (
+            Error::FmapCompressionError { source: io_error() },
+            ErrorKind::Fmap,
+        ),
+        (
+            Error::FmapUnsupportedVersion {
+                version: 9,
+                expected: 1,
+            },
+            ErrorKind::Fmap,
+        ),
+        (Error::FmapInvalidBitWidth { k: 31 }, ErrorKind::Fmap),
+        (
+            Error::FmapTooManyWaypoints {
+                count: 70_000,
+                max: 65_535,
+            },
+            ErrorKind::Fmap,
+        ),
+        (
+            Error::FmapInvalidWaypointType { waypoint_type: 9 },
+            ErrorKind::Fmap,
+        ),
+        (
+            Error::FmapTruncatedData {
+                expected: 8,
+                actual: 2,
+            },
+            ErrorKind::Fmap,
+        ),
+        (
+            Error::FmapInvalidSystemId {
+                system_id: u32::MAX,
+                base_id: 1,
+            },
+            ErrorKind::Fmap,
+        ),
+    ];
+
+    for (error, expected) in cases {
+        assert_eq!(error.kind(), expected, "{error:?}");
+    }
+}
+
+#[test]
+fn kind_labels_are_stable_snake_case() {
+    assert_eq!(ErrorKind::UnknownSystem.as_str(), "unknown_system");
+    assert_eq!(ErrorKind::RouteNotFound.as_str(), "no_path");
+    assert_eq!(
+        ErrorKind::DatasetUnavailable.to_string(),
+        "dataset_unavailable"
+    );
+}
@@ -1,6 +1,45 @@
 use evefrontier_lib::fmap::{
     decode_fmap_token, encode_fmap_token, Waypoint, WaypointType, BASE_SYSTEM_ID, FMAP_VERSION,
+    MAX_BIT_WIDTH,
 };
+use evefrontier_lib::Error;
+use proptest::prelude::*;
+
+mod common;
+use common::seeded_runner;
+
+const ALL_TYPES: [WaypointType; 5] = [
+    WaypointType::Start,
+    WaypointType::Jump,
+    WaypointType::NpcGate,
+    WaypointType::SmartGate,
+    WaypointType::SetDestination,
+];
+
+/// Bits needed to represent `max_offset`, with a floor of one bit.
+fn expected_bit_width(max_offset: u32) -> u8 {
+    max_offset.checked_ilog2().map_or(1, |log| log as u8 + 1)
+}
+
+/// Offsets around every power of two the encoder can represent: 0, 1, 2^n - 1, and 2^n.
+fn boundary_offsets() -> Vec<u32> {
+    let mut offsets = vec![0, 1];
+    for n in 1..MAX_BIT_WIDTH as u32 {
+        offsets.push((1 << n) - 1);
+        offsets.push(1 << n);
+    }
+    offsets.push((1 << MAX_BIT_WIDTH) - 1);
+    offsets.sort_unstable();
+    offsets.dedup();
+    offsets
+}
+
+fn waypoint(offset: u32, waypoint_type: WaypointType) -> Waypoint {
+    Waypoint {
+        system_id: BASE_SYSTEM_ID + offset,
+        waypoint_type,
+    }
+}
 
 #[test]
 fn test_encode_single_waypoint() {
@@ -122,3 +161,92 @@ fn test_encode_rejects_u16_max_plus_one() {
         "expected FmapTooManyWaypoints error, got: {err}"
     );
 }
+
+#[test]
+fn test_bit_width_at_power_of_two_boundaries() {
+    for offset in boundary_offsets() {
+        let waypoints = vec![waypoint(offset, WaypointType::Start)];
+        let token = encode_fmap_token(&waypoints).expect("encode failed");
+        assert_eq!(
+            token.bit_width,
+            expected_bit_width(offset),
+            "bit width for offset {offset}"
+        );
+
+        let decoded = decode_fmap_token(&token.token).expect("decode failed");
+        assert_eq!(decoded.bit_width, token.bit_width);
+        assert_eq!(
+            decoded.waypoints, waypoints,
+            "round trip for offset {offset}"
+        );
+    }
+}
+
+#[test]
+fn test_bit_width_is_chosen_by_largest_offset() {
+    // 2^8 needs 9 bits; the smaller offsets must still round-trip at that width.
+    let waypoints = vec![
+        waypoint(0, WaypointType::Start),
+        waypoint(1 << 8, WaypointType::Jump),
+        waypoint(255, WaypointType::SetDestination),
+    ];
+    let token = encode_fmap_token(&waypoints).expect("encode failed");
+    assert_eq!(token.bit_width, 9);
+
+    let decoded = decode_fmap_token(&token.token).expect("decode failed");
+    assert_eq!(decoded.waypoints, waypoints);
+}
+
+#[test]
+fn test_encode_rejects_offset_beyond_max_bit_width() {
+    let waypoints = vec![waypoint(1 << MAX_BIT_WIDTH, WaypointType::Start)];
+    let err = encode_fmap_token(&waypoints).expect_err("2^30 offset needs 31 bits");
+    assert!(matches!(err, Error::FmapInvalidBitWidth { k: 31 }));
+}
+
+#[test]
+fn test_empty_route_round_trip() {
+    let token = encode_fmap_token(&[]).expect("encode failed");
+    let decoded = decode_fmap_token(&token.token).expect("decode failed");
+    assert_eq!(decoded.waypoint_count, 0);
+    assert!(decoded.waypoints.is_empty());
+}
+
+#[test]
+fn prop_round_trip_random_waypoints() {
+    // Mix boundary offsets with arbitrary in-range ones so most cases land on a
+    // bit-width edge while still covering the general case.
+    let offset = prop_oneof![
+        prop::sample::select(boundary_offsets()),
+        0u32..(1 << MAX_BIT_WIDTH),
+    ];
+    let strategy = prop::collection::vec((offset, prop::sample::select(ALL_TYPES.to_vec())), 0..64)
+        .prop_map(|entries| {
+            entries
+                .into_iter()
+                .map(|(offset, waypoint_type)| waypoint(offset, waypoint_type))
+                .collect::<Vec<_>>()
+        });
+
+    let (mut runner, seed) = seeded_runner(256);
+    let result = runner.run(&strategy, |waypoints| {
+        let token = encode_fmap_token(&waypoints).expect("in-range waypoints encode");
+        let max_offset = waypoints
+            .iter()
+            .map(|wp| wp.system_id - BASE_SYSTEM_ID)
+            .max()
+            .unwrap_or(0);
+        prop_assert_eq!(token.bit_width, expected_bit_width(max_offset));
+
+        let decoded = decode_fmap_token(&token.token).expect("encoded token decodes");
+        prop_assert_eq!(decoded.version, FMAP_VERSION);
+        prop_assert_eq!(decoded.bit_width, token.bit_width);
+        prop_assert_eq!(decoded.waypoint_count, waypoints.len());
+        prop_assert_eq!(decoded.waypoints, waypoints);
+        Ok(())
+    });
+
+    if let Err(error) = result {
+        panic!("{error} (EVEFRONTIER_PROPTEST_SEED={seed})");
+    }
+}
@@ -1,11 +1,44 @@
 use base64::Engine;
-use evefrontier_lib::fmap::{decode_fmap_token, encode_fmap_token, Waypoint, WaypointType};
+use evefrontier_lib::fmap::{
+    decode_fmap_token, encode_fmap_token, DecodedFmapToken, Waypoint, WaypointType,
+    FMAP_HEADER_SIZE,
+};
+use evefrontier_lib::Error;
+use proptest::prelude::*;
+
+mod common;
+use common::seeded_runner;
 
 // System IDs for test routes (relative to BASE_SYSTEM_ID = 30_000_000)
 const JITA: u32 = 30_000_142;
 const PERIMETER: u32 = 30_000_144;
 const AMARR: u32 = 30_002_187;
 
+/// Gzip and base64url-encode raw header + payload bytes, as the encoder does.
+fn token_from_raw(raw: &[u8]) -> String {
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    encoder.write_all(raw).expect("write failed");
+    let compressed = encoder.finish().expect("finish failed");
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(&compressed)
+}
+
+/// A successful decode must be internally consistent; errors are always acceptable.
+fn check_decode(token: &str) -> Result<(), TestCaseError> {
+    if let Ok(DecodedFmapToken {
+        waypoint_count,
+        waypoints,
+        ..
+    }) = decode_fmap_token(token)
+    {
+        prop_assert_eq!(waypoints.len(), waypoint_count);
+    }
+    Ok(())
+}
+
 #[test]
 fn test_decode_single_waypoint() {
     // Encode a single waypoint
@@ -154,3 +187,131 @@ fn test_round_trip_preserves_all_types() {
         assert_eq!(original.waypoint_type, decoded_wp.waypoint_type);
     }
 }
+
+#[test]
+fn test_decode_empty_token() {
+    assert!(decode_fmap_token("").is_err());
+}
+
+#[test]
+fn test_decode_invalid_gzip() {
+    let token = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(b"not gzip data");
+    let err = decode_fmap_token(&token).unwrap_err();
+    assert!(matches!(err, Error::FmapDecompressionError { .. }));
+}
+
+#[test]
+fn test_decode_short_header() {
+    let err = decode_fmap_token(&token_from_raw(&[1, 8])).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::FmapTruncatedData {
+            expected: FMAP_HEADER_SIZE,
+            actual: 2
+        }
+    ));
+}
+
+#[test]
+fn test_decode_count_exceeds_payload() {
+    // Header claims 10 waypoints of 8 + 3 bits (14 payload bytes) but only one byte follows.
+    let err = decode_fmap_token(&token_from_raw(&[1, 8, 0, 10, 0xFF])).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::FmapTruncatedData {
+            expected: 18,
+            actual: 5
+        }
+    ));
+    assert_eq!(
+        err.to_string(),
+        "fmap data truncated: expected 18 bytes, got 5"
+    );
+}
+
+#[test]
+fn test_decode_rejects_oversized_payload() {
+    // Far larger than any header + u16::MAX waypoints could need.
+    let mut raw = vec![1, 8, 0, 1];
+    raw.resize(1 << 20, 0);
+    let err = decode_fmap_token(&token_from_raw(&raw)).unwrap_err();
+    assert!(matches!(err, Error::FmapDecompressionError { .. }));
+}
+
+#[test]
+fn test_decode_ignores_surrounding_whitespace() {
+    let waypoints = vec![Waypoint {
+        system_id: JITA,
+        waypoint_type: WaypointType::Start,
+    }];
+    let token = encode_fmap_token(&waypoints).expect("encode failed").token;
+
+    let decoded = decode_fmap_token(&format!("  {token}\n")).expect("decode failed");
+    assert_eq!(decoded.waypoints, waypoints);
+}
+
+#[test]
+fn fuzz_random_strings_never_panic() {
+    let (mut runner, seed) = seeded_runner(512);
+    let result = runner.run(&"[A-Za-z0-9_\\-=+/ ]{0,96}", |token| check_decode(&token));
+    if let Err(error) = result {
+        panic!("{error} (EVEFRONTIER_PROPTEST_SEED={seed})");
+    }
+}
+
+#[test]
+fn fuzz_random_raw_payloads_never_panic() {
+    // Gzip random bytes so they get past decompression and exercise the parser.
+    // The first bytes are biased towards a valid version and bit width.
+    let strategy = (
+        prop::option::of(Just(1u8)),
+        prop::option::of(0u8..=32),
+        prop::collection::vec(any::<u8>(), 0..64),
+    )
+        .prop_map(|(version, bit_width, rest)| {
+            version
+                .into_iter()
+                .chain(bit_width)
+                .chain(rest)
+                .collect::<Vec<u8>>()
+        });
+
+    let (mut runner, seed) = seeded_runner(512);
+    let result = runner.run(&strategy, |raw| check_decode(&token_from_raw(&raw)));
+    if let Err(error) = result {
+        panic!("{error} (EVEFRONTIER_PROPTEST_SEED={seed})");
+    }
+}
+
+#[test]
+fn fuzz_mutated_tokens_never_panic() {
+    let waypoints = vec![
+        Waypoint {
+            system_id: JITA,
+            waypoint_type: WaypointType::Start,
+        },
+        Waypoint {
+            system_id: PERIMETER,
+            waypoint_type: WaypointType::NpcGate,
+        },
+        Waypoint {
+            system_id: AMARR,
+            waypoint_type: WaypointType::SetDestination,
+        },
+    ];
+    let token = encode_fmap_token(&waypoints).expect("encode failed").token;
+    let len = token.len();
+
+    let strategy = (0..=len, 0..len, any::<char>());
+    let (mut runner, seed) = seeded_runner(512);
+    let result = runner.run(&strategy, |(cut, at, replacement)| {
+        check_decode(&token[..cut])?;
+
+        let mut mutated: Vec<char> = token.chars().collect();
+        mutated[at] = replacement;
+        check_decode(&mutated.into_iter().collect::<String>())
+    });
+    if let Err(error) = result {
+        panic!("{error} (EVEFRONTIER_PROPTEST_SEED={seed})");
+    }
+}
@@ -19,6 +19,7 @@ fn calculates_static_fuel_projection() {
     let config = FuelConfig {
         quality: 10.0,
         dynamic_mass: false,
+        reserve: 0.0,
     };
 
     let projections = calculate_route_fuel(ship, &loadout, &distances(), &config)
@@ -44,10 +45,12 @@ fn calculates_dynamic_mass_and_reduces_total_cost() {
     let static_config = FuelConfig {
         quality: 10.0,
         dynamic_mass: false,
+        reserve: 0.0,
     };
     let dynamic_config = FuelConfig {
         quality: 10.0,
         dynamic_mass: true,
+        reserve: 0.0,
     };
 
     let static_proj = calculate_route_fuel(ship, &loadout, &distances(), &static_config)
@@ -77,6 +80,7 @@ fn calculate_jump_cost_direct() {
     let cfg = FuelConfig {
         quality: 10.0,
         dynamic_mass: false,
+        reserve: 0.0,
     };
 
     let hop = calculate_jump_fuel_cost(mass, distance, &cfg).expect("should compute hop cost");
@@ -119,7 +123,70 @@ fn calculate_jump_cost_invalid_quality() {
         &FuelConfig {
             quality: 0.0,
             dynamic_mass: false,
+            reserve: 0.0,
         },
     );
     assert!(res.is_err(), "quality 0 should be rejected by validation");
 }
+
+#[test]
+fn short_fuel_load_warns_on_first_exhausted_hop() {
+    let catalog = ShipCatalog::from_path(&fixture_path()).expect("fixture should load");
+    let ship = catalog.get("Reflex").expect("reflex present");
+
+    // First hop costs ~201.5 units, so 300 units run dry on the second hop.
+    let loadout = ShipLoadout::new(ship, 300.0, 633_006.0).expect("valid loadout");
+    for dynamic_mass in [false, true] {
+        let config = FuelConfig {
+            quality: 10.0,
+            dynamic_mass,
+            reserve: 0.0,
+        };
+
+        let projections = calculate_route_fuel(ship, &loadout, &distances(), &config)
+            .expect("fuel calculation succeeds");
+
+        assert_eq!(projections[0].warning, None);
+        assert_eq!(projections[1].warning.as_deref(), Some("REFUEL"));
+        assert_eq!(projections[1].remaining, Some(0.0));
+        // The warning is only raised once; later hops stay clamped at zero.
+        assert_eq!(projections[2].warning, None);
+        assert_eq!(projections[2].remaining, Some(0.0));
+    }
+}
+
+#[test]
+fn reserve_warning_when_route_completes_on_raw_fuel() {
+    let catalog = ShipCatalog::from_path(&fixture_path()).expect("fixture should load");
+    let ship = catalog.get("Reflex").expect("reflex present");
+
+    // Cumulative cost is ~854 units, so a full tank never runs dry but a 1000-unit
+    // reserve is crossed on the final hop (~1100 -> ~896 remaining).
+    let loadout = ShipLoadout::new(ship, 1750.0, 633_006.0).expect("valid loadout");
+    let config = FuelConfig {
+        quality: 10.0,
+        dynamic_mass: false,
+        reserve: 1000.0,
+    };
+
+    let projections = calculate_route_fuel(ship, &loadout, &distances(), &config)
+        .expect("fuel calculation succeeds");
+
+    assert_eq!(projections[0].warning, None);
+    assert_eq!(projections[1].warning, None);
+    assert_eq!(projections[2].warning.as_deref(), Some("RESERVE"));
+    assert!(projections[2].remaining.unwrap() > 0.0);
+}
+
+#[test]
+fn negative_reserve_is_rejected() {
+    let catalog = ShipCatalog::from_path(&fixture_path()).expect("fixture should load");
+    let ship = catalog.get("Reflex").expect("reflex present");
+    let loadout = ShipLoadout::new(ship, 1750.0, 0.0).expect("valid loadout");
+    let config = FuelConfig {
+        reserve: -1.0,
+        ..FuelConfig::default()
+    };
+
+    assert!(calculate_route_fuel(ship, &loadout, &distances(), &config).is_err());
+}
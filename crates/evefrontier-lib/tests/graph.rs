@@ -2,8 +2,9 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use evefrontier_lib::{
-    build_gate_graph, build_hybrid_graph, build_spatial_graph, load_starmap, EdgeKind, GraphMode,
-    Starmap, System, SystemMetadata, SystemPosition,
+    build_gate_graph, build_hybrid_graph, build_spatial_graph, find_route_dijkstra, load_starmap,
+    EdgeKind, Graph, GraphMode, PathConstraints, Starmap, System, SystemId, SystemMetadata,
+    SystemPosition,
 };
 
 fn fixture_starmap() -> Starmap {
@@ -55,6 +56,7 @@ fn fixture_starmap() -> Starmap {
         systems,
         name_to_id,
         adjacency,
+        gate_links: Default::default(),
     }
 }
 
@@ -128,6 +130,83 @@ fn hybrid_graph_combines_edges() {
     assert_eq!(spatial_targets, vec![2]);
 }
 
+/// The fixture starmap with every gate listed twice, as a dataset with duplicate jump rows
+/// would produce when assembled by hand.
+fn duplicated_gate_starmap() -> Starmap {
+    let mut starmap = fixture_starmap();
+    let adjacency = starmap
+        .adjacency
+        .iter()
+        .map(|(&system, targets)| {
+            let doubled = targets.iter().chain(targets.iter()).copied().collect();
+            (system, doubled)
+        })
+        .collect();
+    starmap.adjacency = Arc::new(adjacency);
+    starmap
+}
+
+fn edge_keys(graph: &Graph, system: SystemId) -> Vec<(SystemId, EdgeKind)> {
+    let mut keys: Vec<_> = graph
+        .neighbours(system)
+        .iter()
+        .map(|edge| (edge.target, edge.kind))
+        .collect();
+    keys.sort_unstable();
+    keys
+}
+
+#[test]
+fn duplicate_gate_rows_collapse_to_one_edge_per_pair() {
+    let starmap = duplicated_gate_starmap();
+    assert_eq!(starmap.adjacency[&1], vec![2, 3, 2, 3]);
+
+    let gate = build_gate_graph(&starmap);
+    assert_eq!(
+        edge_keys(&gate, 1),
+        vec![(2, EdgeKind::Gate), (3, EdgeKind::Gate)]
+    );
+
+    let hybrid = build_hybrid_graph(&starmap);
+    assert_eq!(
+        edge_keys(&hybrid, 1),
+        vec![
+            (2, EdgeKind::Gate),
+            (2, EdgeKind::Spatial),
+            (3, EdgeKind::Gate)
+        ],
+        "a gate and a jump to the same system are distinct edges"
+    );
+}
+
+#[test]
+fn duplicate_gate_rows_do_not_change_routes() {
+    let clean = fixture_starmap();
+    let duplicated = duplicated_gate_starmap();
+
+    for build in [build_gate_graph, build_hybrid_graph] {
+        let expected = build(&clean);
+        let graph = build(&duplicated);
+        for &system in clean.systems.keys() {
+            let distances = |graph: &Graph| {
+                let mut edges: Vec<_> = graph
+                    .neighbours(system)
+                    .iter()
+                    .map(|edge| (edge.target, edge.kind, edge.distance))
+                    .collect();
+                edges.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+                edges
+            };
+            assert_eq!(distances(&graph), distances(&expected));
+        }
+        let constraints = PathConstraints::default();
+        assert_eq!(
+            find_route_dijkstra(&graph, None, 2, 3, &constraints),
+            find_route_dijkstra(&expected, None, 2, 3, &constraints)
+        );
+    }
+}
+
 #[test]
 fn spatial_graph_has_edges_with_positions_in_fixture() {
     let starmap = load_starmap(&fixture_path(), None).expect("load fixture");
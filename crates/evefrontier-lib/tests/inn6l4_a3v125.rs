@@ -103,6 +103,7 @@ fn build_test_starmap() -> Starmap {
         systems,
         name_to_id,
         adjacency: std::sync::Arc::new(adjacency),
+        gate_links: Default::default(),
     }
 }
 
@@ -147,6 +148,7 @@ fn inn6l4_to_a3v125_distance_vs_fuel_optimization() {
     let fuel_cfg = FuelConfig {
         quality: 10.0,
         dynamic_mass: true,
+        reserve: 0.0,
     };
 
     let constraints_fuel = RouteConstraints {
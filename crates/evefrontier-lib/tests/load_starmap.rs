@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use evefrontier_lib::{build_graph, find_route, load_starmap, Error, Result};
+use evefrontier_lib::{build_graph, find_route, load_starmap, Error, GateLink, Result};
 use rusqlite::Connection;
 use tempfile::NamedTempFile;
 
@@ -44,6 +44,137 @@ fn load_fixture_and_find_route() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn gate_neighbors_within_reports_hop_distance() -> Result<()> {
+    let starmap = load_starmap(&fixture_path(), None)?;
+    let nod = starmap.system_id_by_name("Nod").expect("Nod exists");
+    let named = |max_hops| {
+        let mut found: Vec<(String, usize)> = starmap
+            .gate_neighbors_within(nod, max_hops)
+            .into_iter()
+            .map(|(id, hops)| (starmap.system_name(id).unwrap().to_string(), hops))
+            .collect();
+        found.sort();
+        found
+    };
+
+    let direct = named(1);
+    assert_eq!(
+        direct,
+        [
+            ("D:2NAS".to_string(), 1),
+            ("H:2L2S".to_string(), 1),
+            ("J:35IA".to_string(), 1),
+        ]
+    );
+
+    // Depth 2 adds neighbours-of-neighbours without repeating Nod or direct neighbours.
+    let two = named(2);
+    assert_eq!(two.len(), 4);
+    assert!(two.contains(&("Y:3R7E".to_string(), 2)));
+    assert!(direct.iter().all(|entry| two.contains(entry)));
+
+    assert!(named(0).is_empty());
+    // Brana is three gate hops away.
+    assert!(named(3).contains(&("Brana".to_string(), 3)));
+    Ok(())
+}
+
+#[test]
+fn gate_links_match_adjacency_for_fixture_systems() -> Result<()> {
+    let starmap = load_starmap(&fixture_path(), None)?;
+    let nod = starmap.system_id_by_name("Nod").expect("Nod exists");
+
+    let mut names: Vec<&str> = starmap
+        .gate_links(nod)
+        .iter()
+        .map(|link| starmap.system_name(link.target).unwrap())
+        .collect();
+    names.sort_unstable();
+    assert_eq!(names, ["D:2NAS", "H:2L2S", "J:35IA"]);
+
+    for (&system, neighbours) in starmap.adjacency.iter() {
+        let targets: Vec<_> = starmap
+            .gate_links(system)
+            .iter()
+            .map(|link| link.target)
+            .collect();
+        assert_eq!(&targets, neighbours, "links out of step for {system}");
+    }
+
+    let position = |id| {
+        starmap.systems[&id]
+            .position
+            .expect("fixture has positions")
+    };
+    for link in starmap.gate_links(nod) {
+        let expected = position(nod).distance_to(&position(link.target));
+        let distance = link.distance.expect("both ends are positioned");
+        assert!((distance - expected).abs() < 1e-9);
+        assert_eq!(link.jump_type, None, "fixture leaves jumpType empty");
+    }
+
+    assert!(starmap.gate_links(-1).is_empty());
+    Ok(())
+}
+
+#[test]
+fn gate_links_carry_jump_type() -> Result<()> {
+    let file = NamedTempFile::new()?;
+    let conn = Connection::open(file.path())?;
+    conn.execute_batch(
+        r#"
+        CREATE TABLE SolarSystems (
+            solarSystemId INTEGER PRIMARY KEY,
+            name TEXT NOT NULL,
+            centerX REAL,
+            centerY REAL,
+            centerZ REAL,
+            star_temperature REAL,
+            star_luminosity REAL
+        );
+        CREATE TABLE Jumps (
+            fromSystemId INTEGER NOT NULL,
+            toSystemId INTEGER NOT NULL,
+            jumpType INTEGER
+        );
+        INSERT INTO SolarSystems (solarSystemId, name, centerX, centerY, centerZ) VALUES
+            (1, 'Alpha', 0, 0, 0),
+            (2, 'Beta', 0, 0, 0),
+            (3, 'Gamma', NULL, NULL, NULL);
+        INSERT INTO Jumps (fromSystemId, toSystemId, jumpType) VALUES
+            (1, 2, NULL),
+            (2, 1, 3),
+            (2, 3, 1);
+        "#,
+    )?;
+    drop(conn);
+
+    let starmap = load_starmap(file.path(), None)?;
+    assert_eq!(
+        starmap.gate_links(2),
+        [
+            GateLink {
+                target: 1,
+                jump_type: Some(3),
+                distance: Some(0.0),
+            },
+            GateLink {
+                target: 3,
+                jump_type: Some(1),
+                distance: None,
+            },
+        ]
+    );
+    assert_eq!(
+        starmap.gate_links(1).len(),
+        1,
+        "both rows describe one gate"
+    );
+    assert_eq!(starmap.gate_links(1)[0].jump_type, Some(3));
+    Ok(())
+}
+
 #[test]
 fn load_legacy_schema() -> Result<()> {
     let file = NamedTempFile::new()?;
@@ -72,6 +203,21 @@ fn load_legacy_schema() -> Result<()> {
     let starmap = load_starmap(file.path(), None)?;
     assert_eq!(starmap.systems.len(), 3);
     assert_eq!(starmap.adjacency.len(), 3);
+    assert_eq!(
+        starmap.gate_links(2),
+        [
+            GateLink {
+                target: 1,
+                jump_type: None,
+                distance: None,
+            },
+            GateLink {
+                target: 3,
+                jump_type: None,
+                distance: None,
+            },
+        ]
+    );
 
     for system in starmap.systems.values() {
         assert!(system.metadata.constellation_id.is_none());
@@ -0,0 +1,64 @@
+//! Coarse timing guard for the starmap lookups benchmarked in `benches/lookups.rs`.
+//!
+//! Thresholds are orders of magnitude above the measured cost, even in unoptimized test
+//! builds on a loaded CI runner, so this only trips on an accidental complexity
+//! regression (e.g. a name lookup turning into a scan). Use the criterion benches for
+//! real measurements.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use evefrontier_lib::{load_starmap, NeighbourQuery, SpatialIndex, SystemId};
+
+mod common;
+use common::fixture_db_path;
+
+const ITERATIONS: u32 = 1_000;
+
+/// Run `op` [`ITERATIONS`] times and fail if the average call exceeds `limit`.
+fn assert_average_below(label: &str, limit: Duration, mut op: impl FnMut()) {
+    let started = Instant::now();
+    for _ in 0..ITERATIONS {
+        op();
+    }
+    let average = started.elapsed() / ITERATIONS;
+    assert!(
+        average < limit,
+        "{label} averaged {average:?} per call (limit {limit:?})"
+    );
+}
+
+#[test]
+fn starmap_lookups_stay_fast() {
+    let starmap = load_starmap(&fixture_db_path(), None).expect("fixture loads");
+    let index = SpatialIndex::build(&starmap);
+
+    let names: Vec<&str> = starmap.name_to_id.keys().map(String::as_str).collect();
+    let ids: Vec<SystemId> = starmap.systems.keys().copied().collect();
+    let points: Vec<[f64; 3]> = starmap
+        .systems
+        .values()
+        .filter_map(|system| system.position)
+        .map(|p| [p.x, p.y, p.z])
+        .collect();
+    let query = NeighbourQuery::within_radius(5, 100.0);
+
+    assert_average_below("system_id_by_name", Duration::from_millis(1), || {
+        for name in &names {
+            black_box(starmap.system_id_by_name(name));
+        }
+    });
+    assert_average_below("fuzzy_system_matches", Duration::from_millis(20), || {
+        black_box(starmap.fuzzy_system_matches("Brnaa", 5));
+    });
+    assert_average_below("adjacency lookup", Duration::from_millis(1), || {
+        for id in &ids {
+            black_box(starmap.adjacency.get(id).map(Vec::len));
+        }
+    });
+    assert_average_below("nearest_filtered", Duration::from_millis(20), || {
+        for &point in &points {
+            black_box(index.nearest_filtered(point, &query));
+        }
+    });
+}
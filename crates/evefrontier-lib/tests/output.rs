@@ -1,7 +1,8 @@
 use std::path::PathBuf;
 
 use evefrontier_lib::{
-    load_starmap, RouteAlgorithm, RouteOutputKind, RoutePlan, RouteRenderMode, RouteSummary,
+    load_starmap, Error, MissingNamePolicy, RouteAlgorithm, RouteOutputKind, RoutePlan,
+    RouteRenderMode, RouteSummary,
 };
 
 fn fixture_path() -> PathBuf {
@@ -27,6 +28,7 @@ fn summary_rejects_empty_plans() {
         gates: 0,
         jumps: 0,
         diagnostics: vec![],
+        effective_max_jump: None,
     };
 
     let err = RouteSummary::from_plan(RouteOutputKind::Route, &starmap, &plan, None)
@@ -51,6 +53,7 @@ fn summary_from_plan_populates_names() {
         gates: 1,
         jumps: 0,
         diagnostics: vec![],
+        effective_max_jump: None,
     };
 
     let summary = RouteSummary::from_plan(RouteOutputKind::Route, &starmap, &plan, None)
@@ -61,6 +64,153 @@ fn summary_from_plan_populates_names() {
     assert_eq!(summary.hops, 1);
 }
 
+/// Nod -> H:2L2S -> Brana, with H:2L2S's name blanked as in a partially imported dataset.
+fn plan_through_unnamed_system() -> (evefrontier_lib::Starmap, RoutePlan, i64) {
+    let mut starmap = load_fixture_starmap();
+    let id = |name: &str| starmap.system_id_by_name(name).expect("system exists");
+    let steps = vec![id("Nod"), id("H:2L2S"), id("Brana")];
+    let unnamed = steps[1];
+    starmap
+        .systems
+        .get_mut(&unnamed)
+        .expect("system exists")
+        .name
+        .clear();
+    let plan = RoutePlan {
+        algorithm: RouteAlgorithm::Dijkstra,
+        start: steps[0],
+        goal: steps[2],
+        steps,
+        gates: 1,
+        jumps: 1,
+        diagnostics: vec![],
+        effective_max_jump: None,
+    };
+    (starmap, plan, unnamed)
+}
+
+#[test]
+fn summary_fail_policy_names_unnamed_system_and_position() {
+    let (starmap, plan, unnamed) = plan_through_unnamed_system();
+
+    let err = RouteSummary::from_plan_with(
+        RouteOutputKind::Route,
+        &starmap,
+        &plan,
+        None,
+        MissingNamePolicy::Fail,
+    )
+    .expect_err("nameless step is rejected");
+    assert!(
+        matches!(err, Error::UnnamedRouteSystem { system_id, position: 1 } if system_id == unnamed),
+        "{err:?}"
+    );
+    let message = err.to_string();
+    assert!(message.contains(&unnamed.to_string()), "{message}");
+    assert!(message.contains("route step 1"), "{message}");
+}
+
+#[test]
+fn summary_warn_policy_keeps_unnamed_step() {
+    let (starmap, plan, unnamed) = plan_through_unnamed_system();
+
+    let summary = RouteSummary::from_plan(RouteOutputKind::Route, &starmap, &plan, None)
+        .expect("default policy continues");
+    assert_eq!(summary.steps.len(), 3);
+    assert_eq!(summary.steps[1].id, unnamed);
+    assert_eq!(summary.steps[1].name, None);
+    assert_eq!(summary.start.name.as_deref(), Some("Nod"));
+    assert_eq!(summary.goal.name.as_deref(), Some("Brana"));
+}
+
+#[test]
+fn summary_reports_average_and_max_jump_distance() {
+    let starmap = load_fixture_starmap();
+    let id = |name: &str| starmap.system_id_by_name(name).expect("system exists");
+    // Nod -> H:2L2S is a gate; the remaining two hops are spatial jumps.
+    let steps = vec![id("Nod"), id("H:2L2S"), id("E1J-M5G"), id("Brana")];
+    let plan = RoutePlan {
+        algorithm: RouteAlgorithm::Dijkstra,
+        start: steps[0],
+        goal: steps[3],
+        steps: steps.clone(),
+        gates: 1,
+        jumps: 2,
+        diagnostics: vec![],
+        effective_max_jump: None,
+    };
+
+    let summary = RouteSummary::from_plan(RouteOutputKind::Route, &starmap, &plan, None)
+        .expect("summary builds");
+
+    let distance = |from: usize, to: usize| {
+        let a = starmap.systems[&steps[from]].position.expect("position");
+        let b = starmap.systems[&steps[to]].position.expect("position");
+        a.distance_to(&b)
+    };
+    let jumps = [distance(1, 2), distance(2, 3)];
+    let average = summary.average_jump_distance.expect("route has jumps");
+    let max = summary.max_jump_distance.expect("route has jumps");
+    assert!((average - (jumps[0] + jumps[1]) / 2.0).abs() < 1e-9);
+    assert!((max - jumps[0].max(jumps[1])).abs() < 1e-9);
+    assert!(max >= average);
+}
+
+#[test]
+fn summary_without_jumps_has_no_jump_stats() {
+    let starmap = load_fixture_starmap();
+    let start = starmap.system_id_by_name("Nod").expect("system exists");
+    let goal = starmap.system_id_by_name("H:2L2S").expect("system exists");
+    let plan = RoutePlan {
+        algorithm: RouteAlgorithm::Bfs,
+        start,
+        goal,
+        steps: vec![start, goal],
+        gates: 1,
+        jumps: 0,
+        diagnostics: vec![],
+        effective_max_jump: None,
+    };
+
+    let summary = RouteSummary::from_plan(RouteOutputKind::Route, &starmap, &plan, None)
+        .expect("summary builds");
+
+    assert_eq!(summary.average_jump_distance, None);
+    assert_eq!(summary.max_jump_distance, None);
+}
+
+#[test]
+fn attach_positions_copies_system_coordinates() {
+    let starmap = load_fixture_starmap();
+    let start = starmap
+        .system_id_by_name("Nod")
+        .expect("start system exists");
+    let goal = starmap
+        .system_id_by_name("Brana")
+        .expect("goal system exists");
+    let plan = RoutePlan {
+        algorithm: RouteAlgorithm::Bfs,
+        start,
+        goal,
+        steps: vec![start, goal],
+        gates: 1,
+        jumps: 0,
+        diagnostics: vec![],
+        effective_max_jump: None,
+    };
+
+    let mut summary = RouteSummary::from_plan(RouteOutputKind::Route, &starmap, &plan, None)
+        .expect("summary builds");
+    assert!(summary.steps.iter().all(|step| step.position.is_none()));
+
+    summary.attach_positions(&starmap);
+    for step in &summary.steps {
+        let expected = starmap.systems[&step.id].position;
+        assert!(expected.is_some());
+        assert_eq!(step.position, expected);
+    }
+}
+
 #[test]
 fn render_modes_include_expected_tokens() {
     let starmap = load_fixture_starmap();
@@ -78,6 +228,7 @@ fn render_modes_include_expected_tokens() {
         gates: 0, // In A* hybrid this may be spatial; tests only assert tokens
         jumps: 1,
         diagnostics: vec![],
+        effective_max_jump: None,
     };
 
     let summary = RouteSummary::from_plan(RouteOutputKind::Route, &starmap, &plan, None)
@@ -95,3 +246,55 @@ fn render_modes_include_expected_tokens() {
     assert!(note.contains("Route:"));
     assert!(note.contains("Nod"));
 }
+
+#[test]
+fn summary_round_trips_through_json() {
+    let starmap = load_fixture_starmap();
+    let start = starmap
+        .system_id_by_name("Nod")
+        .expect("start system exists");
+    let goal = starmap
+        .system_id_by_name("Brana")
+        .expect("goal system exists");
+    let plan = RoutePlan {
+        algorithm: RouteAlgorithm::AStar,
+        start,
+        goal,
+        steps: vec![start, goal],
+        gates: 0,
+        jumps: 1,
+        diagnostics: vec![],
+        effective_max_jump: None,
+    };
+
+    let mut summary = RouteSummary::from_plan(RouteOutputKind::Route, &starmap, &plan, None)
+        .expect("summary builds");
+    let ship = evefrontier_lib::ShipAttributes {
+        name: "Reflex".to_string(),
+        base_mass_kg: 10_000_000.0,
+        specific_heat: 0.9,
+        fuel_capacity: 1750.0,
+        cargo_capacity: 800_000.0,
+    };
+    let loadout = evefrontier_lib::ShipLoadout::new(&ship, 1750.0, 0.0).expect("valid loadout");
+    summary
+        .attach_fuel(&ship, &loadout, &evefrontier_lib::FuelConfig::default())
+        .expect("attach fuel");
+    summary
+        .attach_heat(&ship, &loadout, &evefrontier_lib::HeatConfig::default())
+        .expect("attach heat");
+
+    let json = serde_json::to_string(&summary).expect("serialize summary");
+    let restored: RouteSummary = serde_json::from_str(&json).expect("deserialize summary");
+
+    assert_eq!(
+        restored.render(RouteRenderMode::PlainText),
+        summary.render(RouteRenderMode::PlainText)
+    );
+    assert_eq!(restored.steps.len(), summary.steps.len());
+    assert_eq!(
+        restored.fuel.as_ref().map(|f| f.ship_name.clone()),
+        summary.fuel.as_ref().map(|f| f.ship_name.clone())
+    );
+    assert!(restored.heat.is_some());
+}
@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use evefrontier_lib::ship::{FuelConfig, ShipCatalog, ShipLoadout};
+use evefrontier_lib::{
+    load_starmap, plan_reachable, Error, ReachBudget, ReachableRequest, RouteConstraints, Starmap,
+    SystemId,
+};
+
+fn fixture_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../docs/fixtures/minimal/static_data.db")
+}
+
+fn fixture_ship_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../docs/fixtures/ship_data.csv")
+}
+
+fn distance_request(budget_ly: f64) -> ReachableRequest {
+    let mut request = ReachableRequest::distance("Nod", budget_ly);
+    // Tests expect heat-based blocking to be disabled unless explicitly set
+    request.constraints.avoid_critical_state = false;
+    request
+}
+
+fn reachable_ids(starmap: &Starmap, request: &ReachableRequest) -> HashSet<SystemId> {
+    plan_reachable(starmap, request)
+        .expect("reachability succeeds")
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect()
+}
+
+#[test]
+fn reachable_systems_stay_within_budget() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let start = starmap.system_id_by_name("Nod").unwrap();
+
+    for budget in [0.0, 50.0, 150.0, 300.0, 1_000.0] {
+        let reachable = plan_reachable(&starmap, &distance_request(budget)).expect("reachable");
+
+        assert!(reachable.iter().all(|&(id, _)| id != start));
+        assert!(
+            reachable.iter().all(|&(_, cost)| cost <= budget),
+            "budget {budget} exceeded: {reachable:?}"
+        );
+        assert!(
+            reachable.windows(2).all(|pair| pair[0].1 <= pair[1].1),
+            "results should be ordered by cost"
+        );
+    }
+}
+
+#[test]
+fn raising_budget_grows_reachable_set() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+
+    let mut previous = HashSet::new();
+    for budget in [0.0, 50.0, 100.0, 200.0, 400.0, 800.0, 1_600.0] {
+        let current = reachable_ids(&starmap, &distance_request(budget));
+        assert!(
+            current.is_superset(&previous),
+            "budget {budget} lost systems reachable with a smaller budget"
+        );
+        previous = current;
+    }
+
+    assert!(!previous.is_empty(), "large budget should reach something");
+}
+
+#[test]
+fn limit_keeps_cheapest_systems() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let uncapped = plan_reachable(&starmap, &distance_request(1_600.0)).expect("reachable");
+    assert!(uncapped.len() > 2, "fixture should reach several systems");
+
+    let mut request = distance_request(1_600.0);
+    request.limit = 2;
+    let capped = plan_reachable(&starmap, &request).expect("reachable");
+
+    assert_eq!(capped, uncapped[..2]);
+}
+
+#[test]
+fn fuel_budget_treats_gates_as_free() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let catalog = ShipCatalog::from_path(&fixture_ship_path()).expect("ship fixture loads");
+    let ship = catalog.get("Reflex").expect("reflex present");
+    let loadout = ShipLoadout::new(ship, ship.fuel_capacity, 0.0).expect("valid loadout");
+
+    let mut request = distance_request(0.0);
+    request.budget = ReachBudget::Fuel(0.0);
+    request.constraints.ship = Some(ship.clone());
+    request.constraints.loadout = Some(loadout);
+    request.fuel_config = FuelConfig::default();
+
+    let reachable = reachable_ids(&starmap, &request);
+    for neighbour in ["D:2NAS", "H:2L2S", "J:35IA"] {
+        let id = starmap.system_id_by_name(neighbour).unwrap();
+        assert!(reachable.contains(&id), "{neighbour} is a free gate hop");
+    }
+}
+
+#[test]
+fn fuel_budget_requires_ship() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let request = ReachableRequest {
+        budget: ReachBudget::Fuel(100.0),
+        constraints: RouteConstraints::default(),
+        ..distance_request(0.0)
+    };
+
+    let err = plan_reachable(&starmap, &request).expect_err("ship required");
+    assert!(matches!(err, Error::InvalidReachBudget { .. }));
+}
+
+#[test]
+fn unknown_start_reports_suggestions() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let err = plan_reachable(&starmap, &ReachableRequest::distance("Nodd", 100.0))
+        .expect_err("unknown system");
+
+    match err {
+        Error::UnknownSystem { suggestions, .. } => assert!(suggestions.contains(&"Nod".into())),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
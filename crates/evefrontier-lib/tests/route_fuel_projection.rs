@@ -1,8 +1,11 @@
 use std::path::PathBuf;
 
 use evefrontier_lib::routing::{plan_route, RouteAlgorithm, RouteConstraints, RouteRequest};
-use evefrontier_lib::ship::{FuelConfig, ShipCatalog, ShipLoadout};
-use evefrontier_lib::{load_starmap, RouteOutputKind, RouteSummary};
+use evefrontier_lib::ship::{plan_refuel_stops, FuelConfig, ShipCatalog, ShipLoadout};
+use evefrontier_lib::{load_starmap, RouteEndpoint, RouteOutputKind, RouteSummary};
+
+mod common;
+use common::RouteStepBuilder;
 
 fn fixture_db_path() -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR"))
@@ -41,6 +44,7 @@ fn attaches_fuel_projection_to_route_summary() {
     let fuel_config = FuelConfig {
         quality: 10.0,
         dynamic_mass: true,
+        reserve: 0.0,
     };
 
     summary
@@ -90,6 +94,7 @@ fn gate_steps_do_not_consume_fuel() {
     let fuel_config = FuelConfig {
         quality: 10.0,
         dynamic_mass: true,
+        reserve: 0.0,
     };
 
     summary
@@ -122,3 +127,153 @@ fn gate_steps_do_not_consume_fuel() {
         prev_cumulative = projection.cumulative;
     }
 }
+
+fn jump_only_summary(distances: &[f64]) -> RouteSummary {
+    let mut steps = vec![RouteStepBuilder::new()
+        .index(0)
+        .id(0)
+        .name("Origin")
+        .build()];
+    steps[0].distance = None;
+    steps[0].method = None;
+    for (idx, distance) in distances.iter().enumerate() {
+        steps.push(
+            RouteStepBuilder::new()
+                .index(idx + 1)
+                .id(idx as i64 + 1)
+                .distance(*distance)
+                .build(),
+        );
+    }
+    let total: f64 = distances.iter().sum();
+
+    RouteSummary {
+        kind: RouteOutputKind::Route,
+        algorithm: RouteAlgorithm::AStar,
+        hops: distances.len(),
+        gates: 0,
+        jumps: distances.len(),
+        total_distance: total,
+        jump_distance: total,
+        average_jump_distance: None,
+        max_jump_distance: None,
+        start: RouteEndpoint { id: 0, name: None },
+        goal: RouteEndpoint {
+            id: distances.len() as i64,
+            name: None,
+        },
+        steps,
+        fuel: None,
+        refuel_at: Vec::new(),
+        heat: None,
+        cooling_stops: Vec::new(),
+        fmap_url: None,
+        parameters: None,
+        effective_constraints: None,
+    }
+}
+
+#[test]
+fn short_fuel_load_flags_refuel_hop_and_summary() {
+    let catalog = ShipCatalog::from_path(&fixture_ship_path()).expect("ship fixture loads");
+    let ship = catalog.get("Reflex").expect("reflex present");
+    let loadout = ShipLoadout::new(ship, 300.0, 633_006.0).expect("valid loadout");
+    let fuel_config = FuelConfig {
+        quality: 10.0,
+        dynamic_mass: false,
+        reserve: 0.0,
+    };
+
+    let mut summary = jump_only_summary(&[18.95, 38.26, 23.09]);
+    summary
+        .attach_fuel(ship, &loadout, &fuel_config)
+        .expect("fuel projection attaches");
+
+    let warnings: Vec<Option<&str>> = summary.steps[1..]
+        .iter()
+        .map(|s| s.fuel.as_ref().unwrap().warning.as_deref())
+        .collect();
+    assert_eq!(warnings, vec![None, Some("REFUEL"), None]);
+    assert!(summary.fuel.as_ref().unwrap().fuel_exhausted);
+}
+
+#[test]
+fn full_tank_does_not_flag_fuel_exhausted() {
+    let catalog = ShipCatalog::from_path(&fixture_ship_path()).expect("ship fixture loads");
+    let ship = catalog.get("Reflex").expect("reflex present");
+    let loadout = ShipLoadout::new(ship, 1750.0, 633_006.0).expect("valid loadout");
+
+    let mut summary = jump_only_summary(&[18.95, 38.26, 23.09]);
+    summary
+        .attach_fuel(ship, &loadout, &FuelConfig::default())
+        .expect("fuel projection attaches");
+
+    assert!(!summary.fuel.as_ref().unwrap().fuel_exhausted);
+    assert!(summary.steps[1..]
+        .iter()
+        .all(|s| s.fuel.as_ref().unwrap().warning.is_none()));
+}
+
+#[test]
+fn reserve_breach_sets_warning_and_fuel_exhausted() {
+    let catalog = ShipCatalog::from_path(&fixture_ship_path()).expect("ship fixture loads");
+    let ship = catalog.get("Reflex").expect("reflex present");
+    let loadout = ShipLoadout::new(ship, 1750.0, 633_006.0).expect("valid loadout");
+    let fuel_config = FuelConfig {
+        quality: 10.0,
+        dynamic_mass: false,
+        reserve: 1000.0,
+    };
+
+    let mut summary = jump_only_summary(&[18.95, 38.26, 23.09]);
+    summary
+        .attach_fuel(ship, &loadout, &fuel_config)
+        .expect("fuel projection attaches");
+
+    let warnings: Vec<Option<&str>> = summary.steps[1..]
+        .iter()
+        .map(|s| s.fuel.as_ref().unwrap().warning.as_deref())
+        .collect();
+    assert_eq!(warnings, vec![None, None, Some("RESERVE")]);
+
+    let fuel = summary.fuel.as_ref().unwrap();
+    assert!(
+        fuel.fuel_exhausted,
+        "dipping into the reserve counts as exhausted"
+    );
+    assert!(fuel.remaining.unwrap() > 0.0, "route completes on raw fuel");
+}
+
+#[test]
+fn long_route_plans_two_refuel_stops() {
+    let catalog = ShipCatalog::from_path(&fixture_ship_path()).expect("ship fixture loads");
+    let ship = catalog.get("Reflex").expect("reflex present");
+    let loadout = ShipLoadout::new(ship, 1750.0, 0.0).expect("valid loadout");
+    let fuel_config = FuelConfig::default();
+
+    // A full Reflex burns ~1000 units per 100 ly hop, so every second hop needs a refill.
+    let mut summary = jump_only_summary(&[100.0, 100.0, 100.0, 100.0, 50.0]);
+    summary
+        .attach_fuel(ship, &loadout, &fuel_config)
+        .expect("fuel projection attaches");
+
+    assert_eq!(summary.refuel_at, vec![2, 4]);
+    let refuel_warnings: Vec<usize> = summary
+        .steps
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| s.fuel.as_ref().and_then(|f| f.warning.as_deref()) == Some("REFUEL"))
+        .map(|(idx, _)| idx)
+        .collect();
+    assert_eq!(summary.refuel_at, refuel_warnings);
+
+    // A tank large enough for the whole route needs no stops.
+    assert!(plan_refuel_stops(&summary, 10_000.0).is_empty());
+}
+
+#[test]
+fn refuel_stops_are_omitted_from_json_when_empty() {
+    let summary = jump_only_summary(&[10.0]);
+    let json = serde_json::to_value(&summary).expect("summary serializes");
+    assert!(json.get("refuel_at").is_none());
+}
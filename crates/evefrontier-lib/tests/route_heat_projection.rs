@@ -42,6 +42,8 @@ fn make_reflex_route_summary() -> RouteSummary {
         jumps: 3,
         total_distance: 18.95 + 38.26 + 23.09,
         jump_distance: 18.95 + 38.26 + 23.09,
+        average_jump_distance: None,
+        max_jump_distance: None,
         start: RouteEndpoint {
             id: 30000001,
             name: Some("Nod".to_string()),
@@ -52,9 +54,12 @@ fn make_reflex_route_summary() -> RouteSummary {
         },
         steps,
         fuel: None,
+        refuel_at: Vec::new(),
         heat: None,
+        cooling_stops: Vec::new(),
         fmap_url: None,
         parameters: None,
+        effective_constraints: None,
     }
 }
 
@@ -180,6 +185,8 @@ fn attach_heat_suppresses_cooldown_before_gate() {
         jumps: 1,
         total_distance: 50.0,
         jump_distance: 40.0,
+        average_jump_distance: None,
+        max_jump_distance: None,
         start: RouteEndpoint {
             id: 1,
             name: Some("Start".to_string()),
@@ -207,8 +214,11 @@ fn attach_heat_suppresses_cooldown_before_gate() {
                 .build(),
         ],
         fuel: None,
+        refuel_at: Vec::new(),
         heat: None,
+        cooling_stops: Vec::new(),
         fmap_url: None,
+        effective_constraints: None,
     };
 
     summary
@@ -222,3 +232,82 @@ fn attach_heat_suppresses_cooldown_before_gate() {
         "expected no cooldown when the next step is a gate"
     );
 }
+
+#[test]
+fn plan_cooling_stops_carries_residual_heat_between_hops() {
+    // hop heat = 3 × distance / (calibration × hull mass × specific heat) = 10 K per light-year.
+    let ship = evefrontier_lib::ShipAttributes {
+        name: "CarriedHeatShip".to_string(),
+        base_mass_kg: 1e6,
+        specific_heat: 1.0,
+        fuel_capacity: 1000.0,
+        cargo_capacity: 0.0,
+    };
+    let loadout = evefrontier_lib::ShipLoadout::new(&ship, 1000.0, 0.0).expect("loadout ok");
+    let config = HeatConfig {
+        calibration_constant: 3e-7,
+        dynamic_mass: false,
+    };
+
+    let step = |index: usize, name: &str, distance: f64, method: &str| {
+        RouteStepBuilder::new()
+            .index(index)
+            .id(index as i64 + 1)
+            .name(name)
+            .distance(distance)
+            .method(method)
+            .min_temp(5.0)
+            .build()
+    };
+    let mut summary = make_reflex_route_summary();
+    summary.steps = vec![
+        step(0, "Start", 0.0, "jump"),
+        // Two back-to-back near-critical jumps. The first pauses at W; the second arrives at X
+        // right before a gate, so it cannot pause and the gate passes its heat on to Y.
+        step(1, "W", 11.5, "jump"),
+        step(2, "X", 11.5, "jump"),
+        step(3, "Y", 0.0, "gate"),
+        // A short jump that stays cold on its own but not on top of the heat carried from X.
+        step(4, "Z", 0.4, "jump"),
+        step(5, "Goal", 1.0, "jump"),
+    ];
+
+    summary
+        .attach_heat(&ship, &loadout, &config)
+        .expect("attach heat");
+
+    let projection = |idx: usize| summary.steps[idx].heat.clone().expect("heat attached");
+    assert!(projection(2).wait_time_seconds.is_none());
+    assert!(
+        projection(4).wait_time_seconds.is_none(),
+        "from ambient alone the short hop needs no pause"
+    );
+
+    let stops = summary.cooling_stops.clone();
+    assert_eq!(
+        stops,
+        evefrontier_lib::plan_cooling_stops(&summary, &ship, &loadout, &config).unwrap()
+    );
+    assert_eq!(
+        stops.iter().map(|s| s.step_index).collect::<Vec<_>>(),
+        vec![1, 4],
+        "{stops:?}"
+    );
+
+    // The first stop departs from ambient, exactly as attach_heat projected it.
+    let first = projection(1);
+    assert!((stops[0].peak_heat - (5.0 + first.hop_heat)).abs() < 1e-9);
+    assert!((stops[0].wait_seconds - first.wait_time_seconds.unwrap()).abs() < 1e-9);
+    assert_eq!(stops[0].residual_heat, HEAT_NOMINAL);
+
+    // The second stop exists only because of the heat carried out of W and through the gate.
+    let expected_peak = HEAT_NOMINAL + projection(2).hop_heat + projection(4).hop_heat;
+    assert!(
+        (stops[1].peak_heat - expected_peak).abs() < 1e-9,
+        "{stops:?}"
+    );
+    assert!(stops[1].peak_heat > evefrontier_lib::ship::HEAT_OVERHEATED);
+    assert!(stops[1].peak_heat < evefrontier_lib::ship::HEAT_CRITICAL);
+    assert!(stops[1].wait_seconds > evefrontier_lib::ship::COOLDOWN_STOP_THRESHOLD_SECONDS);
+    assert_eq!(stops[1].residual_heat, HEAT_NOMINAL);
+}
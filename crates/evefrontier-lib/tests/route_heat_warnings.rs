@@ -39,6 +39,8 @@ fn make_reflex_route_summary() -> RouteSummary {
         jumps: 3,
         total_distance: 18.95 + 38.26 + 23.09,
         jump_distance: 18.95 + 38.26 + 23.09,
+        average_jump_distance: None,
+        max_jump_distance: None,
         start: RouteEndpoint {
             id: 30000001,
             name: Some("Nod".to_string()),
@@ -49,9 +51,12 @@ fn make_reflex_route_summary() -> RouteSummary {
         },
         steps,
         fuel: None,
+        refuel_at: Vec::new(),
         heat: None,
+        cooling_stops: Vec::new(),
         fmap_url: None,
         parameters: None,
+        effective_constraints: None,
     }
 }
 
@@ -111,3 +116,79 @@ fn heat_warning_and_error_thresholds() {
         total2.warnings
     );
 }
+
+#[test]
+fn cooldown_stops_match_overheated_hops() {
+    let ship = common::reflex_ship();
+    let loadout = evefrontier_lib::ShipLoadout::new(&ship, ship.fuel_capacity, 0.0).unwrap();
+    let config = evefrontier_lib::HeatConfig::default();
+
+    // Reflex heats by ~3.3 K/ly from a 0 K start: 30 ly hops overheat (~100 K) and must
+    // cool before the next jump, 5 ly hops stay below nominal. The final hop is short so
+    // arrival cooldown (never required) does not skew the comparison.
+    let mut summary = make_reflex_route_summary();
+    for (step, distance) in summary.steps.iter_mut().skip(1).zip([30.0, 5.0, 5.0]) {
+        step.distance = Some(distance);
+        step.min_external_temp = Some(0.0);
+    }
+    summary.steps.push(
+        RouteStepBuilder::new()
+            .index(4)
+            .id(30000005)
+            .name("Extra")
+            .distance(30.0)
+            .min_temp(0.0)
+            .build(),
+    );
+    summary.steps.push(
+        RouteStepBuilder::new()
+            .index(5)
+            .id(30000006)
+            .name("Goal")
+            .distance(5.0)
+            .min_temp(0.0)
+            .build(),
+    );
+    summary.steps[0].min_external_temp = Some(0.0);
+
+    summary
+        .attach_heat(&ship, &loadout, &config)
+        .expect("attach heat");
+
+    let overheated = summary
+        .steps
+        .iter()
+        .filter(|s| {
+            s.heat
+                .as_ref()
+                .and_then(|h| h.warning.as_deref())
+                .is_some_and(|w| w == "OVERHEATED")
+        })
+        .count();
+    let heat = summary.heat.as_ref().expect("summary heat");
+    assert_eq!(overheated, 2);
+    assert_eq!(heat.cooldown_stops, overheated);
+    assert!(heat.total_wait_time_seconds > 0.0);
+}
+
+#[test]
+fn cool_route_has_no_cooldown_stops() {
+    let ship = common::reflex_ship();
+    let loadout = evefrontier_lib::ShipLoadout::new(&ship, ship.fuel_capacity, 0.0).unwrap();
+    let mut summary = make_reflex_route_summary();
+    for step in summary.steps.iter_mut() {
+        step.min_external_temp = Some(0.0);
+        if step.distance.is_some_and(|d| d > 0.0) {
+            step.distance = Some(5.0);
+        }
+    }
+
+    summary
+        .attach_heat(&ship, &loadout, &evefrontier_lib::HeatConfig::default())
+        .expect("attach heat");
+
+    assert_eq!(
+        summary.heat.as_ref().expect("summary heat").cooldown_stops,
+        0
+    );
+}
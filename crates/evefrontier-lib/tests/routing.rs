@@ -18,6 +18,39 @@ fn bfs_route_plan_succeeds() {
     assert!(plan.hop_count() >= 1);
 }
 
+#[test]
+fn numeric_id_endpoints_resolve_like_names() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let nod = starmap.system_id_by_name("Nod").unwrap();
+    let brana = starmap.system_id_by_name("Brana").unwrap();
+
+    let by_name = plan_route(&starmap, &RouteRequest::bfs("Nod", "Brana")).expect("route exists");
+    let by_id = plan_route(
+        &starmap,
+        &RouteRequest::bfs(nod.to_string(), brana.to_string()),
+    )
+    .expect("route exists");
+    let mixed =
+        plan_route(&starmap, &RouteRequest::bfs(nod.to_string(), "Brana")).expect("route exists");
+
+    assert_eq!(by_id.start, nod);
+    assert_eq!(by_id.goal, brana);
+    assert_eq!(by_id.steps, by_name.steps);
+    assert_eq!(mixed.steps, by_name.steps);
+}
+
+#[test]
+fn unknown_numeric_id_is_unknown_system() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let err = plan_route(&starmap, &RouteRequest::bfs("Nod", "99999999"))
+        .expect_err("missing ID rejected");
+
+    assert!(matches!(
+        err,
+        evefrontier_lib::Error::UnknownSystem { ref name, .. } if name == "99999999"
+    ));
+}
+
 #[test]
 fn dijkstra_route_plan_succeeds() {
     let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
@@ -90,6 +123,46 @@ fn avoided_goal_rejects_route() {
     assert!(format!("{error}").contains("no route found"));
 }
 
+#[test]
+fn avoided_region_expands_to_member_systems() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let outlier = starmap.system_id_by_name("E1J-M5G").unwrap();
+
+    // 451-Y-F1 holds only E1J-M5G; names match case-insensitively and IDs work too.
+    for region in ["451-Y-F1", "451-y-f1", "10000243"] {
+        let mut request = RouteRequest::bfs("Nod", "E1J-M5G");
+        request.constraints.avoid_regions = vec![region.to_string()];
+        let error = plan_route(&starmap, &request).expect_err("goal region avoided");
+        assert!(
+            matches!(error, evefrontier_lib::Error::RouteNotFound { .. }),
+            "{region}: {error}"
+        );
+    }
+
+    let mut request = RouteRequest::bfs("Nod", "Brana");
+    request.constraints.avoid_regions = vec!["451-Y-F1".to_string()];
+    let plan = plan_route(&starmap, &request).expect("route outside the region");
+    assert!(!plan.steps.contains(&outlier));
+
+    // Avoiding the region holding both endpoints leaves no route.
+    request.constraints.avoid_regions = vec!["948T:C".to_string()];
+    assert!(plan_route(&starmap, &request).is_err());
+}
+
+#[test]
+fn unknown_avoided_region_is_named_in_error() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let mut request = RouteRequest::bfs("Nod", "Brana");
+    request.constraints.avoid_regions = vec!["Nowhere".to_string()];
+
+    let error = plan_route(&starmap, &request).expect_err("unknown region rejected");
+    assert!(matches!(
+        error,
+        evefrontier_lib::Error::UnknownRegion { ref name } if name == "Nowhere"
+    ));
+    assert_eq!(error.kind(), evefrontier_lib::ErrorKind::InvalidRequest);
+}
+
 #[test]
 fn temperature_limit_blocks_hot_systems() {
     let mut starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
@@ -223,6 +296,7 @@ fn ship_max_jump_limits_spatial_edges() {
         systems,
         name_to_id,
         adjacency: std::sync::Arc::new(adjacency),
+        gate_links: Default::default(),
     };
 
     // Ship with insufficient fuel to make the 100 ly hop
@@ -264,3 +338,318 @@ fn ship_max_jump_limits_spatial_edges() {
 }
 
 // inject_positions is no longer needed - real fixture data includes coordinates
+
+#[test]
+fn round_trip_plan_returns_to_start() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let mut request = RouteRequest::bfs("Nod", "Brana");
+    let one_way = plan_route(&starmap, &request).expect("route exists");
+
+    request.constraints.round_trip = true;
+    let plan = plan_route(&starmap, &request).expect("round trip exists");
+
+    assert_eq!(plan.steps.first(), plan.steps.last());
+    assert_eq!(plan.steps.first(), Some(&plan.start));
+    assert_eq!(plan.goal, one_way.goal);
+    assert!(plan.steps.contains(&plan.goal));
+    assert_eq!(&plan.steps[..one_way.steps.len()], one_way.steps.as_slice());
+    assert_eq!(plan.hop_count(), plan.gates + plan.jumps);
+    assert!(plan.hop_count() >= 2 * one_way.hop_count());
+}
+
+#[test]
+fn round_trip_return_leg_honours_avoided_systems() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let mut request = RouteRequest::bfs("Nod", "Brana");
+    request.constraints.avoid_systems = vec!["J:35IA".to_string()];
+    request.constraints.round_trip = true;
+
+    let plan = plan_route(&starmap, &request).expect("round trip exists");
+    let avoided = starmap.system_id_by_name("J:35IA").unwrap();
+
+    assert_eq!(plan.steps.first(), plan.steps.last());
+    assert!(!plan.steps.contains(&avoided));
+}
+
+#[test]
+fn max_hops_bounds_planned_route_length() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let mut request = RouteRequest::bfs("Nod", "Brana");
+    let unbounded = plan_route(&starmap, &request).expect("route exists");
+    let fewest_hops = unbounded.hop_count();
+
+    request.constraints.max_hops = Some(fewest_hops);
+    let bounded = plan_route(&starmap, &request).expect("route fits the budget");
+    assert!(bounded.hop_count() <= fewest_hops);
+
+    request.constraints.max_hops = Some(fewest_hops - 1);
+    let err = plan_route(&starmap, &request).expect_err("no route within budget");
+    assert!(matches!(err, evefrontier_lib::Error::RouteNotFound { .. }));
+}
+
+fn spatial_request(goal: &str, avoid_temperature_range: (f64, f64)) -> RouteRequest {
+    RouteRequest {
+        start: "Nod".to_string(),
+        goal: goal.to_string(),
+        algorithm: RouteAlgorithm::Dijkstra,
+        constraints: RouteConstraints {
+            avoid_gates: true,
+            avoid_temperature_range: Some(avoid_temperature_range),
+            ..RouteConstraints::default()
+        },
+        spatial_index: None,
+        max_spatial_neighbors: evefrontier_lib::GraphBuildOptions::default().max_spatial_neighbors,
+        optimization: evefrontier_lib::routing::RouteOptimization::Distance,
+        fuel_config: evefrontier_lib::ship::FuelConfig::default(),
+    }
+}
+
+#[test]
+fn avoid_temperature_range_excludes_band_but_keeps_cold_and_hot_systems() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    // Fixture star temperatures: D:2NAS ~1873 K, Y:3R7E ~4365 K, H:2L2S ~5581 K.
+    let band = (4_000.0, 5_000.0);
+
+    let cold = plan_route(&starmap, &spatial_request("D:2NAS", band)).expect("cold system kept");
+    assert_eq!(cold.goal, starmap.system_id_by_name("D:2NAS").unwrap());
+
+    let hot = plan_route(&starmap, &spatial_request("H:2L2S", band)).expect("hot system kept");
+    let banded = starmap.system_id_by_name("Y:3R7E").unwrap();
+    assert!(!hot.steps.contains(&banded));
+
+    let err = plan_route(&starmap, &spatial_request("Y:3R7E", band))
+        .expect_err("system inside the band is excluded");
+    assert!(matches!(err, evefrontier_lib::Error::RouteNotFound { .. }));
+}
+
+#[test]
+fn avoid_temperature_range_rejects_reversed_bounds() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let err = plan_route(&starmap, &spatial_request("Brana", (5_000.0, 4_000.0)))
+        .expect_err("reversed range");
+    assert!(matches!(
+        err,
+        evefrontier_lib::Error::InvalidTemperatureRange { .. }
+    ));
+}
+
+#[test]
+fn debug_graph_counts_goal_in_edges() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let (result, debug) =
+        evefrontier_lib::plan_route_with_debug(&starmap, &RouteRequest::bfs("Nod", "Brana"));
+    result.expect("route exists");
+
+    let debug = debug.expect("graph was built");
+    assert_eq!(debug.spatial_edges, 0);
+    assert!(debug.gate_edges > 0);
+    // Brana is gated to G:3OA0 and Y:3R7E.
+    assert_eq!(debug.goal_in_edges, 2);
+}
+
+#[test]
+fn debug_graph_shows_goal_disconnected_by_temperature_limit() {
+    let mut starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let brana_id = starmap.system_id_by_name("Brana").unwrap();
+    starmap
+        .systems
+        .get_mut(&brana_id)
+        .unwrap()
+        .metadata
+        .star_temperature = Some(5_000.0);
+
+    let request = RouteRequest {
+        start: "Nod".to_string(),
+        goal: "Brana".to_string(),
+        algorithm: RouteAlgorithm::Dijkstra,
+        constraints: RouteConstraints {
+            avoid_gates: true,
+            max_temperature: Some(4_000.0),
+            ..RouteConstraints::default()
+        },
+        spatial_index: None,
+        max_spatial_neighbors: evefrontier_lib::GraphBuildOptions::default().max_spatial_neighbors,
+        optimization: evefrontier_lib::routing::RouteOptimization::Distance,
+        fuel_config: evefrontier_lib::ship::FuelConfig::default(),
+    };
+
+    let (result, debug) = evefrontier_lib::plan_route_with_debug(&starmap, &request);
+    assert!(matches!(
+        result,
+        Err(evefrontier_lib::Error::RouteNotFound { .. })
+    ));
+
+    let debug = debug.expect("graph was built");
+    assert_eq!(debug.gate_edges, 0);
+    assert!(debug.spatial_edges > 0);
+    assert_eq!(debug.goal_in_edges, 0);
+}
+
+#[test]
+fn debug_graph_is_absent_for_unknown_systems() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let (result, debug) =
+        evefrontier_lib::plan_route_with_debug(&starmap, &RouteRequest::bfs("Nodd", "Brana"));
+    assert!(result.is_err());
+    assert!(debug.is_none());
+}
+
+fn hybrid_request(jump_penalty: f64) -> RouteRequest {
+    RouteRequest {
+        start: "Nod".to_string(),
+        goal: "Brana".to_string(),
+        algorithm: RouteAlgorithm::Dijkstra,
+        constraints: RouteConstraints {
+            jump_penalty,
+            ..RouteConstraints::default()
+        },
+        spatial_index: None,
+        max_spatial_neighbors: evefrontier_lib::GraphBuildOptions::default().max_spatial_neighbors,
+        optimization: evefrontier_lib::routing::RouteOptimization::Distance,
+        fuel_config: evefrontier_lib::ship::FuelConfig::default(),
+    }
+}
+
+#[test]
+fn jump_penalty_flips_hybrid_route_to_gates() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+
+    // Unpenalised, the direct ~297 ly jump beats the ~615 ly gate chain.
+    let direct = plan_route(&starmap, &hybrid_request(1.0)).expect("route exists");
+    assert_eq!(direct.jumps, 1);
+    assert_eq!(direct.gates, 0);
+
+    let gated = plan_route(&starmap, &hybrid_request(10.0)).expect("route exists");
+    let names: Vec<&str> = gated
+        .steps
+        .iter()
+        .map(|id| starmap.system_name(*id).expect("system name"))
+        .collect();
+    assert_eq!(names, ["Nod", "H:2L2S", "Y:3R7E", "Brana"]);
+    assert_eq!(gated.gates, 3);
+    assert_eq!(gated.jumps, 0);
+}
+
+#[test]
+fn explain_route_covers_every_hop_of_a_gate_route() {
+    use evefrontier_lib::explain_route;
+
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let request = RouteRequest::bfs("Nod", "Brana");
+    let plan = plan_route(&starmap, &request).expect("route exists");
+    let rationale = explain_route(&starmap, &request, &plan).expect("rationale builds");
+
+    assert_eq!(rationale.len(), plan.hop_count());
+    assert_eq!(rationale[0].from, "Nod");
+    assert_eq!(rationale.last().unwrap().to, "Brana");
+    for (index, hop) in rationale.iter().enumerate() {
+        assert_eq!(hop.hop, index);
+        assert_eq!(hop.method, "gate");
+        assert_eq!(hop.constraint, None);
+        assert!(hop.distance.is_some());
+        assert!(hop.reason.contains("gate network"), "{}", hop.reason);
+    }
+}
+
+#[test]
+fn explain_route_names_max_jump_when_it_forces_gates() {
+    use evefrontier_lib::{explain_route, HopConstraint};
+
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let mut request = hybrid_request(1.0);
+    request.constraints.max_jump = Some(1.0);
+    request.constraints.avoid_critical_state = false;
+    let plan = plan_route(&starmap, &request).expect("route exists");
+    assert_eq!(plan.jumps, 0);
+
+    let rationale = explain_route(&starmap, &request, &plan).expect("rationale builds");
+    assert_eq!(rationale.len(), plan.hop_count());
+    for hop in &rationale {
+        assert_eq!(hop.method, "gate");
+        assert_eq!(hop.constraint, Some(HopConstraint::MaxJump));
+        assert!(hop.reason.contains("max_jump"), "{}", hop.reason);
+    }
+}
+
+#[test]
+fn explain_route_names_avoid_gates_on_gate_connected_jumps() {
+    use evefrontier_lib::{explain_route, HopConstraint};
+
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let mut request = hybrid_request(1.0);
+    request.goal = "H:2L2S".to_string();
+    request.constraints.avoid_gates = true;
+    request.constraints.avoid_critical_state = false;
+    let plan = plan_route(&starmap, &request).expect("spatial route exists");
+
+    let rationale = explain_route(&starmap, &request, &plan).expect("rationale builds");
+    assert_eq!(rationale.len(), 1);
+    assert_eq!(rationale[0].method, "jump");
+    assert_eq!(rationale[0].constraint, Some(HopConstraint::AvoidGates));
+}
+
+#[test]
+fn time_optimization_prefers_gate_chain_over_long_jump() {
+    use evefrontier_lib::{routing::RouteOptimization, TravelTimeConfig};
+
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let distance = plan_route(&starmap, &hybrid_request(1.0)).expect("route exists");
+
+    // One 60 s spool-up costs more than the three 10 s gate activations around it.
+    let mut request = hybrid_request(1.0);
+    request.optimization = RouteOptimization::Time(TravelTimeConfig::default());
+    let timed = plan_route(&starmap, &request).expect("route exists");
+
+    assert_ne!(timed.steps, distance.steps);
+    assert_eq!(timed.jumps, 0);
+    assert!(
+        timed.gates <= 5,
+        "{} gates take longer than one jump",
+        timed.gates
+    );
+
+    // With gates slower than a jump, the direct jump is fastest again.
+    request.optimization = RouteOptimization::Time(TravelTimeConfig {
+        gate_activation_secs: 120.0,
+        jump_spool_secs: 60.0,
+        ..TravelTimeConfig::default()
+    });
+    let slow_gates = plan_route(&starmap, &request).expect("route exists");
+    assert_eq!(slow_gates.steps, distance.steps);
+}
+
+#[test]
+fn non_positive_edge_penalty_is_rejected() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let err = plan_route(&starmap, &hybrid_request(0.0)).expect_err("zero penalty rejected");
+
+    assert!(matches!(
+        err,
+        evefrontier_lib::Error::InvalidEdgePenalty { ref kind, value } if kind == "jump" && value == 0.0
+    ));
+}
+
+#[test]
+fn a_star_with_landmarks_matches_plain_a_star_on_gate_graph() {
+    use std::sync::Arc;
+
+    use evefrontier_lib::{
+        build_gate_graph, AStarPlanner, LandmarkData, PathConstraints, RoutePlanner,
+    };
+
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let graph = build_gate_graph(&starmap);
+    let landmarks = Arc::new(LandmarkData::build(&starmap, 4));
+    assert_eq!(landmarks.len(), 4.min(starmap.systems.len()));
+
+    let constraints = PathConstraints::default();
+    let nod = starmap.system_id_by_name("Nod").unwrap();
+    for goal in starmap.systems.keys().copied() {
+        let plain =
+            AStarPlanner::distance().find_path(&graph, Some(&starmap), nod, goal, &constraints);
+        let alt = AStarPlanner::distance()
+            .with_landmarks(Arc::clone(&landmarks))
+            .find_path(&graph, Some(&starmap), nod, goal, &constraints);
+        assert_eq!(alt, plain, "route to {goal} differs with landmarks");
+    }
+}
@@ -107,3 +107,85 @@ fn a_star_allows_when_not_avoiding_critical_state() {
 fn fixture_path() -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../docs/fixtures/minimal/static_data.db")
 }
+
+fn reflex_request(max_jump: f64, avoid_critical_state: bool) -> RouteRequest {
+    let ship_path =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../docs/fixtures/ship_data.csv");
+    let catalog = evefrontier_lib::ShipCatalog::from_path(&ship_path).expect("load ship csv");
+    let ship = catalog.get("Reflex").expect("Reflex available").clone();
+    let loadout = evefrontier_lib::ShipLoadout::new(&ship, ship.fuel_capacity, 0.0).unwrap();
+
+    RouteRequest {
+        start: "Nod".to_string(),
+        goal: "Brana".to_string(),
+        algorithm: RouteAlgorithm::Dijkstra,
+        constraints: RouteConstraints {
+            max_jump: Some(max_jump),
+            avoid_critical_state,
+            ship: Some(ship),
+            loadout: Some(loadout),
+            heat_config: Some(evefrontier_lib::ship::HeatConfig::default()),
+            ..Default::default()
+        },
+        spatial_index: None,
+        max_spatial_neighbors: evefrontier_lib::GraphBuildOptions::default().max_spatial_neighbors,
+        optimization: evefrontier_lib::routing::RouteOptimization::Distance,
+        fuel_config: evefrontier_lib::ship::FuelConfig::default(),
+    }
+}
+
+#[test]
+fn heat_clamped_max_jump_is_reported_as_effective_constraint() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let request = reflex_request(500.0, true);
+
+    let plan = plan_route(&starmap, &request).expect("gate route exists");
+    let effective = plan.effective_max_jump.expect("heat limit applied");
+    assert!(effective < 500.0, "effective max_jump {effective}");
+
+    let summary = evefrontier_lib::RouteSummary::from_plan(
+        evefrontier_lib::RouteOutputKind::Route,
+        &starmap,
+        &plan,
+        Some(&request),
+    )
+    .expect("summary");
+    let requested = summary.parameters.as_ref().expect("parameters");
+    assert_eq!(requested.max_jump, Some(500.0));
+    let applied = summary
+        .effective_constraints
+        .as_ref()
+        .expect("effective constraints");
+    assert_eq!(applied.max_jump, Some(effective));
+    assert_eq!(applied.adjusted, ["max_jump"]);
+
+    let json = serde_json::to_value(&summary).expect("serialize");
+    assert_eq!(json["parameters"]["max_jump"], 500.0);
+    assert_eq!(json["effective_constraints"]["max_jump"], effective);
+    assert_eq!(json["effective_constraints"]["adjusted"][0], "max_jump");
+}
+
+#[test]
+fn unclamped_request_reports_no_adjustments() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let request = reflex_request(500.0, false);
+
+    let plan = plan_route(&starmap, &request).expect("route exists");
+    assert_eq!(plan.effective_max_jump, Some(500.0));
+
+    let summary = evefrontier_lib::RouteSummary::from_plan(
+        evefrontier_lib::RouteOutputKind::Route,
+        &starmap,
+        &plan,
+        Some(&request),
+    )
+    .expect("summary");
+    let applied = summary
+        .effective_constraints
+        .expect("effective constraints");
+    assert!(applied.adjusted.is_empty());
+
+    let json = serde_json::to_value(&applied).expect("serialize");
+    assert_eq!(json["max_jump"], 500.0);
+    assert!(json.get("adjusted").is_none(), "{json}");
+}
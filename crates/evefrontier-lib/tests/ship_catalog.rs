@@ -46,3 +46,61 @@ fn rejects_invalid_numeric_values() {
         other => panic!("unexpected error: {:?}", other),
     }
 }
+
+#[test]
+fn json_ships_merge_over_csv_catalog() {
+    let json = r#"[
+        {"name": "reflex", "base_mass_kg": 12000000, "specific_heat": 0.9,
+         "fuel_capacity": 3000, "cargo_capacity": 800000},
+        {"name": "Prototype", "base_mass_kg": 5000000.5, "specific_heat": 1.2,
+         "fuel_capacity": 900, "cargo_capacity": 100000}
+    ]"#;
+    let custom = ShipCatalog::from_json_reader(json.as_bytes()).expect("valid ship JSON");
+
+    let mut catalog = ShipCatalog::from_path(&fixture_path()).expect("fixture should load");
+    catalog.merge(custom);
+
+    assert_eq!(
+        catalog.ship_names(),
+        vec!["Forager", "Prototype", "Warden", "reflex"]
+    );
+    let reflex = catalog.get("Reflex").expect("overridden ship present");
+    assert_eq!(reflex.fuel_capacity, 3000.0);
+    assert_eq!(reflex.base_mass_kg, 12_000_000.0);
+    assert_eq!(catalog.get("prototype").unwrap().base_mass_kg, 5_000_000.5);
+    assert_eq!(catalog.source_path(), Some(fixture_path().as_path()));
+}
+
+#[test]
+fn json_rejects_missing_or_invalid_numeric_fields_with_ship_name() {
+    let cases = [
+        (
+            r#"[{"name": "Prototype", "base_mass_kg": 1, "specific_heat": 1, "fuel_capacity": 1}]"#,
+            "cargo_capacity",
+        ),
+        (
+            r#"[{"name": "Prototype", "base_mass_kg": "heavy", "specific_heat": 1,
+                 "fuel_capacity": 1, "cargo_capacity": 1}]"#,
+            "base_mass_kg",
+        ),
+        (
+            r#"[{"name": "Prototype", "base_mass_kg": 1, "specific_heat": 0,
+                 "fuel_capacity": 1, "cargo_capacity": 1}]"#,
+            "specific_heat",
+        ),
+    ];
+
+    for (json, field) in cases {
+        let err = ShipCatalog::from_json_reader(json.as_bytes()).expect_err("should reject");
+        match err {
+            Error::ShipDataValidation { message } => {
+                assert!(message.contains("'Prototype'"), "{message}");
+                assert!(message.contains(field), "{message}");
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    assert!(ShipCatalog::from_json_reader(r#"{"name": "Prototype"}"#.as_bytes()).is_err());
+    assert!(ShipCatalog::from_json_reader(r#"[{"base_mass_kg": 1}]"#.as_bytes()).is_err());
+}
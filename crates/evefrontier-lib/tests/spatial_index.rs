@@ -7,9 +7,13 @@
 //! - Radius queries
 //! - Temperature-filtered queries
 //! - Checksum validation
+//! - Order-independent content hashing
+//! - In-memory byte serialization
+//! - Axis-aligned bounding-box queries
 
 use std::path::PathBuf;
 
+use evefrontier_lib::spatial::DatasetMetadata;
 use evefrontier_lib::{load_starmap, NeighbourQuery, SpatialIndex};
 
 fn fixture_path() -> PathBuf {
@@ -305,3 +309,373 @@ fn excessive_k_allocation_is_capped() {
         "Should not return more systems than exist"
     );
 }
+
+#[test]
+fn try_nearest_filtered_errors_on_empty_index() {
+    let index = SpatialIndex::build(&evefrontier_lib::Starmap::default());
+    let query = NeighbourQuery::nearest(5);
+
+    // The infallible variant cannot distinguish this from "no matches".
+    assert!(index.nearest_filtered([0.0, 0.0, 0.0], &query).is_empty());
+
+    let err = index
+        .try_nearest_filtered([0.0, 0.0, 0.0], &query)
+        .expect_err("empty index should be reported");
+    assert!(matches!(err, evefrontier_lib::Error::SpatialIndexEmpty));
+}
+
+#[test]
+fn try_nearest_filtered_returns_ok_when_nothing_matches() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let index = SpatialIndex::build(&starmap);
+    assert!(!index.is_empty());
+
+    // Every fixture system is far from the origin, so a tiny radius matches nothing.
+    let query = NeighbourQuery::within_radius(5, 1.0);
+    let results = index
+        .try_nearest_filtered([0.0, 0.0, 0.0], &query)
+        .expect("populated index should not error");
+    assert!(results.is_empty());
+
+    // k == 0 is a legitimate (if pointless) query rather than a misconfigured index.
+    let results = index
+        .try_nearest_filtered([0.0, 0.0, 0.0], &NeighbourQuery::nearest(0))
+        .expect("k == 0 should not error");
+    assert!(results.is_empty());
+    assert!(index
+        .nearest_filtered([0.0, 0.0, 0.0], &NeighbourQuery::nearest(0))
+        .is_empty());
+}
+
+#[test]
+fn content_hash_is_stable_across_builds() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let reloaded = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let first = SpatialIndex::build(&starmap);
+    let second = SpatialIndex::build(&reloaded);
+
+    assert_eq!(first.content_hash(), second.content_hash());
+    assert_eq!(first, second);
+}
+
+#[test]
+fn content_hash_survives_save_and_load() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let original = SpatialIndex::build(&starmap);
+
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    let index_path = temp_dir.path().join("test_index.bin");
+    original.save(&index_path).expect("save succeeds");
+    let loaded = SpatialIndex::load(&index_path).expect("load succeeds");
+
+    assert_eq!(original.content_hash(), loaded.content_hash());
+}
+
+#[test]
+fn content_hash_changes_with_starmap() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let original = SpatialIndex::build(&starmap);
+
+    let mut modified = starmap.clone();
+    let nod_id = modified.system_id_by_name("Nod").expect("Nod exists");
+    let nod = modified.systems.get_mut(&nod_id).expect("Nod system");
+    let position = nod.position.as_mut().expect("Nod has a position");
+    position.x += 1.0;
+    let changed = SpatialIndex::build(&modified);
+
+    assert_ne!(original.content_hash(), changed.content_hash());
+    assert_ne!(original, changed);
+}
+
+#[test]
+fn builds_are_byte_reproducible() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    let first_path = temp_dir.path().join("first.bin");
+    let second_path = temp_dir.path().join("second.bin");
+
+    SpatialIndex::build(&starmap)
+        .save(&first_path)
+        .expect("save succeeds");
+    // A second load gets a freshly seeded HashMap with its own iteration order.
+    let reloaded = load_starmap(&fixture_path(), None).expect("fixture loads");
+    SpatialIndex::build(&reloaded)
+        .save(&second_path)
+        .expect("save succeeds");
+
+    let first = std::fs::read(&first_path).expect("read first index");
+    let second = std::fs::read(&second_path).expect("read second index");
+    assert_eq!(first, second);
+}
+
+#[test]
+fn batch_query_matches_single_queries_in_order() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let index = SpatialIndex::build(&starmap);
+
+    let mut ids: Vec<_> = starmap.systems.keys().copied().collect();
+    ids.sort();
+    let points: Vec<[f64; 3]> = ids
+        .iter()
+        .filter_map(|id| index.position(*id))
+        .map(|p| [p[0] as f64, p[1] as f64, p[2] as f64])
+        .collect();
+    assert!(!points.is_empty());
+
+    let queries = [
+        NeighbourQuery::nearest(3),
+        NeighbourQuery::within_radius(5, 50.0),
+        NeighbourQuery::with_temperature(5, 20.0),
+    ];
+    for query in &queries {
+        let batch = index.nearest_filtered_batch(&points, query);
+        let single: Vec<_> = points
+            .iter()
+            .map(|&point| index.nearest_filtered(point, query))
+            .collect();
+        assert_eq!(batch, single, "query {query:?}");
+    }
+
+    assert!(index
+        .nearest_filtered_batch(&[], &NeighbourQuery::nearest(3))
+        .is_empty());
+}
+
+#[test]
+fn mmap_load_matches_buffered_load() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let index = SpatialIndex::build(&starmap);
+
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    let index_path = temp_dir.path().join("test_index.bin");
+    index.save(&index_path).expect("save succeeds");
+
+    let buffered = SpatialIndex::load(&index_path).expect("load succeeds");
+    let mapped = SpatialIndex::load_mmap(&index_path).expect("load_mmap succeeds");
+    assert_eq!(buffered.content_hash(), mapped.content_hash());
+    assert_eq!(buffered.source_metadata(), mapped.source_metadata());
+
+    let mut ids: Vec<_> = starmap.systems.keys().copied().collect();
+    ids.sort();
+    let queries = [
+        NeighbourQuery::nearest(3),
+        NeighbourQuery::within_radius(5, 50.0),
+        NeighbourQuery::with_temperature(5, 20.0),
+    ];
+    for id in ids {
+        assert_eq!(buffered.position(id), mapped.position(id));
+        assert_eq!(buffered.temperature(id), mapped.temperature(id));
+        let Some(p) = buffered.position(id) else {
+            continue;
+        };
+        let point = [p[0] as f64, p[1] as f64, p[2] as f64];
+        for query in &queries {
+            assert_eq!(
+                buffered.nearest_filtered(point, query),
+                mapped.nearest_filtered(point, query),
+                "query {query:?} from system {id}"
+            );
+        }
+    }
+}
+
+#[test]
+fn mmap_load_rejects_corrupted_checksum() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let index = SpatialIndex::build(&starmap);
+
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    let index_path = temp_dir.path().join("corrupted_index.bin");
+    index.save(&index_path).expect("save succeeds");
+
+    let mut data = std::fs::read(&index_path).expect("read file");
+    let last = data.len() - 1;
+    data[last] ^= 0xFF;
+    std::fs::write(&index_path, &data).expect("write corrupted");
+
+    let err = SpatialIndex::load_mmap(&index_path).expect_err("corrupted file should fail");
+    assert!(err.to_string().contains("checksum"), "{err}");
+}
+
+#[test]
+fn bytes_round_trip_preserves_nodes() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let original = SpatialIndex::build(&starmap);
+
+    let bytes = original.to_bytes().expect("to_bytes succeeds");
+    let restored = SpatialIndex::from_bytes(&bytes).expect("from_bytes succeeds");
+
+    assert_eq!(original.len(), restored.len());
+    assert_eq!(original.content_hash(), restored.content_hash());
+    assert_eq!(restored.source_metadata(), None);
+    for &id in starmap.systems.keys() {
+        assert_eq!(original.position(id), restored.position(id), "system {id}");
+        assert_eq!(
+            original.temperature(id),
+            restored.temperature(id),
+            "system {id}"
+        );
+    }
+
+    // The in-memory format is exactly what `save` writes.
+    let temp_dir = tempfile::tempdir().expect("temp dir");
+    let index_path = temp_dir.path().join("test_index.bin");
+    original.save(&index_path).expect("save succeeds");
+    assert_eq!(std::fs::read(&index_path).expect("read file"), bytes);
+}
+
+#[test]
+fn bytes_round_trip_preserves_metadata() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let metadata = DatasetMetadata {
+        checksum: [7u8; 32],
+        release_tag: Some("e6c3".to_string()),
+        build_timestamp: 1_700_000_000,
+    };
+    let original = SpatialIndex::build_with_metadata(&starmap, metadata.clone());
+
+    let bytes = original.to_bytes().expect("to_bytes succeeds");
+    let restored = SpatialIndex::from_bytes(&bytes).expect("from_bytes succeeds");
+
+    assert_eq!(restored.source_metadata(), Some(&metadata));
+    assert_eq!(original.len(), restored.len());
+    assert_eq!(original.content_hash(), restored.content_hash());
+}
+
+#[test]
+fn from_bytes_rejects_corrupted_data() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let mut bytes = SpatialIndex::build(&starmap)
+        .to_bytes()
+        .expect("to_bytes succeeds");
+    let last = bytes.len() - 1;
+    bytes[last] ^= 0xFF;
+
+    let err = SpatialIndex::from_bytes(&bytes).expect_err("corrupted data should fail");
+    assert!(err.to_string().contains("checksum"), "{err}");
+    assert!(SpatialIndex::from_bytes(b"EFSI").is_err());
+}
+
+#[test]
+fn bbox_query_excludes_systems_outside_box() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let index = SpatialIndex::build(&starmap);
+
+    let nod_id = starmap.system_id_by_name("Nod").expect("Nod exists");
+    let brana_id = starmap.system_id_by_name("Brana").expect("Brana exists");
+    let nod = index.position(nod_id).expect("Nod indexed");
+    let brana = index.position(brana_id).expect("Brana indexed");
+
+    // The box spanned by Nod and Brana has them on its corners (bounds are inclusive).
+    let min: [f64; 3] = std::array::from_fn(|axis| nod[axis].min(brana[axis]) as f64);
+    let max: [f64; 3] = std::array::from_fn(|axis| nod[axis].max(brana[axis]) as f64);
+    let results = index.within_bbox(min, max);
+
+    let mut ids: Vec<_> = results.iter().map(|(id, _)| *id).collect();
+    ids.sort();
+    assert_eq!(ids, vec![nod_id, brana_id]);
+
+    for &id in starmap.systems.keys() {
+        let Some(p) = index.position(id) else {
+            continue;
+        };
+        let inside = (0..3).all(|axis| (min[axis]..=max[axis]).contains(&(p[axis] as f64)));
+        assert_eq!(
+            inside,
+            ids.contains(&id),
+            "system {id} inside={inside} but result membership differs"
+        );
+    }
+
+    let distances: Vec<f64> = results.iter().map(|(_, d)| *d).collect();
+    assert!(distances.windows(2).all(|w| w[0] <= w[1]));
+}
+
+#[test]
+fn bbox_query_rejects_degenerate_boxes() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let index = SpatialIndex::build(&starmap);
+
+    let nod_id = starmap.system_id_by_name("Nod").expect("Nod exists");
+    let nod = index.position(nod_id).expect("Nod indexed");
+    let point = nod.map(f64::from);
+
+    // Zero-width box through Nod, inverted box, and a non-finite bound.
+    assert!(index.within_bbox(point, point).is_empty());
+    let min = point.map(|c| c + 1.0);
+    let max = point.map(|c| c - 1.0);
+    assert!(index.within_bbox(min, max).is_empty());
+    let min = point.map(|c| c - 1.0);
+    assert!(index
+        .within_bbox(min, [f64::NAN, point[1] + 1.0, point[2] + 1.0])
+        .is_empty());
+
+    // A valid box around Nod finds it at the center.
+    let results = index.within_bbox(min, point.map(|c| c + 1.0));
+    assert_eq!(results.first().map(|(id, _)| *id), Some(nod_id));
+}
+
+#[test]
+fn equidistant_systems_are_ordered_by_id() {
+    let nod_position = |starmap: &evefrontier_lib::Starmap| {
+        let nod_id = starmap.system_id_by_name("Nod").expect("Nod exists");
+        let p = starmap.systems[&nod_id].position.expect("Nod has position");
+        [p.x, p.y, p.z]
+    };
+    // Move a second fixture system onto Brana so both are exactly as far from Nod.
+    let equidistant_starmap = || {
+        let mut starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+        let nod_id = starmap.system_id_by_name("Nod").expect("Nod exists");
+        let brana_id = starmap.system_id_by_name("Brana").expect("Brana exists");
+        let twin_id = starmap
+            .systems
+            .keys()
+            .copied()
+            .filter(|id| *id != nod_id && *id != brana_id)
+            .max()
+            .expect("fixture has a third system");
+        let brana_position = starmap.systems[&brana_id].position;
+        starmap
+            .systems
+            .get_mut(&twin_id)
+            .expect("twin exists")
+            .position = brana_position;
+        let mut pair = [brana_id, twin_id];
+        pair.sort();
+        (starmap, pair)
+    };
+
+    let (starmap, pair) = equidistant_starmap();
+    let origin = nod_position(&starmap);
+    let index = SpatialIndex::build(&starmap);
+    let nearest = index.nearest_filtered(origin, &NeighbourQuery::nearest(starmap.systems.len()));
+    let within = index.within_radius_filtered(origin, 1e6, None);
+
+    for results in [&nearest, &within] {
+        let positions: Vec<usize> = pair
+            .iter()
+            .map(|id| {
+                results
+                    .iter()
+                    .position(|(r, _)| r == id)
+                    .expect("tied system returned")
+            })
+            .collect();
+        assert_eq!(
+            positions[1],
+            positions[0] + 1,
+            "equidistant systems should be adjacent, lower ID first: {results:?}"
+        );
+    }
+
+    // Reloading reseeds the starmap's HashMaps; the order must not change.
+    for _ in 0..3 {
+        let (reloaded, _) = equidistant_starmap();
+        let index = SpatialIndex::build(&reloaded);
+        assert_eq!(
+            index.nearest_filtered(origin, &NeighbourQuery::nearest(reloaded.systems.len())),
+            nearest
+        );
+        assert_eq!(index.within_radius_filtered(origin, 1e6, None), within);
+    }
+}
@@ -3,7 +3,7 @@
 //! These tests verify:
 //! - DatasetMetadata serialization/deserialization
 //! - FreshnessResult variants and behavior
-//! - compute_dataset_checksum function
+//! - compute_dataset_checksum function (and its progress-reporting variant)
 //! - read_release_tag function
 //! - verify_freshness function with all result variants
 //! - v2 format save/load with embedded metadata
@@ -14,7 +14,9 @@ use std::io::Write;
 use std::path::PathBuf;
 
 use evefrontier_lib::spatial::{
-    compute_dataset_checksum, read_release_tag, verify_freshness, DatasetMetadata, FreshnessResult,
+    compute_dataset_checksum, compute_dataset_checksum_from_bytes,
+    compute_dataset_checksum_with_progress, install_spatial_index, read_release_tag,
+    verify_freshness, DatasetMetadata, FreshnessResult, SpatialIndexSource,
 };
 use evefrontier_lib::{load_starmap, SpatialIndex};
 use tempfile::TempDir;
@@ -197,6 +199,52 @@ fn test_compute_dataset_checksum() {
     assert_eq!(checksum, checksum2, "checksum should be deterministic");
 }
 
+#[test]
+fn test_compute_dataset_checksum_from_bytes_matches_file() {
+    let path = fixture_path();
+    let bytes = fs::read(&path).expect("read fixture");
+
+    assert_eq!(
+        compute_dataset_checksum_from_bytes(&bytes),
+        compute_dataset_checksum(&path).expect("checksum")
+    );
+}
+
+#[test]
+fn test_compute_dataset_checksum_with_progress_reports_bytes_hashed() {
+    let path = fixture_path();
+    let file_size = fs::metadata(&path).expect("fixture metadata").len();
+
+    let mut calls: Vec<(u64, u64)> = Vec::new();
+    let mut record = |hashed: u64, total: u64| calls.push((hashed, total));
+    let checksum =
+        compute_dataset_checksum_with_progress(&path, Some(&mut record)).expect("checksum");
+
+    assert_eq!(checksum, compute_dataset_checksum(&path).expect("checksum"));
+    assert!(
+        calls.len() >= 2,
+        "expected start and chunk reports: {calls:?}"
+    );
+    assert!(calls.iter().all(|&(_, total)| total == file_size));
+    assert!(calls.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+    assert_eq!(calls.last(), Some(&(file_size, file_size)));
+}
+
+#[test]
+fn test_short_checksum_is_hex_prefix() {
+    let mut checksum = [0u8; 32];
+    checksum[0] = 0xab;
+    checksum[7] = 0x01;
+    checksum[8] = 0xff;
+    let metadata = DatasetMetadata {
+        checksum,
+        release_tag: None,
+        build_timestamp: 0,
+    };
+
+    assert_eq!(metadata.short_checksum(), "ab00000000000001");
+}
+
 #[test]
 fn test_read_release_tag_exists() {
     // T013: Verify release tag is read when marker exists
@@ -468,6 +516,28 @@ fn try_load_bytes(dir: &TempDir, data: &[u8]) -> Result<SpatialIndex, evefrontie
     SpatialIndex::load(&path)
 }
 
+#[test]
+fn test_future_version_reports_unsupported_version() {
+    let tmp = TempDir::new().unwrap();
+    let mut data = make_header(9, 0, 0).to_vec();
+    data.extend_from_slice(&[0u8; 64]);
+
+    let loaded = try_load_bytes(&tmp, &data);
+    let from_bytes = SpatialIndex::from_bytes(&data);
+    for result in [loaded, from_bytes] {
+        match result {
+            Err(evefrontier_lib::Error::SpatialIndexUnsupportedVersion {
+                version,
+                max_supported,
+            }) => {
+                assert_eq!(version, 9);
+                assert_eq!(max_supported, 2);
+            }
+            other => panic!("expected unsupported version error, got {other:?}"),
+        }
+    }
+}
+
 #[test]
 fn test_malformed_header_only_no_payload_no_checksum() {
     // A bare 16-byte header with v1 and no metadata flag, but no compressed data or checksum.
@@ -557,3 +627,48 @@ fn test_malformed_file_smaller_than_header() {
         "file shorter than header must fail cleanly"
     );
 }
+
+// =============================================================================
+// Installing a published index alongside a downloaded dataset
+// =============================================================================
+
+#[test]
+fn test_install_spatial_index_uses_matching_published_index() {
+    let fixture = TestFixture::new();
+    fixture.build_v2_index();
+    let published = fixture.db_path.with_extension("published.bin");
+    fs::rename(&fixture.index_path, &published).expect("move published index");
+
+    let source = install_spatial_index(&published, &fixture.db_path).expect("install index");
+
+    assert_eq!(source, SpatialIndexSource::Prebuilt);
+    assert_eq!(
+        fs::read(&published).unwrap(),
+        fs::read(&fixture.index_path).unwrap()
+    );
+}
+
+#[test]
+fn test_install_spatial_index_rebuilds_mismatched_index() {
+    let fixture = TestFixture::new();
+    let starmap = load_starmap(&fixture.db_path, None).expect("load starmap");
+    let stale = SpatialIndex::build_with_metadata(
+        &starmap,
+        DatasetMetadata {
+            checksum: [0u8; 32],
+            release_tag: Some("e6c2".to_string()),
+            build_timestamp: 0,
+        },
+    );
+    let published = fixture.db_path.with_extension("published.bin");
+    stale.save(&published).expect("save stale index");
+
+    let source = install_spatial_index(&published, &fixture.db_path).expect("install index");
+
+    assert_eq!(source, SpatialIndexSource::Rebuilt);
+    let freshness = verify_freshness(&fixture.index_path, &fixture.db_path);
+    assert!(
+        matches!(freshness, FreshnessResult::Fresh { .. }),
+        "rebuilt index should match the dataset, got {freshness:?}"
+    );
+}
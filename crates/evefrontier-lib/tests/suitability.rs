@@ -0,0 +1,90 @@
+//! Integration tests for suitability ranking of spatial neighbour results.
+
+use std::path::PathBuf;
+
+use evefrontier_lib::{
+    load_starmap, rank_by_suitability, NeighbourQuery, SpatialIndex, Starmap, SuitabilityWeights,
+    System, SystemId,
+};
+
+fn fixture_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../docs/fixtures/minimal/static_data.db")
+}
+
+/// Systems within 300 ly of Nod (excluding Nod), nearest first.
+fn neighbours_of_nod(starmap: &Starmap) -> Vec<(SystemId, f64)> {
+    let index = SpatialIndex::build(starmap);
+    let nod = starmap.system_id_by_name("Nod").expect("Nod in fixture");
+    let position = starmap.systems[&nod].position.expect("Nod has coordinates");
+    let query = NeighbourQuery::within_radius(10, 300.0);
+    index
+        .nearest_filtered([position.x, position.y, position.z], &query)
+        .into_iter()
+        .filter(|(id, _)| *id != nod)
+        .collect()
+}
+
+fn names(starmap: &Starmap, ids: impl IntoIterator<Item = SystemId>) -> Vec<String> {
+    ids.into_iter()
+        .map(|id| starmap.system_name(id).unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn default_scorer_ordering_differs_from_distance_ordering() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let neighbours = neighbours_of_nod(&starmap);
+    let by_distance = names(&starmap, neighbours.iter().map(|(id, _)| *id));
+
+    let ranked = rank_by_suitability(&starmap, &neighbours, &SuitabilityWeights::default());
+    let by_score = names(&starmap, ranked.iter().map(|n| n.system_id));
+
+    assert_eq!(ranked.len(), neighbours.len());
+    assert!(
+        ranked.windows(2).all(|pair| pair[0].score >= pair[1].score),
+        "results must be sorted by score: {ranked:?}"
+    );
+    assert_ne!(by_score, by_distance);
+
+    // H:2L2S (5 planets, 17 moons) is farther than G:3OA0 (3 planets, 1 moon) but scores higher.
+    let position = |name: &str| by_score.iter().position(|n| n == name).unwrap();
+    assert!(
+        by_distance.iter().position(|n| n == "G:3OA0")
+            < by_distance.iter().position(|n| n == "H:2L2S")
+    );
+    assert!(position("H:2L2S") < position("G:3OA0"), "{by_score:?}");
+}
+
+#[test]
+fn custom_scorer_closure_is_used() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let neighbours = neighbours_of_nod(&starmap);
+
+    // Prefer the farthest system.
+    let farthest_first = |_: &System, distance_ly: f64| distance_ly;
+    let ranked = rank_by_suitability(&starmap, &neighbours, &farthest_first);
+
+    let expected: Vec<SystemId> = neighbours.iter().rev().map(|(id, _)| *id).collect();
+    let actual: Vec<SystemId> = ranked.iter().map(|n| n.system_id).collect();
+    assert_eq!(actual, expected);
+    assert_eq!(ranked[0].score, ranked[0].distance_ly);
+}
+
+#[test]
+fn zeroed_weights_fall_back_to_distance_ordering() {
+    let starmap = load_starmap(&fixture_path(), None).expect("fixture loads");
+    let neighbours = neighbours_of_nod(&starmap);
+
+    let weights = SuitabilityWeights {
+        planet: 0.0,
+        moon: 0.0,
+        cold: 0.0,
+        distance: 0.0,
+    };
+    let ranked = rank_by_suitability(&starmap, &neighbours, &weights);
+
+    // Every score ties, so the distance tie-break keeps the original order.
+    let ids: Vec<SystemId> = ranked.iter().map(|n| n.system_id).collect();
+    let expected: Vec<SystemId> = neighbours.iter().map(|(id, _)| *id).collect();
+    assert_eq!(ids, expected);
+}
@@ -8,7 +8,7 @@
 //!
 //! The MCP server is organized into the following submodules:
 //! - `server`: Main server initialization and lifecycle management
-//! - `tools`: Tool implementations (route_plan, system_info, systems_nearby, gates_from)
+//! - `tools`: Tool implementations (route_plan, system_info, systems_nearby, reachable_systems, gates_from, fmap_encode, fuel_projection)
 //! - `resources`: Resource implementations (dataset metadata, algorithms, spatial index status)
 //! - `error`: Error types and RFC 9457 problem details
 //!
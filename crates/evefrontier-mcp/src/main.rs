@@ -8,7 +8,11 @@ use evefrontier_mcp::resources::{
     AlgorithmsResource, DatasetInfoResource, SpatialIndexStatusResource,
 };
 use evefrontier_mcp::server::McpServerState;
-use evefrontier_mcp::types::{GatesFromInput, RoutePlanInput, SystemInfoInput, SystemsNearbyInput};
+use evefrontier_mcp::types::{
+    FmapEncodeInput, FuelProjectionInput, GatesFromInput, ReachableSystemsInput, RoutePlanInput,
+    SystemInfoInput, SystemsNearbyInput,
+};
+use evefrontier_mcp::Error as McpError;
 
 /// JSON-RPC 2.0 request
 #[derive(Debug, Deserialize)]
@@ -247,15 +251,35 @@ async fn handle_tools_list(_state: &McpServerState) -> Result<Value, JsonRpcErro
         }),
         serde_json::json!({
             "name": "systems_nearby",
-            "description": "Find star systems within a radius",
+            "description": "Find the star systems nearest to a system, optionally within a radius",
             "inputSchema": {
                 "type": "object",
                 "properties": {
-                    "origin": { "type": "string", "description": "Center system name" },
-                    "radius": { "type": "number", "description": "Search radius in light years" },
-                    "max_temperature": { "type": "number", "description": "Maximum system temperature in Kelvin" }
+                    "system_name": { "type": "string", "description": "Center system name" },
+                    "radius_ly": { "type": "number", "description": "Search radius in light years (omit for the nearest `limit` systems)" },
+                    "limit": { "type": "integer", "description": "Maximum number of results (default 20, max 100); caps results within the radius" },
+                    "max_temperature": { "type": "number", "description": "Maximum system temperature in Kelvin" },
+                    "ship_name": { "type": "string", "description": "Ship name from the ship catalog; adds fuel and heat for a direct jump to each system" },
+                    "fuel_quality": { "type": "number", "minimum": 1, "maximum": 100, "default": 10, "description": "Fuel quality percentage (with ship_name)" },
+                    "cargo_mass_kg": { "type": "number", "minimum": 0, "default": 0, "description": "Cargo mass in kilograms (with ship_name)" }
                 },
-                "required": ["origin", "radius"]
+                "required": ["system_name"]
+            }
+        }),
+        serde_json::json!({
+            "name": "reachable_systems",
+            "description": "Find every star system reachable from a system within a total travel distance budget",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "system_name": { "type": "string", "description": "Origin system name" },
+                    "max_distance_ly": { "type": "number", "description": "Total travel budget in light years across gate and spatial hops" },
+                    "max_jump": { "type": "number", "description": "Maximum single spatial jump in light years" },
+                    "max_temperature": { "type": "number", "description": "Maximum system temperature in Kelvin" },
+                    "avoid_gates": { "type": "boolean", "description": "Avoid jump gates" },
+                    "limit": { "type": "integer", "description": "Maximum number of results (default 20, max 100), nearest first" }
+                },
+                "required": ["system_name", "max_distance_ly"]
             }
         }),
         serde_json::json!({
@@ -269,13 +293,50 @@ async fn handle_tools_list(_state: &McpServerState) -> Result<Value, JsonRpcErro
                 "required": ["system_name"]
             }
         }),
+        serde_json::json!({
+            "name": "fmap_encode",
+            "description": "Encode an ordered list of star systems as a shareable fmap route token",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "systems": { "type": "array", "items": { "type": "string" }, "minItems": 1, "description": "System names in route order" },
+                    "waypoint_types": {
+                        "type": "array",
+                        "items": { "type": "string", "enum": ["start", "jump", "npc-gate", "smart-gate", "destination"] },
+                        "description": "Waypoint type per system; defaults to start, then jump, ending with destination"
+                    }
+                },
+                "required": ["systems"]
+            }
+        }),
+        serde_json::json!({
+            "name": "fuel_projection",
+            "description": "Plan a route for a ship and project per-hop and total fuel use, including refuel stops",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "origin": { "type": "string", "description": "Starting system name" },
+                    "destination": { "type": "string", "description": "Destination system name" },
+                    "ship_name": { "type": "string", "description": "Ship name from the ship catalog (e.g. Reflex)" },
+                    "fuel_quality": { "type": "number", "minimum": 1, "maximum": 100, "default": 10, "description": "Fuel quality percentage" },
+                    "cargo_mass_kg": { "type": "number", "minimum": 0, "default": 0, "description": "Cargo mass in kilograms" }
+                },
+                "required": ["origin", "destination", "ship_name"]
+            }
+        }),
     ];
     Ok(serde_json::json!({ "tools": tools }))
 }
 
+/// Handle `tools/call`.
+///
+/// Failures caused by the tool's input, such as an unknown system or an out-of-range
+/// parameter, are returned as a normal result with `isError: true` so the client can
+/// hand them back to the model. JSON-RPC errors are reserved for protocol problems:
+/// missing parameters, an unknown tool, or arguments that do not match the tool schema.
 async fn handle_tools_call(
     params: &Option<Value>,
-    _state: &McpServerState,
+    state: &McpServerState,
 ) -> Result<Value, JsonRpcError> {
     let params = params
         .as_ref()
@@ -287,68 +348,138 @@ async fn handle_tools_call(
 
     let arguments = &params["arguments"];
 
-    let result = match tool_name {
+    match tool_name {
         "route_plan" => {
-            let input: RoutePlanInput = serde_json::from_value(arguments.clone())
-                .map_err(|e| JsonRpcError::invalid_params(format!("Invalid input: {}", e)))?;
-
+            let input: RoutePlanInput = parse_tool_input(arguments)?;
             // Validation happens inside tool execute()
-            let output = evefrontier_mcp::tools::RoutePlanTool::execute(input)
-                .await
-                .map_err(|e| JsonRpcError::internal_error(e.to_string()))?;
-
-            serde_json::to_value(output).map_err(|e| JsonRpcError::internal_error(e.to_string()))?
+            tool_result(evefrontier_mcp::tools::RoutePlanTool::execute(input).await)
         }
         "system_info" => {
-            let input: SystemInfoInput = serde_json::from_value(arguments.clone())
-                .map_err(|e| JsonRpcError::invalid_params(format!("Invalid input: {}", e)))?;
-
-            // Validation happens inside tool execute()
-            let output = evefrontier_mcp::tools::SystemInfoTool::execute(input)
-                .await
-                .map_err(|e| JsonRpcError::internal_error(e.to_string()))?;
-
-            serde_json::to_value(output).map_err(|e| JsonRpcError::internal_error(e.to_string()))?
+            let input: SystemInfoInput = parse_tool_input(arguments)?;
+            let output = state.starmap().and_then(|starmap| {
+                evefrontier_mcp::tools::SystemInfoTool::lookup(&starmap, &input)
+            });
+            tool_result(output)
         }
         "systems_nearby" => {
-            let input: SystemsNearbyInput = serde_json::from_value(arguments.clone())
-                .map_err(|e| JsonRpcError::invalid_params(format!("Invalid input: {}", e)))?;
-
-            // Validation happens inside tool execute()
-            let output = evefrontier_mcp::tools::SystemsNearbyTool::execute(input)
-                .await
-                .map_err(|e| JsonRpcError::internal_error(e.to_string()))?;
-
-            serde_json::to_value(output).map_err(|e| JsonRpcError::internal_error(e.to_string()))?
+            let input: SystemsNearbyInput = parse_tool_input(arguments)?;
+            let output = state.starmap().and_then(|starmap| {
+                let spatial_index = state.spatial_index()?;
+                // Ship data is only needed (and only required to exist) for projections.
+                let catalog = if input.ship_name.is_some() {
+                    Some(state.ship_catalog()?)
+                } else {
+                    None
+                };
+                evefrontier_mcp::tools::SystemsNearbyTool::search(
+                    &starmap,
+                    &spatial_index,
+                    catalog.as_deref(),
+                    &input,
+                )
+            });
+            tool_result(output)
+        }
+        "reachable_systems" => {
+            let input: ReachableSystemsInput = parse_tool_input(arguments)?;
+            let output = state.starmap().and_then(|starmap| {
+                let spatial_index = state.spatial_index()?;
+                evefrontier_mcp::tools::ReachableSystemsTool::search(
+                    &starmap,
+                    spatial_index,
+                    &input,
+                )
+            });
+            tool_result(output)
         }
         "gates_from" => {
-            let input: GatesFromInput = serde_json::from_value(arguments.clone())
-                .map_err(|e| JsonRpcError::invalid_params(format!("Invalid input: {}", e)))?;
-
+            let input: GatesFromInput = parse_tool_input(arguments)?;
             // Validation happens inside tool execute()
-            let output = evefrontier_mcp::tools::GatesFromTool::execute(input)
-                .await
-                .map_err(|e| JsonRpcError::internal_error(e.to_string()))?;
-
-            serde_json::to_value(output).map_err(|e| JsonRpcError::internal_error(e.to_string()))?
+            tool_result(evefrontier_mcp::tools::GatesFromTool::execute(input).await)
         }
-        _ => {
-            return Err(JsonRpcError::method_not_found(format!(
-                "Unknown tool: {}",
-                tool_name
-            )))
+        "fmap_encode" => {
+            let input: FmapEncodeInput = parse_tool_input(arguments)?;
+            let output = state.starmap().and_then(|starmap| {
+                evefrontier_mcp::tools::FmapEncodeTool::encode(&starmap, &input)
+            });
+            tool_result(output)
+        }
+        "fuel_projection" => {
+            let input: FuelProjectionInput = parse_tool_input(arguments)?;
+            let output = state.starmap().and_then(|starmap| {
+                let spatial_index = state.spatial_index()?;
+                let catalog = state.ship_catalog()?;
+                evefrontier_mcp::tools::FuelProjectionTool::project(
+                    &starmap,
+                    spatial_index,
+                    &catalog,
+                    &input,
+                )
+            });
+            tool_result(output)
+        }
+        _ => Err(JsonRpcError::method_not_found(format!(
+            "Unknown tool: {}",
+            tool_name
+        ))),
+    }
+}
+
+fn parse_tool_input<T: serde::de::DeserializeOwned>(arguments: &Value) -> Result<T, JsonRpcError> {
+    serde_json::from_value(arguments.clone())
+        .map_err(|e| JsonRpcError::invalid_params(format!("Invalid input: {}", e)))
+}
+
+/// Wrap a tool outcome in a `tools/call` result, reporting tool errors in-band.
+fn tool_result<T: Serialize>(output: evefrontier_mcp::Result<T>) -> Result<Value, JsonRpcError> {
+    let output = match output {
+        Ok(output) => output,
+        Err(error) => {
+            return Ok(serde_json::json!({
+                "content": [{
+                    "type": "text",
+                    "text": describe_tool_error(&error)
+                }],
+                "isError": true
+            }))
         }
     };
 
     Ok(serde_json::json!({
         "content": [{
             "type": "text",
-            "text": serde_json::to_string_pretty(&result)
+            "text": serde_json::to_string_pretty(&output)
                 .map_err(|e| JsonRpcError::internal_error(e.to_string()))?
         }]
     }))
 }
 
+/// Tool error text for the model: the message, then any reason and suggestions
+/// carried in the error context.
+fn describe_tool_error(error: &McpError) -> String {
+    let mut text = error.message.clone();
+    let Some(context) = &error.context else {
+        return text;
+    };
+
+    if let Some(reason) = context["reason"].as_str() {
+        text.push_str(": ");
+        text.push_str(reason);
+    }
+
+    let suggestions: Vec<&str> = context["suggestions"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+    if !suggestions.is_empty() {
+        text.push_str(&format!(". Did you mean: {}?", suggestions.join(", ")));
+    }
+
+    text
+}
+
 async fn handle_resources_list(state: &McpServerState) -> Result<Value, JsonRpcError> {
     let resources = state.resources();
     Ok(serde_json::json!({ "resources": resources }))
@@ -119,6 +119,9 @@ mod tests {
             gate_count: 12,
             schema_version: "e6c3".to_string(),
             spatial_index_available: true,
+            starmap: Default::default(),
+            spatial_index: Default::default(),
+            ship_catalog: Default::default(),
         }
     }
 
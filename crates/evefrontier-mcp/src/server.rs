@@ -5,9 +5,11 @@
 //! request dispatching.
 
 use crate::Error;
+use evefrontier_lib::{load_starmap, try_load_spatial_index, ShipCatalog, SpatialIndex, Starmap};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
-use tracing::{debug, info};
+use std::sync::{Arc, OnceLock};
+use tracing::{debug, info, warn};
 
 /// Main server state holding all runtime resources
 ///
@@ -32,6 +34,15 @@ pub struct McpServerState {
 
     /// Whether the spatial index has been loaded or auto-built
     pub spatial_index_available: bool,
+
+    /// Starmap loaded on first use by tools that resolve system names
+    pub starmap: OnceLock<Arc<Starmap>>,
+
+    /// Spatial index loaded (or built) on first use by tools that plan routes
+    pub spatial_index: OnceLock<Arc<SpatialIndex>>,
+
+    /// Ship catalog loaded on first use by tools that project fuel
+    pub ship_catalog: OnceLock<Arc<ShipCatalog>>,
 }
 
 /// Metadata about the loaded dataset
@@ -101,6 +112,9 @@ impl McpServerState {
             gate_count,
             schema_version,
             spatial_index_available: false, // TODO: Load at startup
+            starmap: OnceLock::new(),
+            spatial_index: OnceLock::new(),
+            ship_catalog: OnceLock::new(),
         };
 
         info!(
@@ -166,6 +180,80 @@ impl McpServerState {
         Ok(())
     }
 
+    /// Starmap for the loaded database, read from disk on the first call
+    pub fn starmap(&self) -> crate::Result<Arc<Starmap>> {
+        if let Some(starmap) = self.starmap.get() {
+            return Ok(Arc::clone(starmap));
+        }
+
+        let loaded = load_starmap(&self.database_path, None).map_err(|e| {
+            Error::internal(format!(
+                "failed to load starmap from {}: {}",
+                self.database_path.display(),
+                e
+            ))
+        })?;
+        Ok(Arc::clone(self.starmap.get_or_init(|| Arc::new(loaded))))
+    }
+
+    /// Spatial index for the loaded database
+    ///
+    /// Uses the `.spatial.bin` file next to the database when present, otherwise
+    /// builds the index from the starmap (with a warning) on the first call.
+    pub fn spatial_index(&self) -> crate::Result<Arc<SpatialIndex>> {
+        if let Some(index) = self.spatial_index.get() {
+            return Ok(Arc::clone(index));
+        }
+
+        let index = match try_load_spatial_index(&self.database_path) {
+            Some(index) => index,
+            None => {
+                warn!(
+                    "No spatial index found for {}; building one in memory",
+                    self.database_path.display()
+                );
+                SpatialIndex::build(&self.starmap()?)
+            }
+        };
+        Ok(Arc::clone(
+            self.spatial_index.get_or_init(|| Arc::new(index)),
+        ))
+    }
+
+    /// Ship catalog, read on the first call
+    ///
+    /// Looks for `EVEFRONTIER_SHIP_DATA`, then `ship_data.csv` next to the database.
+    pub fn ship_catalog(&self) -> crate::Result<Arc<ShipCatalog>> {
+        if let Some(catalog) = self.ship_catalog.get() {
+            return Ok(Arc::clone(catalog));
+        }
+
+        let path = std::env::var_os("EVEFRONTIER_SHIP_DATA")
+            .map(PathBuf::from)
+            .into_iter()
+            .chain(
+                self.database_path
+                    .parent()
+                    .map(|parent| parent.join("ship_data.csv")),
+            )
+            .find(|path| path.exists())
+            .ok_or_else(|| {
+                Error::internal(
+                    "ship_data.csv not found; set EVEFRONTIER_SHIP_DATA or place it next to the dataset",
+                )
+            })?;
+        let catalog = ShipCatalog::from_path(&path).map_err(|e| {
+            Error::internal(format!(
+                "failed to load ship data from {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        Ok(Arc::clone(
+            self.ship_catalog.get_or_init(|| Arc::new(catalog)),
+        ))
+    }
+
     /// Get dataset metadata for the evefrontier://dataset/info resource
     pub fn dataset_info(&self) -> DatasetInfo {
         DatasetInfo {
@@ -283,6 +371,9 @@ mod tests {
             gate_count: 12,
             schema_version: "e6c3".into(),
             spatial_index_available: true,
+            starmap: Default::default(),
+            spatial_index: Default::default(),
+            ship_catalog: Default::default(),
         };
 
         let resources = state.resources();
@@ -304,6 +395,9 @@ mod tests {
             gate_count: 0,
             schema_version: "test".to_string(),
             spatial_index_available: false,
+            starmap: Default::default(),
+            spatial_index: Default::default(),
+            ship_catalog: Default::default(),
         };
 
         let results = state.find_system_fuzzy("");
@@ -319,6 +413,9 @@ mod tests {
             gate_count: 12,
             schema_version: "e6c3".to_string(),
             spatial_index_available: false,
+            starmap: Default::default(),
+            spatial_index: Default::default(),
+            ship_catalog: Default::default(),
         };
 
         let result = state.initialize().await;
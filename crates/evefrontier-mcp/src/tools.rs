@@ -1,13 +1,25 @@
 //! MCP tool implementations for route planning and system queries
 //!
-//! This module defines the four main tools exposed by the MCP server:
+//! This module defines the tools exposed by the MCP server:
 //! - route_plan: Plan a route between two systems with constraints
 //! - system_info: Get detailed information about a single system
-//! - systems_nearby: Find systems within a spatial radius
+//! - systems_nearby: Find the nearest systems, optionally within a spatial radius
+//! - reachable_systems: Find every system reachable within a travel budget
 //! - gates_from: Get gate-connected neighbors of a system
+//! - fmap_encode: Encode an ordered list of systems as a shareable fmap route token
+//! - fuel_projection: Plan a route and project fuel use for a ship
+
+use std::sync::Arc;
 
 use crate::types::*;
 use crate::Error;
+use evefrontier_lib::ship::{project_heat_for_jump, HeatProjection};
+use evefrontier_lib::{
+    calculate_jump_fuel_cost, encode_fmap_token, plan_reachable, plan_route, project_fuel_for_hop,
+    FuelConfig, FuelProjection, HeatConfig, HeatProjectionParams, ReachBudget, ReachableRequest,
+    RouteAlgorithm, RouteConstraints, RouteOutputKind, RouteRequest, RouteSummary, ShipAttributes,
+    ShipCatalog, ShipLoadout, SpatialIndex, Starmap, WaypointType,
+};
 use tracing::{debug, info};
 
 /// Route planning tool handler
@@ -113,45 +125,55 @@ impl RoutePlanTool {
 pub struct SystemInfoTool;
 
 impl SystemInfoTool {
-    /// Handle a system information request
-    ///
-    /// Queries the loaded dataset for a specific system by exact or fuzzy match,
-    /// returning detailed metadata including coordinates, temperature, and gates.
-    ///
-    /// # Arguments
+    /// Look up a system in a loaded starmap
     ///
-    /// * `input` - SystemInfoInput with system_name to query
-    ///
-    /// # Returns
-    ///
-    /// SystemInfoOutput with either system details or error information
-    pub async fn execute(input: SystemInfoInput) -> crate::Result<SystemInfoOutput> {
+    /// Resolves `system_name` exactly and returns its coordinates, temperature,
+    /// planet and moon counts, and gate connections. Unknown names fail with
+    /// a not-found error carrying fuzzy-match suggestions.
+    pub fn lookup(starmap: &Starmap, input: &SystemInfoInput) -> crate::Result<SystemInfoOutput> {
         debug!("Querying system info: {}", input.system_name);
 
-        // Validate input
-        if input.system_name.is_empty() {
-            return Err(Error::invalid_param(
-                "system_name",
-                "System name cannot be empty",
-            ));
-        }
-
-        // TODO: Phase 4+ - Integrate with evefrontier-lib
-        // 1. Load starmap from database
-        // 2. Fuzzy match system name (using strsim crate)
-        // 3. Query system metadata (coordinates, temp, planet/moon counts)
-        // 4. Query gate connections
-        // 5. Construct SystemInfoOutput
+        Self::validate_input(input)?;
+
+        let system = starmap
+            .system_id_by_name(&input.system_name)
+            .and_then(|id| starmap.systems.get(&id))
+            .ok_or_else(|| {
+                Error::system_not_found(
+                    &input.system_name,
+                    starmap.fuzzy_system_matches(&input.system_name, 3),
+                )
+            })?;
+
+        let connected_gates = starmap
+            .adjacency
+            .get(&system.id)
+            .into_iter()
+            .flatten()
+            .filter_map(|&neighbour| {
+                Some(GateConnection {
+                    destination_system: starmap.system_name(neighbour)?.to_string(),
+                    destination_id: neighbour as u64,
+                })
+            })
+            .collect();
 
-        // For now: Return not-found with suggestions for fuzzy matching demo
         Ok(SystemInfoOutput {
-            found: false,
-            system: None,
-            error: Some(SystemError {
-                code: "SYSTEM_NOT_FOUND".to_string(),
-                message: format!("System '{}' not found in dataset", input.system_name),
-                suggestions: vec!["Nod".to_string(), "Brana".to_string()],
+            found: true,
+            system: Some(SystemDetails {
+                system_id: system.id as u64,
+                name: system.name.clone(),
+                position: system.position.map(|p| Position3D {
+                    x: p.x,
+                    y: p.y,
+                    z: p.z,
+                }),
+                min_external_temperature_k: system.metadata.min_external_temp,
+                planet_count: system.metadata.planet_count.unwrap_or(0) as usize,
+                moon_count: system.metadata.moon_count.unwrap_or(0) as usize,
+                connected_gates,
             }),
+            error: None,
         })
     }
 
@@ -169,15 +191,19 @@ impl SystemInfoTool {
 
 /// Systems nearby tool handler
 ///
-/// This tool returns systems within a specified radius of a central system,
-/// optionally filtered by temperature tolerance.
+/// This tool returns the systems nearest to a central system. `limit` caps the
+/// number of results and `radius_ly`, when given, bounds their distance; both
+/// may be combined (see [`SystemsNearbyInput`]). Results can additionally be
+/// filtered by temperature tolerance, and given a ship they carry the fuel and
+/// heat of jumping to each system directly.
 pub struct SystemsNearbyTool;
 
 impl SystemsNearbyTool {
     /// Handle a systems nearby request
     ///
-    /// Performs a spatial query to find all systems within the specified radius
-    /// from a central system, optionally filtering by temperature tolerance.
+    /// Performs a spatial query for the nearest systems to a central system,
+    /// bounded by `limit` and the optional radius, optionally filtering by
+    /// temperature tolerance.
     ///
     /// # Arguments
     ///
@@ -188,8 +214,8 @@ impl SystemsNearbyTool {
     /// SystemsNearbyOutput with list of nearby systems or error information
     pub async fn execute(input: SystemsNearbyInput) -> crate::Result<SystemsNearbyOutput> {
         debug!(
-            "Querying systems near {}, radius: {}ly",
-            input.system_name, input.radius_ly
+            "Querying systems near {}, radius: {:?}ly, limit: {}",
+            input.system_name, input.radius_ly, input.limit
         );
 
         // Validate input
@@ -198,15 +224,13 @@ impl SystemsNearbyTool {
         // TODO: Phase 4+ - Integrate with evefrontier-lib spatial index
         // 1. Load starmap and spatial index from database
         // 2. Fuzzy match system name to find center coordinates
-        // 3. Use spatial index to query nearby systems within radius
-        // 4. Filter by temperature if max_temperature specified
-        // 5. Paginate results using limit and offset
-        // 6. Construct SystemsNearbyOutput
+        // 3. Run the query via Self::search once the dataset is wired in
 
         // For now: Return empty result (Phase 4+ will implement spatial queries)
         Ok(SystemsNearbyOutput {
             center_system: input.system_name.clone(),
             radius_ly: input.radius_ly,
+            ship_name: None,
             count: 0,
             systems: vec![],
         })
@@ -221,10 +245,19 @@ impl SystemsNearbyTool {
             ));
         }
 
-        if input.radius_ly <= 0.0 {
+        if let Some(radius) = input.radius_ly {
+            if radius <= 0.0 {
+                return Err(Error::invalid_param(
+                    "radius_ly",
+                    "Radius must be positive (> 0)",
+                ));
+            }
+        }
+
+        if input.limit == 0 || input.limit > MAX_NEARBY_LIMIT {
             return Err(Error::invalid_param(
-                "radius_ly",
-                "Radius must be positive (> 0)",
+                "limit",
+                format!("Must be between 1 and {}", MAX_NEARBY_LIMIT),
             ));
         }
 
@@ -237,8 +270,291 @@ impl SystemsNearbyTool {
             }
         }
 
+        if let Some(ship_name) = &input.ship_name {
+            if ship_name.trim().is_empty() {
+                return Err(Error::invalid_param("ship_name", "Must not be empty"));
+            }
+            validate_ship_load(input.fuel_quality, input.cargo_mass_kg)?;
+        }
+
         Ok(())
     }
+
+    /// Run a systems_nearby query against a loaded starmap and spatial index
+    ///
+    /// Returns up to `limit` systems ordered by distance, excluding the center
+    /// system itself. With `radius_ly` set, only systems within the radius are
+    /// returned, so the result may be shorter than `limit`.
+    ///
+    /// With `ship_name` set, each system also gets the fuel and heat of a direct
+    /// jump from the center on a full tank, looked up in `catalog`.
+    pub fn search(
+        starmap: &Starmap,
+        index: &SpatialIndex,
+        catalog: Option<&ShipCatalog>,
+        input: &SystemsNearbyInput,
+    ) -> crate::Result<SystemsNearbyOutput> {
+        Self::validate_input(input)?;
+
+        let ship = match (&input.ship_name, catalog) {
+            (None, _) => None,
+            (Some(ship_name), Some(catalog)) => Some(find_ship(catalog, ship_name)?),
+            (Some(_), None) => return Err(Error::internal("Ship data is not loaded")),
+        };
+        let loadout = ship
+            .map(|ship| ShipLoadout::new(ship, ship.fuel_capacity, input.cargo_mass_kg))
+            .transpose()
+            .map_err(|e| Error::invalid_param("cargo_mass_kg", e.to_string()))?;
+        let fuel_config = FuelConfig {
+            quality: input.fuel_quality,
+            dynamic_mass: false,
+            reserve: 0.0,
+        };
+
+        let center_id = starmap
+            .system_id_by_name(&input.system_name)
+            .ok_or_else(|| {
+                Error::system_not_found(
+                    &input.system_name,
+                    starmap.fuzzy_system_matches(&input.system_name, 3),
+                )
+            })?;
+        let center = starmap.systems.get(&center_id);
+        let position = center.and_then(|system| system.position).ok_or_else(|| {
+            Error::invalid_param(
+                "system_name",
+                format!("System '{}' has no spatial coordinates", input.system_name),
+            )
+        })?;
+
+        // Fetch one extra result so the center system can be dropped.
+        let mut query = input.neighbour_query();
+        query.k += 1;
+
+        let mut systems = Vec::new();
+        for (id, distance) in index
+            .nearest_filtered([position.x, position.y, position.z], &query)
+            .into_iter()
+            .filter(|(id, _)| *id != center_id)
+            .take(input.limit)
+        {
+            let Some(system) = starmap.systems.get(&id) else {
+                continue;
+            };
+            let min_temperature_k = system.metadata.min_external_temp;
+            let (fuel, heat) = match (ship, &loadout) {
+                (Some(ship), Some(loadout)) => {
+                    let (fuel, heat) = project_direct_jump(
+                        ship,
+                        loadout,
+                        &fuel_config,
+                        distance,
+                        center.and_then(|center| center.metadata.min_external_temp),
+                        min_temperature_k,
+                    )?;
+                    (Some(fuel), Some(heat))
+                }
+                _ => (None, None),
+            };
+            systems.push(NearbySystem {
+                name: system.name.clone(),
+                system_id: id as u64,
+                distance_ly: distance,
+                min_temperature_k,
+                fuel,
+                heat,
+            });
+        }
+
+        Ok(SystemsNearbyOutput {
+            center_system: input.system_name.clone(),
+            radius_ly: input.radius_ly,
+            ship_name: ship.map(|ship| ship.name.clone()),
+            count: systems.len(),
+            systems,
+        })
+    }
+}
+
+/// Fuel and heat for a single jump of `distance_ly` with the given loadout
+///
+/// The jump is treated as the last hop of a route starting on `loadout`'s fuel,
+/// so the fuel projection reports what is left in the tank on arrival.
+fn project_direct_jump(
+    ship: &ShipAttributes,
+    loadout: &ShipLoadout,
+    fuel_config: &FuelConfig,
+    distance_ly: f64,
+    departure_temperature_k: Option<f64>,
+    arrival_temperature_k: Option<f64>,
+) -> crate::Result<(FuelProjection, HeatProjection)> {
+    let mass = loadout.total_mass_kg(ship);
+    let hop_cost = if distance_ly > 0.0 {
+        calculate_jump_fuel_cost(mass, distance_ly, fuel_config)
+            .map_err(|e| Error::internal(e.to_string()))?
+    } else {
+        0.0
+    };
+    let (fuel, _) = project_fuel_for_hop(hop_cost, hop_cost, loadout.fuel_load, ship.fuel_capacity);
+
+    let heat = project_heat_for_jump(HeatProjectionParams {
+        mass,
+        specific_heat: ship.specific_heat,
+        distance_ly,
+        hull_mass_kg: ship.base_mass_kg,
+        calibration_constant: HeatConfig::default().calibration_constant,
+        prev_ambient: departure_temperature_k,
+        current_min_external_temp: arrival_temperature_k,
+        is_goal: true,
+        next_is_gate: false,
+    })
+    .map_err(|e| Error::internal(e.to_string()))?;
+
+    Ok((fuel, heat))
+}
+
+/// Look up a ship by name, listing the catalog's ships when it is unknown
+fn find_ship<'a>(catalog: &'a ShipCatalog, ship_name: &str) -> crate::Result<&'a ShipAttributes> {
+    catalog.get(ship_name).ok_or_else(|| {
+        Error::invalid_param(
+            "ship_name",
+            format!(
+                "Unknown ship '{}'. Available: {}",
+                ship_name,
+                catalog.ship_names().join(", ")
+            ),
+        )
+    })
+}
+
+/// Validate the fuel quality and cargo mass that accompany a ship name
+fn validate_ship_load(fuel_quality: f64, cargo_mass_kg: f64) -> crate::Result<()> {
+    if !fuel_quality.is_finite() || !(1.0..=100.0).contains(&fuel_quality) {
+        return Err(Error::invalid_param(
+            "fuel_quality",
+            "Must be between 1 and 100",
+        ));
+    }
+
+    if !cargo_mass_kg.is_finite() || cargo_mass_kg < 0.0 {
+        return Err(Error::invalid_param(
+            "cargo_mass_kg",
+            "Must be a non-negative number",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Reachable systems tool handler
+///
+/// This tool floods outward from an origin over gates and spatial jumps and
+/// returns every system whose cheapest travel distance fits the budget, nearest
+/// first and capped by `limit`.
+pub struct ReachableSystemsTool;
+
+impl ReachableSystemsTool {
+    /// Validate reachable_systems input
+    fn validate_input(input: &ReachableSystemsInput) -> crate::Result<()> {
+        if input.system_name.is_empty() {
+            return Err(Error::invalid_param(
+                "system_name",
+                "System name cannot be empty",
+            ));
+        }
+
+        if !input.max_distance_ly.is_finite() || input.max_distance_ly <= 0.0 {
+            return Err(Error::invalid_param(
+                "max_distance_ly",
+                "Budget must be positive (> 0)",
+            ));
+        }
+
+        if let Some(max_jump) = input.max_jump {
+            if max_jump <= 0.0 {
+                return Err(Error::invalid_param("max_jump", "Must be positive"));
+            }
+        }
+
+        if let Some(max_temp) = input.max_temperature {
+            if max_temp <= 0.0 {
+                return Err(Error::invalid_param(
+                    "max_temperature",
+                    "Must be positive (Kelvin)",
+                ));
+            }
+        }
+
+        if input.limit == 0 || input.limit > MAX_REACHABLE_LIMIT {
+            return Err(Error::invalid_param(
+                "limit",
+                format!("Must be between 1 and {}", MAX_REACHABLE_LIMIT),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Run a reachable_systems query against a loaded starmap and spatial index
+    ///
+    /// Returns up to `limit` systems ordered by cheapest travel distance,
+    /// excluding the origin. No ship is involved, so heat limits are not applied.
+    pub fn search(
+        starmap: &Starmap,
+        index: Arc<SpatialIndex>,
+        input: &ReachableSystemsInput,
+    ) -> crate::Result<ReachableSystemsOutput> {
+        debug!(
+            "Querying systems reachable from {} within {}ly, limit: {}",
+            input.system_name, input.max_distance_ly, input.limit
+        );
+
+        Self::validate_input(input)?;
+
+        if starmap.system_id_by_name(&input.system_name).is_none() {
+            return Err(Error::system_not_found(
+                &input.system_name,
+                starmap.fuzzy_system_matches(&input.system_name, 3),
+            ));
+        }
+
+        let request = ReachableRequest {
+            budget: ReachBudget::Distance(input.max_distance_ly),
+            constraints: RouteConstraints {
+                max_jump: input.max_jump,
+                avoid_gates: input.avoid_gates,
+                max_temperature: input.max_temperature,
+                avoid_critical_state: false,
+                ..RouteConstraints::default()
+            },
+            limit: input.limit,
+            ..ReachableRequest::distance(&input.system_name, input.max_distance_ly)
+        }
+        .with_spatial_index(index);
+
+        let reachable =
+            plan_reachable(starmap, &request).map_err(|e| Error::internal(e.to_string()))?;
+
+        let systems: Vec<ReachableSystem> = reachable
+            .into_iter()
+            .filter_map(|(id, distance)| {
+                let system = starmap.systems.get(&id)?;
+                Some(ReachableSystem {
+                    name: system.name.clone(),
+                    system_id: id as u64,
+                    distance_ly: distance,
+                    min_temperature_k: system.metadata.min_external_temp,
+                })
+            })
+            .collect();
+
+        Ok(ReachableSystemsOutput {
+            origin_system: input.system_name.clone(),
+            max_distance_ly: input.max_distance_ly,
+            count: systems.len(),
+            systems,
+        })
+    }
 }
 
 /// Gates from tool handler
@@ -292,6 +608,226 @@ impl GatesFromTool {
     }
 }
 
+/// fmap encode tool handler
+///
+/// This tool resolves an ordered list of system names against the starmap and
+/// encodes them as an fmap route token that can be shared as a starmap URL.
+pub struct FmapEncodeTool;
+
+impl FmapEncodeTool {
+    /// Encode the systems in `input` as an fmap token
+    ///
+    /// Unknown system names and waypoint types are reported as invalid
+    /// parameters, with fuzzy suggestions for misspelled systems.
+    pub fn encode(starmap: &Starmap, input: &FmapEncodeInput) -> crate::Result<FmapEncodeOutput> {
+        debug!("Encoding fmap token for {} systems", input.systems.len());
+
+        Self::validate_input(input)?;
+
+        let last = input.systems.len() - 1;
+        let waypoints = input
+            .systems
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let waypoint_type = match input.waypoint_types.get(i) {
+                    Some(value) => Self::parse_waypoint_type(value)?,
+                    None if i == 0 => WaypointType::Start,
+                    None if i == last => WaypointType::SetDestination,
+                    None => WaypointType::Jump,
+                };
+                let system_id = starmap.system_id_by_name(name).ok_or_else(|| {
+                    let suggestions = starmap.fuzzy_system_matches(name, 3);
+                    let reason = if suggestions.is_empty() {
+                        format!("Unknown system '{}'", name)
+                    } else {
+                        format!(
+                            "Unknown system '{}' (did you mean: {})",
+                            name,
+                            suggestions.join(", ")
+                        )
+                    };
+                    Error::invalid_param("systems", reason)
+                })?;
+                let system_id = u32::try_from(system_id).map_err(|_| {
+                    Error::invalid_param(
+                        "systems",
+                        format!("System '{}' has an ID outside the fmap range", name),
+                    )
+                })?;
+                Ok(evefrontier_lib::Waypoint {
+                    system_id,
+                    waypoint_type,
+                })
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        let token = encode_fmap_token(&waypoints)
+            .map_err(|e| Error::invalid_param("systems", e.to_string()))?;
+
+        Ok(FmapEncodeOutput {
+            token: token.token,
+            waypoint_count: token.waypoint_count,
+            bit_width: token.bit_width,
+            version: token.version,
+        })
+    }
+
+    /// Validate fmap_encode input
+    fn validate_input(input: &FmapEncodeInput) -> crate::Result<()> {
+        if input.systems.is_empty() {
+            return Err(Error::invalid_param(
+                "systems",
+                "At least one system is required",
+            ));
+        }
+
+        if input.waypoint_types.len() > input.systems.len() {
+            return Err(Error::invalid_param(
+                "waypoint_types",
+                format!("Expected at most {} entries", input.systems.len()),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Parse a waypoint type name as accepted by `evefrontier-cli fmap encode`
+    fn parse_waypoint_type(value: &str) -> crate::Result<WaypointType> {
+        match value {
+            "start" => Ok(WaypointType::Start),
+            "jump" => Ok(WaypointType::Jump),
+            "npc-gate" => Ok(WaypointType::NpcGate),
+            "smart-gate" => Ok(WaypointType::SmartGate),
+            "destination" | "dest" => Ok(WaypointType::SetDestination),
+            other => Err(Error::invalid_param(
+                "waypoint_types",
+                format!(
+                    "Unknown waypoint type '{}'. Valid: start, jump, npc-gate, smart-gate, destination",
+                    other
+                ),
+            )),
+        }
+    }
+}
+
+/// Fuel projection tool handler
+///
+/// This tool plans a route for a catalog ship with a full tank and projects the
+/// fuel burned on each hop, the same way `evefrontier-cli route --ship` does.
+pub struct FuelProjectionTool;
+
+impl FuelProjectionTool {
+    /// Plan the route in `input` and attach per-hop fuel projections
+    ///
+    /// Unknown ships and out-of-range fuel or cargo values are reported as
+    /// invalid parameters; unknown systems and unreachable destinations use the
+    /// same errors as route planning.
+    pub fn project(
+        starmap: &Starmap,
+        spatial_index: Arc<SpatialIndex>,
+        catalog: &ShipCatalog,
+        input: &FuelProjectionInput,
+    ) -> crate::Result<FuelProjectionOutput> {
+        debug!(
+            "Projecting fuel: {} → {} ({})",
+            input.origin, input.destination, input.ship_name
+        );
+
+        Self::validate_input(input)?;
+
+        let ship = find_ship(catalog, &input.ship_name)?;
+        let loadout = ShipLoadout::new(ship, ship.fuel_capacity, input.cargo_mass_kg)
+            .map_err(|e| Error::invalid_param("cargo_mass_kg", e.to_string()))?;
+        let fuel_config = FuelConfig {
+            quality: input.fuel_quality,
+            dynamic_mass: false,
+            reserve: 0.0,
+        };
+
+        let mut request =
+            RouteRequest::bfs(&input.origin, &input.destination).with_spatial_index(spatial_index);
+        request.algorithm = RouteAlgorithm::AStar;
+        request.constraints.ship = Some(ship.clone());
+        request.constraints.loadout = Some(loadout);
+        request.fuel_config = fuel_config;
+
+        let plan = plan_route(starmap, &request).map_err(Self::map_route_error)?;
+        let mut summary =
+            RouteSummary::from_plan(RouteOutputKind::Route, starmap, &plan, Some(&request))
+                .map_err(|e| Error::internal(e.to_string()))?;
+        summary
+            .attach_fuel(ship, &loadout, &fuel_config)
+            .map_err(|e| Error::internal(e.to_string()))?;
+
+        let fuel = summary.fuel.as_ref();
+        let steps: Vec<FuelProjectionStep> = summary
+            .steps
+            .iter()
+            .map(|step| FuelProjectionStep {
+                system: step.name.clone().unwrap_or_else(|| step.id.to_string()),
+                system_id: step.id as u64,
+                method: step.method.clone(),
+                distance_ly: step.distance,
+                hop_fuel: step.fuel.as_ref().map(|f| f.hop_cost),
+                cumulative_fuel: step.fuel.as_ref().map(|f| f.cumulative),
+                remaining_fuel: step.fuel.as_ref().and_then(|f| f.remaining),
+                warning: step.fuel.as_ref().and_then(|f| f.warning.clone()),
+            })
+            .collect();
+        let warnings = steps
+            .iter()
+            .filter_map(|step| {
+                step.warning
+                    .as_ref()
+                    .map(|warning| format!("{} at {}", warning, step.system))
+            })
+            .collect();
+
+        Ok(FuelProjectionOutput {
+            origin: input.origin.clone(),
+            destination: input.destination.clone(),
+            ship_name: ship.name.clone(),
+            fuel_quality: input.fuel_quality,
+            hops: summary.hops,
+            total_distance_ly: summary.total_distance,
+            total_fuel: fuel.map_or(0.0, |f| f.total),
+            remaining_fuel: fuel.and_then(|f| f.remaining),
+            refuel_at: summary.refuel_at.clone(),
+            warnings,
+            steps,
+        })
+    }
+
+    /// Validate fuel_projection input
+    fn validate_input(input: &FuelProjectionInput) -> crate::Result<()> {
+        for (parameter, value) in [
+            ("origin", &input.origin),
+            ("destination", &input.destination),
+            ("ship_name", &input.ship_name),
+        ] {
+            if value.trim().is_empty() {
+                return Err(Error::invalid_param(parameter, "Must not be empty"));
+            }
+        }
+
+        validate_ship_load(input.fuel_quality, input.cargo_mass_kg)
+    }
+
+    /// Map a route planning error onto the MCP error shapes
+    fn map_route_error(err: evefrontier_lib::Error) -> Error {
+        match err {
+            evefrontier_lib::Error::UnknownSystem { name, suggestions } => {
+                Error::system_not_found(name, suggestions)
+            }
+            evefrontier_lib::Error::RouteNotFound { start, goal } => {
+                Error::route_not_found(start, goal)
+            }
+            other => Error::internal(other.to_string()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -302,7 +838,10 @@ mod tests {
         let _route_tool = RoutePlanTool;
         let _info_tool = SystemInfoTool;
         let _nearby_tool = SystemsNearbyTool;
+        let _reachable_tool = ReachableSystemsTool;
         let _gates_tool = GatesFromTool;
+        let _fmap_tool = FmapEncodeTool;
+        let _fuel_tool = FuelProjectionTool;
     }
 
     // Route planning tool tests
@@ -389,42 +928,48 @@ mod tests {
     }
 
     // System info tool tests
-    #[tokio::test]
-    async fn test_system_info_validation_empty_name() {
+    #[test]
+    fn test_system_info_validation_empty_name() {
+        let (starmap, _) = fixture_dataset();
         let input = SystemInfoInput {
             system_name: "".to_string(),
         };
 
-        let result = SystemInfoTool::execute(input).await;
-        assert!(result.is_err());
+        let err = SystemInfoTool::lookup(&starmap, &input).expect_err("empty name");
+        assert_eq!(err.code, 400);
     }
 
-    #[tokio::test]
-    async fn test_system_info_execution_not_found() {
+    #[test]
+    fn test_system_info_lookup_known_system() {
+        let (starmap, _) = fixture_dataset();
         let input = SystemInfoInput {
-            system_name: "UnknownSystem".to_string(),
+            system_name: "Nod".to_string(),
         };
 
-        let result = SystemInfoTool::execute(input).await;
-        assert!(result.is_ok());
-        let output = result.unwrap();
-        assert!(!output.found);
-        assert!(output.system.is_none());
-        assert!(output.error.is_some());
+        let output = SystemInfoTool::lookup(&starmap, &input).expect("Nod is in the fixture");
+        assert!(output.found);
+        assert!(output.error.is_none());
+        let system = output.system.expect("details");
+        assert_eq!(system.name, "Nod");
+        assert!(system.position.is_some());
+        assert!(!system.connected_gates.is_empty());
     }
 
-    #[tokio::test]
-    async fn test_system_info_error_includes_suggestions() {
+    #[test]
+    fn test_system_info_unknown_system_includes_suggestions() {
+        let (starmap, _) = fixture_dataset();
         let input = SystemInfoInput {
-            system_name: "InvalidName".to_string(),
+            system_name: "Nodd".to_string(),
         };
 
-        let result = SystemInfoTool::execute(input).await;
-        assert!(result.is_ok());
-        let output = result.unwrap();
-        if let Some(error) = output.error {
-            assert!(!error.suggestions.is_empty());
-        }
+        let err = SystemInfoTool::lookup(&starmap, &input).expect_err("unknown");
+        assert_eq!(err.code, 404);
+        let suggestions = err.context.expect("context")["suggestions"].clone();
+        assert!(suggestions
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|name| name == "Nod"));
     }
 
     // Systems nearby tool tests
@@ -432,9 +977,12 @@ mod tests {
     async fn test_systems_nearby_validation_empty_name() {
         let input = SystemsNearbyInput {
             system_name: "".to_string(),
-            radius_ly: 50.0,
+            radius_ly: Some(50.0),
             max_temperature: None,
             limit: 20,
+            ship_name: None,
+            fuel_quality: 10.0,
+            cargo_mass_kg: 0.0,
         };
 
         let result = SystemsNearbyTool::execute(input).await;
@@ -445,9 +993,12 @@ mod tests {
     async fn test_systems_nearby_validation_zero_radius() {
         let input = SystemsNearbyInput {
             system_name: "Nod".to_string(),
-            radius_ly: 0.0,
+            radius_ly: Some(0.0),
             max_temperature: None,
             limit: 20,
+            ship_name: None,
+            fuel_quality: 10.0,
+            cargo_mass_kg: 0.0,
         };
 
         let result = SystemsNearbyTool::execute(input).await;
@@ -458,9 +1009,12 @@ mod tests {
     async fn test_systems_nearby_validation_negative_radius() {
         let input = SystemsNearbyInput {
             system_name: "Nod".to_string(),
-            radius_ly: -50.0,
+            radius_ly: Some(-50.0),
             max_temperature: None,
             limit: 20,
+            ship_name: None,
+            fuel_quality: 10.0,
+            cargo_mass_kg: 0.0,
         };
 
         let result = SystemsNearbyTool::execute(input).await;
@@ -471,9 +1025,12 @@ mod tests {
     async fn test_systems_nearby_validation_negative_temperature() {
         let input = SystemsNearbyInput {
             system_name: "Nod".to_string(),
-            radius_ly: 50.0,
+            radius_ly: Some(50.0),
             max_temperature: Some(-100.0),
             limit: 20,
+            ship_name: None,
+            fuel_quality: 10.0,
+            cargo_mass_kg: 0.0,
         };
 
         let result = SystemsNearbyTool::execute(input).await;
@@ -484,25 +1041,31 @@ mod tests {
     async fn test_systems_nearby_execution_valid_input() {
         let input = SystemsNearbyInput {
             system_name: "Nod".to_string(),
-            radius_ly: 80.0,
+            radius_ly: Some(80.0),
             max_temperature: Some(500.0),
             limit: 20,
+            ship_name: None,
+            fuel_quality: 10.0,
+            cargo_mass_kg: 0.0,
         };
 
         let result = SystemsNearbyTool::execute(input).await;
         assert!(result.is_ok());
         let output = result.unwrap();
         assert_eq!(output.center_system, "Nod");
-        assert_eq!(output.radius_ly, 80.0);
+        assert_eq!(output.radius_ly, Some(80.0));
     }
 
     #[tokio::test]
     async fn test_systems_nearby_execution_no_temperature_filter() {
         let input = SystemsNearbyInput {
             system_name: "Brana".to_string(),
-            radius_ly: 100.0,
+            radius_ly: Some(100.0),
             max_temperature: None,
             limit: 20,
+            ship_name: None,
+            fuel_quality: 10.0,
+            cargo_mass_kg: 0.0,
         };
 
         let result = SystemsNearbyTool::execute(input).await;
@@ -511,6 +1074,223 @@ mod tests {
         assert_eq!(output.center_system, "Brana");
     }
 
+    fn fixture_dataset() -> (Starmap, SpatialIndex) {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../docs/fixtures/minimal/static_data.db");
+        let starmap = evefrontier_lib::load_starmap(&path, None).expect("fixture loads");
+        let index = SpatialIndex::build(&starmap);
+        (starmap, index)
+    }
+
+    fn nearby_input(radius_ly: Option<f64>, limit: usize) -> SystemsNearbyInput {
+        SystemsNearbyInput {
+            system_name: "Nod".to_string(),
+            radius_ly,
+            max_temperature: None,
+            limit,
+            ship_name: None,
+            fuel_quality: 10.0,
+            cargo_mass_kg: 0.0,
+        }
+    }
+
+    fn names(output: &SystemsNearbyOutput) -> Vec<&str> {
+        output.systems.iter().map(|s| s.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_systems_nearby_search_radius_only() {
+        let (starmap, index) = fixture_dataset();
+
+        // Default limit (20) does not bind; the radius does.
+        let output =
+            SystemsNearbyTool::search(&starmap, &index, None, &nearby_input(Some(250.0), 20))
+                .unwrap();
+
+        assert_eq!(names(&output), ["J:35IA", "G:3OA0", "D:2NAS"]);
+        assert_eq!(output.count, 3);
+        assert!(output.systems.iter().all(|s| s.distance_ly <= 250.0));
+    }
+
+    #[test]
+    fn test_systems_nearby_search_limit_only() {
+        let (starmap, index) = fixture_dataset();
+
+        let output =
+            SystemsNearbyTool::search(&starmap, &index, None, &nearby_input(None, 2)).unwrap();
+
+        assert_eq!(names(&output), ["J:35IA", "G:3OA0"]);
+        assert_eq!(output.radius_ly, None);
+    }
+
+    #[test]
+    fn test_systems_nearby_search_limit_caps_results_within_radius() {
+        let (starmap, index) = fixture_dataset();
+
+        // Limit binds inside a radius that holds three systems.
+        let output =
+            SystemsNearbyTool::search(&starmap, &index, None, &nearby_input(Some(250.0), 2))
+                .unwrap();
+        assert_eq!(names(&output), ["J:35IA", "G:3OA0"]);
+
+        // Radius binds before a larger limit is reached.
+        let output =
+            SystemsNearbyTool::search(&starmap, &index, None, &nearby_input(Some(150.0), 5))
+                .unwrap();
+        assert_eq!(names(&output), ["J:35IA"]);
+    }
+
+    #[test]
+    fn test_systems_nearby_search_rejects_invalid_limit() {
+        let (starmap, index) = fixture_dataset();
+
+        for limit in [0, MAX_NEARBY_LIMIT + 1] {
+            let err = SystemsNearbyTool::search(&starmap, &index, None, &nearby_input(None, limit))
+                .expect_err("limit out of range");
+            assert!(err.message.contains("limit"));
+        }
+    }
+
+    #[test]
+    fn test_systems_nearby_search_unknown_system() {
+        let (starmap, index) = fixture_dataset();
+        let mut input = nearby_input(None, 5);
+        input.system_name = "Nodd".to_string();
+
+        let err = SystemsNearbyTool::search(&starmap, &index, None, &input).expect_err("unknown");
+        assert_eq!(err.code, 404);
+    }
+
+    #[test]
+    fn test_systems_nearby_search_with_ship_projects_fuel_and_heat() {
+        let (starmap, index) = fixture_dataset();
+        let catalog = fixture_ship_catalog();
+        let mut input = nearby_input(Some(250.0), 20);
+        input.ship_name = Some("Reflex".to_string());
+
+        let output = SystemsNearbyTool::search(&starmap, &index, Some(&catalog), &input).unwrap();
+
+        assert_eq!(output.ship_name.as_deref(), Some("Reflex"));
+        assert!(!output.systems.is_empty());
+        for system in &output.systems {
+            let fuel = system.fuel.as_ref().expect("fuel projected");
+            assert!(fuel.hop_cost > 0.0, "{}", system.name);
+            assert_eq!(fuel.cumulative, fuel.hop_cost);
+            let heat = system.heat.as_ref().expect("heat projected");
+            assert!(heat.hop_heat > 0.0, "{}", system.name);
+        }
+        // Farther systems cost more fuel to reach directly.
+        let costs: Vec<f64> = output
+            .systems
+            .iter()
+            .map(|s| s.fuel.as_ref().unwrap().hop_cost)
+            .collect();
+        assert!(costs.windows(2).all(|pair| pair[0] <= pair[1]), "{costs:?}");
+    }
+
+    #[test]
+    fn test_systems_nearby_search_without_ship_omits_projections() {
+        let (starmap, index) = fixture_dataset();
+        let catalog = fixture_ship_catalog();
+
+        let output = SystemsNearbyTool::search(
+            &starmap,
+            &index,
+            Some(&catalog),
+            &nearby_input(Some(250.0), 20),
+        )
+        .unwrap();
+
+        assert!(output.ship_name.is_none());
+        assert!(output
+            .systems
+            .iter()
+            .all(|system| system.fuel.is_none() && system.heat.is_none()));
+        let json = serde_json::to_value(&output).unwrap();
+        assert!(json.get("ship_name").is_none());
+        assert!(json["systems"][0].get("fuel").is_none());
+        assert!(json["systems"][0].get("heat").is_none());
+    }
+
+    #[test]
+    fn test_systems_nearby_search_unknown_ship() {
+        let (starmap, index) = fixture_dataset();
+        let catalog = fixture_ship_catalog();
+        let mut input = nearby_input(None, 5);
+        input.ship_name = Some("Nonexistent".to_string());
+
+        let err = SystemsNearbyTool::search(&starmap, &index, Some(&catalog), &input)
+            .expect_err("unknown ship");
+        assert_eq!(err.code, 400);
+        assert!(err.message.contains("ship_name"), "{}", err.message);
+    }
+
+    // Reachable systems tool tests
+    fn reachable_input(max_distance_ly: f64, limit: usize) -> ReachableSystemsInput {
+        ReachableSystemsInput {
+            system_name: "Nod".to_string(),
+            max_distance_ly,
+            max_jump: None,
+            max_temperature: None,
+            avoid_gates: false,
+            limit,
+        }
+    }
+
+    #[test]
+    fn test_reachable_systems_validation_rejects_non_positive_budget() {
+        let (starmap, index) = fixture_dataset();
+        let index = Arc::new(index);
+
+        for budget in [0.0, -10.0, f64::NAN] {
+            let result =
+                ReachableSystemsTool::search(&starmap, index.clone(), &reachable_input(budget, 20));
+            assert!(result.is_err(), "budget {budget} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_reachable_systems_search_within_budget() {
+        let (starmap, index) = fixture_dataset();
+        let index = Arc::new(index);
+
+        let output =
+            ReachableSystemsTool::search(&starmap, index, &reachable_input(250.0, 20)).unwrap();
+
+        assert!(output.count > 0);
+        assert!(output.systems.iter().all(|s| s.distance_ly <= 250.0));
+        assert!(output.systems.iter().all(|s| s.name != "Nod"));
+    }
+
+    #[test]
+    fn test_reachable_systems_search_caps_results() {
+        let (starmap, index) = fixture_dataset();
+        let index = Arc::new(index);
+
+        let output =
+            ReachableSystemsTool::search(&starmap, index, &reachable_input(10_000.0, 2)).unwrap();
+        assert_eq!(output.count, 2);
+
+        let err = ReachableSystemsTool::search(
+            &starmap,
+            Arc::new(SpatialIndex::build(&starmap)),
+            &reachable_input(100.0, MAX_REACHABLE_LIMIT + 1),
+        )
+        .expect_err("limit out of range");
+        assert!(err.message.contains("limit"));
+    }
+
+    #[test]
+    fn test_reachable_systems_search_unknown_system() {
+        let (starmap, index) = fixture_dataset();
+        let mut input = reachable_input(100.0, 5);
+        input.system_name = "Nodd".to_string();
+
+        let err =
+            ReachableSystemsTool::search(&starmap, Arc::new(index), &input).expect_err("unknown");
+        assert_eq!(err.code, 404);
+    }
+
     // Gates from tool tests
     #[tokio::test]
     async fn test_gates_from_validation_empty_name() {
@@ -560,4 +1340,152 @@ mod tests {
         let output = result.unwrap();
         assert_eq!(output.system_name, "Nod");
     }
+
+    // fmap encode tool tests
+    fn fmap_input(systems: &[&str], waypoint_types: &[&str]) -> FmapEncodeInput {
+        FmapEncodeInput {
+            systems: systems.iter().map(|s| s.to_string()).collect(),
+            waypoint_types: waypoint_types.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_fmap_encode_round_trips_with_default_types() {
+        let (starmap, _) = fixture_dataset();
+
+        let output =
+            FmapEncodeTool::encode(&starmap, &fmap_input(&["Nod", "Brana", "D:2NAS"], &[]))
+                .unwrap();
+        assert_eq!(output.waypoint_count, 3);
+        assert_eq!(output.version, evefrontier_lib::fmap::FMAP_VERSION);
+
+        let decoded = evefrontier_lib::decode_fmap_token(&output.token).unwrap();
+        assert_eq!(decoded.bit_width, output.bit_width);
+        let waypoints: Vec<_> = decoded
+            .waypoints
+            .iter()
+            .map(|w| (i64::from(w.system_id), w.waypoint_type))
+            .collect();
+        assert_eq!(
+            waypoints,
+            vec![
+                (
+                    starmap.system_id_by_name("Nod").unwrap(),
+                    WaypointType::Start
+                ),
+                (
+                    starmap.system_id_by_name("Brana").unwrap(),
+                    WaypointType::Jump
+                ),
+                (
+                    starmap.system_id_by_name("D:2NAS").unwrap(),
+                    WaypointType::SetDestination
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fmap_encode_uses_explicit_waypoint_types() {
+        let (starmap, _) = fixture_dataset();
+
+        let output = FmapEncodeTool::encode(
+            &starmap,
+            &fmap_input(&["Nod", "Brana"], &["start", "npc-gate"]),
+        )
+        .unwrap();
+        let decoded = evefrontier_lib::decode_fmap_token(&output.token).unwrap();
+        assert_eq!(decoded.waypoints[1].waypoint_type, WaypointType::NpcGate);
+    }
+
+    #[test]
+    fn test_fmap_encode_unknown_system_is_invalid_param() {
+        let (starmap, _) = fixture_dataset();
+
+        let err = FmapEncodeTool::encode(&starmap, &fmap_input(&["Nod", "Brana", "Nodd"], &[]))
+            .expect_err("unknown system");
+        assert_eq!(err.code, 400);
+        let reason = err.context.as_ref().unwrap()["reason"].as_str().unwrap();
+        assert!(reason.contains("Unknown system 'Nodd'"), "{reason}");
+        assert!(reason.contains("Nod"), "{reason}");
+    }
+
+    #[test]
+    fn test_fmap_encode_rejects_invalid_input() {
+        let (starmap, _) = fixture_dataset();
+
+        for (input, parameter) in [
+            (fmap_input(&[], &[]), "systems"),
+            (fmap_input(&["Nod"], &["start", "jump"]), "waypoint_types"),
+            (
+                fmap_input(&["Nod", "Brana"], &["start", "warp"]),
+                "waypoint_types",
+            ),
+        ] {
+            let err = FmapEncodeTool::encode(&starmap, &input).expect_err("invalid input");
+            assert_eq!(err.context.unwrap()["parameter"], parameter);
+        }
+    }
+
+    fn fixture_ship_catalog() -> ShipCatalog {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../docs/fixtures/minimal/ship_data.csv");
+        ShipCatalog::from_path(&path).expect("fixture ship data loads")
+    }
+
+    fn fuel_input(ship_name: &str) -> FuelProjectionInput {
+        FuelProjectionInput {
+            origin: "Nod".to_string(),
+            destination: "E1J-M5G".to_string(),
+            ship_name: ship_name.to_string(),
+            fuel_quality: 10.0,
+            cargo_mass_kg: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_fuel_projection_for_fixture_ship() {
+        let (starmap, index) = fixture_dataset();
+        let catalog = fixture_ship_catalog();
+
+        // E1J-M5G has no gates, so the route has to end with a fuel-burning jump.
+        let output =
+            FuelProjectionTool::project(&starmap, Arc::new(index), &catalog, &fuel_input("Reflex"))
+                .unwrap();
+
+        assert_eq!(output.ship_name, "Reflex");
+        assert_eq!(output.steps.len(), output.hops + 1);
+        assert_eq!(output.steps.first().unwrap().system, "Nod");
+        assert_eq!(output.steps.last().unwrap().system, "E1J-M5G");
+        assert!(output.steps[0].hop_fuel.is_none());
+        assert!(output.total_fuel > 0.0);
+
+        let last = output.steps.last().unwrap();
+        assert_eq!(last.method.as_deref(), Some("jump"));
+        assert!(last.hop_fuel.unwrap() > 0.0);
+        assert!((last.cumulative_fuel.unwrap() - output.total_fuel).abs() < 1e-9);
+        assert_eq!(
+            output.warnings.len(),
+            output.steps.iter().filter(|s| s.warning.is_some()).count()
+        );
+    }
+
+    #[test]
+    fn test_fuel_projection_unknown_ship() {
+        let (starmap, index) = fixture_dataset();
+        let catalog = fixture_ship_catalog();
+
+        let err = FuelProjectionTool::project(
+            &starmap,
+            Arc::new(index),
+            &catalog,
+            &fuel_input("Nonexistent"),
+        )
+        .expect_err("unknown ship");
+
+        assert_eq!(err.code, 400);
+        let context = err.context.unwrap();
+        assert_eq!(context["parameter"], "ship_name");
+        assert!(context["reason"].as_str().unwrap().contains("Reflex"));
+    }
 }
@@ -3,6 +3,8 @@
 //! This module defines all the serializable request and response types
 //! for MCP tools, with JSON Schema generation for automatic validation.
 
+use evefrontier_lib::ship::HeatProjection;
+use evefrontier_lib::{FuelProjection, NeighbourQuery};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -46,26 +48,88 @@ pub struct SystemInfoInput {
 }
 
 /// Input for the systems_nearby tool
+///
+/// `radius_ly` and `limit` combine: results are the nearest systems to the
+/// center, at most `limit` of them, and (when `radius_ly` is set) only those
+/// within the radius. Without a radius this is a plain k-nearest query.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct SystemsNearbyInput {
     /// Center system name (required)
     pub system_name: String,
 
-    /// Search radius in light-years (required)
-    pub radius_ly: f64,
+    /// Search radius in light-years (optional; omit for the nearest `limit` systems)
+    #[serde(default)]
+    pub radius_ly: Option<f64>,
 
     /// Maximum system temperature in Kelvin (optional)
     pub max_temperature: Option<f64>,
 
-    /// Maximum number of results (default: 20, max: 100)
+    /// Maximum number of results (default: 20, max: 100); caps results within the radius
     #[serde(default = "default_limit")]
     pub limit: usize,
+
+    /// Ship name from the ship catalog (optional); adds per-system fuel and heat
+    #[serde(default)]
+    pub ship_name: Option<String>,
+
+    /// Fuel quality percentage, 1-100 (default: 10); used with `ship_name`
+    #[serde(default = "default_fuel_quality")]
+    pub fuel_quality: f64,
+
+    /// Cargo mass in kilograms (default: 0); used with `ship_name`
+    #[serde(default)]
+    pub cargo_mass_kg: f64,
 }
 
 fn default_limit() -> usize {
     20
 }
 
+/// Upper bound for `SystemsNearbyInput::limit`
+pub const MAX_NEARBY_LIMIT: usize = 100;
+
+impl SystemsNearbyInput {
+    /// Spatial query equivalent to this input (the center system is not excluded)
+    pub fn neighbour_query(&self) -> NeighbourQuery {
+        NeighbourQuery {
+            k: self.limit,
+            radius: self.radius_ly,
+            max_temperature: self.max_temperature,
+        }
+    }
+}
+
+/// Input for the reachable_systems tool
+///
+/// Floods outward from the origin over gates and spatial jumps, returning every
+/// system whose cheapest total travel distance stays within `max_distance_ly`,
+/// at most `limit` of them (nearest first).
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ReachableSystemsInput {
+    /// Origin system name (required)
+    pub system_name: String,
+
+    /// Total travel budget in light-years (required)
+    pub max_distance_ly: f64,
+
+    /// Maximum single spatial jump in light-years (optional)
+    pub max_jump: Option<f64>,
+
+    /// Maximum system temperature in Kelvin (optional)
+    pub max_temperature: Option<f64>,
+
+    /// Use spatial jumps only, ignore jump gates (default: false)
+    #[serde(default)]
+    pub avoid_gates: bool,
+
+    /// Maximum number of results (default: 20, max: 100)
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+/// Upper bound for `ReachableSystemsInput::limit`
+pub const MAX_REACHABLE_LIMIT: usize = 100;
+
 /// Input for the gates_from tool
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct GatesFromInput {
@@ -73,6 +137,45 @@ pub struct GatesFromInput {
     pub system_name: String,
 }
 
+/// Input for the fmap_encode tool
+///
+/// Waypoint types pair with `systems` by position. Missing entries default to
+/// "start" for the first system, "destination" for the last, and "jump" otherwise.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct FmapEncodeInput {
+    /// System names in route order (at least one)
+    pub systems: Vec<String>,
+
+    /// Waypoint types: "start", "jump", "npc-gate", "smart-gate", or "destination" (optional)
+    #[serde(default)]
+    pub waypoint_types: Vec<String>,
+}
+
+/// Input for the fuel_projection tool
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct FuelProjectionInput {
+    /// Origin system name
+    pub origin: String,
+
+    /// Destination system name
+    pub destination: String,
+
+    /// Ship name from the ship catalog (e.g. "Reflex")
+    pub ship_name: String,
+
+    /// Fuel quality percentage, 1-100 (default: 10)
+    #[serde(default = "default_fuel_quality")]
+    pub fuel_quality: f64,
+
+    /// Cargo mass in kilograms (default: 0)
+    #[serde(default)]
+    pub cargo_mass_kg: f64,
+}
+
+fn default_fuel_quality() -> f64 {
+    10.0
+}
+
 // ============================================================================
 // TOOL OUTPUTS
 // ============================================================================
@@ -166,8 +269,8 @@ pub struct SystemInfoOutput {
 pub struct SystemDetails {
     pub system_id: u64,
     pub name: String,
-    pub position: Position3D,
-    pub min_external_temperature_k: f64,
+    pub position: Option<Position3D>,
+    pub min_external_temperature_k: Option<f64>,
     pub planet_count: usize,
     pub moon_count: usize,
     pub connected_gates: Vec<GateConnection>,
@@ -190,7 +293,10 @@ pub struct SystemError {
 #[derive(Debug, Clone, Serialize)]
 pub struct SystemsNearbyOutput {
     pub center_system: String,
-    pub radius_ly: f64,
+    pub radius_ly: Option<f64>,
+    /// Ship the fuel and heat projections were computed for, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ship_name: Option<String>,
     pub count: usize,
     pub systems: Vec<NearbySystem>,
 }
@@ -200,7 +306,31 @@ pub struct NearbySystem {
     pub name: String,
     pub system_id: u64,
     pub distance_ly: f64,
-    pub min_temperature_k: f64,
+    pub min_temperature_k: Option<f64>,
+    /// Fuel for a direct jump from the center on a full tank (only with `ship_name`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fuel: Option<FuelProjection>,
+    /// Heat for a direct jump from the center (only with `ship_name`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heat: Option<HeatProjection>,
+}
+
+/// Output from the reachable_systems tool
+#[derive(Debug, Clone, Serialize)]
+pub struct ReachableSystemsOutput {
+    pub origin_system: String,
+    pub max_distance_ly: f64,
+    pub count: usize,
+    pub systems: Vec<ReachableSystem>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReachableSystem {
+    pub name: String,
+    pub system_id: u64,
+    /// Cheapest total travel distance from the origin in light-years
+    pub distance_ly: f64,
+    pub min_temperature_k: Option<f64>,
 }
 
 /// Output from the gates_from tool
@@ -211,6 +341,51 @@ pub struct GatesFromOutput {
     pub gates: Vec<GateConnection>,
 }
 
+/// Output from the fmap_encode tool
+#[derive(Debug, Clone, Serialize)]
+pub struct FmapEncodeOutput {
+    /// Base64url-encoded, gzipped route token
+    pub token: String,
+    pub waypoint_count: usize,
+    /// Bits used per system ID offset
+    pub bit_width: u8,
+    /// fmap format version
+    pub version: u8,
+}
+
+/// Output from the fuel_projection tool
+#[derive(Debug, Clone, Serialize)]
+pub struct FuelProjectionOutput {
+    pub origin: String,
+    pub destination: String,
+    pub ship_name: String,
+    pub fuel_quality: f64,
+    pub hops: usize,
+    pub total_distance_ly: f64,
+    /// Fuel burned across the whole route
+    pub total_fuel: f64,
+    /// Fuel left in the tank on arrival
+    pub remaining_fuel: Option<f64>,
+    /// Step indices where the tank must be refilled
+    pub refuel_at: Vec<usize>,
+    pub warnings: Vec<String>,
+    pub steps: Vec<FuelProjectionStep>,
+}
+
+/// One system along a fuel_projection route; the origin carries no fuel data
+#[derive(Debug, Clone, Serialize)]
+pub struct FuelProjectionStep {
+    pub system: String,
+    pub system_id: u64,
+    /// How the system was reached: "gate" or "jump"
+    pub method: Option<String>,
+    pub distance_ly: Option<f64>,
+    pub hop_fuel: Option<f64>,
+    pub cumulative_fuel: Option<f64>,
+    pub remaining_fuel: Option<f64>,
+    pub warning: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -257,6 +432,50 @@ mod tests {
         assert_eq!(input.limit, 20);
     }
 
+    #[test]
+    fn test_systems_nearby_input_radius_is_optional() {
+        let json = r#"{
+            "system_name": "Brana",
+            "limit": 5
+        }"#;
+
+        let input: SystemsNearbyInput = serde_json::from_str(json).unwrap();
+        assert_eq!(input.radius_ly, None);
+
+        let query = input.neighbour_query();
+        assert_eq!(query.k, 5);
+        assert_eq!(query.radius, None);
+    }
+
+    #[test]
+    fn test_systems_nearby_query_combines_radius_and_limit() {
+        let json = r#"{
+            "system_name": "Brana",
+            "radius_ly": 50.0,
+            "limit": 3,
+            "max_temperature": 400.0
+        }"#;
+
+        let input: SystemsNearbyInput = serde_json::from_str(json).unwrap();
+        let query = input.neighbour_query();
+        assert_eq!(query.k, 3);
+        assert_eq!(query.radius, Some(50.0));
+        assert_eq!(query.max_temperature, Some(400.0));
+    }
+
+    #[test]
+    fn test_reachable_systems_input_defaults() {
+        let json = r#"{
+            "system_name": "Nod",
+            "max_distance_ly": 120.0
+        }"#;
+
+        let input: ReachableSystemsInput = serde_json::from_str(json).unwrap();
+        assert_eq!(input.limit, 20);
+        assert_eq!(input.max_jump, None);
+        assert!(!input.avoid_gates);
+    }
+
     #[test]
     fn test_route_plan_output_serialization() {
         let output = RoutePlanOutput {
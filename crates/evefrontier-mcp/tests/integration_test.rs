@@ -95,13 +95,16 @@ fn test_tools_list() {
     assert!(response["result"]["tools"].is_array());
 
     let tools = response["result"]["tools"].as_array().unwrap();
-    assert_eq!(tools.len(), 4);
+    assert_eq!(tools.len(), 7);
 
     let tool_names: Vec<_> = tools.iter().map(|t| t["name"].as_str().unwrap()).collect();
     assert!(tool_names.contains(&"route_plan"));
     assert!(tool_names.contains(&"system_info"));
     assert!(tool_names.contains(&"systems_nearby"));
+    assert!(tool_names.contains(&"reachable_systems"));
     assert!(tool_names.contains(&"gates_from"));
+    assert!(tool_names.contains(&"fmap_encode"));
+    assert!(tool_names.contains(&"fuel_projection"));
 
     server.kill().ok();
     server.wait().ok();
@@ -169,6 +172,7 @@ fn test_tools_call_route_plan_stub() {
     let output: Value = serde_json::from_str(text).unwrap();
     assert_eq!(output["success"], false);
     assert_eq!(output["error"]["code"], "NOT_IMPLEMENTED");
+    assert!(response["result"].get("isError").is_none());
 
     server.kill().ok();
     server.wait().ok();
@@ -203,6 +207,238 @@ fn test_tools_call_invalid_tool() {
     server.wait().ok();
 }
 
+#[test]
+fn test_tools_call_unknown_system_sets_is_error() {
+    let mut server = spawn_server().expect("Failed to spawn server");
+
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 12,
+        "method": "tools/call",
+        "params": {
+            "name": "system_info",
+            "arguments": { "system_name": "Nodd" }
+        }
+    });
+
+    let response = send_request(&mut server, request).expect("Failed to get response");
+
+    assert_eq!(response["id"], 12);
+    assert!(response.get("error").is_none(), "{response}");
+    assert_eq!(response["result"]["isError"], true);
+    let text = response["result"]["content"][0]["text"].as_str().unwrap();
+    assert!(text.contains("System 'Nodd' not found"), "{text}");
+    assert!(text.contains("Did you mean"), "{text}");
+
+    server.kill().ok();
+    server.wait().ok();
+}
+
+#[test]
+fn test_tools_call_known_system_does_not_set_is_error() {
+    let mut server = spawn_server().expect("Failed to spawn server");
+
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 19,
+        "method": "tools/call",
+        "params": {
+            "name": "system_info",
+            "arguments": { "system_name": "Nod" }
+        }
+    });
+
+    let response = send_request(&mut server, request).expect("Failed to get response");
+
+    assert_eq!(response["id"], 19);
+    assert!(response.get("error").is_none(), "{response}");
+    assert!(response["result"].get("isError").is_none(), "{response}");
+    let text = response["result"]["content"][0]["text"].as_str().unwrap();
+    let output: Value = serde_json::from_str(text).expect("tool output is JSON");
+    assert_eq!(output["found"], true);
+    assert_eq!(output["system"]["name"], "Nod");
+
+    server.kill().ok();
+    server.wait().ok();
+}
+
+#[test]
+fn test_tools_call_invalid_argument_sets_is_error() {
+    let mut server = spawn_server().expect("Failed to spawn server");
+
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 13,
+        "method": "tools/call",
+        "params": {
+            "name": "systems_nearby",
+            "arguments": { "system_name": "Nod", "radius_ly": -1.0 }
+        }
+    });
+
+    let response = send_request(&mut server, request).expect("Failed to get response");
+
+    assert!(response.get("error").is_none(), "{response}");
+    assert_eq!(response["result"]["isError"], true);
+    let text = response["result"]["content"][0]["text"].as_str().unwrap();
+    assert!(text.contains("radius_ly"), "{text}");
+
+    server.kill().ok();
+    server.wait().ok();
+}
+
+#[test]
+fn test_tools_call_fmap_encode() {
+    let mut server = spawn_server().expect("Failed to spawn server");
+
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 14,
+        "method": "tools/call",
+        "params": {
+            "name": "fmap_encode",
+            "arguments": { "systems": ["Nod", "Brana"] }
+        }
+    });
+
+    let response = send_request(&mut server, request).expect("Failed to get response");
+
+    assert_eq!(response["id"], 14);
+    assert!(response["result"].get("isError").is_none(), "{response}");
+    let text = response["result"]["content"][0]["text"].as_str().unwrap();
+    let output: Value = serde_json::from_str(text).expect("tool output is JSON");
+    assert!(!output["token"].as_str().unwrap().is_empty());
+    assert_eq!(output["waypoint_count"], 2);
+    assert_eq!(output["version"], 1);
+    assert!(output["bit_width"].as_u64().unwrap() > 0);
+
+    server.kill().ok();
+    server.wait().ok();
+}
+
+#[test]
+fn test_tools_call_fmap_encode_unknown_system() {
+    let mut server = spawn_server().expect("Failed to spawn server");
+
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 15,
+        "method": "tools/call",
+        "params": {
+            "name": "fmap_encode",
+            "arguments": { "systems": ["Nod", "Nodd"] }
+        }
+    });
+
+    let response = send_request(&mut server, request).expect("Failed to get response");
+
+    assert!(response.get("error").is_none(), "{response}");
+    assert_eq!(response["result"]["isError"], true);
+    let text = response["result"]["content"][0]["text"].as_str().unwrap();
+    assert!(text.contains("Invalid parameter: systems"), "{text}");
+    assert!(text.contains("Unknown system 'Nodd'"), "{text}");
+
+    server.kill().ok();
+    server.wait().ok();
+}
+
+#[test]
+fn test_tools_call_fuel_projection_unknown_ship() {
+    let mut server = spawn_server().expect("Failed to spawn server");
+
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 16,
+        "method": "tools/call",
+        "params": {
+            "name": "fuel_projection",
+            "arguments": { "origin": "Nod", "destination": "Brana", "ship_name": "Nonexistent" }
+        }
+    });
+
+    let response = send_request(&mut server, request).expect("Failed to get response");
+
+    assert!(response.get("error").is_none(), "{response}");
+    assert_eq!(response["result"]["isError"], true);
+    let text = response["result"]["content"][0]["text"].as_str().unwrap();
+    assert!(text.contains("Invalid parameter: ship_name"), "{text}");
+    assert!(text.contains("Reflex"), "{text}");
+
+    server.kill().ok();
+    server.wait().ok();
+}
+
+#[test]
+fn test_tools_call_systems_nearby_with_ship() {
+    let mut server = spawn_server().expect("Failed to spawn server");
+
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 17,
+        "method": "tools/call",
+        "params": {
+            "name": "systems_nearby",
+            "arguments": { "system_name": "Nod", "limit": 3, "ship_name": "Reflex" }
+        }
+    });
+
+    let response = send_request(&mut server, request).expect("Failed to get response");
+
+    assert!(response.get("error").is_none(), "{response}");
+    assert!(response["result"].get("isError").is_none(), "{response}");
+    let text = response["result"]["content"][0]["text"].as_str().unwrap();
+    let output: Value = serde_json::from_str(text).expect("tool output is JSON");
+    assert_eq!(output["ship_name"], "Reflex");
+    let systems = output["systems"].as_array().unwrap();
+    assert_eq!(systems.len(), 3);
+    for system in systems {
+        assert!(
+            system["fuel"]["hop_cost"].as_f64().unwrap() > 0.0,
+            "{system}"
+        );
+        assert!(
+            system["heat"]["hop_heat"].as_f64().unwrap() > 0.0,
+            "{system}"
+        );
+    }
+
+    server.kill().ok();
+    server.wait().ok();
+}
+
+#[test]
+fn test_tools_call_reachable_systems() {
+    let mut server = spawn_server().expect("Failed to spawn server");
+
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 18,
+        "method": "tools/call",
+        "params": {
+            "name": "reachable_systems",
+            "arguments": { "system_name": "Nod", "max_distance_ly": 250.0 }
+        }
+    });
+
+    let response = send_request(&mut server, request).expect("Failed to get response");
+
+    assert!(response.get("error").is_none(), "{response}");
+    assert!(response["result"].get("isError").is_none(), "{response}");
+    let text = response["result"]["content"][0]["text"].as_str().unwrap();
+    let output: Value = serde_json::from_str(text).expect("tool output is JSON");
+    assert_eq!(output["origin_system"], "Nod");
+    let systems = output["systems"].as_array().unwrap();
+    assert!(!systems.is_empty(), "{output}");
+    assert_eq!(output["count"].as_u64().unwrap() as usize, systems.len());
+    for system in systems {
+        assert_ne!(system["name"], "Nod");
+        assert!(system["distance_ly"].as_f64().unwrap() <= 250.0, "{system}");
+    }
+
+    server.kill().ok();
+    server.wait().ok();
+}
+
 #[test]
 fn test_invalid_json_rpc_version() {
     let mut server = spawn_server().expect("Failed to spawn server");
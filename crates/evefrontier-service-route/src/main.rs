@@ -6,6 +6,8 @@
 //! # Endpoints
 //!
 //! - `POST /api/v1/route` - Compute a route between two systems
+//! - `POST /api/v1/route/compare` - Run BFS, Dijkstra, and A* side by side
+//! - `GET /openapi.json` - OpenAPI 3.1 description of the route endpoint
 //! - `GET /metrics` - Prometheus metrics endpoint
 //! - `GET /health/live` - Kubernetes liveness probe
 //! - `GET /health/ready` - Kubernetes readiness probe
@@ -16,9 +18,16 @@
 //! - `RUST_LOG` - Log level (default: info)
 //! - `LOG_FORMAT` - Log format: json (default) or text
 //! - `SERVICE_PORT` - HTTP port (default: 8080)
+//! - `DEFAULT_ROUTE_ALGORITHM` - Algorithm for requests that omit one (default: a-star)
+//! - `RATE_LIMIT_RPS` / `RATE_LIMIT_BURST` - Per-IP rate limit (default: off)
+//! - `SHUTDOWN_GRACE_SECS` - Seconds in-flight requests get to finish after SIGTERM (default: 25)
+
+mod openapi;
 
 use std::env;
+use std::future::IntoFuture;
 use std::net::SocketAddr;
+use std::time::Instant;
 
 use axum::{
     Json, Router,
@@ -31,13 +40,16 @@ use serde::Serialize;
 use tracing::{error, info};
 
 use evefrontier_lib::{
-    RouteAlgorithm as LibAlgorithm, RouteConstraints as LibConstraints, RouteRequest as LibRequest,
-    plan_route,
+    HopRationale, RouteAlgorithm as LibAlgorithm, RouteConstraints as LibConstraints,
+    RouteOutputKind, RoutePlan, RouteRequest as LibRequest, RouteSummary, Starmap, SystemId,
+    explain_route, plan_route,
 };
 use evefrontier_service_shared::{
-    AppState, LoggingConfig, MetricsConfig, MetricsLayer, ProblemDetails, RouteRequest,
+    AppState, DatasetHeadersLayer, GracefulShutdown, LoggingConfig, MetricsConfig, MetricsLayer,
+    ProblemDetails, RateLimitLayer, RouteAlgorithm, RouteCompareRequest, RouteRequest,
     ServiceResponse, Validate, from_lib_error, health_live, health_ready, init_logging,
-    init_metrics, metrics_handler, record_route_calculated, record_route_failed, record_route_hops,
+    init_metrics, metrics_handler, record_build_info, record_route_calculated,
+    record_route_duration, record_route_error, record_route_failed, record_route_hops,
 };
 
 /// Route response returned to the caller.
@@ -53,17 +65,71 @@ struct RouteResponse {
     algorithm: String,
     /// Ordered list of system names in the route.
     route: Vec<String>,
+    /// Per-system details for each entry in `route`, in the same order.
+    steps: Vec<RouteStepResponse>,
+    /// Per-hop rationale, present only when the request sets `explain`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rationale: Option<Vec<HopRationale>>,
+    /// Constraints the route was planned with; send them back as the request's
+    /// `constraints` to replay the route.
+    constraints: LibConstraints,
+}
+
+/// Celestial details for one system along a route.
+#[derive(Debug, Serialize, PartialEq)]
+struct RouteStepResponse {
+    /// System name.
+    system: String,
+    /// Number of planets in the system (omitted when the dataset has none recorded).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    planet_count: Option<u32>,
+    /// Number of moons in the system (omitted when the dataset has none recorded).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    moon_count: Option<u32>,
+}
+
+/// One algorithm's result within a route comparison.
+#[derive(Debug, Serialize)]
+struct AlgorithmComparison {
+    /// Algorithm used.
+    algorithm: String,
+    /// Total number of hops in the route.
+    hops: usize,
+    /// Number of gate jumps.
+    gates: usize,
+    /// Number of spatial jumps.
+    jumps: usize,
+    /// Total distance travelled in light-years.
+    distance_ly: f64,
+    /// Wall-clock time spent planning, in milliseconds.
+    latency_ms: f64,
+    /// Ordered list of system names in the route.
+    route: Vec<String>,
+    /// Earlier algorithm in `results` that produced exactly the same path, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    same_path_as: Option<String>,
+}
+
+/// Route comparison response returned to the caller.
+#[derive(Debug, Serialize)]
+struct RouteCompareResponse {
+    /// Starting system name.
+    from: String,
+    /// Destination system name.
+    to: String,
+    /// Per-algorithm results, in BFS, Dijkstra, A* order.
+    results: Vec<AlgorithmComparison>,
 }
 
 /// HTTP response - either success or RFC 9457 error.
 #[derive(Debug, Serialize)]
 #[serde(untagged)]
-enum Response {
-    Success(ServiceResponse<RouteResponse>),
+enum Response<T> {
+    Success(ServiceResponse<T>),
     Error(ProblemDetails),
 }
 
-impl IntoResponse for Response {
+impl<T: Serialize> IntoResponse for Response<T> {
     fn into_response(self) -> axum::response::Response {
         match self {
             Response::Success(data) => (StatusCode::OK, Json(data)).into_response(),
@@ -78,13 +144,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let logging_config = LoggingConfig::from_env().with_service("route");
     init_logging(&logging_config);
 
-    // Initialize metrics
-    let metrics_config = MetricsConfig::from_env();
-    if let Err(e) = init_metrics(&metrics_config) {
-        // Log but don't fail - metrics are optional
-        tracing::warn!(error = %e, "failed to initialize metrics, continuing without metrics");
-    }
-
     // Load configuration from environment
     let data_path =
         env::var("EVEFRONTIER_DATA_PATH").unwrap_or_else(|_| "/data/static_data.db".to_string());
@@ -96,41 +155,86 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!(data_path = %data_path, port = port, "starting route service");
 
     // Load application state
-    let state = AppState::load(&data_path).map_err(|e| {
-        error!(error = %e, path = %data_path, "failed to load application state");
-        e
-    })?;
+    let state = AppState::load(&data_path)
+        .and_then(AppState::with_default_algorithm_from_env)
+        .map_err(|e| {
+            error!(error = %e, path = %data_path, "failed to load application state");
+            e
+        })?;
 
     info!(
         systems = state.starmap().systems.len(),
         spatial_index = state.has_spatial_index(),
+        default_algorithm = ?state.default_algorithm(),
         "application state loaded"
     );
 
+    // Initialize metrics, labelled with the loaded dataset release
+    let metrics_config = MetricsConfig::from_env()
+        .with_dataset_release(
+            state
+                .dataset_metadata()
+                .and_then(|metadata| metadata.release_tag.clone()),
+        )
+        .with_app_version(env!("CARGO_PKG_VERSION"));
+    if let Err(e) = init_metrics(&metrics_config) {
+        // Log but don't fail - metrics are optional
+        tracing::warn!(error = %e, "failed to initialize metrics, continuing without metrics");
+    }
+    record_build_info(
+        &metrics_config.app_version,
+        metrics_config.dataset_release.as_deref(),
+    );
+
     // Build the router
-    let app = Router::new()
-        .route("/api/v1/route", post(route_handler))
-        .route("/metrics", get(metrics_handler))
-        .route("/health/live", get(health_live))
-        .route("/health/ready", get(health_ready))
-        .layer(MetricsLayer)
-        .with_state(state);
+    let app = router(state);
 
     // Bind and serve
     let addr = SocketAddr::from(([0, 0, 0, 0], port));
     info!(addr = %addr, "listening on");
 
     let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
+    let shutdown = GracefulShutdown::from_env();
+    let server = axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown.signal());
+
+    // Stop accepting connections on SIGTERM/SIGINT, then give in-flight requests
+    // the grace period to finish.
+    tokio::select! {
+        result = server.into_future() => result?,
+        () = shutdown.grace_elapsed() => tracing::warn!(
+            grace_period_secs = shutdown.grace_period().as_secs(),
+            "shutdown grace period elapsed; dropping remaining connections"
+        ),
+    }
+    info!("shutdown complete");
 
     Ok(())
 }
 
+/// Build the service router with all endpoints and middleware layers.
+fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/api/v1/route", post(route_handler))
+        .route("/api/v1/route/compare", post(compare_handler))
+        .route(openapi::OPENAPI_PATH, get(openapi_handler))
+        .route("/metrics", get(metrics_handler))
+        .route("/health/live", get(health_live))
+        .route("/health/ready", get(health_ready))
+        .layer(RateLimitLayer::from_env())
+        .layer(MetricsLayer)
+        .layer(DatasetHeadersLayer::new(state.dataset_metadata()))
+        .with_state(state)
+}
+
 /// Handle POST /api/v1/route requests.
 async fn route_handler(
     State(state): State<AppState>,
     Json(request): Json<RouteRequest>,
-) -> Response {
+) -> Response<RouteResponse> {
     // Generate a request ID for tracing
     let request_id = generate_request_id();
 
@@ -149,56 +253,39 @@ async fn route_handler(
     }
 
     let starmap = state.starmap();
+    let lib_request = to_lib_request(&state, &request);
 
-    // Convert to library request
-    let lib_request = LibRequest {
-        start: request.from.clone(),
-        goal: request.to.clone(),
-        algorithm: LibAlgorithm::from(request.algorithm),
-        constraints: LibConstraints {
-            max_jump: request.max_jump,
-            avoid_systems: request.avoid.clone(),
-            avoid_gates: request.avoid_gates,
-            max_temperature: request.max_temperature,
-            // NOTE: `avoid_critical_state` is intentionally not exposed on the service API in
-            // this change and is currently CLI-only. If we decide to support it via the
-            // service, add a request field, validation, and tests; consider adding a
-            // follow-up issue to track the work.
-            avoid_critical_state: false,
-            ship: None,
-            loadout: None,
-            heat_config: None,
-        },
-        spatial_index: state.spatial_index_arc(),
-        max_spatial_neighbors: evefrontier_lib::GraphBuildOptions::default().max_spatial_neighbors,
-        optimization: evefrontier_lib::routing::RouteOptimization::Distance,
-        fuel_config: evefrontier_lib::ship::FuelConfig::default(),
-    };
-
-    // Plan the route
-    let plan = match plan_route(starmap, &lib_request) {
+    // Plan the route, timing only the planner itself
+    let started = Instant::now();
+    let planned = plan_route(starmap, &lib_request);
+    record_route_duration(
+        started.elapsed().as_secs_f64(),
+        &lib_request.algorithm.to_string().to_lowercase(),
+    );
+    let plan = match planned {
         Ok(plan) => plan,
         Err(e) => {
             error!(request_id = %request_id, error = %e, "route planning failed");
-            // Determine failure reason from error
-            let reason = if e.to_string().contains("Unknown system") {
-                "unknown_system"
-            } else if e.to_string().contains("No path") || e.to_string().contains("no path") {
-                "no_path"
-            } else {
-                "internal_error"
-            };
-            record_route_failed(reason, "route");
+            record_route_error(&e, "route");
             return Response::Error(from_lib_error(&e, &request_id));
         }
     };
 
     // Convert system IDs to names
-    let route: Vec<String> = plan
-        .steps
-        .iter()
-        .filter_map(|&id| starmap.system_name(id).map(String::from))
-        .collect();
+    let steps = route_steps(starmap, &plan);
+    let route: Vec<String> = steps.iter().map(|step| step.system.clone()).collect();
+
+    let rationale = if request.explain {
+        match explain_route(starmap, &lib_request, &plan) {
+            Ok(rationale) => Some(rationale),
+            Err(e) => {
+                record_route_error(&e, "route");
+                return Response::Error(from_lib_error(&e, &request_id));
+            }
+        }
+    } else {
+        None
+    };
 
     let algorithm_name = plan.algorithm.to_string();
     let hops = plan.hop_count();
@@ -209,6 +296,9 @@ async fn route_handler(
         jumps: plan.jumps,
         algorithm: algorithm_name.clone(),
         route,
+        steps,
+        rationale,
+        constraints: lib_request.constraints.clone(),
     };
 
     // Record business metrics
@@ -226,6 +316,153 @@ async fn route_handler(
     Response::Success(ServiceResponse::new(response))
 }
 
+/// Handle GET /openapi.json requests.
+async fn openapi_handler() -> Json<serde_json::Value> {
+    Json(openapi::document())
+}
+
+/// Handle POST /api/v1/route/compare requests.
+async fn compare_handler(
+    State(state): State<AppState>,
+    Json(request): Json<RouteCompareRequest>,
+) -> Response<RouteCompareResponse> {
+    let request_id = generate_request_id();
+
+    info!(
+        request_id = %request_id,
+        from = %request.from,
+        to = %request.to,
+        "handling route comparison request"
+    );
+
+    if let Err(problem) = request.validate(&request_id) {
+        record_route_failed("validation_error", "route");
+        return Response::Error(*problem);
+    }
+
+    match compare_routes(&state, &request, &request_id) {
+        Ok(response) => Response::Success(ServiceResponse::new(response)),
+        Err(problem) => Response::Error(*problem),
+    }
+}
+
+/// Plan the same route with every algorithm, sharing the loaded starmap.
+fn compare_routes(
+    state: &AppState,
+    request: &RouteCompareRequest,
+    request_id: &str,
+) -> Result<RouteCompareResponse, Box<ProblemDetails>> {
+    let starmap = state.starmap();
+    let mut results = Vec::with_capacity(RouteCompareRequest::ALGORITHMS.len());
+    let mut route_hashes: Vec<(u64, Vec<SystemId>, String)> = Vec::new();
+
+    for algorithm in RouteCompareRequest::ALGORITHMS {
+        let lib_request = to_lib_request(state, &request.with_algorithm(algorithm));
+
+        let started = Instant::now();
+        let planned = plan_route(starmap, &lib_request);
+        let latency_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+        let plan = planned.map_err(|e| {
+            error!(request_id = %request_id, error = %e, "route comparison failed");
+            record_route_error(&e, "route");
+            Box::new(from_lib_error(&e, request_id))
+        })?;
+        let summary =
+            RouteSummary::from_plan(RouteOutputKind::Route, starmap, &plan, Some(&lib_request))
+                .map_err(|e| Box::new(from_lib_error(&e, request_id)))?;
+
+        let algorithm_name = plan.algorithm.to_string();
+        record_route_calculated(&algorithm_name.to_lowercase(), "route");
+        record_route_hops(plan.hop_count(), &algorithm_name.to_lowercase());
+
+        let route_hash = plan.route_hash();
+        let same_path_as = route_hashes
+            .iter()
+            .find(|(hash, steps, _)| *hash == route_hash && *steps == plan.steps)
+            .map(|(_, _, algorithm)| algorithm.clone());
+        route_hashes.push((route_hash, plan.steps.clone(), algorithm_name.clone()));
+
+        results.push(AlgorithmComparison {
+            algorithm: algorithm_name,
+            hops: plan.hop_count(),
+            gates: plan.gates,
+            jumps: plan.jumps,
+            distance_ly: summary.total_distance,
+            latency_ms,
+            route: plan
+                .steps
+                .iter()
+                .filter_map(|&id| starmap.system_name(id).map(String::from))
+                .collect(),
+            same_path_as,
+        });
+    }
+
+    info!(
+        request_id = %request_id,
+        algorithms = results.len(),
+        "route comparison computed successfully"
+    );
+
+    Ok(RouteCompareResponse {
+        from: request.from.clone(),
+        to: request.to.clone(),
+        results,
+    })
+}
+
+/// Build the per-system step details for a plan, skipping IDs missing from the starmap.
+fn route_steps(starmap: &Starmap, plan: &RoutePlan) -> Vec<RouteStepResponse> {
+    plan.steps
+        .iter()
+        .filter_map(|id| starmap.systems.get(id))
+        .map(|system| RouteStepResponse {
+            system: system.name.clone(),
+            planet_count: system.metadata.planet_count,
+            moon_count: system.metadata.moon_count,
+        })
+        .collect()
+}
+
+/// Convert a validated service request into a library route request.
+fn to_lib_request(state: &AppState, request: &RouteRequest) -> LibRequest {
+    // The service plans without a ship, so a preset or replayed constraint block contributes
+    // only its plain constraints. Validation rejects requests that set both.
+    let base = match (&request.constraints, request.preset) {
+        (Some(constraints), _) => constraints.clone(),
+        (None, preset) => preset.map(LibConstraints::preset).unwrap_or_default(),
+    };
+    LibRequest {
+        start: request.from.clone(),
+        goal: request.to.clone(),
+        algorithm: LibAlgorithm::from(request.algorithm.unwrap_or(state.default_algorithm())),
+        constraints: LibConstraints {
+            max_jump: request.max_jump.or(base.max_jump),
+            avoid_systems: if request.avoid.is_empty() {
+                base.avoid_systems
+            } else {
+                request.avoid.clone()
+            },
+            avoid_gates: request.avoid_gates || base.avoid_gates,
+            max_temperature: request.max_temperature.or(base.max_temperature),
+            // NOTE: `avoid_critical_state` is intentionally not exposed on the service API in
+            // this change and is currently CLI-only. If we decide to support it via the
+            // service, add a request field, validation, and tests; consider adding a
+            // follow-up issue to track the work.
+            avoid_critical_state: false,
+            ship: None,
+            loadout: None,
+            heat_config: None,
+            ..base
+        },
+        spatial_index: state.spatial_index_arc(),
+        max_spatial_neighbors: evefrontier_lib::GraphBuildOptions::default().max_spatial_neighbors,
+        optimization: evefrontier_lib::routing::RouteOptimization::Distance,
+        fuel_config: evefrontier_lib::ship::FuelConfig::default(),
+    }
+}
+
 /// Generate a unique request ID for tracing.
 fn generate_request_id() -> String {
     use std::time::{SystemTime, UNIX_EPOCH};
@@ -237,3 +474,523 @@ fn generate_request_id() -> String {
 
     format!("req-{:x}", timestamp)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::Method;
+    use evefrontier_service_shared::test_utils::{
+        fixture_systems, send_json, test_request_id, test_state,
+    };
+    use evefrontier_service_shared::{PROBLEM_INVALID_REQUEST, PROBLEM_UNKNOWN_SYSTEM};
+    use serde_json::json;
+
+    fn compare_request(from: &str, to: &str) -> RouteCompareRequest {
+        RouteCompareRequest {
+            from: from.to_string(),
+            to: to.to_string(),
+            max_jump: None,
+            avoid: vec![],
+            avoid_gates: false,
+            max_temperature: None,
+        }
+    }
+
+    #[test]
+    fn test_compare_returns_every_algorithm() {
+        let request = compare_request(fixture_systems::NOD, fixture_systems::BRANA);
+        let response = compare_routes(&test_state(), &request, &test_request_id())
+            .expect("comparison succeeds");
+
+        let algorithms: Vec<&str> = response
+            .results
+            .iter()
+            .map(|r| r.algorithm.as_str())
+            .collect();
+        assert_eq!(algorithms, ["bfs", "dijkstra", "a-star"]);
+
+        for result in &response.results {
+            assert_eq!(result.route.first().map(String::as_str), Some("Nod"));
+            assert_eq!(result.route.last().map(String::as_str), Some("Brana"));
+            assert_eq!(result.hops, result.route.len() - 1);
+            assert_eq!(result.hops, result.gates + result.jumps);
+            assert!(result.latency_ms >= 0.0);
+        }
+    }
+
+    #[test]
+    fn test_compare_notes_identical_paths() {
+        let request = compare_request(fixture_systems::NOD, fixture_systems::BRANA);
+        let response = compare_routes(&test_state(), &request, &test_request_id())
+            .expect("comparison succeeds");
+
+        assert!(response.results[0].same_path_as.is_none());
+        for (index, result) in response.results.iter().enumerate() {
+            // The first earlier result with the same route, if any, is the one named.
+            let expected = response.results[..index]
+                .iter()
+                .find(|earlier| earlier.route == result.route)
+                .map(|earlier| earlier.algorithm.as_str());
+            assert_eq!(
+                result.same_path_as.as_deref(),
+                expected,
+                "{}",
+                result.algorithm
+            );
+        }
+    }
+
+    #[test]
+    fn test_compare_bfs_minimizes_hops() {
+        let request = compare_request(fixture_systems::D_2NAS, fixture_systems::BRANA);
+        let response = compare_routes(&test_state(), &request, &test_request_id())
+            .expect("comparison succeeds");
+
+        let bfs_hops = response.results[0].hops;
+        assert!(response.results.iter().all(|r| bfs_hops <= r.hops));
+    }
+
+    #[test]
+    fn test_compare_accepts_numeric_system_ids() {
+        let state = test_state();
+        let nod = state
+            .starmap()
+            .system_id_by_name(fixture_systems::NOD)
+            .expect("Nod exists");
+        let request = compare_request(&nod.to_string(), fixture_systems::BRANA);
+        let response =
+            compare_routes(&state, &request, &test_request_id()).expect("comparison succeeds");
+
+        for result in &response.results {
+            assert_eq!(result.route.first().map(String::as_str), Some("Nod"));
+        }
+    }
+
+    #[test]
+    fn test_preset_seeds_constraints_and_explicit_fields_win() {
+        let state = test_state();
+        let mut request = compare_request(fixture_systems::NOD, fixture_systems::BRANA)
+            .with_algorithm(Default::default());
+        request.preset = Some(evefrontier_lib::RoutePreset::Safe);
+
+        let seeded = to_lib_request(&state, &request);
+        assert_eq!(
+            seeded.constraints.max_temperature,
+            Some(evefrontier_lib::SAFE_PRESET_MAX_TEMPERATURE)
+        );
+
+        request.max_temperature = Some(900.0);
+        let overridden = to_lib_request(&state, &request);
+        assert_eq!(overridden.constraints.max_temperature, Some(900.0));
+    }
+
+    #[tokio::test]
+    async fn test_http_route_constraints_replay_into_equivalent_request() {
+        let app = router(test_state());
+        let response = send_json(
+            app.clone(),
+            Method::POST,
+            "/api/v1/route",
+            Some(json!({
+                "from": fixture_systems::NOD,
+                "to": fixture_systems::BRANA,
+                "max_jump": 80.0,
+                "avoid": [fixture_systems::D_2NAS]
+            })),
+        )
+        .await;
+        assert_eq!(response.status, StatusCode::OK);
+        let echoed = &response.body["constraints"];
+        assert_eq!(echoed["max_jump"], 80.0);
+        assert_eq!(echoed["avoid_systems"], json!([fixture_systems::D_2NAS]));
+
+        // The echoed block deserializes into a request that plans with the same constraints.
+        let replay: RouteRequest = serde_json::from_value(json!({
+            "from": fixture_systems::NOD,
+            "to": fixture_systems::BRANA,
+            "constraints": echoed
+        }))
+        .expect("echoed constraints deserialize");
+        let replayed = to_lib_request(&test_state(), &replay);
+        assert_eq!(
+            &serde_json::to_value(&replayed.constraints).unwrap(),
+            echoed
+        );
+
+        let response = send_json(
+            app,
+            Method::POST,
+            "/api/v1/route",
+            Some(serde_json::to_value(&replay).unwrap()),
+        )
+        .await;
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(&response.body["constraints"], echoed);
+    }
+
+    #[test]
+    fn test_omitted_algorithm_uses_state_default() {
+        let state = test_state();
+        let mut request = compare_request(fixture_systems::NOD, fixture_systems::BRANA)
+            .with_algorithm(Default::default());
+        request.algorithm = None;
+        assert_eq!(
+            to_lib_request(&state, &request).algorithm,
+            LibAlgorithm::AStar
+        );
+
+        let state = state.with_default_algorithm(RouteAlgorithm::Dijkstra);
+        assert_eq!(
+            to_lib_request(&state, &request).algorithm,
+            LibAlgorithm::Dijkstra
+        );
+    }
+
+    #[tokio::test]
+    async fn test_http_route_configured_default_algorithm() {
+        let app = router(test_state().with_default_algorithm(RouteAlgorithm::Dijkstra));
+
+        let response = send_json(
+            app.clone(),
+            Method::POST,
+            "/api/v1/route",
+            Some(json!({ "from": fixture_systems::NOD, "to": fixture_systems::BRANA })),
+        )
+        .await;
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.body["algorithm"], "dijkstra");
+
+        let response = send_json(
+            app,
+            Method::POST,
+            "/api/v1/route",
+            Some(json!({
+                "from": fixture_systems::NOD,
+                "to": fixture_systems::BRANA,
+                "algorithm": "bfs"
+            })),
+        )
+        .await;
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.body["algorithm"], "bfs");
+    }
+
+    #[test]
+    fn test_route_steps_include_celestial_counts() {
+        let state = test_state();
+        let lib_request = to_lib_request(
+            &state,
+            &compare_request(fixture_systems::NOD, fixture_systems::BRANA)
+                .with_algorithm(Default::default()),
+        );
+        let plan = plan_route(state.starmap(), &lib_request).expect("route exists");
+        let steps = route_steps(state.starmap(), &plan);
+
+        let brana = steps.last().expect("route has steps");
+        assert_eq!(brana.system, "Brana");
+        assert_eq!(brana.planet_count, Some(2));
+        assert_eq!(brana.moon_count, Some(2));
+
+        // Nod has planets but no moons in the fixture, so the moon count is omitted.
+        let nod = steps.first().expect("route has steps");
+        assert_eq!(nod.planet_count, Some(2));
+        assert_eq!(nod.moon_count, None);
+    }
+
+    #[test]
+    fn test_compare_unknown_system_is_not_found() {
+        let request = compare_request("Nodd", fixture_systems::BRANA);
+        let problem = compare_routes(&test_state(), &request, &test_request_id())
+            .expect_err("unknown system rejected");
+        assert_eq!(problem.status, 404);
+    }
+
+    #[tokio::test]
+    async fn test_http_route_returns_json_route() {
+        let response = send_json(
+            router(test_state()),
+            Method::POST,
+            "/api/v1/route",
+            Some(json!({ "from": fixture_systems::NOD, "to": fixture_systems::BRANA })),
+        )
+        .await;
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.content_type(), Some("application/json"));
+        assert_eq!(response.body["content_type"], "application/json");
+        assert_eq!(response.body["route"][0], "Nod");
+        assert_eq!(response.body["steps"][0]["system"], "Nod");
+        let hops = response.body["hops"].as_u64().expect("hops is a number");
+        assert_eq!(
+            response.body["route"].as_array().map(Vec::len),
+            Some(hops as usize + 1)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_http_route_explain_covers_every_hop() {
+        let response = send_json(
+            router(test_state()),
+            Method::POST,
+            "/api/v1/route",
+            Some(json!({
+                "from": fixture_systems::NOD,
+                "to": fixture_systems::BRANA,
+                "explain": true
+            })),
+        )
+        .await;
+
+        assert_eq!(response.status, StatusCode::OK);
+        let hops = response.body["hops"].as_u64().expect("hops is a number") as usize;
+        let rationale = response.body["rationale"]
+            .as_array()
+            .expect("rationale is an array");
+        assert_eq!(rationale.len(), hops);
+        for (index, hop) in rationale.iter().enumerate() {
+            assert_eq!(hop["hop"], index);
+            assert_eq!(hop["from"], response.body["route"][index]);
+            assert_eq!(hop["to"], response.body["route"][index + 1]);
+            assert!(hop["method"] == "gate" || hop["method"] == "jump");
+            assert!(hop["reason"].as_str().is_some_and(|r| !r.is_empty()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_http_route_omits_rationale_by_default() {
+        let response = send_json(
+            router(test_state()),
+            Method::POST,
+            "/api/v1/route",
+            Some(json!({ "from": fixture_systems::NOD, "to": fixture_systems::BRANA })),
+        )
+        .await;
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert!(response.body.get("rationale").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_http_route_unknown_system_is_problem_json() {
+        let response = send_json(
+            router(test_state()),
+            Method::POST,
+            "/api/v1/route",
+            Some(json!({ "from": "Nodd", "to": fixture_systems::BRANA })),
+        )
+        .await;
+
+        assert_eq!(response.status, StatusCode::NOT_FOUND);
+        assert_eq!(response.content_type(), Some("application/problem+json"));
+        assert_eq!(response.body["type"], PROBLEM_UNKNOWN_SYSTEM);
+        assert_eq!(response.body["status"], 404);
+    }
+
+    #[tokio::test]
+    async fn test_http_route_validation_error_is_bad_request() {
+        let response = send_json(
+            router(test_state()),
+            Method::POST,
+            "/api/v1/route",
+            Some(json!({ "from": "", "to": fixture_systems::BRANA })),
+        )
+        .await;
+
+        assert_eq!(response.status, StatusCode::BAD_REQUEST);
+        assert_eq!(response.content_type(), Some("application/problem+json"));
+        assert_eq!(response.body["type"], PROBLEM_INVALID_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_http_route_missing_field_is_rejected() {
+        let response = send_json(
+            router(test_state()),
+            Method::POST,
+            "/api/v1/route",
+            Some(json!({ "from": fixture_systems::NOD })),
+        )
+        .await;
+
+        assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_http_compare_returns_every_algorithm() {
+        let response = send_json(
+            router(test_state()),
+            Method::POST,
+            "/api/v1/route/compare",
+            Some(json!({ "from": fixture_systems::NOD, "to": fixture_systems::BRANA })),
+        )
+        .await;
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.body["from"], "Nod");
+        assert_eq!(response.body["results"].as_array().map(Vec::len), Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_http_health_live_is_ok() {
+        let response = send_json(router(test_state()), Method::GET, "/health/live", None).await;
+        assert_eq!(response.status, StatusCode::OK);
+    }
+
+    fn property_names(schema: &serde_json::Value) -> std::collections::BTreeSet<String> {
+        schema["properties"]
+            .as_object()
+            .expect("schema has properties")
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    fn object_keys(value: &serde_json::Value) -> std::collections::BTreeSet<String> {
+        value
+            .as_object()
+            .expect("value is an object")
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_http_openapi_document_describes_route() {
+        let response = send_json(router(test_state()), Method::GET, "/openapi.json", None).await;
+
+        assert_eq!(response.status, StatusCode::OK);
+        let document = &response.body;
+        assert!(document["openapi"].as_str().unwrap().starts_with("3.1"));
+
+        let operation = &document["paths"]["/api/v1/route"]["post"];
+        assert!(operation.is_object(), "route operation missing");
+        for status in ["200", "400", "404", "429"] {
+            assert!(
+                operation["responses"][status].is_object(),
+                "{status} missing"
+            );
+        }
+
+        let schemas = &document["components"]["schemas"];
+        assert_eq!(schemas["RouteRequest"]["required"], json!(["from", "to"]));
+        assert_eq!(
+            schemas["RouteResponse"]["required"],
+            json!([
+                "hops",
+                "gates",
+                "jumps",
+                "algorithm",
+                "route",
+                "steps",
+                "constraints",
+                "content_type"
+            ])
+        );
+        assert_eq!(
+            schemas["ProblemDetails"]["required"],
+            json!(["type", "title", "status", "content_type"])
+        );
+        assert_eq!(
+            schemas["RouteAlgorithm"]["enum"],
+            json!(["bfs", "dijkstra", "a-star"])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_openapi_schemas_match_wire_types() {
+        use evefrontier_lib::HopConstraint;
+
+        let document = openapi::document();
+        let schemas = &document["components"]["schemas"];
+
+        // Every request field is documented, and nothing else.
+        let mut request = compare_request(fixture_systems::NOD, fixture_systems::BRANA)
+            .with_algorithm(RouteAlgorithm::Dijkstra);
+        request.preset = Some(evefrontier_lib::RoutePreset::Safe);
+        request.max_jump = Some(80.0);
+        request.avoid = vec![fixture_systems::BRANA.to_string()];
+        request.max_temperature = Some(5000.0);
+        request.constraints = Some(LibConstraints::default());
+        let request = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            object_keys(&request),
+            property_names(&schemas["RouteRequest"])
+        );
+        for preset in [
+            evefrontier_lib::RoutePreset::Fast,
+            evefrontier_lib::RoutePreset::Safe,
+            evefrontier_lib::RoutePreset::Cheap,
+        ] {
+            let name = serde_json::to_value(preset).unwrap();
+            assert!(
+                schemas["RoutePreset"]["enum"]
+                    .as_array()
+                    .unwrap()
+                    .contains(&name)
+            );
+        }
+
+        // A full response uses only documented fields and includes every required one.
+        let response = send_json(
+            router(test_state()),
+            Method::POST,
+            "/api/v1/route",
+            Some(json!({
+                "from": fixture_systems::NOD,
+                "to": fixture_systems::BRANA,
+                "explain": true
+            })),
+        )
+        .await;
+        let body = &response.body;
+        assert_eq!(object_keys(body), property_names(&schemas["RouteResponse"]));
+        assert_eq!(
+            object_keys(&body["constraints"]),
+            property_names(&schemas["RouteConstraints"])
+        );
+        assert!(
+            schemas["RouteAlgorithm"]["enum"]
+                .as_array()
+                .unwrap()
+                .contains(&body["algorithm"])
+        );
+        assert!(object_keys(&body["steps"][0]).is_subset(&property_names(&schemas["RouteStep"])));
+        assert!(
+            object_keys(&body["rationale"][0]).is_subset(&property_names(&schemas["HopRationale"]))
+        );
+        for constraint in [
+            HopConstraint::MaxJump,
+            HopConstraint::AvoidGates,
+            HopConstraint::AvoidSystems,
+            HopConstraint::MaxTemperature,
+            HopConstraint::AvoidTemperatureRange,
+            HopConstraint::AvoidCriticalState,
+        ] {
+            let name = serde_json::to_value(constraint).unwrap();
+            assert!(
+                schemas["HopRationale"]["properties"]["constraint"]["enum"]
+                    .as_array()
+                    .unwrap()
+                    .contains(&name)
+            );
+        }
+
+        // Problem bodies match the ProblemDetails schema.
+        let response = send_json(
+            router(test_state()),
+            Method::POST,
+            "/api/v1/route",
+            Some(json!({ "from": "Nodd", "to": fixture_systems::BRANA })),
+        )
+        .await;
+        assert_eq!(
+            object_keys(&response.body),
+            property_names(&schemas["ProblemDetails"])
+        );
+        assert!(
+            schemas["ProblemDetails"]["properties"]["type"]["enum"]
+                .as_array()
+                .unwrap()
+                .contains(&response.body["type"])
+        );
+    }
+}
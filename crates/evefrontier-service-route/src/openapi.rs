@@ -0,0 +1,289 @@
+//! OpenAPI 3.1 description of the route endpoint, served at `GET /openapi.json`.
+//!
+//! The document is written by hand rather than derived, so the tests in `main.rs`
+//! compare its schemas against the serialized request and response types to keep
+//! the two from drifting apart.
+
+use serde_json::{Value, json};
+
+use evefrontier_service_shared::{CONTENT_TYPE_PROBLEM_JSON, PROBLEM_TYPES, RouteCompareRequest};
+
+/// Path the document is served from.
+pub const OPENAPI_PATH: &str = "/openapi.json";
+
+/// Wire names of the service routing algorithms (`bfs`, `dijkstra`, `a-star`).
+fn algorithm_names() -> Vec<Value> {
+    RouteCompareRequest::ALGORITHMS
+        .iter()
+        .map(|algorithm| {
+            serde_json::to_value(algorithm).expect("algorithm names serialize as strings")
+        })
+        .collect()
+}
+
+/// Response entry for a problem status.
+fn problem_response(description: &str) -> Value {
+    json!({
+        "description": description,
+        "content": {
+            CONTENT_TYPE_PROBLEM_JSON: {
+                "schema": { "$ref": "#/components/schemas/ProblemDetails" }
+            }
+        }
+    })
+}
+
+/// Build the OpenAPI document.
+pub fn document() -> Value {
+    let mut rate_limited = problem_response("Per-IP rate limit exceeded (`rate-limited`).");
+    rate_limited["headers"] = json!({
+        "Retry-After": {
+            "description": "Seconds to wait before retrying.",
+            "schema": { "type": "integer", "minimum": 1 }
+        }
+    });
+
+    json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": "EVE Frontier Route Service",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "Plans routes between EVE Frontier solar systems.",
+            "license": { "name": "MIT", "identifier": "MIT" }
+        },
+        "paths": {
+            "/api/v1/route": {
+                "post": {
+                    "operationId": "planRoute",
+                    "summary": "Plan a route between two systems",
+                    "requestBody": {
+                        "required": true,
+                        "content": {
+                            "application/json": {
+                                "schema": { "$ref": "#/components/schemas/RouteRequest" }
+                            }
+                        }
+                    },
+                    "responses": {
+                        "200": {
+                            "description": "The planned route.",
+                            "content": {
+                                "application/json": {
+                                    "schema": { "$ref": "#/components/schemas/RouteResponse" }
+                                }
+                            }
+                        },
+                        "400": problem_response("Invalid request fields (`invalid-request`)."),
+                        "404": problem_response(
+                            "Unknown system (`unknown-system`) or no route under the \
+                             constraints (`route-not-found`)."
+                        ),
+                        "422": {
+                            "description": "The body is not valid JSON for `RouteRequest`, \
+                                            e.g. a required field is missing.",
+                            "content": {
+                                "text/plain": { "schema": { "type": "string" } }
+                            }
+                        },
+                        "429": rate_limited,
+                        "500": problem_response("Unexpected failure (`internal-error`)."),
+                        "503": problem_response(
+                            "Dataset or spatial index unavailable (`service-unavailable`)."
+                        )
+                    }
+                }
+            }
+        },
+        "components": {
+            "schemas": {
+                "RouteAlgorithm": {
+                    "type": "string",
+                    "enum": algorithm_names(),
+                    "description": "Routing algorithm."
+                },
+                "RoutePreset": {
+                    "type": "string",
+                    "enum": ["fast", "safe", "cheap"],
+                    "description": "Named preset seeding the constraints; explicit fields win."
+                },
+                "RouteRequest": {
+                    "type": "object",
+                    "required": ["from", "to"],
+                    "properties": {
+                        "from": {
+                            "type": "string",
+                            "minLength": 1,
+                            "description": "Starting system name or numeric system ID."
+                        },
+                        "to": {
+                            "type": "string",
+                            "minLength": 1,
+                            "description": "Destination system name or numeric system ID."
+                        },
+                        "algorithm": {
+                            "$ref": "#/components/schemas/RouteAlgorithm",
+                            "description": "Defaults to the service's configured algorithm \
+                                            (`a-star` unless `DEFAULT_ROUTE_ALGORITHM` is set)."
+                        },
+                        "preset": { "$ref": "#/components/schemas/RoutePreset" },
+                        "constraints": {
+                            "$ref": "#/components/schemas/RouteConstraints",
+                            "description": "Constraint block echoed by a previous response, to \
+                                            replay that route. Cannot be combined with `preset`; \
+                                            the explicit fields below take precedence."
+                        },
+                        "max_jump": {
+                            "type": "number",
+                            "exclusiveMinimum": 0,
+                            "description": "Maximum jump distance in light-years."
+                        },
+                        "avoid": {
+                            "type": "array",
+                            "items": { "type": "string" },
+                            "default": [],
+                            "description": "Systems to avoid."
+                        },
+                        "avoid_gates": {
+                            "type": "boolean",
+                            "default": false,
+                            "description": "Use only spatial jumps."
+                        },
+                        "max_temperature": {
+                            "type": "number",
+                            "exclusiveMinimum": 0,
+                            "description": "Maximum star temperature in Kelvin."
+                        },
+                        "explain": {
+                            "type": "boolean",
+                            "default": false,
+                            "description": "Include a per-hop `rationale` in the response."
+                        }
+                    }
+                },
+                "RouteConstraints": {
+                    "type": "object",
+                    "description": "Constraints a route was planned with. Omitted fields take \
+                                    their defaults.",
+                    "properties": {
+                        "max_jump": { "type": ["number", "null"], "exclusiveMinimum": 0 },
+                        "avoid_systems": { "type": "array", "items": { "type": "string" } },
+                        "avoid_regions": { "type": "array", "items": { "type": "string" } },
+                        "avoid_gates": { "type": "boolean", "default": false },
+                        "max_temperature": { "type": ["number", "null"], "exclusiveMinimum": 0 },
+                        "avoid_temperature_range": {
+                            "type": ["array", "null"],
+                            "prefixItems": [{ "type": "number" }, { "type": "number" }],
+                            "minItems": 2,
+                            "maxItems": 2,
+                            "description": "Inclusive `[min, max]` star temperature band in Kelvin."
+                        },
+                        "avoid_critical_state": {
+                            "type": "boolean",
+                            "description": "Always `false` here; the service plans without a ship."
+                        },
+                        "round_trip": { "type": "boolean", "default": false },
+                        "max_hops": { "type": ["integer", "null"], "minimum": 0 },
+                        "gate_penalty": { "type": "number", "exclusiveMinimum": 0, "default": 1 },
+                        "jump_penalty": { "type": "number", "exclusiveMinimum": 0, "default": 1 },
+                        "gate_time_seconds": {
+                            "type": "array",
+                            "items": {
+                                "type": "object",
+                                "required": ["from", "to", "seconds"],
+                                "properties": {
+                                    "from": { "type": "integer" },
+                                    "to": { "type": "integer" },
+                                    "seconds": { "type": "number", "minimum": 0 }
+                                }
+                            },
+                            "description": "Per-gate traversal times by endpoint system IDs."
+                        }
+                    }
+                },
+                "RouteStep": {
+                    "type": "object",
+                    "required": ["system"],
+                    "properties": {
+                        "system": { "type": "string" },
+                        "planet_count": { "type": "integer", "minimum": 0 },
+                        "moon_count": { "type": "integer", "minimum": 0 }
+                    }
+                },
+                "HopRationale": {
+                    "type": "object",
+                    "required": ["hop", "from", "to", "method", "reason"],
+                    "properties": {
+                        "hop": { "type": "integer", "minimum": 0 },
+                        "from": { "type": "string" },
+                        "to": { "type": "string" },
+                        "method": { "type": "string", "enum": ["gate", "jump"] },
+                        "distance": {
+                            "type": "number",
+                            "description": "Straight-line distance in light-years."
+                        },
+                        "constraint": {
+                            "type": "string",
+                            "enum": [
+                                "max_jump",
+                                "avoid_gates",
+                                "avoid_systems",
+                                "max_temperature",
+                                "avoid_temperature_range",
+                                "avoid_critical_state"
+                            ],
+                            "description": "Constraint that ruled out the other way of making \
+                                            this hop."
+                        },
+                        "reason": { "type": "string" }
+                    }
+                },
+                "RouteResponse": {
+                    "type": "object",
+                    "required": [
+                        "hops", "gates", "jumps", "algorithm", "route", "steps", "constraints",
+                        "content_type"
+                    ],
+                    "properties": {
+                        "hops": { "type": "integer", "minimum": 0 },
+                        "gates": { "type": "integer", "minimum": 0 },
+                        "jumps": { "type": "integer", "minimum": 0 },
+                        "algorithm": { "$ref": "#/components/schemas/RouteAlgorithm" },
+                        "route": {
+                            "type": "array",
+                            "items": { "type": "string" },
+                            "description": "System names in travel order."
+                        },
+                        "steps": {
+                            "type": "array",
+                            "items": { "$ref": "#/components/schemas/RouteStep" }
+                        },
+                        "rationale": {
+                            "type": "array",
+                            "items": { "$ref": "#/components/schemas/HopRationale" },
+                            "description": "Present only when the request sets `explain`."
+                        },
+                        "constraints": {
+                            "$ref": "#/components/schemas/RouteConstraints",
+                            "description": "Constraints the route was planned with; send them \
+                                            back as the request's `constraints` to replay it."
+                        },
+                        "content_type": { "const": "application/json" }
+                    }
+                },
+                "ProblemDetails": {
+                    "type": "object",
+                    "description": "RFC 9457 problem details.",
+                    "required": ["type", "title", "status", "content_type"],
+                    "properties": {
+                        "type": { "type": "string", "format": "uri", "enum": PROBLEM_TYPES },
+                        "title": { "type": "string" },
+                        "status": { "type": "integer" },
+                        "detail": { "type": "string" },
+                        "instance": { "type": "string" },
+                        "content_type": { "const": CONTENT_TYPE_PROBLEM_JSON }
+                    }
+                }
+            }
+        }
+    })
+}
@@ -6,6 +6,8 @@
 //! # Endpoints
 //!
 //! - `POST /api/v1/scout/gates` - Find gate-connected neighbors
+//! - `GET /api/v1/scout/gates/{system}` - Same, with the system in the path and an
+//!   optional `?depth=` query parameter
 //! - `GET /metrics` - Prometheus metrics endpoint
 //! - `GET /health/live` - Kubernetes liveness probe
 //! - `GET /health/ready` - Kubernetes readiness probe
@@ -16,24 +18,27 @@
 //! - `RUST_LOG` - Log level (default: info)
 //! - `LOG_FORMAT` - Log format: json (default) or text
 //! - `SERVICE_PORT` - HTTP port (default: 8080)
+//! - `SHUTDOWN_GRACE_SECS` - Seconds in-flight requests get to finish after SIGTERM (default: 25)
 
 use std::env;
+use std::future::IntoFuture;
 use std::net::SocketAddr;
 
 use axum::{
     Json, Router,
-    extract::State,
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     routing::{get, post},
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use tracing::{error, info};
 
 use evefrontier_service_shared::{
-    AppState, LoggingConfig, MetricsConfig, MetricsLayer, ProblemDetails, ScoutGatesRequest,
-    ServiceResponse, Validate, health_live, health_ready, init_logging, init_metrics,
-    metrics_handler, record_neighbors_returned, record_systems_queried,
+    AppState, DatasetHeadersLayer, GracefulShutdown, LoggingConfig, MetricsConfig, MetricsLayer,
+    ProblemDetails, RateLimitLayer, ScoutGatesRequest, ServiceResponse, Validate, health_live,
+    health_ready, init_logging, init_metrics, metrics_handler, record_build_info,
+    record_neighbors_returned, record_systems_queried,
 };
 
 /// Gate neighbor information.
@@ -43,6 +48,8 @@ struct GateNeighbor {
     id: i64,
     /// System name.
     name: String,
+    /// Gate hops from the queried system (1 for direct neighbors).
+    hops: usize,
 }
 
 /// Scout gates response returned to the caller.
@@ -52,12 +59,21 @@ struct ScoutGatesResponse {
     system: String,
     /// System ID.
     system_id: i64,
+    /// Gate-hop depth that was searched.
+    depth: usize,
     /// Number of gate-connected neighbors.
     count: usize,
-    /// List of gate-connected neighbors.
+    /// Gate-connected neighbors within `depth` hops, ordered by hop count.
     neighbors: Vec<GateNeighbor>,
 }
 
+/// Query parameters accepted by the GET variant.
+#[derive(Debug, Default, Deserialize)]
+struct ScoutGatesQuery {
+    /// Number of gate hops to explore.
+    depth: Option<usize>,
+}
+
 /// HTTP response - either success or RFC 9457 error.
 #[derive(Debug, Serialize)]
 #[serde(untagged)]
@@ -81,13 +97,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let logging_config = LoggingConfig::from_env().with_service("scout-gates");
     init_logging(&logging_config);
 
-    // Initialize metrics
-    let metrics_config = MetricsConfig::from_env();
-    if let Err(e) = init_metrics(&metrics_config) {
-        // Log but don't fail - metrics are optional
-        tracing::warn!(error = %e, "failed to initialize metrics, continuing without metrics");
-    }
-
     // Load configuration from environment
     let data_path =
         env::var("EVEFRONTIER_DATA_PATH").unwrap_or_else(|_| "/data/static_data.db".to_string());
@@ -109,30 +118,94 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         "application state loaded"
     );
 
+    // Initialize metrics, labelled with the loaded dataset release
+    let metrics_config = MetricsConfig::from_env()
+        .with_dataset_release(
+            state
+                .dataset_metadata()
+                .and_then(|metadata| metadata.release_tag.clone()),
+        )
+        .with_app_version(env!("CARGO_PKG_VERSION"));
+    if let Err(e) = init_metrics(&metrics_config) {
+        // Log but don't fail - metrics are optional
+        tracing::warn!(error = %e, "failed to initialize metrics, continuing without metrics");
+    }
+    record_build_info(
+        &metrics_config.app_version,
+        metrics_config.dataset_release.as_deref(),
+    );
+
     // Build the router
-    let app = Router::new()
-        .route("/api/v1/scout/gates", post(scout_gates_handler))
-        .route("/metrics", get(metrics_handler))
-        .route("/health/live", get(health_live))
-        .route("/health/ready", get(health_ready))
-        .layer(MetricsLayer)
-        .with_state(state);
+    let app = router(state);
 
     // Bind and serve
     let addr = SocketAddr::from(([0, 0, 0, 0], port));
     info!(addr = %addr, "listening on");
 
     let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
+    let shutdown = GracefulShutdown::from_env();
+    let server = axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown.signal());
+
+    // Stop accepting connections on SIGTERM/SIGINT, then give in-flight requests
+    // the grace period to finish.
+    tokio::select! {
+        result = server.into_future() => result?,
+        () = shutdown.grace_elapsed() => tracing::warn!(
+            grace_period_secs = shutdown.grace_period().as_secs(),
+            "shutdown grace period elapsed; dropping remaining connections"
+        ),
+    }
+    info!("shutdown complete");
 
     Ok(())
 }
 
+/// Build the service router with all endpoints and middleware layers.
+fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/api/v1/scout/gates", post(scout_gates_handler))
+        .route(
+            "/api/v1/scout/gates/{system}",
+            get(scout_gates_path_handler),
+        )
+        .route("/metrics", get(metrics_handler))
+        .route("/health/live", get(health_live))
+        .route("/health/ready", get(health_ready))
+        .layer(RateLimitLayer::from_env())
+        .layer(MetricsLayer)
+        .layer(DatasetHeadersLayer::new(state.dataset_metadata()))
+        .with_state(state)
+}
+
 /// Handle POST /api/v1/scout/gates requests.
 async fn scout_gates_handler(
     State(state): State<AppState>,
     Json(request): Json<ScoutGatesRequest>,
 ) -> Response {
+    scout_gates(&state, &request)
+}
+
+/// Handle GET /api/v1/scout/gates/{system} requests.
+///
+/// The path segment arrives percent-decoded, so `H%3A2L2S` names `H:2L2S`.
+async fn scout_gates_path_handler(
+    State(state): State<AppState>,
+    Path(system): Path<String>,
+    Query(query): Query<ScoutGatesQuery>,
+) -> Response {
+    let request = ScoutGatesRequest {
+        system,
+        depth: query.depth,
+    };
+    scout_gates(&state, &request)
+}
+
+/// Shared scout logic behind both the POST and GET routes.
+fn scout_gates(state: &AppState, request: &ScoutGatesRequest) -> Response {
     // Generate a request ID for tracing
     let request_id = generate_request_id();
 
@@ -163,25 +236,24 @@ async fn scout_gates_handler(
         }
     };
 
-    // Get gate-connected neighbors from adjacency list
+    // Breadth-first search over gate adjacency up to the requested depth
+    let depth = request.depth();
     let neighbors: Vec<GateNeighbor> = starmap
-        .adjacency
-        .get(&system_id)
-        .map(|ids| {
-            ids.iter()
-                .filter_map(|&id| {
-                    starmap.system_name(id).map(|name| GateNeighbor {
-                        id,
-                        name: name.to_string(),
-                    })
-                })
-                .collect()
+        .gate_neighbors_within(system_id, depth)
+        .into_iter()
+        .filter_map(|(id, hops)| {
+            starmap.system_name(id).map(|name| GateNeighbor {
+                id,
+                name: name.to_string(),
+                hops,
+            })
         })
-        .unwrap_or_default();
+        .collect();
 
     let response = ScoutGatesResponse {
         system: request.system.clone(),
         system_id,
+        depth,
         count: neighbors.len(),
         neighbors,
     };
@@ -211,3 +283,151 @@ fn generate_request_id() -> String {
 
     format!("req-{:x}", timestamp)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::Method;
+    use evefrontier_service_shared::build_recorder;
+    use evefrontier_service_shared::test_utils::{fixture_systems, send_json, test_state};
+    use evefrontier_service_shared::{PROBLEM_INVALID_REQUEST, PROBLEM_UNKNOWN_SYSTEM};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_neighbor_count_histogram_records_each_request() {
+        let recorder = build_recorder(&MetricsConfig::default()).expect("recorder builds");
+        let handle = recorder.handle();
+        let _guard = metrics::set_default_local_recorder(&recorder);
+
+        // Nod has 3 gate neighbors, Brana 2, and D:2NAS 1.
+        for system in [
+            fixture_systems::NOD,
+            fixture_systems::BRANA,
+            fixture_systems::D_2NAS,
+        ] {
+            let request = ScoutGatesRequest {
+                system: system.to_string(),
+                depth: None,
+            };
+            let response = scout_gates_handler(State(test_state()), Json(request)).await;
+            assert!(matches!(response, Response::Success(_)));
+        }
+
+        let rendered = handle.render();
+        let series = |prefix: &str, label: &str| {
+            rendered
+                .lines()
+                .find(|line| line.starts_with(prefix) && line.contains(label))
+                .and_then(|line| line.rsplit(' ').next())
+        };
+        let count = series("evefrontier_neighbors_returned_count{", "");
+        assert_eq!(count, Some("3"), "{rendered}");
+        let sum = series("evefrontier_neighbors_returned_sum{", "");
+        assert_eq!(sum, Some("6"), "{rendered}");
+        let up_to_two = series("evefrontier_neighbors_returned_bucket{", r#"le="2""#);
+        assert_eq!(up_to_two, Some("2"), "{rendered}");
+    }
+
+    #[tokio::test]
+    async fn test_http_scout_gates_returns_neighbors() {
+        let response = send_json(
+            router(test_state()),
+            Method::POST,
+            "/api/v1/scout/gates",
+            Some(json!({ "system": fixture_systems::NOD })),
+        )
+        .await;
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.content_type(), Some("application/json"));
+        assert_eq!(response.body["system"], "Nod");
+        assert_eq!(response.body["depth"], 1);
+        assert_eq!(response.body["count"], 3);
+        assert_eq!(response.body["neighbors"].as_array().map(Vec::len), Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_http_get_scout_gates_matches_post() {
+        for (system, path) in [
+            (fixture_systems::NOD, "/api/v1/scout/gates/Nod"),
+            (fixture_systems::H_2L2S, "/api/v1/scout/gates/H%3A2L2S"),
+        ] {
+            let post = send_json(
+                router(test_state()),
+                Method::POST,
+                "/api/v1/scout/gates",
+                Some(json!({ "system": system, "depth": 2 })),
+            )
+            .await;
+            let get = send_json(
+                router(test_state()),
+                Method::GET,
+                &format!("{path}?depth=2"),
+                None,
+            )
+            .await;
+
+            assert_eq!(get.status, StatusCode::OK);
+            assert_eq!(get.content_type(), Some("application/json"));
+            assert_eq!(get.body, post.body);
+            assert_eq!(get.body["system"], system);
+        }
+
+        let get = send_json(
+            router(test_state()),
+            Method::GET,
+            "/api/v1/scout/gates/Nod",
+            None,
+        )
+        .await;
+        assert_eq!(get.body["depth"], 1);
+        assert_eq!(get.body["count"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_http_get_scout_gates_unknown_system_suggests_matches() {
+        let response = send_json(
+            router(test_state()),
+            Method::GET,
+            "/api/v1/scout/gates/Nodd",
+            None,
+        )
+        .await;
+
+        assert_eq!(response.status, StatusCode::NOT_FOUND);
+        assert_eq!(response.content_type(), Some("application/problem+json"));
+        assert_eq!(response.body["type"], PROBLEM_UNKNOWN_SYSTEM);
+        let detail = response.body["detail"].as_str().expect("detail");
+        assert!(detail.contains("Did you mean"), "{detail}");
+        assert!(detail.contains("Nod"), "{detail}");
+    }
+
+    #[tokio::test]
+    async fn test_http_scout_gates_unknown_system_is_problem_json() {
+        let response = send_json(
+            router(test_state()),
+            Method::POST,
+            "/api/v1/scout/gates",
+            Some(json!({ "system": "Nodd" })),
+        )
+        .await;
+
+        assert_eq!(response.status, StatusCode::NOT_FOUND);
+        assert_eq!(response.content_type(), Some("application/problem+json"));
+        assert_eq!(response.body["type"], PROBLEM_UNKNOWN_SYSTEM);
+    }
+
+    #[tokio::test]
+    async fn test_http_scout_gates_zero_depth_is_bad_request() {
+        let response = send_json(
+            router(test_state()),
+            Method::POST,
+            "/api/v1/scout/gates",
+            Some(json!({ "system": fixture_systems::NOD, "depth": 0 })),
+        )
+        .await;
+
+        assert_eq!(response.status, StatusCode::BAD_REQUEST);
+        assert_eq!(response.body["type"], PROBLEM_INVALID_REQUEST);
+    }
+}
@@ -16,8 +16,10 @@
 //! - `RUST_LOG` - Log level (default: info)
 //! - `LOG_FORMAT` - Log format: json (default) or text
 //! - `SERVICE_PORT` - HTTP port (default: 8080)
+//! - `SHUTDOWN_GRACE_SECS` - Seconds in-flight requests get to finish after SIGTERM (default: 25)
 
 use std::env;
+use std::future::IntoFuture;
 use std::net::SocketAddr;
 
 use axum::{
@@ -31,10 +33,12 @@ use serde::Serialize;
 use tracing::{error, info, warn};
 
 use evefrontier_lib::spatial::NeighbourQuery;
+use evefrontier_lib::{SpatialIndex, Starmap, SystemId};
 use evefrontier_service_shared::{
-    AppState, LoggingConfig, MetricsConfig, MetricsLayer, ProblemDetails, ScoutRangeRequest,
-    ServiceResponse, Validate, health_live, health_ready, init_logging, init_metrics,
-    metrics_handler, record_neighbors_returned, record_systems_queried,
+    AppState, DatasetHeadersLayer, GracefulShutdown, LoggingConfig, MetricsConfig, MetricsLayer,
+    ProblemDetails, RateLimitLayer, ScoutRangeRequest, ServiceResponse, Validate, health_live,
+    health_ready, init_logging, init_metrics, metrics_handler, record_build_info,
+    record_neighbors_returned, record_systems_queried,
 };
 
 /// Nearby system information.
@@ -84,13 +88,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let logging_config = LoggingConfig::from_env().with_service("scout-range");
     init_logging(&logging_config);
 
-    // Initialize metrics
-    let metrics_config = MetricsConfig::from_env();
-    if let Err(e) = init_metrics(&metrics_config) {
-        // Log but don't fail - metrics are optional
-        tracing::warn!(error = %e, "failed to initialize metrics, continuing without metrics");
-    }
-
     // Load configuration from environment
     let data_path =
         env::var("EVEFRONTIER_DATA_PATH").unwrap_or_else(|_| "/data/static_data.db".to_string());
@@ -113,25 +110,65 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         "application state loaded"
     );
 
+    // Initialize metrics, labelled with the loaded dataset release
+    let metrics_config = MetricsConfig::from_env()
+        .with_dataset_release(
+            state
+                .dataset_metadata()
+                .and_then(|metadata| metadata.release_tag.clone()),
+        )
+        .with_app_version(env!("CARGO_PKG_VERSION"));
+    if let Err(e) = init_metrics(&metrics_config) {
+        // Log but don't fail - metrics are optional
+        tracing::warn!(error = %e, "failed to initialize metrics, continuing without metrics");
+    }
+    record_build_info(
+        &metrics_config.app_version,
+        metrics_config.dataset_release.as_deref(),
+    );
+
     // Build the router
-    let app = Router::new()
-        .route("/api/v1/scout/range", post(scout_range_handler))
-        .route("/metrics", get(metrics_handler))
-        .route("/health/live", get(health_live))
-        .route("/health/ready", get(health_ready))
-        .layer(MetricsLayer)
-        .with_state(state);
+    let app = router(state);
 
     // Bind and serve
     let addr = SocketAddr::from(([0, 0, 0, 0], port));
     info!(addr = %addr, "listening on");
 
     let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
+    let shutdown = GracefulShutdown::from_env();
+    let server = axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown.signal());
+
+    // Stop accepting connections on SIGTERM/SIGINT, then give in-flight requests
+    // the grace period to finish.
+    tokio::select! {
+        result = server.into_future() => result?,
+        () = shutdown.grace_elapsed() => warn!(
+            grace_period_secs = shutdown.grace_period().as_secs(),
+            "shutdown grace period elapsed; dropping remaining connections"
+        ),
+    }
+    info!("shutdown complete");
 
     Ok(())
 }
 
+/// Build the service router with all endpoints and middleware layers.
+fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/api/v1/scout/range", post(scout_range_handler))
+        .route("/metrics", get(metrics_handler))
+        .route("/health/live", get(health_live))
+        .route("/health/ready", get(health_ready))
+        .layer(RateLimitLayer::from_env())
+        .layer(MetricsLayer)
+        .layer(DatasetHeadersLayer::new(state.dataset_metadata()))
+        .with_state(state)
+}
+
 /// Handle POST /api/v1/scout/range requests.
 async fn scout_range_handler(
     State(state): State<AppState>,
@@ -206,28 +243,7 @@ async fn scout_range_handler(
         }
     };
 
-    // Build the query
-    let query = NeighbourQuery {
-        k: request.limit,
-        radius: request.radius,
-        max_temperature: request.max_temperature,
-    };
-
-    // Query the spatial index with the system's position
-    let results = spatial_index.nearest_filtered(position, &query);
-
-    // Convert results to response (filter out the queried system itself)
-    let nearby: Vec<NearbySystem> = results
-        .into_iter()
-        .filter(|(id, _)| *id != system_id) // Exclude the queried system
-        .filter_map(|(id, distance)| {
-            starmap.system_name(id).map(|name| NearbySystem {
-                id,
-                name: name.to_string(),
-                distance_ly: distance,
-            })
-        })
-        .collect();
+    let nearby = nearby_systems(starmap, spatial_index, system_id, position, &request);
 
     let response = ScoutRangeResponse {
         system: request.system.clone(),
@@ -250,6 +266,42 @@ async fn scout_range_handler(
     Response::Success(ServiceResponse::new(response))
 }
 
+/// Find systems near `position`, ordered by distance.
+///
+/// The origin is excluded unless `request.include_origin` is set, in which case it comes first
+/// at distance 0 (even when it would fail the temperature filter) and counts toward the limit.
+fn nearby_systems(
+    starmap: &Starmap,
+    spatial_index: &SpatialIndex,
+    origin: SystemId,
+    position: [f64; 3],
+    request: &ScoutRangeRequest,
+) -> Vec<NearbySystem> {
+    let query = NeighbourQuery {
+        k: request.limit,
+        radius: request.radius,
+        max_temperature: request.max_temperature,
+    };
+
+    // Query the spatial index with the system's position
+    let results = spatial_index.nearest_filtered(position, &query);
+
+    request
+        .include_origin
+        .then_some((origin, 0.0))
+        .into_iter()
+        .chain(results.into_iter().filter(|(id, _)| *id != origin))
+        .take(request.limit)
+        .filter_map(|(id, distance)| {
+            starmap.system_name(id).map(|name| NearbySystem {
+                id,
+                name: name.to_string(),
+                distance_ly: distance,
+            })
+        })
+        .collect()
+}
+
 /// Generate a unique request ID for tracing.
 fn generate_request_id() -> String {
     use std::time::{SystemTime, UNIX_EPOCH};
@@ -261,3 +313,160 @@ fn generate_request_id() -> String {
 
     format!("req-{:x}", timestamp)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::Method;
+    use evefrontier_service_shared::build_recorder;
+    use evefrontier_service_shared::test_utils::{
+        fixture_app_state, fixture_systems, send_json, test_state,
+    };
+    use evefrontier_service_shared::{
+        PROBLEM_INVALID_REQUEST, PROBLEM_SERVICE_UNAVAILABLE, PROBLEM_UNKNOWN_SYSTEM,
+    };
+    use serde_json::json;
+
+    fn nearby_from_nod(include_origin: bool) -> (SystemId, Vec<NearbySystem>) {
+        let state = fixture_app_state();
+        let starmap = state.starmap();
+        let index = state.spatial_index().expect("fixture state has an index");
+        let nod = starmap
+            .system_id_by_name(fixture_systems::NOD)
+            .expect("Nod exists");
+        let position = starmap.systems[&nod].position.expect("Nod has position");
+        let request = ScoutRangeRequest {
+            system: fixture_systems::NOD.to_string(),
+            limit: 5,
+            radius: None,
+            max_temperature: None,
+            include_origin,
+        };
+        let nearby = nearby_systems(
+            starmap,
+            index,
+            nod,
+            [position.x, position.y, position.z],
+            &request,
+        );
+        (nod, nearby)
+    }
+
+    #[test]
+    fn test_origin_excluded_by_default() {
+        let (nod, nearby) = nearby_from_nod(false);
+        assert!(!nearby.is_empty());
+        assert!(nearby.iter().all(|s| s.id != nod));
+    }
+
+    #[test]
+    fn test_include_origin_sorts_origin_first_at_zero() {
+        let (nod, nearby) = nearby_from_nod(true);
+        assert_eq!(nearby[0].id, nod);
+        assert_eq!(nearby[0].distance_ly, 0.0);
+        assert_eq!(nearby.iter().filter(|s| s.id == nod).count(), 1);
+        assert!(nearby.len() <= 5);
+    }
+
+    #[tokio::test]
+    async fn test_neighbor_count_histogram_records_each_request() {
+        let recorder = build_recorder(&MetricsConfig::default()).expect("recorder builds");
+        let handle = recorder.handle();
+        let _guard = metrics::set_default_local_recorder(&recorder);
+
+        let state = fixture_app_state();
+        for limit in [1, 2, 5] {
+            let request = ScoutRangeRequest {
+                system: fixture_systems::NOD.to_string(),
+                limit,
+                radius: None,
+                max_temperature: None,
+                include_origin: false,
+            };
+            let response = scout_range_handler(State(state.clone()), Json(request)).await;
+            assert!(matches!(response, Response::Success(_)));
+        }
+
+        let rendered = handle.render();
+        let series = |prefix: &str| {
+            rendered
+                .lines()
+                .find(|line| line.starts_with(prefix))
+                .and_then(|line| line.rsplit(' ').next())
+        };
+        assert_eq!(
+            series("evefrontier_neighbors_returned_count{"),
+            Some("3"),
+            "{rendered}"
+        );
+        assert_eq!(
+            series("evefrontier_neighbors_returned_sum{"),
+            Some("8"),
+            "{rendered}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_http_scout_range_returns_nearby_systems() {
+        let response = send_json(
+            router(fixture_app_state()),
+            Method::POST,
+            "/api/v1/scout/range",
+            Some(json!({ "system": fixture_systems::NOD, "limit": 3 })),
+        )
+        .await;
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.content_type(), Some("application/json"));
+        assert_eq!(response.body["system"], "Nod");
+        let nearby = response.body["nearby"]
+            .as_array()
+            .expect("nearby is an array");
+        assert!(!nearby.is_empty() && nearby.len() <= 3);
+        assert_eq!(response.body["count"], nearby.len());
+    }
+
+    #[tokio::test]
+    async fn test_http_scout_range_unknown_system_is_problem_json() {
+        let response = send_json(
+            router(fixture_app_state()),
+            Method::POST,
+            "/api/v1/scout/range",
+            Some(json!({ "system": "Nodd" })),
+        )
+        .await;
+
+        assert_eq!(response.status, StatusCode::NOT_FOUND);
+        assert_eq!(response.content_type(), Some("application/problem+json"));
+        assert_eq!(response.body["type"], PROBLEM_UNKNOWN_SYSTEM);
+    }
+
+    #[tokio::test]
+    async fn test_http_scout_range_zero_limit_is_bad_request() {
+        let response = send_json(
+            router(fixture_app_state()),
+            Method::POST,
+            "/api/v1/scout/range",
+            Some(json!({ "system": fixture_systems::NOD, "limit": 0 })),
+        )
+        .await;
+
+        assert_eq!(response.status, StatusCode::BAD_REQUEST);
+        assert_eq!(response.body["type"], PROBLEM_INVALID_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_http_scout_range_without_index_is_unavailable() {
+        // The fixture ships without a spatial index file.
+        let response = send_json(
+            router(test_state()),
+            Method::POST,
+            "/api/v1/scout/range",
+            Some(json!({ "system": fixture_systems::NOD })),
+        )
+        .await;
+
+        assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.body["type"], PROBLEM_SERVICE_UNAVAILABLE);
+    }
+}
@@ -1,9 +1,14 @@
 //! Health check handlers for Kubernetes probes.
 //!
 //! Provides `/health/live` and `/health/ready` endpoints that return JSON
-//! status responses for Kubernetes liveness and readiness probes.
+//! status responses for Kubernetes liveness and readiness probes. When the
+//! dataset path is known, readiness also verifies that the spatial index was
+//! built from the loaded dataset (see [`FreshnessProbe`]).
 
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
 
 use axum::{
     extract::State,
@@ -11,10 +16,18 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
+use evefrontier_lib::spatial::{spatial_index_path, verify_freshness, FreshnessResult};
 use serde::{Deserialize, Serialize};
 
 use crate::AppState;
 
+/// Environment variable setting how long a spatial index freshness result is
+/// reused by the readiness probe, in seconds.
+pub const FRESHNESS_CACHE_SECS_ENV: &str = "READINESS_FRESHNESS_CACHE_SECS";
+
+/// Default time a freshness result is reused before the dataset is hashed again.
+pub const DEFAULT_FRESHNESS_CACHE: Duration = Duration::from_secs(60);
+
 /// Health check status values.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -187,6 +200,104 @@ impl HealthStatus {
     }
 }
 
+/// Spatial index freshness check for the readiness probe.
+///
+/// Verifying freshness hashes the whole dataset, so a result is reused for the
+/// configured interval rather than recomputed on every probe.
+#[derive(Debug)]
+pub struct FreshnessProbe {
+    db_path: PathBuf,
+    index_path: PathBuf,
+    interval: Duration,
+    cached: Mutex<Option<(Instant, FreshnessResult)>>,
+}
+
+impl FreshnessProbe {
+    /// Check the index stored next to `db_path`, reusing each result for `interval`.
+    pub fn new(db_path: impl AsRef<Path>, interval: Duration) -> Self {
+        let db_path = db_path.as_ref();
+        Self {
+            db_path: db_path.to_path_buf(),
+            index_path: spatial_index_path(db_path),
+            interval,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Cache interval from `READINESS_FRESHNESS_CACHE_SECS`, or
+    /// [`DEFAULT_FRESHNESS_CACHE`] when unset or not a whole number of seconds.
+    pub fn interval_from_env() -> Duration {
+        match std::env::var(FRESHNESS_CACHE_SECS_ENV) {
+            Ok(value) => value
+                .trim()
+                .parse()
+                .map(Duration::from_secs)
+                .unwrap_or_else(|_| {
+                    tracing::warn!(
+                        value = %value,
+                        "ignoring invalid {FRESHNESS_CACHE_SECS_ENV}; using the default"
+                    );
+                    DEFAULT_FRESHNESS_CACHE
+                }),
+            Err(_) => DEFAULT_FRESHNESS_CACHE,
+        }
+    }
+
+    /// Freshness of the index, verified at most once per interval.
+    pub fn check(&self) -> FreshnessResult {
+        self.check_at(Instant::now())
+    }
+
+    fn check_at(&self, now: Instant) -> FreshnessResult {
+        // Holding the lock while verifying keeps concurrent probes from hashing twice.
+        let mut cached = self.cached.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some((checked_at, result)) = cached.as_ref() {
+            if now.saturating_duration_since(*checked_at) < self.interval {
+                return result.clone();
+            }
+        }
+
+        let result = verify_freshness(&self.index_path, &self.db_path);
+        *cached = Some((now, result.clone()));
+        result
+    }
+}
+
+/// Readiness check for a freshness result. A legacy index cannot be verified,
+/// so it only degrades readiness; every other failure makes the service not ready.
+fn freshness_check(result: &FreshnessResult) -> CheckResult {
+    match result {
+        FreshnessResult::Fresh { checksum, .. } => {
+            CheckResult::ok_with_detail("checksum", checksum.as_str())
+        }
+        FreshnessResult::Stale {
+            expected_checksum,
+            actual_checksum,
+            ..
+        } => {
+            let mut check =
+                CheckResult::error("spatial index is stale; rebuild it with `index-build --force`");
+            check.details.insert(
+                "index_checksum".to_string(),
+                expected_checksum.as_str().into(),
+            );
+            check.details.insert(
+                "dataset_checksum".to_string(),
+                actual_checksum.as_str().into(),
+            );
+            check
+        }
+        FreshnessResult::LegacyFormat { message, .. } => CheckResult::degraded(message.as_str()),
+        FreshnessResult::Missing { expected_path } => {
+            CheckResult::error(format!("spatial index missing at {expected_path}"))
+        }
+        FreshnessResult::DatasetMissing { expected_path } => {
+            CheckResult::error(format!("dataset missing at {expected_path}"))
+        }
+        FreshnessResult::Error { message } => CheckResult::error(message.as_str()),
+    }
+}
+
 /// Liveness probe handler.
 ///
 /// Returns 200 OK if the service is running. This is a simple check that does
@@ -206,8 +317,10 @@ pub async fn health_live() -> impl IntoResponse {
 /// Readiness probe handler.
 ///
 /// Returns 200 OK if the service is ready to accept traffic. Checks that the
-/// starmap and spatial index are loaded. Returns detailed check results for
-/// each dependency.
+/// starmap and spatial index are loaded and, when the state carries a
+/// [`FreshnessProbe`], that the index on disk matches the dataset
+/// (`spatial_index_freshness`). Returns detailed check results for each
+/// dependency.
 ///
 /// # Example (success)
 ///
@@ -259,6 +372,18 @@ pub async fn health_ready(State(state): State<AppState>) -> Response {
         );
     }
 
+    if let Some(probe) = state.freshness_probe() {
+        let result = tokio::task::spawn_blocking(move || probe.check())
+            .await
+            .unwrap_or_else(|e| FreshnessResult::Error {
+                message: format!("freshness check failed: {e}"),
+            });
+        checks.insert(
+            "spatial_index_freshness".to_string(),
+            freshness_check(&result),
+        );
+    }
+
     let status =
         HealthStatus::ready_with_checks(service, version, systems_count, spatial_ready, checks);
 
@@ -273,6 +398,8 @@ pub async fn health_ready(State(state): State<AppState>) -> Response {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use evefrontier_lib::db::load_starmap;
+    use evefrontier_lib::spatial::{compute_dataset_checksum, DatasetMetadata, SpatialIndex};
 
     #[test]
     fn test_health_status_alive() {
@@ -431,4 +558,119 @@ mod tests {
         assert_eq!(parsed["checks"]["database"]["status"], "ok");
         assert_eq!(parsed["checks"]["database"]["systems_count"], 100);
     }
+
+    /// Copy the fixture database into a temp dir so tests can place an index next to it.
+    fn temp_dataset() -> (tempfile::TempDir, PathBuf) {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let db_path = dir.path().join("static_data.db");
+        std::fs::copy(crate::test_utils::fixture_db_path(), &db_path).expect("copy fixture");
+        (dir, db_path)
+    }
+
+    /// Save an index next to `db_path` that claims to be built from `checksum`.
+    fn write_index(db_path: &Path, checksum: [u8; 32]) {
+        let starmap = load_starmap(db_path, None).expect("fixture loads");
+        let metadata = DatasetMetadata {
+            checksum,
+            release_tag: None,
+            build_timestamp: 0,
+        };
+        SpatialIndex::build_with_metadata(&starmap, metadata)
+            .save(&spatial_index_path(db_path))
+            .expect("save index");
+    }
+
+    fn fresh_dataset() -> (tempfile::TempDir, PathBuf) {
+        let (dir, db_path) = temp_dataset();
+        write_index(
+            &db_path,
+            compute_dataset_checksum(&db_path).expect("checksum"),
+        );
+        (dir, db_path)
+    }
+
+    async fn ready_response(db_path: &Path) -> (StatusCode, serde_json::Value) {
+        let state = AppState::load(db_path).expect("state loads");
+        let response = health_ready(State(state)).await;
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body");
+        (status, serde_json::from_slice(&body).expect("json body"))
+    }
+
+    #[tokio::test]
+    async fn test_health_ready_fresh_index() {
+        let (_dir, db_path) = fresh_dataset();
+
+        let (status, body) = ready_response(&db_path).await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(body["status"], "ok");
+        let check = &body["checks"]["spatial_index_freshness"];
+        assert_eq!(check["status"], "ok", "{body}");
+        assert_eq!(check["checksum"].as_str().map(str::len), Some(64));
+    }
+
+    #[tokio::test]
+    async fn test_health_ready_stale_index() {
+        let (_dir, db_path) = temp_dataset();
+        write_index(&db_path, [0u8; 32]);
+
+        let (status, body) = ready_response(&db_path).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE, "{body}");
+        assert_eq!(body["status"], "not_ready");
+        let check = &body["checks"]["spatial_index_freshness"];
+        assert_eq!(check["status"], "error", "{body}");
+        assert!(
+            check["message"].as_str().unwrap().contains("stale"),
+            "{body}"
+        );
+        assert_eq!(check["index_checksum"], "0".repeat(64));
+    }
+
+    #[tokio::test]
+    async fn test_health_ready_missing_index() {
+        let (_dir, db_path) = temp_dataset();
+
+        let (status, body) = ready_response(&db_path).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE, "{body}");
+        let check = &body["checks"]["spatial_index_freshness"];
+        assert_eq!(check["status"], "error", "{body}");
+        assert!(
+            check["message"].as_str().unwrap().contains("missing"),
+            "{body}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_health_ready_skips_freshness_without_dataset_path() {
+        let state = crate::test_utils::fixture_app_state();
+        let response = health_ready(State(state)).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn test_freshness_probe_caches_result_for_interval() {
+        let (_dir, db_path) = fresh_dataset();
+        let probe = FreshnessProbe::new(&db_path, Duration::from_secs(60));
+        let start = Instant::now();
+
+        assert!(matches!(
+            probe.check_at(start),
+            FreshnessResult::Fresh { .. }
+        ));
+
+        std::fs::remove_file(spatial_index_path(&db_path)).expect("remove index");
+        assert!(
+            matches!(
+                probe.check_at(start + Duration::from_secs(30)),
+                FreshnessResult::Fresh { .. }
+            ),
+            "result is reused within the interval"
+        );
+        assert!(matches!(
+            probe.check_at(start + Duration::from_secs(61)),
+            FreshnessResult::Missing { .. }
+        ));
+    }
 }
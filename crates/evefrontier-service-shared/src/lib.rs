@@ -8,7 +8,8 @@
 //! - [`ServiceResponse`]: Wrapper for successful responses with content type
 //! - [`metrics`]: Prometheus metrics infrastructure
 //! - [`logging`]: Structured JSON logging setup
-//! - [`middleware`]: Request tracking and metrics middleware
+//! - [`middleware`]: Request tracking, metrics, and rate-limiting middleware
+//! - [`GracefulShutdown`]: SIGTERM/SIGINT handling with a bounded drain period
 //! - Request types with validation for each endpoint
 //!
 //! # Architecture
@@ -40,22 +41,39 @@ pub mod middleware;
 mod problem;
 mod request;
 mod response;
+mod shutdown;
 mod state;
 
 #[cfg(any(test, feature = "test-utils"))]
 pub mod test_utils;
 
-pub use health::{health_live, health_ready, HealthStatus};
+pub use health::{
+    health_live, health_ready, FreshnessProbe, HealthStatus, DEFAULT_FRESHNESS_CACHE,
+    FRESHNESS_CACHE_SECS_ENV,
+};
 pub use logging::{init_logging, LogFormat, LoggingConfig};
 pub use metrics::{
-    init_metrics, metrics_handler, record_neighbors_returned, record_route_calculated,
-    record_route_failed, record_route_hops, record_systems_queried, MetricsConfig, MetricsError,
+    build_recorder, init_metrics, metrics_handler, record_build_info, record_neighbors_returned,
+    record_route_calculated, record_route_duration, record_route_error, record_route_failed,
+    record_route_hops, record_systems_queried, MetricsConfig, MetricsError,
+};
+pub use middleware::{
+    extract_or_generate_request_id, DatasetHeadersLayer, MetricsLayer, RateLimitConfig,
+    RateLimitLayer, RateLimiter, RequestId, DATASET_CHECKSUM_HEADER, DATASET_RELEASE_HEADER,
+    RATE_LIMIT_BURST_ENV, RATE_LIMIT_RPS_ENV,
 };
-pub use middleware::{extract_or_generate_request_id, MetricsLayer, RequestId};
 pub use problem::{
-    from_lib_error, ProblemDetails, PROBLEM_INTERNAL_ERROR, PROBLEM_INVALID_REQUEST,
-    PROBLEM_ROUTE_NOT_FOUND, PROBLEM_SERVICE_UNAVAILABLE, PROBLEM_UNKNOWN_SYSTEM,
+    from_lib_error, instance_urn, problem_type_for_kind, ProblemDetails, CONTENT_TYPE_PROBLEM_JSON,
+    INSTANCE_URN_PREFIX, PROBLEM_INTERNAL_ERROR, PROBLEM_INVALID_REQUEST, PROBLEM_RATE_LIMITED,
+    PROBLEM_ROUTE_NOT_FOUND, PROBLEM_SERVICE_UNAVAILABLE, PROBLEM_TYPES, PROBLEM_TYPE_BASE_URL,
+    PROBLEM_UNKNOWN_SYSTEM,
+};
+pub use request::{
+    RouteAlgorithm, RouteCompareRequest, RouteRequest, ScoutGatesRequest, ScoutRangeRequest,
+    Validate, MAX_SCOUT_GATES_DEPTH,
 };
-pub use request::{RouteAlgorithm, RouteRequest, ScoutGatesRequest, ScoutRangeRequest, Validate};
 pub use response::ServiceResponse;
-pub use state::{AppState, AppStateError};
+pub use shutdown::{
+    shutdown_signal, GracefulShutdown, DEFAULT_SHUTDOWN_GRACE, SHUTDOWN_GRACE_SECS_ENV,
+};
+pub use state::{AppState, AppStateError, DEFAULT_ROUTE_ALGORITHM_ENV};
@@ -4,6 +4,7 @@
 //! - [`MetricsConfig`]: Configuration for the metrics system
 //! - [`init_metrics`]: Initialize the Prometheus metrics recorder
 //! - [`metrics_handler`]: Axum handler for `/metrics` endpoint
+//! - [`record_build_info`]: Constant `evefrontier_build_info` gauge for joining metrics to versions
 //! - Business metric helpers for route and scout services
 //!
 //! # Example
@@ -21,7 +22,10 @@
 //!     .route("/metrics", get(metrics_handler));
 //! ```
 
-use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+use evefrontier_lib::Error as LibError;
+use metrics_exporter_prometheus::{
+    Matcher, PrometheusBuilder, PrometheusHandle, PrometheusRecorder,
+};
 use once_cell::sync::OnceCell;
 use serde::{Deserialize, Serialize};
 
@@ -35,6 +39,33 @@ pub struct MetricsConfig {
     pub enabled: bool,
     /// Path for the metrics endpoint (e.g., "/metrics").
     pub path: String,
+    /// Dataset release tag exported as the `dataset_release` constant label
+    /// (`"unknown"` when not set).
+    #[serde(default)]
+    pub dataset_release: Option<String>,
+    /// Application version exported as the `app_version` constant label.
+    #[serde(default = "default_app_version")]
+    pub app_version: String,
+}
+
+/// Histogram buckets for `evefrontier_neighbors_returned` (results per scout request).
+///
+/// The `0` bucket isolates empty responses so always-empty queries stand out.
+const NEIGHBORS_RETURNED_BUCKETS: &[f64] = &[0.0, 1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0];
+
+/// Name of the route computation latency histogram.
+const ROUTE_DURATION_METRIC: &str = "evefrontier_route_duration_seconds";
+
+/// Histogram buckets for `evefrontier_route_duration_seconds`, from 1 ms to 5 s.
+const ROUTE_DURATION_BUCKETS: &[f64] = &[
+    0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0,
+];
+
+/// Label value used when the dataset release tag is not known.
+const UNKNOWN_RELEASE: &str = "unknown";
+
+fn default_app_version() -> String {
+    env!("CARGO_PKG_VERSION").to_string()
 }
 
 impl Default for MetricsConfig {
@@ -42,6 +73,8 @@ impl Default for MetricsConfig {
         Self {
             enabled: true,
             path: "/metrics".to_string(),
+            dataset_release: None,
+            app_version: default_app_version(),
         }
     }
 }
@@ -58,14 +91,32 @@ impl MetricsConfig {
 
         let path = std::env::var("METRICS_PATH").unwrap_or_else(|_| "/metrics".to_string());
 
-        Self { enabled, path }
+        Self {
+            enabled,
+            path,
+            ..Self::default()
+        }
+    }
+
+    /// Set the dataset release tag exported as the `dataset_release` label.
+    pub fn with_dataset_release(mut self, release: Option<impl Into<String>>) -> Self {
+        self.dataset_release = release.map(Into::into);
+        self
+    }
+
+    /// Set the version exported as the `app_version` label, typically the
+    /// service's `CARGO_PKG_VERSION`.
+    pub fn with_app_version(mut self, version: impl Into<String>) -> Self {
+        self.app_version = version.into();
+        self
     }
 }
 
 /// Initialize the Prometheus metrics recorder.
 ///
 /// This must be called once at application startup before any metrics are recorded.
-/// Subsequent calls will return an error.
+/// Subsequent calls will return an error. Every exported series carries the
+/// `dataset_release` and `app_version` constant labels from `config`.
 ///
 /// # Errors
 ///
@@ -78,7 +129,7 @@ pub fn init_metrics(config: &MetricsConfig) -> Result<(), MetricsError> {
         return Err(MetricsError::Disabled);
     }
 
-    let handle = PrometheusBuilder::new()
+    let handle = prometheus_builder(config)?
         .install_recorder()
         .map_err(|e| MetricsError::InstallFailed(e.to_string()))?;
 
@@ -86,9 +137,44 @@ pub fn init_metrics(config: &MetricsConfig) -> Result<(), MetricsError> {
         .set(handle)
         .map_err(|_| MetricsError::AlreadyInitialized)?;
 
+    metrics::describe_histogram!(
+        ROUTE_DURATION_METRIC,
+        metrics::Unit::Seconds,
+        "Time spent planning a route, excluding request parsing and response rendering"
+    );
+
     Ok(())
 }
 
+/// Build a Prometheus recorder configured like [`init_metrics`] without installing it.
+///
+/// Useful for scoping metrics to a thread with `metrics::set_default_local_recorder`,
+/// e.g. to assert on handler metrics in tests.
+pub fn build_recorder(config: &MetricsConfig) -> Result<PrometheusRecorder, MetricsError> {
+    Ok(prometheus_builder(config)?.build_recorder())
+}
+
+/// Prometheus builder with the constant labels and histogram buckets from `config` applied.
+fn prometheus_builder(config: &MetricsConfig) -> Result<PrometheusBuilder, MetricsError> {
+    PrometheusBuilder::new()
+        .add_global_label(
+            "dataset_release",
+            config.dataset_release.as_deref().unwrap_or(UNKNOWN_RELEASE),
+        )
+        .add_global_label("app_version", &config.app_version)
+        .set_buckets_for_metric(
+            Matcher::Full("evefrontier_neighbors_returned".to_string()),
+            NEIGHBORS_RETURNED_BUCKETS,
+        )
+        .and_then(|builder| {
+            builder.set_buckets_for_metric(
+                Matcher::Full(ROUTE_DURATION_METRIC.to_string()),
+                ROUTE_DURATION_BUCKETS,
+            )
+        })
+        .map_err(|e| MetricsError::InstallFailed(e.to_string()))
+}
+
 /// Get the Prometheus handle for rendering metrics.
 ///
 /// Returns `None` if [`init_metrics`] has not been called.
@@ -131,6 +217,20 @@ impl std::fmt::Display for MetricsError {
 
 impl std::error::Error for MetricsError {}
 
+/// Record the `evefrontier_build_info` gauge.
+///
+/// Sets a gauge with `version` and `release` labels to 1, so dashboards can join other
+/// series to the running build. Call once at startup after [`init_metrics`];
+/// `dataset_release` is reported as `"unknown"` when not set.
+pub fn record_build_info(version: &str, dataset_release: Option<&str>) {
+    metrics::gauge!(
+        "evefrontier_build_info",
+        "version" => version.to_string(),
+        "release" => dataset_release.unwrap_or(UNKNOWN_RELEASE).to_string()
+    )
+    .set(1.0);
+}
+
 // =============================================================================
 // Business Metrics Helpers
 // =============================================================================
@@ -169,6 +269,14 @@ pub fn record_route_failed(reason: &str, service: &str) {
     .increment(1);
 }
 
+/// Record a route calculation that failed inside the library.
+///
+/// The `reason` label is derived from [`LibError::kind`], so it stays stable when
+/// error messages are reworded.
+pub fn record_route_error(error: &LibError, service: &str) {
+    record_route_failed(error.kind().as_str(), service);
+}
+
 /// Record the number of hops in a successful route.
 ///
 /// Records to the `evefrontier_route_hops` histogram.
@@ -185,6 +293,24 @@ pub fn record_route_hops(hops: usize, algorithm: &str) {
     .record(hops as f64);
 }
 
+/// Record how long a route calculation took.
+///
+/// Records to the `evefrontier_route_duration_seconds` histogram, exported with buckets
+/// from 1 ms to 5 s. Callers time only the planning call, so the value excludes request
+/// parsing and response rendering.
+///
+/// # Arguments
+///
+/// * `seconds` - Elapsed planning time in seconds
+/// * `algorithm` - The algorithm used (e.g., "bfs", "dijkstra", "astar")
+pub fn record_route_duration(seconds: f64, algorithm: &str) {
+    metrics::histogram!(
+        ROUTE_DURATION_METRIC,
+        "algorithm" => algorithm.to_string()
+    )
+    .record(seconds);
+}
+
 /// Record a system query from scout endpoints.
 ///
 /// Increments the `evefrontier_systems_queried_total` counter.
@@ -204,7 +330,8 @@ pub fn record_systems_queried(query_type: &str, service: &str) {
 
 /// Record the number of neighbors returned by scout queries.
 ///
-/// Records to the `evefrontier_neighbors_returned` histogram.
+/// Records one observation per request to the `evefrontier_neighbors_returned` histogram,
+/// exported with result-count buckets (0, 1, 2, 5, 10, 20, 50, 100).
 ///
 /// # Arguments
 ///
@@ -227,6 +354,8 @@ mod tests {
         let config = MetricsConfig::default();
         assert!(config.enabled);
         assert_eq!(config.path, "/metrics");
+        assert_eq!(config.dataset_release, None);
+        assert_eq!(config.app_version, env!("CARGO_PKG_VERSION"));
     }
 
     #[test]
@@ -302,6 +431,83 @@ mod tests {
         record_route_failed("validation_error", "route");
     }
 
+    #[test]
+    fn test_route_error_reason_derives_from_error_kind() {
+        let recorder = PrometheusBuilder::new().build_recorder();
+        let handle = recorder.handle();
+
+        metrics::with_local_recorder(&recorder, || {
+            let error = LibError::UnknownSystem {
+                name: "Nodd".to_string(),
+                suggestions: vec![],
+            };
+            record_route_error(&error, "route");
+            let error = LibError::RouteNotFound {
+                start: "Nod".to_string(),
+                goal: "Brana".to_string(),
+            };
+            record_route_error(&error, "route");
+        });
+
+        let rendered = handle.render();
+        assert!(
+            rendered.contains(r#"reason="unknown_system""#),
+            "{rendered}"
+        );
+        assert!(rendered.contains(r#"reason="no_path""#), "{rendered}");
+    }
+
+    #[test]
+    fn test_exported_metrics_include_constant_labels() {
+        let config = MetricsConfig::default()
+            .with_dataset_release(Some("e6c3"))
+            .with_app_version("1.2.3");
+        let recorder = build_recorder(&config).expect("recorder builds");
+        let handle = recorder.handle();
+
+        metrics::with_local_recorder(&recorder, || {
+            record_route_calculated("bfs", "route");
+        });
+
+        let rendered = handle.render();
+        assert!(rendered.contains(r#"dataset_release="e6c3""#), "{rendered}");
+        assert!(rendered.contains(r#"app_version="1.2.3""#), "{rendered}");
+    }
+
+    #[test]
+    fn test_missing_dataset_release_is_labelled_unknown() {
+        let recorder = build_recorder(&MetricsConfig::default()).expect("recorder builds");
+        let handle = recorder.handle();
+
+        metrics::with_local_recorder(&recorder, || {
+            record_systems_queried("gates", "scout-gates");
+        });
+
+        let rendered = handle.render();
+        assert!(
+            rendered.contains(r#"dataset_release="unknown""#),
+            "{rendered}"
+        );
+    }
+
+    #[test]
+    fn test_build_info_gauge_in_metrics_output() {
+        // The only test that installs the global recorder, so `metrics_handler` renders it.
+        init_metrics(&MetricsConfig::default()).expect("metrics install once");
+        record_build_info("1.2.3", Some("e6c3"));
+
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let output = rt.block_on(async { metrics_handler().await });
+
+        let line = output
+            .lines()
+            .find(|line| line.starts_with("evefrontier_build_info{"))
+            .unwrap_or_else(|| panic!("build info gauge missing: {output}"));
+        assert!(line.contains(r#"version="1.2.3""#), "{line}");
+        assert!(line.contains(r#"release="e6c3""#), "{line}");
+        assert!(line.ends_with(" 1"), "{line}");
+    }
+
     #[test]
     fn test_business_metric_route_hops() {
         record_route_hops(5, "bfs");
@@ -309,6 +515,35 @@ mod tests {
         record_route_hops(15, "astar");
     }
 
+    #[test]
+    fn test_route_duration_updates_count_and_sum() {
+        let recorder = build_recorder(&MetricsConfig::default()).expect("recorder builds");
+        let handle = recorder.handle();
+
+        metrics::with_local_recorder(&recorder, || {
+            record_route_duration(0.004, "dijkstra");
+            record_route_duration(0.2, "dijkstra");
+        });
+
+        let rendered = handle.render();
+        let value = |suffix: &str, le: Option<&str>| {
+            rendered
+                .lines()
+                .find(|line| {
+                    line.starts_with(&format!("evefrontier_route_duration_seconds_{suffix}{{"))
+                        && line.contains(r#"algorithm="dijkstra""#)
+                        && le.is_none_or(|le| line.contains(&format!(r#"le="{le}""#)))
+                })
+                .and_then(|line| line.rsplit(' ').next())
+                .and_then(|value| value.parse::<f64>().ok())
+        };
+        assert_eq!(value("count", None), Some(2.0), "{rendered}");
+        let sum = value("sum", None).unwrap_or_else(|| panic!("sum missing: {rendered}"));
+        assert!((sum - 0.204).abs() < 1e-9, "{rendered}");
+        assert_eq!(value("bucket", Some("0.005")), Some(1.0), "{rendered}");
+        assert_eq!(value("bucket", Some("5")), Some(2.0), "{rendered}");
+    }
+
     #[test]
     fn test_business_metric_systems_queried() {
         record_systems_queried("gates", "scout-gates");
@@ -321,6 +556,33 @@ mod tests {
         record_neighbors_returned(10, "range");
     }
 
+    #[test]
+    fn test_neighbors_returned_exports_buckets() {
+        let recorder = build_recorder(&MetricsConfig::default()).expect("recorder builds");
+        let handle = recorder.handle();
+
+        metrics::with_local_recorder(&recorder, || {
+            for count in [0, 3, 7] {
+                record_neighbors_returned(count, "range");
+            }
+        });
+
+        let rendered = handle.render();
+        let bucket = |le: &str| {
+            rendered
+                .lines()
+                .find(|line| {
+                    line.starts_with("evefrontier_neighbors_returned_bucket{")
+                        && line.contains(&format!(r#"le="{le}""#))
+                })
+                .and_then(|line| line.rsplit(' ').next())
+                .map(str::to_string)
+        };
+        assert_eq!(bucket("0").as_deref(), Some("1"), "{rendered}");
+        assert_eq!(bucket("5").as_deref(), Some("2"), "{rendered}");
+        assert_eq!(bucket("10").as_deref(), Some("3"), "{rendered}");
+    }
+
     #[test]
     fn test_metrics_error_display() {
         let disabled = MetricsError::Disabled;
@@ -4,6 +4,9 @@
 //! - [`RequestId`]: Newtype for correlation ID extraction/generation
 //! - [`extract_or_generate_request_id`]: Extract X-Request-ID header or generate UUID v7
 //! - [`MetricsLayer`]: Tower middleware for recording HTTP metrics
+//! - [`DatasetHeadersLayer`]: Tower middleware tagging successful responses with
+//!   the dataset version
+//! - [`RateLimitLayer`]: Tower middleware enforcing a per-IP token bucket
 //!
 //! # Request ID Propagation
 //!
@@ -18,18 +21,42 @@
 //! - `http_request_duration_seconds`: Histogram by method, path
 //! - `http_request_size_bytes`: Histogram by method, path
 //! - `http_response_size_bytes`: Histogram by method, path
+//!
+//! # Dataset Version Headers
+//!
+//! The `DatasetHeadersLayer` adds `X-Dataset-Checksum` (first 16 hex characters
+//! of the dataset SHA-256) and `X-Dataset-Release` (when the release tag is
+//! known) to every 2xx response, so clients can tell which dataset answered.
+//!
+//! # Rate Limiting
+//!
+//! The `RateLimitLayer` gives each client IP a token bucket refilled at
+//! `RATE_LIMIT_RPS` tokens per second and holding at most `RATE_LIMIT_BURST`
+//! tokens. A request that finds the bucket empty gets a 429 problem with a
+//! `Retry-After` header. Health and metrics endpoints are never limited.
 
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
 use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::net::{IpAddr, SocketAddr};
 use std::pin::Pin;
+use std::sync::{Arc, Mutex, PoisonError};
 use std::task::{Context, Poll};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-use axum::http::{HeaderMap, Request, Response};
+use axum::body::Body;
+use axum::extract::ConnectInfo;
+use axum::http::{HeaderMap, HeaderName, HeaderValue, Request, Response};
+use axum::response::IntoResponse;
+use evefrontier_lib::spatial::DatasetMetadata;
 use pin_project_lite::pin_project;
 use tower::{Layer, Service};
 use tracing::{info_span, Span};
 use uuid::Uuid;
 
+use crate::ProblemDetails;
+
 /// Newtype wrapper for request correlation IDs.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RequestId(pub String);
@@ -317,6 +344,375 @@ where
     }
 }
 
+// =============================================================================
+// DatasetHeadersLayer - Tower middleware for dataset version headers
+// =============================================================================
+
+/// Response header carrying the first 16 hex characters of the dataset checksum.
+pub const DATASET_CHECKSUM_HEADER: &str = "x-dataset-checksum";
+
+/// Response header carrying the dataset release tag (e.g. `e6c3`).
+pub const DATASET_RELEASE_HEADER: &str = "x-dataset-release";
+
+/// Tower layer adding dataset version headers to successful responses.
+///
+/// Build it from [`AppState::dataset_metadata`](crate::AppState::dataset_metadata);
+/// with no metadata the layer is a pass-through.
+#[derive(Debug, Clone)]
+pub struct DatasetHeadersLayer {
+    headers: Arc<[(HeaderName, HeaderValue)]>,
+}
+
+impl DatasetHeadersLayer {
+    /// Create a layer reporting the given dataset metadata.
+    pub fn new(metadata: Option<&DatasetMetadata>) -> Self {
+        let mut headers = Vec::new();
+        if let Some(metadata) = metadata {
+            if let Ok(value) = HeaderValue::from_str(&metadata.short_checksum()) {
+                headers.push((HeaderName::from_static(DATASET_CHECKSUM_HEADER), value));
+            }
+            if let Some(value) = metadata
+                .release_tag
+                .as_deref()
+                .and_then(|tag| HeaderValue::from_str(tag).ok())
+            {
+                headers.push((HeaderName::from_static(DATASET_RELEASE_HEADER), value));
+            }
+        }
+        Self {
+            headers: headers.into(),
+        }
+    }
+}
+
+impl<S> Layer<S> for DatasetHeadersLayer {
+    type Service = DatasetHeadersMiddleware<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        DatasetHeadersMiddleware {
+            inner,
+            headers: Arc::clone(&self.headers),
+        }
+    }
+}
+
+/// Middleware service that adds dataset version headers to 2xx responses.
+#[derive(Debug, Clone)]
+pub struct DatasetHeadersMiddleware<S> {
+    inner: S,
+    headers: Arc<[(HeaderName, HeaderValue)]>,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for DatasetHeadersMiddleware<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = DatasetHeadersFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        DatasetHeadersFuture {
+            inner: self.inner.call(req),
+            headers: Arc::clone(&self.headers),
+        }
+    }
+}
+
+pin_project! {
+    /// Future wrapper that adds dataset headers to successful responses.
+    pub struct DatasetHeadersFuture<F> {
+        #[pin]
+        inner: F,
+        headers: Arc<[(HeaderName, HeaderValue)]>,
+    }
+}
+
+impl<F, ResBody, E> Future for DatasetHeadersFuture<F>
+where
+    F: Future<Output = Result<Response<ResBody>, E>>,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut result = std::task::ready!(this.inner.poll(cx));
+        if let Ok(response) = &mut result {
+            if response.status().is_success() {
+                for (name, value) in this.headers.iter() {
+                    response.headers_mut().insert(name.clone(), value.clone());
+                }
+            }
+        }
+        Poll::Ready(result)
+    }
+}
+
+// =============================================================================
+// RateLimitLayer - Tower middleware for per-IP rate limiting
+// =============================================================================
+
+/// Environment variable holding the sustained request rate per client IP.
+pub const RATE_LIMIT_RPS_ENV: &str = "RATE_LIMIT_RPS";
+
+/// Environment variable holding the burst size (bucket capacity) per client IP.
+pub const RATE_LIMIT_BURST_ENV: &str = "RATE_LIMIT_BURST";
+
+/// Number of independently locked bucket maps; requests from different IPs
+/// rarely contend on the same lock.
+const RATE_LIMIT_SHARDS: usize = 16;
+
+/// Shard size above which idle, fully refilled buckets are evicted.
+const RATE_LIMIT_PRUNE_THRESHOLD: usize = 4096;
+
+/// Token bucket parameters shared by every client IP.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimitConfig {
+    /// Tokens added per second.
+    pub rps: f64,
+    /// Maximum tokens a bucket holds; also the number of back-to-back requests
+    /// a fresh client may make.
+    pub burst: u32,
+}
+
+impl RateLimitConfig {
+    /// Load configuration from `RATE_LIMIT_RPS` and `RATE_LIMIT_BURST`.
+    ///
+    /// Returns `None` (rate limiting disabled) when `RATE_LIMIT_RPS` is unset,
+    /// unparsable, or not positive. `RATE_LIMIT_BURST` defaults to the rate
+    /// rounded up, with a minimum of one.
+    pub fn from_env() -> Option<Self> {
+        Self::parse(
+            std::env::var(RATE_LIMIT_RPS_ENV).ok().as_deref(),
+            std::env::var(RATE_LIMIT_BURST_ENV).ok().as_deref(),
+        )
+    }
+
+    fn parse(rps: Option<&str>, burst: Option<&str>) -> Option<Self> {
+        let rps = rps
+            .and_then(|v| v.trim().parse::<f64>().ok())
+            .filter(|v| v.is_finite() && *v > 0.0)?;
+        let burst = burst
+            .and_then(|v| v.trim().parse::<u32>().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or_else(|| (rps.ceil() as u32).max(1));
+        Some(Self { rps, burst })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TokenBucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// Per-IP token buckets, sharded by IP hash to avoid a global lock.
+#[derive(Debug)]
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    shards: Box<[Mutex<HashMap<IpAddr, TokenBucket>>]>,
+}
+
+impl RateLimiter {
+    /// Create a limiter where every IP starts with a full bucket.
+    pub fn new(config: RateLimitConfig) -> Self {
+        let shards = (0..RATE_LIMIT_SHARDS)
+            .map(|_| Mutex::new(HashMap::new()))
+            .collect();
+        Self { config, shards }
+    }
+
+    /// The configuration this limiter enforces.
+    pub fn config(&self) -> RateLimitConfig {
+        self.config
+    }
+
+    /// Take a token for `ip`, or return how long until one is available.
+    pub fn check(&self, ip: IpAddr) -> Result<(), Duration> {
+        self.check_at(ip, Instant::now())
+    }
+
+    /// [`check`](Self::check) at an explicit instant.
+    pub fn check_at(&self, ip: IpAddr, now: Instant) -> Result<(), Duration> {
+        let capacity = f64::from(self.config.burst);
+        let rps = self.config.rps;
+
+        let mut buckets = self.shards[shard_index(ip)]
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if buckets.len() >= RATE_LIMIT_PRUNE_THRESHOLD && !buckets.contains_key(&ip) {
+            buckets.retain(|_, bucket| {
+                let idle = now.saturating_duration_since(bucket.updated).as_secs_f64();
+                bucket.tokens + idle * rps < capacity
+            });
+        }
+
+        let bucket = buckets.entry(ip).or_insert(TokenBucket {
+            tokens: capacity,
+            updated: now,
+        });
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * rps).min(capacity);
+        bucket.updated = bucket.updated.max(now);
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / rps))
+        }
+    }
+}
+
+fn shard_index(ip: IpAddr) -> usize {
+    let mut hasher = DefaultHasher::new();
+    ip.hash(&mut hasher);
+    (hasher.finish() % RATE_LIMIT_SHARDS as u64) as usize
+}
+
+/// Whether `path` bypasses rate limiting (probes and metric scrapes).
+fn is_rate_limit_exempt(path: &str) -> bool {
+    path.starts_with("/health/") || path == "/metrics"
+}
+
+/// Client IP from axum's `ConnectInfo`, falling back to a bare `SocketAddr`
+/// extension.
+fn client_ip<B>(req: &Request<B>) -> Option<IpAddr> {
+    req.extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip())
+        .or_else(|| req.extensions().get::<SocketAddr>().map(SocketAddr::ip))
+}
+
+/// Tower layer rejecting clients that exceed their token bucket.
+///
+/// The client IP comes from `ConnectInfo<SocketAddr>`, so serve the router with
+/// `into_make_service_with_connect_info::<SocketAddr>()`. Requests without a
+/// peer address pass through, as does everything when the layer is disabled.
+#[derive(Debug, Clone)]
+pub struct RateLimitLayer {
+    limiter: Option<Arc<RateLimiter>>,
+}
+
+impl RateLimitLayer {
+    /// Create a layer enforcing `config`, or a pass-through layer for `None`.
+    pub fn new(config: Option<RateLimitConfig>) -> Self {
+        Self {
+            limiter: config.map(|config| Arc::new(RateLimiter::new(config))),
+        }
+    }
+
+    /// Create a layer from [`RateLimitConfig::from_env`].
+    pub fn from_env() -> Self {
+        Self::new(RateLimitConfig::from_env())
+    }
+
+    /// Whether this layer limits requests.
+    pub fn is_enabled(&self) -> bool {
+        self.limiter.is_some()
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimitMiddleware<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimitMiddleware {
+            inner,
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+/// Middleware service that answers over-limit requests with a 429 problem.
+#[derive(Debug, Clone)]
+pub struct RateLimitMiddleware<S> {
+    inner: S,
+    limiter: Option<Arc<RateLimiter>>,
+}
+
+impl<S, ReqBody> Service<Request<ReqBody>> for RateLimitMiddleware<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<Body>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = RateLimitFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        if let Some(limiter) = &self.limiter {
+            if !is_rate_limit_exempt(normalize_path(req.uri().path())) {
+                if let Some(ip) = client_ip(&req) {
+                    if let Err(wait) = limiter.check(ip) {
+                        let request_id = extract_or_generate_request_id(req.headers());
+                        tracing::debug!(
+                            request_id = %request_id,
+                            client_ip = %ip,
+                            "rate limit exceeded"
+                        );
+                        return RateLimitFuture::Limited {
+                            response: Some(rate_limited_response(wait, &request_id)),
+                        };
+                    }
+                }
+            }
+        }
+
+        RateLimitFuture::Allowed {
+            inner: self.inner.call(req),
+        }
+    }
+}
+
+/// Build the 429 response; `Retry-After` is whole seconds, at least one.
+fn rate_limited_response(wait: Duration, request_id: &RequestId) -> Response<Body> {
+    let retry_after = wait.as_secs_f64().ceil().max(1.0) as u64;
+    let mut response =
+        ProblemDetails::rate_limited(retry_after, request_id.as_str()).into_response();
+    response
+        .headers_mut()
+        .insert(http::header::RETRY_AFTER, HeaderValue::from(retry_after));
+    response
+}
+
+pin_project! {
+    /// Future that either runs the inner service or yields a 429 response.
+    #[project = RateLimitFutureProj]
+    pub enum RateLimitFuture<F> {
+        Allowed {
+            #[pin]
+            inner: F,
+        },
+        Limited {
+            response: Option<Response<Body>>,
+        },
+    }
+}
+
+impl<F, E> Future for RateLimitFuture<F>
+where
+    F: Future<Output = Result<Response<Body>, E>>,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.project() {
+            RateLimitFutureProj::Allowed { inner } => inner.poll(cx),
+            RateLimitFutureProj::Limited { response } => Poll::Ready(Ok(response
+                .take()
+                .expect("rate-limited future polled after completion"))),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -392,4 +788,254 @@ mod tests {
         assert_eq!(status_bucket(500), "5xx");
         assert_eq!(status_bucket(503), "5xx");
     }
+
+    fn dataset_router(metadata: Option<&DatasetMetadata>) -> axum::Router {
+        use axum::routing::get;
+
+        axum::Router::new()
+            .route("/ok", get(|| async { "ok" }))
+            .route(
+                "/missing",
+                get(|| async { (http::StatusCode::NOT_FOUND, "missing") }),
+            )
+            .layer(DatasetHeadersLayer::new(metadata))
+    }
+
+    async fn call(router: &mut axum::Router, path: &str) -> Response<axum::body::Body> {
+        let request = Request::builder()
+            .uri(path)
+            .body(axum::body::Body::empty())
+            .unwrap();
+        Service::call(router, request).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_dataset_headers_on_success() {
+        let state = crate::test_utils::test_state();
+        let metadata = state.dataset_metadata().expect("fixture metadata");
+        let expected = evefrontier_lib::spatial::compute_dataset_checksum(
+            &crate::test_utils::fixture_db_path(),
+        )
+        .expect("checksum");
+        let expected: String = expected[..8].iter().map(|b| format!("{:02x}", b)).collect();
+
+        let mut router = dataset_router(Some(metadata));
+        let response = call(&mut router, "/ok").await;
+
+        assert_eq!(
+            response.headers().get(DATASET_CHECKSUM_HEADER).unwrap(),
+            expected.as_str()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dataset_headers_include_release_tag() {
+        let metadata = DatasetMetadata {
+            checksum: [0x12; 32],
+            release_tag: Some("e6c3".to_string()),
+            build_timestamp: 0,
+        };
+
+        let mut router = dataset_router(Some(&metadata));
+        let response = call(&mut router, "/ok").await;
+
+        assert_eq!(
+            response.headers().get(DATASET_CHECKSUM_HEADER).unwrap(),
+            "1212121212121212"
+        );
+        assert_eq!(
+            response.headers().get(DATASET_RELEASE_HEADER).unwrap(),
+            "e6c3"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dataset_headers_skipped_on_error_and_without_metadata() {
+        let metadata = DatasetMetadata {
+            checksum: [0x12; 32],
+            release_tag: None,
+            build_timestamp: 0,
+        };
+
+        let mut router = dataset_router(Some(&metadata));
+        let response = call(&mut router, "/missing").await;
+        assert!(response.headers().get(DATASET_CHECKSUM_HEADER).is_none());
+
+        let response = call(&mut router, "/ok").await;
+        assert!(response.headers().get(DATASET_RELEASE_HEADER).is_none());
+
+        let mut router = dataset_router(None);
+        let response = call(&mut router, "/ok").await;
+        assert!(response.headers().get(DATASET_CHECKSUM_HEADER).is_none());
+    }
+
+    fn client(last: u8) -> IpAddr {
+        IpAddr::from([203, 0, 113, last])
+    }
+
+    #[test]
+    fn test_rate_limit_config_parse() {
+        let config = RateLimitConfig::parse(Some("2.5"), Some("10")).unwrap();
+        assert_eq!(
+            config,
+            RateLimitConfig {
+                rps: 2.5,
+                burst: 10
+            }
+        );
+
+        let config = RateLimitConfig::parse(Some("2.5"), None).unwrap();
+        assert_eq!(config.burst, 3);
+        let config = RateLimitConfig::parse(Some("0.2"), Some("0")).unwrap();
+        assert_eq!(config.burst, 1);
+
+        assert!(RateLimitConfig::parse(None, Some("10")).is_none());
+        assert!(RateLimitConfig::parse(Some("0"), None).is_none());
+        assert!(RateLimitConfig::parse(Some("-1"), None).is_none());
+        assert!(RateLimitConfig::parse(Some("fast"), None).is_none());
+    }
+
+    #[test]
+    fn test_rate_limiter_rejects_after_burst_and_refills() {
+        let limiter = RateLimiter::new(RateLimitConfig { rps: 2.0, burst: 3 });
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            assert!(limiter.check_at(client(1), start).is_ok());
+        }
+        let wait = limiter.check_at(client(1), start).unwrap_err();
+        assert_eq!(wait, Duration::from_millis(500));
+
+        // Other clients have their own bucket.
+        assert!(limiter.check_at(client(2), start).is_ok());
+
+        // Half a second refills exactly one token.
+        let later = start + Duration::from_millis(500);
+        assert!(limiter.check_at(client(1), later).is_ok());
+        assert!(limiter.check_at(client(1), later).is_err());
+
+        // A long idle period refills to the burst size, not beyond.
+        let idle = later + Duration::from_secs(60);
+        for _ in 0..3 {
+            assert!(limiter.check_at(client(1), idle).is_ok());
+        }
+        assert!(limiter.check_at(client(1), idle).is_err());
+    }
+
+    #[test]
+    fn test_rate_limiter_prunes_refilled_buckets() {
+        let limiter = RateLimiter::new(RateLimitConfig { rps: 1.0, burst: 1 });
+        let start = Instant::now();
+        for i in 0..(RATE_LIMIT_PRUNE_THRESHOLD * RATE_LIMIT_SHARDS) as u32 {
+            let _ = limiter.check_at(IpAddr::from(i.to_be_bytes()), start);
+        }
+
+        let later = start + Duration::from_secs(5);
+        for i in 0..RATE_LIMIT_SHARDS as u32 * 8 {
+            let _ = limiter.check_at(IpAddr::from((u32::MAX - i).to_be_bytes()), later);
+        }
+        let total: usize = limiter
+            .shards
+            .iter()
+            .map(|shard| shard.lock().unwrap().len())
+            .sum();
+        assert!(total < RATE_LIMIT_PRUNE_THRESHOLD * RATE_LIMIT_SHARDS);
+    }
+
+    fn limited_router(config: RateLimitConfig) -> axum::Router {
+        use axum::routing::get;
+
+        axum::Router::new()
+            .route("/api/v1/ping", get(|| async { "pong" }))
+            .route("/health/live", get(|| async { "ok" }))
+            .layer(RateLimitLayer::new(Some(config)))
+    }
+
+    async fn call_from(
+        router: &mut axum::Router,
+        path: &str,
+        ip: IpAddr,
+    ) -> Response<axum::body::Body> {
+        let mut request = Request::builder()
+            .uri(path)
+            .header("x-request-id", "req-rl")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(SocketAddr::new(ip, 40000)));
+        Service::call(router, request).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_layer_rejects_with_problem_and_retry_after() {
+        let mut router = limited_router(RateLimitConfig { rps: 0.5, burst: 2 });
+
+        for _ in 0..2 {
+            let response = call_from(&mut router, "/api/v1/ping", client(1)).await;
+            assert_eq!(response.status(), http::StatusCode::OK);
+        }
+
+        let response = call_from(&mut router, "/api/v1/ping", client(1)).await;
+        assert_eq!(response.status(), http::StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(
+            response.headers().get(http::header::RETRY_AFTER).unwrap(),
+            "2"
+        );
+        assert_eq!(
+            response.headers().get(http::header::CONTENT_TYPE).unwrap(),
+            crate::CONTENT_TYPE_PROBLEM_JSON
+        );
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["type"], crate::PROBLEM_RATE_LIMITED);
+        assert_eq!(body["status"], 429);
+        assert_eq!(body["instance"], "urn:evefrontier:request:req-rl");
+
+        // Health probes and other clients are unaffected.
+        let response = call_from(&mut router, "/health/live", client(1)).await;
+        assert_eq!(response.status(), http::StatusCode::OK);
+        let response = call_from(&mut router, "/api/v1/ping", client(2)).await;
+        assert_eq!(response.status(), http::StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_layer_allows_again_after_refill() {
+        let mut router = limited_router(RateLimitConfig {
+            rps: 20.0,
+            burst: 1,
+        });
+
+        let response = call_from(&mut router, "/api/v1/ping", client(1)).await;
+        assert_eq!(response.status(), http::StatusCode::OK);
+        let response = call_from(&mut router, "/api/v1/ping", client(1)).await;
+        assert_eq!(response.status(), http::StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(
+            response.headers().get(http::header::RETRY_AFTER).unwrap(),
+            "1"
+        );
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let response = call_from(&mut router, "/api/v1/ping", client(1)).await;
+        assert_eq!(response.status(), http::StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_layer_disabled_or_without_peer_passes_through() {
+        let mut router = axum::Router::new()
+            .route("/api/v1/ping", axum::routing::get(|| async { "pong" }))
+            .layer(RateLimitLayer::new(None));
+        for _ in 0..5 {
+            let response = call_from(&mut router, "/api/v1/ping", client(1)).await;
+            assert_eq!(response.status(), http::StatusCode::OK);
+        }
+
+        let mut router = limited_router(RateLimitConfig { rps: 1.0, burst: 1 });
+        for _ in 0..3 {
+            let response = call(&mut router, "/api/v1/ping").await;
+            assert_eq!(response.status(), http::StatusCode::OK);
+        }
+    }
 }
@@ -8,24 +8,95 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::Value;
 
-use evefrontier_lib::Error as LibError;
+use evefrontier_lib::{Error as LibError, ErrorKind};
+
+/// Media type of every problem body (RFC 9457 §3).
+pub const CONTENT_TYPE_PROBLEM_JSON: &str = "application/problem+json";
+
+/// Base URL that every problem `type` URI lives under.
+pub const PROBLEM_TYPE_BASE_URL: &str = "https://evefrontier.dev/problems/";
 
 /// Problem type URI for unknown system names.
-pub const PROBLEM_UNKNOWN_SYSTEM: &str = "/problems/unknown-system";
+pub const PROBLEM_UNKNOWN_SYSTEM: &str = "https://evefrontier.dev/problems/unknown-system";
 
 /// Problem type URI for routes that cannot be found.
-pub const PROBLEM_ROUTE_NOT_FOUND: &str = "/problems/route-not-found";
+pub const PROBLEM_ROUTE_NOT_FOUND: &str = "https://evefrontier.dev/problems/route-not-found";
 
 /// Problem type URI for invalid request parameters.
-pub const PROBLEM_INVALID_REQUEST: &str = "/problems/invalid-request";
+pub const PROBLEM_INVALID_REQUEST: &str = "https://evefrontier.dev/problems/invalid-request";
 
 /// Problem type URI for internal server errors.
-pub const PROBLEM_INTERNAL_ERROR: &str = "/problems/internal-error";
+pub const PROBLEM_INTERNAL_ERROR: &str = "https://evefrontier.dev/problems/internal-error";
 
 /// Problem type URI for service unavailable (e.g., missing spatial index).
-pub const PROBLEM_SERVICE_UNAVAILABLE: &str = "/problems/service-unavailable";
+pub const PROBLEM_SERVICE_UNAVAILABLE: &str =
+    "https://evefrontier.dev/problems/service-unavailable";
+
+/// Problem type URI for clients that exceeded the per-IP rate limit.
+pub const PROBLEM_RATE_LIMITED: &str = "https://evefrontier.dev/problems/rate-limited";
+
+/// Every problem type URI this crate emits.
+pub const PROBLEM_TYPES: [&str; 6] = [
+    PROBLEM_UNKNOWN_SYSTEM,
+    PROBLEM_ROUTE_NOT_FOUND,
+    PROBLEM_INVALID_REQUEST,
+    PROBLEM_INTERNAL_ERROR,
+    PROBLEM_SERVICE_UNAVAILABLE,
+    PROBLEM_RATE_LIMITED,
+];
+
+/// Problem type URI for a library error kind.
+///
+/// This is the single mapping from [`ErrorKind`] to `type`; [`from_lib_error`] produces problems
+/// whose `type` always matches it, so clients can branch on the URI instead of the title.
+pub fn problem_type_for_kind(kind: ErrorKind) -> &'static str {
+    match kind {
+        ErrorKind::UnknownSystem => PROBLEM_UNKNOWN_SYSTEM,
+        ErrorKind::RouteNotFound => PROBLEM_ROUTE_NOT_FOUND,
+        ErrorKind::InvalidRequest => PROBLEM_INVALID_REQUEST,
+        ErrorKind::DatasetUnavailable => PROBLEM_SERVICE_UNAVAILABLE,
+        ErrorKind::EmptyRoute
+        | ErrorKind::Temperature
+        | ErrorKind::ShipData
+        | ErrorKind::DatasetInvalid
+        | ErrorKind::SpatialIndex
+        | ErrorKind::Fmap
+        | ErrorKind::Io
+        | ErrorKind::Http => PROBLEM_INTERNAL_ERROR,
+    }
+}
+
+/// URN prefix for `instance` values; the request id and any problem-specific segments follow.
+pub const INSTANCE_URN_PREFIX: &str = "urn:evefrontier:request:";
+
+/// Build the `instance` URN for a problem occurrence.
+///
+/// Produces `urn:evefrontier:request:<request_id>` followed by `:<segment>` for each extra
+/// segment (e.g. `unknown-system`, `<name>`). Segments are percent-encoded, including `:`,
+/// so system names such as `D:2NAS` cannot be confused with segment boundaries.
+pub fn instance_urn(request_id: &str, segments: &[&str]) -> String {
+    let mut urn = format!("{}{}", INSTANCE_URN_PREFIX, encode_urn_segment(request_id));
+    for segment in segments {
+        urn.push(':');
+        urn.push_str(&encode_urn_segment(segment));
+    }
+    urn
+}
+
+fn encode_urn_segment(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
 
 /// RFC 9457 Problem Details response structure.
 ///
@@ -45,9 +116,9 @@ pub const PROBLEM_SERVICE_UNAVAILABLE: &str = "/problems/service-unavailable";
 /// .with_detail("System 'InvalidName' not found. Did you mean: 'Nod', 'Brana'?")
 /// .with_request_id("req-12345");
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct ProblemDetails {
-    /// URI reference identifying the problem type (relative).
+    /// URI identifying the problem type (one of [`PROBLEM_TYPES`]).
     #[serde(rename = "type")]
     pub type_uri: String,
 
@@ -58,14 +129,12 @@ pub struct ProblemDetails {
     pub status: u16,
 
     /// Human-readable explanation specific to this occurrence.
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub detail: Option<String>,
 
-    /// URI reference identifying the specific occurrence (e.g., request ID).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// URN identifying the specific occurrence (see [`instance_urn`]).
     pub instance: Option<String>,
 
-    /// Content type for this response (always "application/problem+json").
+    /// Content type for this response (always [`CONTENT_TYPE_PROBLEM_JSON`]).
     pub content_type: String,
 }
 
@@ -78,19 +147,37 @@ impl ProblemDetails {
             status: status.as_u16(),
             detail: None,
             instance: None,
-            content_type: "application/problem+json".to_string(),
+            content_type: CONTENT_TYPE_PROBLEM_JSON.to_string(),
         }
     }
 
+    /// The JSON body for this problem as a [`Value`].
+    ///
+    /// Built from the same [`Serialize`] impl that every response enum embedding a problem
+    /// uses, so problem bodies carry identical fields across transports. `detail` and
+    /// `instance` are omitted when unset. A `Value` object does not keep key order;
+    /// serialize the problem itself to get the `type, title, status, detail, instance`
+    /// wire order.
+    pub fn to_json_value(&self) -> Value {
+        serde_json::to_value(ProblemBody::from(self))
+            .expect("problem bodies contain only strings and integers")
+    }
+
     /// Add a detailed explanation of this specific problem occurrence.
     pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
         self.detail = Some(detail.into());
         self
     }
 
-    /// Add the request identifier for tracing.
+    /// Set `instance` to the request's URN (`urn:evefrontier:request:<request_id>`).
     pub fn with_request_id(mut self, request_id: impl Into<String>) -> Self {
-        self.instance = Some(request_id.into());
+        self.instance = Some(instance_urn(&request_id.into(), &[]));
+        self
+    }
+
+    /// Set `instance` to the request's URN with problem-specific segments appended.
+    fn with_instance_segments(mut self, request_id: impl Into<String>, segments: &[&str]) -> Self {
+        self.instance = Some(instance_urn(&request_id.into(), segments));
         self
     }
 
@@ -127,7 +214,7 @@ impl ProblemDetails {
             StatusCode::NOT_FOUND,
         )
         .with_detail(detail)
-        .with_request_id(request_id)
+        .with_instance_segments(request_id, &["unknown-system", name])
     }
 
     /// Create a 404 Not Found problem for unreachable routes.
@@ -138,7 +225,7 @@ impl ProblemDetails {
             StatusCode::NOT_FOUND,
         )
         .with_detail(format!("No route exists from '{}' to '{}'", start, goal))
-        .with_request_id(request_id)
+        .with_instance_segments(request_id, &["route-not-found", start, goal])
     }
 
     /// Create a 500 Internal Server Error problem.
@@ -162,6 +249,37 @@ impl ProblemDetails {
         .with_detail(detail)
         .with_request_id(request_id)
     }
+
+    /// Create a 429 Too Many Requests problem.
+    ///
+    /// The matching `Retry-After` header is added by the rate-limiting middleware.
+    pub fn rate_limited(retry_after_secs: u64, request_id: impl Into<String>) -> Self {
+        Self::new(
+            PROBLEM_RATE_LIMITED,
+            "Too Many Requests",
+            StatusCode::TOO_MANY_REQUESTS,
+        )
+        .with_detail(format!(
+            "Rate limit exceeded; retry after {} second(s)",
+            retry_after_secs
+        ))
+        .with_request_id(request_id)
+    }
+
+    /// Convert an `anyhow::Error` to ProblemDetails.
+    ///
+    /// If any error in the chain is a library error, this delegates to [`from_lib_error`] so
+    /// callers get the same status and problem type as the services. Anything else becomes a
+    /// 500 whose `detail` carries the full context chain (`outer: inner: ...`).
+    pub fn from_anyhow(err: &anyhow::Error, request_id: &str) -> Self {
+        let lib_error = err
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<LibError>());
+        match lib_error {
+            Some(lib_error) => from_lib_error(lib_error, request_id),
+            None => Self::internal_error(format!("{err:#}"), request_id),
+        }
+    }
 }
 
 impl std::fmt::Display for ProblemDetails {
@@ -177,6 +295,39 @@ impl std::fmt::Display for ProblemDetails {
 
 impl std::error::Error for ProblemDetails {}
 
+/// Borrowed view of a [`ProblemDetails`] that serializes its fields in wire order.
+#[derive(Serialize)]
+struct ProblemBody<'a> {
+    #[serde(rename = "type")]
+    type_uri: &'a str,
+    title: &'a str,
+    status: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detail: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instance: Option<&'a str>,
+    content_type: &'a str,
+}
+
+impl<'a> From<&'a ProblemDetails> for ProblemBody<'a> {
+    fn from(problem: &'a ProblemDetails) -> Self {
+        Self {
+            type_uri: &problem.type_uri,
+            title: &problem.title,
+            status: problem.status,
+            detail: problem.detail.as_deref(),
+            instance: problem.instance.as_deref(),
+            content_type: &problem.content_type,
+        }
+    }
+}
+
+impl Serialize for ProblemDetails {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ProblemBody::from(self).serialize(serializer)
+    }
+}
+
 /// Implement IntoResponse for axum to return ProblemDetails as HTTP responses.
 impl IntoResponse for ProblemDetails {
     fn into_response(self) -> Response {
@@ -186,7 +337,7 @@ impl IntoResponse for ProblemDetails {
         let mut response = Json(&self).into_response();
         response.headers_mut().insert(
             axum::http::header::CONTENT_TYPE,
-            axum::http::HeaderValue::from_static("application/problem+json"),
+            axum::http::HeaderValue::from_static(CONTENT_TYPE_PROBLEM_JSON),
         );
 
         // Update status code
@@ -213,7 +364,13 @@ pub fn from_lib_error(error: &LibError, request_id: &str) -> ProblemDetails {
         LibError::UnsupportedSchema => {
             ProblemDetails::internal_error("Unsupported dataset schema", request_id)
         }
-        _ => ProblemDetails::internal_error(error.to_string(), request_id),
+        _ => match problem_type_for_kind(error.kind()) {
+            PROBLEM_SERVICE_UNAVAILABLE => {
+                ProblemDetails::service_unavailable(error.to_string(), request_id)
+            }
+            PROBLEM_INVALID_REQUEST => ProblemDetails::bad_request(error.to_string(), request_id),
+            _ => ProblemDetails::internal_error(error.to_string(), request_id),
+        },
     }
 }
 
@@ -253,7 +410,10 @@ mod tests {
     fn test_problem_details_bad_request() {
         let problem = ProblemDetails::bad_request("Invalid JSON", "req-123");
         assert_eq!(problem.status, 400);
-        assert_eq!(problem.instance.as_deref(), Some("req-123"));
+        assert_eq!(
+            problem.instance.as_deref(),
+            Some("urn:evefrontier:request:req-123")
+        );
     }
 
     #[test]
@@ -274,16 +434,53 @@ mod tests {
         assert!(!problem.detail.as_deref().unwrap().contains("Did you mean"));
     }
 
+    #[test]
+    fn test_serialize_goes_through_to_json_value() {
+        let problem = ProblemDetails::new(
+            PROBLEM_INTERNAL_ERROR,
+            "Internal Error",
+            StatusCode::INTERNAL_SERVER_ERROR,
+        );
+        let json = problem.to_json_value();
+        assert_eq!(serde_json::to_value(&problem).unwrap(), json);
+        assert!(json.get("detail").is_none());
+        assert!(json.get("instance").is_none());
+        assert_eq!(json["content_type"], CONTENT_TYPE_PROBLEM_JSON);
+
+        let parsed: ProblemDetails = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.status, 500);
+        assert_eq!(parsed.detail, None);
+    }
+
+    #[test]
+    fn test_serialize_keeps_field_order() {
+        let problem = ProblemDetails::internal_error("boom", "req-1");
+        let json = serde_json::to_string(&problem).unwrap();
+
+        let positions: Vec<usize> = [
+            "type",
+            "title",
+            "status",
+            "detail",
+            "instance",
+            "content_type",
+        ]
+        .iter()
+        .map(|key| json.find(&format!("\"{key}\":")).expect(key))
+        .collect();
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]), "{json}");
+    }
+
     #[test]
     fn test_problem_details_serialization() {
         let problem = ProblemDetails::bad_request("Test error", "req-test");
         let json = serde_json::to_string(&problem).unwrap();
 
-        assert!(json.contains("\"type\":\"/problems/invalid-request\""));
+        assert!(json.contains("\"type\":\"https://evefrontier.dev/problems/invalid-request\""));
         assert!(json.contains("\"title\":\"Invalid Request\""));
         assert!(json.contains("\"status\":400"));
         assert!(json.contains("\"detail\":\"Test error\""));
-        assert!(json.contains("\"instance\":\"req-test\""));
+        assert!(json.contains("\"instance\":\"urn:evefrontier:request:req-test\""));
     }
 
     #[test]
@@ -310,4 +507,159 @@ mod tests {
         assert!(problem.detail.as_deref().unwrap().contains("A"));
         assert!(problem.detail.as_deref().unwrap().contains("B"));
     }
+
+    #[test]
+    fn test_from_lib_error_uses_error_kind_fallbacks() {
+        let error = LibError::InvalidReachBudget {
+            message: "negative".to_string(),
+        };
+        assert_eq!(from_lib_error(&error, "req-kind").status, 400);
+
+        let error = LibError::DatasetReleaseNotFound {
+            tag: "e0000".to_string(),
+        };
+        assert_eq!(from_lib_error(&error, "req-kind").status, 503);
+
+        let error = LibError::SpatialIndexEmpty;
+        assert_eq!(from_lib_error(&error, "req-kind").status, 500);
+    }
+
+    #[test]
+    fn test_from_anyhow_downcasts_lib_error() {
+        let error = anyhow::Error::new(LibError::UnknownSystem {
+            name: "Nodd".to_string(),
+            suggestions: vec!["Nod".to_string()],
+        })
+        .context("failed to plan route");
+        let problem = ProblemDetails::from_anyhow(&error, "req-anyhow");
+
+        assert_eq!(problem.type_uri, PROBLEM_UNKNOWN_SYSTEM);
+        assert_eq!(problem.status, 404);
+        assert_eq!(
+            problem.instance.as_deref(),
+            Some("urn:evefrontier:request:req-anyhow:unknown-system:Nodd")
+        );
+    }
+
+    #[test]
+    fn test_from_anyhow_generic_error_is_internal() {
+        let error = anyhow::anyhow!("disk full").context("failed to write route");
+        let problem = ProblemDetails::from_anyhow(&error, "req-anyhow");
+
+        assert_eq!(problem.type_uri, PROBLEM_INTERNAL_ERROR);
+        assert_eq!(problem.status, 500);
+        assert_eq!(
+            problem.detail.as_deref(),
+            Some("failed to write route: disk full")
+        );
+    }
+
+    #[test]
+    fn test_instance_is_request_urn_for_every_problem_type() {
+        let problems = [
+            ProblemDetails::bad_request("bad", "req-1"),
+            ProblemDetails::internal_error("boom", "req-1"),
+            ProblemDetails::service_unavailable("down", "req-1"),
+            ProblemDetails::unknown_system("Nodd", &[], "req-1"),
+            ProblemDetails::route_not_found("Nod", "Brana", "req-1"),
+        ];
+
+        for problem in &problems {
+            let instance = problem.instance.as_deref().expect("instance is set");
+            assert!(
+                instance.starts_with("urn:evefrontier:request:req-1"),
+                "{instance}"
+            );
+            assert!(!instance.contains(' '), "{instance}");
+        }
+    }
+
+    #[test]
+    fn test_instance_names_offending_values() {
+        let problem = ProblemDetails::unknown_system("D:2NAS", &[], "req-2");
+        assert_eq!(
+            problem.instance.as_deref(),
+            Some("urn:evefrontier:request:req-2:unknown-system:D%3A2NAS")
+        );
+
+        let problem = ProblemDetails::route_not_found("Nod", "E1J M5G", "req-3");
+        assert_eq!(
+            problem.instance.as_deref(),
+            Some("urn:evefrontier:request:req-3:route-not-found:Nod:E1J%20M5G")
+        );
+
+        let problem = ProblemDetails::bad_request("bad", "req-4");
+        assert_eq!(
+            problem.instance.as_deref(),
+            Some("urn:evefrontier:request:req-4")
+        );
+    }
+
+    #[test]
+    fn test_constructors_set_registered_type() {
+        let cases = [
+            (
+                ProblemDetails::unknown_system("Nodd", &[], "req-t"),
+                PROBLEM_UNKNOWN_SYSTEM,
+            ),
+            (
+                ProblemDetails::route_not_found("Nod", "Brana", "req-t"),
+                PROBLEM_ROUTE_NOT_FOUND,
+            ),
+            (
+                ProblemDetails::bad_request("bad", "req-t"),
+                PROBLEM_INVALID_REQUEST,
+            ),
+            (
+                ProblemDetails::internal_error("boom", "req-t"),
+                PROBLEM_INTERNAL_ERROR,
+            ),
+            (
+                ProblemDetails::service_unavailable("down", "req-t"),
+                PROBLEM_SERVICE_UNAVAILABLE,
+            ),
+            (
+                ProblemDetails::rate_limited(2, "req-t"),
+                PROBLEM_RATE_LIMITED,
+            ),
+        ];
+
+        for (problem, expected) in &cases {
+            assert_eq!(problem.type_uri, *expected);
+            assert!(PROBLEM_TYPES.contains(&problem.type_uri.as_str()));
+            assert!(problem.type_uri.starts_with(PROBLEM_TYPE_BASE_URL));
+        }
+    }
+
+    #[test]
+    fn test_from_lib_error_type_matches_registry() {
+        let errors = [
+            LibError::UnknownSystem {
+                name: "Nodd".to_string(),
+                suggestions: vec![],
+            },
+            LibError::RouteNotFound {
+                start: "Nod".to_string(),
+                goal: "Brana".to_string(),
+            },
+            LibError::InvalidReachBudget {
+                message: "negative".to_string(),
+            },
+            LibError::DatasetReleaseNotFound {
+                tag: "e0000".to_string(),
+            },
+            LibError::UnsupportedSchema,
+            LibError::SpatialIndexEmpty,
+            LibError::EmptyRoutePlan,
+        ];
+
+        for error in &errors {
+            let problem = from_lib_error(error, "req-t");
+            assert_eq!(
+                problem.type_uri,
+                problem_type_for_kind(error.kind()),
+                "{error:?}"
+            );
+        }
+    }
 }
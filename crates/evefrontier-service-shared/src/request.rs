@@ -1,5 +1,7 @@
 //! Request types and validation for HTTP endpoints.
 
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
 use crate::ProblemDetails;
@@ -21,15 +23,28 @@ pub trait Validate {
 /// Request for computing a route between two systems.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RouteRequest {
-    /// Starting system name.
+    /// Starting system name or numeric system ID.
     pub from: String,
 
-    /// Destination system name.
+    /// Destination system name or numeric system ID.
     pub to: String,
 
-    /// Routing algorithm to use.
-    #[serde(default)]
-    pub algorithm: RouteAlgorithm,
+    /// Routing algorithm to use; when omitted the service uses its configured
+    /// default (see [`AppState::default_algorithm`](crate::AppState::default_algorithm)),
+    /// which is [`RouteAlgorithm::default`] (A*) unless the deployment overrides it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<RouteAlgorithm>,
+
+    /// Named preset (`fast`, `safe`, or `cheap`) seeding the constraints below; explicit
+    /// constraint fields take precedence over the preset's values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preset: Option<evefrontier_lib::RoutePreset>,
+
+    /// Full constraint set echoed in a previous route response, to replay that route.
+    /// Seeds the constraints the same way as `preset` (and cannot be combined with it);
+    /// explicit constraint fields still take precedence.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constraints: Option<evefrontier_lib::RouteConstraints>,
 
     /// Maximum jump distance in light-years (for spatial routes).
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -46,6 +61,10 @@ pub struct RouteRequest {
     /// Maximum star temperature threshold in Kelvin.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_temperature: Option<f64>,
+
+    /// Include a per-hop `rationale` in the response. Off by default to keep responses small.
+    #[serde(default)]
+    pub explain: bool,
 }
 
 /// Supported routing algorithms.
@@ -61,6 +80,24 @@ pub enum RouteAlgorithm {
     AStar,
 }
 
+impl FromStr for RouteAlgorithm {
+    type Err = String;
+
+    /// Parse an algorithm name as accepted in request bodies (`bfs`, `dijkstra`,
+    /// `a-star`), case-insensitively; `astar` is accepted as an alias.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "bfs" => Ok(Self::Bfs),
+            "dijkstra" => Ok(Self::Dijkstra),
+            "a-star" | "astar" => Ok(Self::AStar),
+            other => Err(format!(
+                "unknown routing algorithm '{}'; expected bfs, dijkstra, or a-star",
+                other
+            )),
+        }
+    }
+}
+
 impl From<RouteAlgorithm> for evefrontier_lib::RouteAlgorithm {
     fn from(value: RouteAlgorithm) -> Self {
         match value {
@@ -87,6 +124,21 @@ impl Validate for RouteRequest {
             )));
         }
 
+        if let Some(constraints) = &self.constraints {
+            if self.preset.is_some() {
+                return Err(Box::new(ProblemDetails::bad_request(
+                    "The 'preset' and 'constraints' fields cannot be combined",
+                    request_id,
+                )));
+            }
+            if let Err(e) = constraints.validate() {
+                return Err(Box::new(ProblemDetails::bad_request(
+                    format!("The 'constraints' field is invalid: {}", e),
+                    request_id,
+                )));
+            }
+        }
+
         if let Some(max_jump) = self.max_jump {
             if max_jump <= 0.0 {
                 return Err(Box::new(ProblemDetails::bad_request(
@@ -109,11 +161,86 @@ impl Validate for RouteRequest {
     }
 }
 
+/// Request for comparing every routing algorithm between the same two systems.
+///
+/// Carries the same constraints as [`RouteRequest`] minus the algorithm, which
+/// the comparison endpoint varies itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteCompareRequest {
+    /// Starting system name or numeric system ID.
+    pub from: String,
+
+    /// Destination system name or numeric system ID.
+    pub to: String,
+
+    /// Maximum jump distance in light-years (for spatial routes).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_jump: Option<f64>,
+
+    /// Systems to avoid when computing the routes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub avoid: Vec<String>,
+
+    /// If true, avoid gates and use only spatial jumps.
+    #[serde(default)]
+    pub avoid_gates: bool,
+
+    /// Maximum star temperature threshold in Kelvin.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_temperature: Option<f64>,
+}
+
+impl RouteCompareRequest {
+    /// Algorithms compared by the endpoint, in response order.
+    pub const ALGORITHMS: [RouteAlgorithm; 3] = [
+        RouteAlgorithm::Bfs,
+        RouteAlgorithm::Dijkstra,
+        RouteAlgorithm::AStar,
+    ];
+
+    /// Build the single-algorithm [`RouteRequest`] sharing these constraints.
+    pub fn with_algorithm(&self, algorithm: RouteAlgorithm) -> RouteRequest {
+        RouteRequest {
+            from: self.from.clone(),
+            to: self.to.clone(),
+            algorithm: Some(algorithm),
+            preset: None,
+            constraints: None,
+            max_jump: self.max_jump,
+            avoid: self.avoid.clone(),
+            avoid_gates: self.avoid_gates,
+            max_temperature: self.max_temperature,
+            explain: false,
+        }
+    }
+}
+
+impl Validate for RouteCompareRequest {
+    fn validate(&self, request_id: &str) -> Result<(), Box<ProblemDetails>> {
+        self.with_algorithm(RouteAlgorithm::default())
+            .validate(request_id)
+    }
+}
+
+/// Maximum gate-hop depth accepted by [`ScoutGatesRequest`], bounding result size.
+pub const MAX_SCOUT_GATES_DEPTH: usize = 3;
+
 /// Request for finding gate-connected neighbors of a system.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScoutGatesRequest {
     /// System name or ID to find neighbors for.
     pub system: String,
+
+    /// Number of gate hops to explore (default 1, max [`MAX_SCOUT_GATES_DEPTH`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub depth: Option<usize>,
+}
+
+impl ScoutGatesRequest {
+    /// Gate-hop depth to search, applying the default of 1.
+    pub fn depth(&self) -> usize {
+        self.depth.unwrap_or(1)
+    }
 }
 
 impl Validate for ScoutGatesRequest {
@@ -124,6 +251,20 @@ impl Validate for ScoutGatesRequest {
                 request_id,
             )));
         }
+
+        if self.depth == Some(0) {
+            return Err(Box::new(ProblemDetails::bad_request(
+                "The 'depth' field must be at least 1",
+                request_id,
+            )));
+        }
+
+        if self.depth() > MAX_SCOUT_GATES_DEPTH {
+            return Err(Box::new(ProblemDetails::bad_request(
+                format!("The 'depth' field cannot exceed {}", MAX_SCOUT_GATES_DEPTH),
+                request_id,
+            )));
+        }
         Ok(())
     }
 }
@@ -145,6 +286,10 @@ pub struct ScoutRangeRequest {
     /// Maximum star temperature threshold in Kelvin.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_temperature: Option<f64>,
+
+    /// Include the origin system itself as the first result, at distance 0.
+    #[serde(default)]
+    pub include_origin: bool,
 }
 
 fn default_limit() -> usize {
@@ -205,11 +350,14 @@ mod tests {
         let req = RouteRequest {
             from: "Nod".to_string(),
             to: "Brana".to_string(),
-            algorithm: RouteAlgorithm::AStar,
+            algorithm: Some(RouteAlgorithm::AStar),
+            preset: None,
+            constraints: None,
             max_jump: Some(80.0),
             avoid: vec![],
             avoid_gates: false,
             max_temperature: None,
+            explain: false,
         };
         assert!(req.validate("test").is_ok());
     }
@@ -219,11 +367,14 @@ mod tests {
         let req = RouteRequest {
             from: "".to_string(),
             to: "Brana".to_string(),
-            algorithm: RouteAlgorithm::Bfs,
+            algorithm: Some(RouteAlgorithm::Bfs),
+            preset: None,
+            constraints: None,
             max_jump: None,
             avoid: vec![],
             avoid_gates: false,
             max_temperature: None,
+            explain: false,
         };
         let err = req.validate("test").unwrap_err();
         assert!(err.detail.as_deref().unwrap().contains("'from'"));
@@ -234,11 +385,14 @@ mod tests {
         let req = RouteRequest {
             from: "Nod".to_string(),
             to: "   ".to_string(),
-            algorithm: RouteAlgorithm::Bfs,
+            algorithm: Some(RouteAlgorithm::Bfs),
+            preset: None,
+            constraints: None,
             max_jump: None,
             avoid: vec![],
             avoid_gates: false,
             max_temperature: None,
+            explain: false,
         };
         let err = req.validate("test").unwrap_err();
         assert!(err.detail.as_deref().unwrap().contains("'to'"));
@@ -249,16 +403,52 @@ mod tests {
         let req = RouteRequest {
             from: "Nod".to_string(),
             to: "Brana".to_string(),
-            algorithm: RouteAlgorithm::Dijkstra,
+            algorithm: Some(RouteAlgorithm::Dijkstra),
+            preset: None,
+            constraints: None,
             max_jump: Some(-10.0),
             avoid: vec![],
             avoid_gates: false,
             max_temperature: None,
+            explain: false,
         };
         let err = req.validate("test").unwrap_err();
         assert!(err.detail.as_deref().unwrap().contains("'max_jump'"));
     }
 
+    #[test]
+    fn test_route_request_preset_deserialization() {
+        let req: RouteRequest =
+            serde_json::from_str(r#"{"from": "Nod", "to": "Brana", "preset": "safe"}"#).unwrap();
+        assert_eq!(req.preset, Some(evefrontier_lib::RoutePreset::Safe));
+
+        let err = serde_json::from_str::<RouteRequest>(
+            r#"{"from": "Nod", "to": "Brana", "preset": "scenic"}"#,
+        );
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_route_request_constraints_block() {
+        let mut req: RouteRequest = serde_json::from_str(
+            r#"{"from": "Nod", "to": "Brana", "constraints": {"max_jump": 80.0, "gate_penalty": 2.0}}"#,
+        )
+        .unwrap();
+        let constraints = req.constraints.as_ref().expect("constraints parsed");
+        assert_eq!(constraints.max_jump, Some(80.0));
+        assert_eq!(constraints.gate_penalty, 2.0);
+        assert!(req.validate("test").is_ok());
+
+        req.preset = Some(evefrontier_lib::RoutePreset::Safe);
+        let err = req.validate("test").unwrap_err();
+        assert!(err.detail.as_deref().unwrap().contains("'constraints'"));
+
+        req.preset = None;
+        req.constraints.as_mut().unwrap().jump_penalty = 0.0;
+        let err = req.validate("test").unwrap_err();
+        assert!(err.detail.as_deref().unwrap().contains("'constraints'"));
+    }
+
     #[test]
     fn test_route_algorithm_serialization() {
         let algo = RouteAlgorithm::AStar;
@@ -269,10 +459,43 @@ mod tests {
         assert_eq!(bfs, RouteAlgorithm::Bfs);
     }
 
+    #[test]
+    fn test_route_algorithm_from_str_matches_serde_names() {
+        for algorithm in RouteCompareRequest::ALGORITHMS {
+            let name = serde_json::to_value(algorithm).unwrap();
+            let parsed: RouteAlgorithm = name.as_str().unwrap().parse().unwrap();
+            assert_eq!(parsed, algorithm);
+        }
+        assert_eq!("AStar".parse(), Ok(RouteAlgorithm::AStar));
+        assert_eq!(" Dijkstra ".parse(), Ok(RouteAlgorithm::Dijkstra));
+
+        let err = "greedy".parse::<RouteAlgorithm>().unwrap_err();
+        assert!(err.contains("'greedy'"), "{err}");
+    }
+
+    #[test]
+    fn test_route_compare_request_validates_shared_fields() {
+        let json = r#"{"from":"Nod","to":"Brana","max_jump":80.0}"#;
+        let req: RouteCompareRequest = serde_json::from_str(json).unwrap();
+        assert!(req.validate("test").is_ok());
+
+        let bfs = req.with_algorithm(RouteAlgorithm::Bfs);
+        assert_eq!(bfs.algorithm, Some(RouteAlgorithm::Bfs));
+        assert_eq!(bfs.max_jump, Some(80.0));
+
+        let req = RouteCompareRequest {
+            to: " ".to_string(),
+            ..req
+        };
+        let err = req.validate("test").unwrap_err();
+        assert!(err.detail.as_deref().unwrap().contains("'to'"));
+    }
+
     #[test]
     fn test_scout_gates_request_valid() {
         let req = ScoutGatesRequest {
             system: "Nod".to_string(),
+            depth: None,
         };
         assert!(req.validate("test").is_ok());
     }
@@ -281,11 +504,32 @@ mod tests {
     fn test_scout_gates_request_empty() {
         let req = ScoutGatesRequest {
             system: "".to_string(),
+            depth: None,
         };
         let err = req.validate("test").unwrap_err();
         assert!(err.detail.as_deref().unwrap().contains("'system'"));
     }
 
+    #[test]
+    fn test_scout_gates_request_depth_bounds() {
+        let json = r#"{"system": "Nod"}"#;
+        let req: ScoutGatesRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.depth(), 1);
+
+        for (depth, ok) in [
+            (0, false),
+            (1, true),
+            (MAX_SCOUT_GATES_DEPTH, true),
+            (4, false),
+        ] {
+            let req = ScoutGatesRequest {
+                system: "Nod".to_string(),
+                depth: Some(depth),
+            };
+            assert_eq!(req.validate("test").is_ok(), ok, "depth {}", depth);
+        }
+    }
+
     #[test]
     fn test_scout_range_request_valid() {
         let req = ScoutRangeRequest {
@@ -293,6 +537,7 @@ mod tests {
             limit: 10,
             radius: Some(50.0),
             max_temperature: Some(8000.0),
+            include_origin: false,
         };
         assert!(req.validate("test").is_ok());
     }
@@ -304,6 +549,7 @@ mod tests {
             limit: 0,
             radius: None,
             max_temperature: None,
+            include_origin: false,
         };
         let err = req.validate("test").unwrap_err();
         assert!(err.detail.as_deref().unwrap().contains("'limit'"));
@@ -316,6 +562,7 @@ mod tests {
             limit: 101,
             radius: None,
             max_temperature: None,
+            include_origin: false,
         };
         let err = req.validate("test").unwrap_err();
         assert!(err.detail.as_deref().unwrap().contains("exceed 100"));
@@ -328,6 +575,7 @@ mod tests {
             limit: 10,
             radius: Some(-5.0),
             max_temperature: None,
+            include_origin: false,
         };
         let err = req.validate("test").unwrap_err();
         assert!(err.detail.as_deref().unwrap().contains("'radius'"));
@@ -337,7 +585,8 @@ mod tests {
     fn test_route_request_deserialization_defaults() {
         let json = r#"{"from":"Nod","to":"Brana"}"#;
         let req: RouteRequest = serde_json::from_str(json).unwrap();
-        assert_eq!(req.algorithm, RouteAlgorithm::AStar); // default
+        assert_eq!(req.algorithm, None); // service default applies
+        assert_eq!(req.algorithm.unwrap_or_default(), RouteAlgorithm::AStar);
         assert!(req.avoid.is_empty()); // default
         assert!(!req.avoid_gates); // default
     }
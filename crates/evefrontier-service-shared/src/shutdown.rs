@@ -0,0 +1,186 @@
+//! Graceful shutdown for the HTTP services.
+//!
+//! Kubernetes sends `SIGTERM` when it replaces a pod during a rolling deploy. The
+//! services pass [`GracefulShutdown::signal`] to `axum::serve(..).with_graceful_shutdown`
+//! so the listener stops accepting connections on the signal while in-flight requests
+//! finish. [`GracefulShutdown::grace_elapsed`] bounds how long they get:
+//!
+//! ```ignore
+//! let shutdown = GracefulShutdown::from_env();
+//! let server = axum::serve(listener, app).with_graceful_shutdown(shutdown.signal());
+//! tokio::select! {
+//!     result = server.into_future() => result?,
+//!     () = shutdown.grace_elapsed() => tracing::warn!("grace period elapsed"),
+//! }
+//! ```
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::watch;
+
+/// Environment variable overriding the shutdown grace period, in seconds.
+pub const SHUTDOWN_GRACE_SECS_ENV: &str = "SHUTDOWN_GRACE_SECS";
+
+/// Default grace period, just under the Kubernetes 30 second termination grace period.
+pub const DEFAULT_SHUTDOWN_GRACE: Duration = Duration::from_secs(25);
+
+/// Future that resolves when the process receives `SIGTERM` or `SIGINT` (Ctrl+C).
+///
+/// The Unix signal handlers are installed when this function is called rather than
+/// when the future is first polled, so a signal arriving in between is not missed.
+pub fn shutdown_signal() -> impl Future<Output = ()> + Send + 'static {
+    #[cfg(unix)]
+    let signal = {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let terminate = signal(SignalKind::terminate());
+        let interrupt = signal(SignalKind::interrupt());
+        async move {
+            let (mut terminate, mut interrupt) = match (terminate, interrupt) {
+                (Ok(terminate), Ok(interrupt)) => (terminate, interrupt),
+                (Err(e), _) | (_, Err(e)) => {
+                    tracing::error!(error = %e, "failed to install signal handlers");
+                    return std::future::pending().await;
+                }
+            };
+            tokio::select! {
+                _ = terminate.recv() => tracing::info!("received SIGTERM, shutting down"),
+                _ = interrupt.recv() => tracing::info!("received SIGINT, shutting down"),
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let signal = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::error!(error = %e, "failed to listen for Ctrl+C");
+            return std::future::pending().await;
+        }
+        tracing::info!("received Ctrl+C, shutting down");
+    };
+
+    signal
+}
+
+/// Shutdown coordination for one server: the signal future plus the grace period
+/// active requests get once it fires.
+#[derive(Debug, Clone)]
+pub struct GracefulShutdown {
+    grace_period: Duration,
+    triggered: Arc<watch::Sender<bool>>,
+}
+
+impl GracefulShutdown {
+    /// Create a coordinator with the given grace period.
+    pub fn new(grace_period: Duration) -> Self {
+        Self {
+            grace_period,
+            triggered: Arc::new(watch::Sender::new(false)),
+        }
+    }
+
+    /// Create a coordinator using [`SHUTDOWN_GRACE_SECS_ENV`], falling back to
+    /// [`DEFAULT_SHUTDOWN_GRACE`] when it is unset or invalid.
+    pub fn from_env() -> Self {
+        let grace_period = match std::env::var(SHUTDOWN_GRACE_SECS_ENV) {
+            Ok(value) => value
+                .trim()
+                .parse()
+                .map(Duration::from_secs)
+                .unwrap_or_else(|_| {
+                    tracing::warn!(
+                        value = %value,
+                        "ignoring invalid {SHUTDOWN_GRACE_SECS_ENV}; using the default"
+                    );
+                    DEFAULT_SHUTDOWN_GRACE
+                }),
+            Err(_) => DEFAULT_SHUTDOWN_GRACE,
+        };
+        Self::new(grace_period)
+    }
+
+    /// How long active requests may run after the shutdown signal.
+    pub fn grace_period(&self) -> Duration {
+        self.grace_period
+    }
+
+    /// Future for `with_graceful_shutdown`; resolves on [`shutdown_signal`] and starts
+    /// the grace period.
+    pub fn signal(&self) -> impl Future<Output = ()> + Send + 'static {
+        self.on(shutdown_signal())
+    }
+
+    /// Like [`signal`](Self::signal), but triggered by an arbitrary future.
+    fn on<F>(&self, signal: F) -> impl Future<Output = ()> + Send + 'static
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let triggered = Arc::clone(&self.triggered);
+        async move {
+            signal.await;
+            triggered.send_replace(true);
+        }
+    }
+
+    /// Resolves once the grace period has passed after the signal fired; pending
+    /// until then.
+    pub async fn grace_elapsed(&self) {
+        let mut triggered = self.triggered.subscribe();
+        // The sender lives as long as `self`, so waiting cannot fail here.
+        let _ = triggered.wait_for(|triggered| *triggered).await;
+        tokio::time::sleep(self.grace_period).await;
+    }
+}
+
+impl Default for GracefulShutdown {
+    fn default() -> Self {
+        Self::new(DEFAULT_SHUTDOWN_GRACE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::time::timeout;
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn shutdown_signal_resolves_on_sigterm() {
+        // Handlers are installed here, before the signal is sent.
+        let signal = shutdown_signal();
+
+        let status = std::process::Command::new("kill")
+            .args(["-TERM", &std::process::id().to_string()])
+            .status()
+            .expect("run kill");
+        assert!(status.success());
+
+        timeout(Duration::from_secs(5), signal)
+            .await
+            .expect("shutdown signal should resolve after SIGTERM");
+    }
+
+    #[tokio::test]
+    async fn grace_elapsed_waits_for_signal_then_grace_period() {
+        let shutdown = GracefulShutdown::new(Duration::from_millis(50));
+        let (send, recv) = tokio::sync::oneshot::channel::<()>();
+        let signal = tokio::spawn(shutdown.on(async move {
+            let _ = recv.await;
+        }));
+
+        assert!(
+            timeout(Duration::from_millis(200), shutdown.grace_elapsed())
+                .await
+                .is_err(),
+            "grace period must not start before the signal"
+        );
+
+        send.send(()).unwrap();
+        signal.await.unwrap();
+        timeout(Duration::from_secs(5), shutdown.grace_elapsed())
+            .await
+            .expect("grace period should elapse after the signal");
+    }
+}
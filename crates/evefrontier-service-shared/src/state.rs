@@ -7,9 +7,17 @@ use std::path::Path;
 use std::sync::Arc;
 
 use evefrontier_lib::db::{load_starmap, Starmap};
-use evefrontier_lib::spatial::{try_load_spatial_index, SpatialIndex};
+use evefrontier_lib::spatial::{
+    compute_dataset_checksum, read_release_tag, spatial_index_path, DatasetMetadata, SpatialIndex,
+};
 use evefrontier_lib::Error as LibError;
 
+use crate::health::FreshnessProbe;
+use crate::RouteAlgorithm;
+
+/// Environment variable selecting the algorithm used when a route request omits one.
+pub const DEFAULT_ROUTE_ALGORITHM_ENV: &str = "DEFAULT_ROUTE_ALGORITHM";
+
 /// Error during application state initialization.
 #[derive(Debug)]
 pub enum AppStateError {
@@ -24,6 +32,20 @@ pub enum AppStateError {
 
     /// Spatial index file not found.
     SpatialIndexNotFound(String),
+
+    /// The spatial index file uses a format version this service cannot read,
+    /// usually because it was built by a newer release.
+    UnsupportedSpatialIndexVersion {
+        path: String,
+        version: u8,
+        max_supported: u8,
+    },
+
+    /// The spatial index file exists but could not be read or parsed.
+    CorruptSpatialIndex { path: String, source: LibError },
+
+    /// `DEFAULT_ROUTE_ALGORITHM` does not name a routing algorithm.
+    InvalidDefaultAlgorithm(String),
 }
 
 impl std::fmt::Display for AppStateError {
@@ -33,6 +55,26 @@ impl std::fmt::Display for AppStateError {
             Self::SpatialIndexLoad(e) => write!(f, "failed to load spatial index: {}", e),
             Self::DatabaseNotFound(path) => write!(f, "database not found: {}", path),
             Self::SpatialIndexNotFound(path) => write!(f, "spatial index not found: {}", path),
+            Self::UnsupportedSpatialIndexVersion {
+                path,
+                version,
+                max_supported,
+            } => write!(
+                f,
+                "spatial index {} has format version {}, but this service reads versions up to {}; \
+                 rebuild it with `evefrontier-cli index-build --force` from this release or \
+                 deploy a service version that supports it",
+                path, version, max_supported
+            ),
+            Self::CorruptSpatialIndex { path, source } => write!(
+                f,
+                "spatial index {} is present but invalid ({}); rebuild it with \
+                 `evefrontier-cli index-build --force` or remove it to run without the index",
+                path, source
+            ),
+            Self::InvalidDefaultAlgorithm(message) => {
+                write!(f, "invalid {}: {}", DEFAULT_ROUTE_ALGORITHM_ENV, message)
+            }
         }
     }
 }
@@ -42,6 +84,7 @@ impl std::error::Error for AppStateError {
         match self {
             Self::StarmapLoad(e) => Some(e),
             Self::SpatialIndexLoad(e) => Some(e),
+            Self::CorruptSpatialIndex { source, .. } => Some(source),
             _ => None,
         }
     }
@@ -77,18 +120,31 @@ impl From<LibError> for AppStateError {
 #[derive(Clone)]
 pub struct AppState {
     inner: Arc<AppStateInner>,
+    default_algorithm: RouteAlgorithm,
+    freshness_probe: Option<Arc<FreshnessProbe>>,
 }
 
 struct AppStateInner {
     starmap: Starmap,
     spatial_index: Option<Arc<SpatialIndex>>,
+    dataset_metadata: Option<DatasetMetadata>,
 }
 
 impl AppState {
     /// Load application state from a database file.
     ///
     /// Attempts to load the starmap from the specified database path. Also
-    /// attempts to load a spatial index from `{db_path}.spatial.bin` if present.
+    /// attempts to load a spatial index from `{db_path}.spatial.bin`: a missing
+    /// index is logged as a warning and the state loads without one, while an
+    /// index file that exists but cannot be loaded fails with
+    /// [`AppStateError::CorruptSpatialIndex`].
+    ///
+    /// Dataset metadata is taken from the spatial index when it embeds source
+    /// metadata; otherwise the database file is hashed and the release tag read
+    /// from its `.db.release` marker.
+    ///
+    /// The readiness probe checks the index against `db_path` through a
+    /// [`FreshnessProbe`] cached for [`FreshnessProbe::interval_from_env`].
     ///
     /// # Arguments
     ///
@@ -114,39 +170,94 @@ impl AppState {
             "starmap loaded successfully"
         );
 
-        // Try to load spatial index (optional) - uses the library's try_load function
-        // which handles path construction and error handling
+        // Try to load spatial index (optional)
         tracing::info!(path = %db_path.display(), "attempting to load spatial index");
-        let spatial_index = try_load_spatial_index(db_path).map(Arc::new);
+        let spatial_index = load_spatial_index(db_path)?.map(Arc::new);
         if let Some(ref index) = spatial_index {
             tracing::info!(
                 indexed_systems = index.len(),
                 "spatial index loaded successfully"
             );
-        } else {
-            tracing::info!("spatial index not found, spatial queries may be slower");
         }
 
+        let dataset_metadata = spatial_index
+            .as_deref()
+            .and_then(SpatialIndex::source_metadata)
+            .cloned()
+            .or_else(|| dataset_metadata_from_file(db_path));
+
         Ok(Self {
             inner: Arc::new(AppStateInner {
                 starmap,
                 spatial_index,
+                dataset_metadata,
             }),
+            default_algorithm: RouteAlgorithm::default(),
+            freshness_probe: Some(Arc::new(FreshnessProbe::new(
+                db_path,
+                FreshnessProbe::interval_from_env(),
+            ))),
         })
     }
 
     /// Create application state from pre-loaded components.
     ///
-    /// This is useful for testing or when loading from bundled bytes.
+    /// This is useful for testing or when loading from bundled bytes. Dataset
+    /// metadata is taken from the spatial index, if it embeds any. There is no
+    /// dataset path, so readiness skips the index freshness check.
     pub fn from_components(starmap: Starmap, spatial_index: Option<SpatialIndex>) -> Self {
+        let dataset_metadata = spatial_index
+            .as_ref()
+            .and_then(SpatialIndex::source_metadata)
+            .cloned();
         Self {
             inner: Arc::new(AppStateInner {
                 starmap,
                 spatial_index: spatial_index.map(Arc::new),
+                dataset_metadata,
             }),
+            default_algorithm: RouteAlgorithm::default(),
+            freshness_probe: None,
         }
     }
 
+    /// Use `algorithm` for route requests that do not name one.
+    pub fn with_default_algorithm(mut self, algorithm: RouteAlgorithm) -> Self {
+        self.default_algorithm = algorithm;
+        self
+    }
+
+    /// Apply `DEFAULT_ROUTE_ALGORITHM` when it is set and non-empty.
+    ///
+    /// Fails with [`AppStateError::InvalidDefaultAlgorithm`] when the value is not
+    /// an algorithm name, so a misconfigured deployment stops at startup instead of
+    /// silently routing with A*.
+    pub fn with_default_algorithm_from_env(self) -> Result<Self, AppStateError> {
+        match std::env::var(DEFAULT_ROUTE_ALGORITHM_ENV) {
+            Ok(value) if !value.trim().is_empty() => value
+                .parse()
+                .map(|algorithm| self.with_default_algorithm(algorithm))
+                .map_err(AppStateError::InvalidDefaultAlgorithm),
+            _ => Ok(self),
+        }
+    }
+
+    /// Check spatial index freshness with `probe` in the readiness probe.
+    pub fn with_freshness_probe(mut self, probe: FreshnessProbe) -> Self {
+        self.freshness_probe = Some(Arc::new(probe));
+        self
+    }
+
+    /// Freshness probe used by readiness, when the dataset path is known.
+    pub fn freshness_probe(&self) -> Option<Arc<FreshnessProbe>> {
+        self.freshness_probe.clone()
+    }
+
+    /// Algorithm used when a route request omits `algorithm` (A* unless configured).
+    pub fn default_algorithm(&self) -> RouteAlgorithm {
+        self.default_algorithm
+    }
+
     /// Access the loaded starmap.
     pub fn starmap(&self) -> &Starmap {
         &self.inner.starmap
@@ -168,6 +279,62 @@ impl AppState {
     pub fn spatial_index_arc(&self) -> Option<Arc<SpatialIndex>> {
         self.inner.spatial_index.clone()
     }
+
+    /// Metadata identifying the loaded dataset, if it could be determined.
+    pub fn dataset_metadata(&self) -> Option<&DatasetMetadata> {
+        self.inner.dataset_metadata.as_ref()
+    }
+}
+
+/// Load the spatial index stored next to `db_path`, if there is one.
+///
+/// A missing index only means it has not been built yet, so it is logged and
+/// the service runs without it. An index that is present but unreadable fails
+/// startup: an unsupported format version signals a deployment mismatch, and
+/// any other failure a damaged file.
+fn load_spatial_index(db_path: &Path) -> Result<Option<SpatialIndex>, AppStateError> {
+    let index_path = spatial_index_path(db_path);
+    if !index_path.exists() {
+        tracing::warn!(
+            path = %index_path.display(),
+            "spatial index not found, spatial queries will be unavailable or slower"
+        );
+        return Ok(None);
+    }
+
+    match SpatialIndex::load(&index_path) {
+        Ok(index) => Ok(Some(index)),
+        Err(LibError::SpatialIndexUnsupportedVersion {
+            version,
+            max_supported,
+        }) => Err(AppStateError::UnsupportedSpatialIndexVersion {
+            path: index_path.display().to_string(),
+            version,
+            max_supported,
+        }),
+        Err(source) => Err(AppStateError::CorruptSpatialIndex {
+            path: index_path.display().to_string(),
+            source,
+        }),
+    }
+}
+
+/// Build dataset metadata by hashing the database file.
+///
+/// Failures are logged rather than returned: the starmap already loaded, so a
+/// missing checksum only means responses omit the dataset headers.
+fn dataset_metadata_from_file(db_path: &Path) -> Option<DatasetMetadata> {
+    match compute_dataset_checksum(db_path) {
+        Ok(checksum) => Some(DatasetMetadata {
+            checksum,
+            release_tag: read_release_tag(db_path),
+            build_timestamp: 0,
+        }),
+        Err(e) => {
+            tracing::warn!(error = %e, "failed to compute dataset checksum");
+            None
+        }
+    }
 }
 
 impl std::fmt::Debug for AppState {
@@ -175,6 +342,16 @@ impl std::fmt::Debug for AppState {
         f.debug_struct("AppState")
             .field("system_count", &self.inner.starmap.systems.len())
             .field("has_spatial_index", &self.inner.spatial_index.is_some())
+            .field(
+                "dataset_checksum",
+                &self
+                    .inner
+                    .dataset_metadata
+                    .as_ref()
+                    .map(DatasetMetadata::short_checksum),
+            )
+            .field("default_algorithm", &self.default_algorithm)
+            .field("checks_freshness", &self.freshness_probe.is_some())
             .finish()
     }
 }
@@ -215,6 +392,7 @@ mod tests {
         Starmap {
             systems,
             adjacency: Arc::new(HashMap::new()),
+            gate_links: Default::default(),
             name_to_id: {
                 let mut m = HashMap::new();
                 m.insert("testsystem".to_string(), 1);
@@ -264,6 +442,23 @@ mod tests {
         assert!(err.to_string().contains("not found"));
     }
 
+    #[test]
+    fn test_app_state_load_records_dataset_checksum() {
+        let path = crate::test_utils::fixture_db_path();
+        let state = AppState::load(&path).expect("fixture loads");
+
+        let metadata = state.dataset_metadata().expect("dataset metadata");
+        let expected = compute_dataset_checksum(&path).expect("checksum");
+        assert_eq!(metadata.checksum, expected);
+        assert_eq!(metadata.short_checksum().len(), 16);
+    }
+
+    #[test]
+    fn test_app_state_from_components_without_index_has_no_metadata() {
+        let state = AppState::from_components(minimal_starmap(), None);
+        assert!(state.dataset_metadata().is_none());
+    }
+
     #[test]
     fn test_app_state_load_nonexistent() {
         let result = AppState::load("/nonexistent/path/to/database.db");
@@ -276,4 +471,109 @@ mod tests {
             other => panic!("unexpected error: {:?}", other),
         }
     }
+
+    /// Copy the fixture database into a temp dir so tests can place an index next to it.
+    fn temp_dataset() -> (tempfile::TempDir, std::path::PathBuf) {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let db_path = dir.path().join("static_data.db");
+        std::fs::copy(crate::test_utils::fixture_db_path(), &db_path).expect("copy fixture");
+        (dir, db_path)
+    }
+
+    #[test]
+    fn test_app_state_load_without_index_file() {
+        let (_dir, db_path) = temp_dataset();
+
+        let state = AppState::load(&db_path).expect("missing index is tolerated");
+        assert!(!state.has_spatial_index());
+        assert!(state.starmap().systems.len() > 1);
+    }
+
+    #[test]
+    fn test_app_state_load_with_valid_index() {
+        let (_dir, db_path) = temp_dataset();
+        let starmap = load_starmap(&db_path, None).expect("fixture loads");
+        let index = SpatialIndex::build(&starmap);
+        index
+            .save(&spatial_index_path(&db_path))
+            .expect("save index");
+
+        let state = AppState::load(&db_path).expect("valid index loads");
+        assert!(state.has_spatial_index());
+        assert_eq!(
+            state.spatial_index().map(SpatialIndex::len),
+            Some(index.len())
+        );
+    }
+
+    #[test]
+    fn test_app_state_load_rejects_corrupt_index() {
+        let (_dir, db_path) = temp_dataset();
+        std::fs::write(spatial_index_path(&db_path), b"not a spatial index at all")
+            .expect("write index");
+
+        let err = AppState::load(&db_path).expect_err("corrupt index should fail");
+        assert!(
+            matches!(err, AppStateError::CorruptSpatialIndex { .. }),
+            "unexpected error: {err}"
+        );
+        let message = err.to_string();
+        assert!(message.contains("present but invalid"), "{message}");
+        assert!(std::error::Error::source(&err).is_some());
+    }
+
+    #[test]
+    fn test_app_state_load_rejects_future_index_version() {
+        let (_dir, db_path) = temp_dataset();
+
+        // A header claiming a future format version, followed by filler bytes.
+        let mut index = vec![0u8; 16];
+        index[0..4].copy_from_slice(b"EFSI");
+        index[4] = 42;
+        index.extend_from_slice(&[0u8; 64]);
+        std::fs::write(spatial_index_path(&db_path), &index).expect("write index");
+
+        let err = AppState::load(&db_path).expect_err("future index version should fail");
+        assert!(
+            matches!(
+                err,
+                AppStateError::UnsupportedSpatialIndexVersion {
+                    version: 42,
+                    max_supported: 2,
+                    ..
+                }
+            ),
+            "unexpected error: {err}"
+        );
+        let message = err.to_string();
+        assert!(message.contains("index-build"), "{message}");
+    }
+
+    #[test]
+    fn test_default_algorithm_from_env() {
+        let state = AppState::from_components(minimal_starmap(), None);
+        assert_eq!(state.default_algorithm(), RouteAlgorithm::AStar);
+
+        std::env::set_var(DEFAULT_ROUTE_ALGORITHM_ENV, "dijkstra");
+        let configured = state.clone().with_default_algorithm_from_env();
+        std::env::set_var(DEFAULT_ROUTE_ALGORITHM_ENV, "greedy");
+        let invalid = state.clone().with_default_algorithm_from_env();
+        std::env::remove_var(DEFAULT_ROUTE_ALGORITHM_ENV);
+        let unset = state.with_default_algorithm_from_env();
+
+        assert_eq!(
+            configured.expect("valid algorithm").default_algorithm(),
+            RouteAlgorithm::Dijkstra
+        );
+        let err = invalid.expect_err("unknown algorithm rejected");
+        assert!(
+            matches!(err, AppStateError::InvalidDefaultAlgorithm(_)),
+            "unexpected error: {err}"
+        );
+        assert!(err.to_string().contains(DEFAULT_ROUTE_ALGORITHM_ENV));
+        assert_eq!(
+            unset.expect("unset is fine").default_algorithm(),
+            RouteAlgorithm::AStar
+        );
+    }
 }
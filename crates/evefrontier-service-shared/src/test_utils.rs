@@ -1,11 +1,20 @@
 //! Test utilities for microservice handler testing.
 //!
 //! This module provides fixtures and helpers for testing HTTP handlers
-//! with a pre-loaded minimal starmap.
+//! with a pre-loaded minimal starmap, plus [`send_json`] for driving a full
+//! service `Router` in-process so middleware and serialization are exercised
+//! end to end.
 
 use std::path::PathBuf;
 use std::sync::OnceLock;
 
+use axum::body::Body;
+use axum::http::{header, HeaderMap, Method, Request, StatusCode};
+use axum::Router;
+use tower::ServiceExt;
+
+use evefrontier_lib::{load_starmap, SpatialIndex};
+
 use crate::state::AppState;
 
 /// Path to the test fixture database.
@@ -36,6 +45,31 @@ pub fn test_state() -> AppState {
         .clone()
 }
 
+/// Lazily-initialized fixture state with an in-memory spatial index.
+static FIXTURE_APP_STATE: OnceLock<AppState> = OnceLock::new();
+
+/// Get a shared fixture AppState with a spatial index built from the fixture starmap.
+///
+/// The fixture ships without an index file, so [`test_state`] has no spatial index;
+/// use this for handlers that need one (e.g. range queries). The index is built with
+/// [`SpatialIndex::build`], which inserts systems in ID order, so every test sees the
+/// same index.
+///
+/// # Panics
+///
+/// Panics if the fixture database cannot be loaded.
+pub fn fixture_app_state() -> AppState {
+    FIXTURE_APP_STATE
+        .get_or_init(|| {
+            let path = fixture_db_path();
+            let starmap = load_starmap(&path, None)
+                .unwrap_or_else(|e| panic!("failed to load test fixture from {:?}: {}", path, e));
+            let index = SpatialIndex::build(&starmap);
+            AppState::from_components(starmap, Some(index))
+        })
+        .clone()
+}
+
 /// Get the absolute path to the test fixture database.
 pub fn fixture_db_path() -> PathBuf {
     PathBuf::from(TEST_FIXTURE_PATH)
@@ -66,6 +100,65 @@ pub fn test_request_id() -> String {
     format!("test-{}", timestamp)
 }
 
+/// Response captured by [`send_json`].
+#[derive(Debug)]
+pub struct TestResponse {
+    /// HTTP status code.
+    pub status: StatusCode,
+    /// Response headers, including any added by middleware layers.
+    pub headers: HeaderMap,
+    /// Body parsed as JSON, or `Value::Null` when the body is empty or not JSON.
+    pub body: serde_json::Value,
+}
+
+impl TestResponse {
+    /// The `content-type` header value, if present and valid UTF-8.
+    pub fn content_type(&self) -> Option<&str> {
+        self.headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+    }
+}
+
+/// Issue a single in-process request against `router` and collect the response.
+///
+/// When `body` is provided it is serialized as the JSON request body with an
+/// `application/json` content type. The router is consumed via
+/// [`tower::ServiceExt::oneshot`], so no socket is bound.
+///
+/// # Panics
+///
+/// Panics if the request cannot be built or the response body cannot be read.
+pub async fn send_json(
+    router: Router,
+    method: Method,
+    uri: &str,
+    body: Option<serde_json::Value>,
+) -> TestResponse {
+    let builder = Request::builder().method(method).uri(uri);
+    let request = match body {
+        Some(json) => builder
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(json.to_string())),
+        None => builder.body(Body::empty()),
+    }
+    .expect("test request builds");
+
+    let response = router.oneshot(request).await.expect("router is infallible");
+    let status = response.status();
+    let headers = response.headers().clone();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("response body readable");
+    let body = serde_json::from_slice(&bytes).unwrap_or(serde_json::Value::Null);
+
+    TestResponse {
+        status,
+        headers,
+        body,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,6 +191,49 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_send_json_round_trips_through_router() {
+        use axum::routing::post;
+        use axum::Json;
+
+        let router = Router::new().route(
+            "/echo",
+            post(|Json(value): Json<serde_json::Value>| async move { Json(value) }),
+        );
+        let response = send_json(
+            router,
+            Method::POST,
+            "/echo",
+            Some(serde_json::json!({ "system": "Nod" })),
+        )
+        .await;
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.content_type(), Some("application/json"));
+        assert_eq!(response.body["system"], "Nod");
+    }
+
+    #[test]
+    fn test_fixture_app_state_has_spatial_index() {
+        let state = fixture_app_state();
+        assert_eq!(state.starmap().systems.len(), 8);
+        assert_eq!(
+            state.starmap().systems.len(),
+            test_state().starmap().systems.len()
+        );
+
+        let index = state.spatial_index().expect("fixture state has an index");
+        assert_eq!(index, &SpatialIndex::build(state.starmap()));
+
+        let nod = state
+            .starmap()
+            .system_id_by_name(fixture_systems::NOD)
+            .expect("Nod exists");
+        let position = index.position(nod).expect("Nod is indexed").map(f64::from);
+        let nearest = index.nearest(position, 2);
+        assert_eq!(nearest.first().map(|(id, _)| *id), Some(nod));
+    }
+
     #[test]
     fn test_request_id_unique() {
         let id1 = test_request_id();
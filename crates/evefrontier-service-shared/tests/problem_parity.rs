@@ -0,0 +1,118 @@
+//! Problem bodies must be byte-identical whether a Lambda or a service reports the error.
+//!
+//! The Lambda runtime serializes handler responses with `serde_json`, while the services go
+//! through axum's `IntoResponse`; both must produce the same bytes for the same error.
+
+use axum::body::to_bytes;
+use axum::http::header::CONTENT_TYPE;
+use axum::response::IntoResponse;
+use evefrontier_lambda_shared as lambda;
+use evefrontier_lib::Error as LibError;
+use evefrontier_service_shared as service;
+
+fn lib_errors() -> Vec<LibError> {
+    vec![
+        LibError::UnknownSystem {
+            name: "D:2NAS".to_string(),
+            suggestions: vec!["Nod".to_string(), "Brana".to_string()],
+        },
+        LibError::UnknownSystem {
+            name: "Nodd".to_string(),
+            suggestions: Vec::new(),
+        },
+        LibError::RouteNotFound {
+            start: "Nod".to_string(),
+            goal: "E1J-M5G".to_string(),
+        },
+        LibError::InvalidReachBudget {
+            message: "negative".to_string(),
+        },
+        LibError::DatasetReleaseNotFound {
+            tag: "e0000".to_string(),
+        },
+        LibError::SpatialIndexEmpty,
+    ]
+}
+
+/// Serialize the way the Lambda runtime does: the problem inside an untagged response enum.
+fn lambda_body(problem: lambda::ProblemDetails) -> Vec<u8> {
+    #[derive(serde::Serialize)]
+    #[serde(untagged)]
+    enum Response {
+        Error(lambda::ProblemDetails),
+    }
+
+    serde_json::to_vec(&Response::Error(problem)).expect("problem serializes")
+}
+
+async fn service_body(problem: service::ProblemDetails) -> (Option<String>, Vec<u8>) {
+    let response = problem.into_response();
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let body = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("body is readable");
+    (content_type, body.to_vec())
+}
+
+#[tokio::test]
+async fn lambda_and_service_problem_bodies_are_byte_identical() {
+    for error in lib_errors() {
+        let request_id = "req-parity";
+        let lambda_bytes = lambda_body(lambda::from_lib_error(&error, request_id));
+        let (content_type, service_bytes) =
+            service_body(service::from_lib_error(&error, request_id)).await;
+
+        assert_eq!(
+            String::from_utf8_lossy(&lambda_bytes),
+            String::from_utf8_lossy(&service_bytes),
+            "bodies differ for {error:?}"
+        );
+        assert_eq!(
+            content_type.as_deref(),
+            Some(service::CONTENT_TYPE_PROBLEM_JSON)
+        );
+    }
+}
+
+#[tokio::test]
+async fn constructors_match_across_transports() {
+    let pairs = [
+        (
+            lambda::ProblemDetails::bad_request("bad", "req-1"),
+            service::ProblemDetails::bad_request("bad", "req-1"),
+        ),
+        (
+            lambda::ProblemDetails::internal_error("boom", "req-2"),
+            service::ProblemDetails::internal_error("boom", "req-2"),
+        ),
+        (
+            lambda::ProblemDetails::service_unavailable("down", "req-3"),
+            service::ProblemDetails::service_unavailable("down", "req-3"),
+        ),
+    ];
+
+    for (lambda_problem, service_problem) in pairs {
+        assert_eq!(
+            lambda_problem.to_json_value(),
+            service_problem.to_json_value()
+        );
+        let (_, service_bytes) = service_body(service_problem).await;
+        assert_eq!(lambda_body(lambda_problem), service_bytes);
+    }
+}
+
+#[test]
+fn content_type_constants_agree() {
+    assert_eq!(
+        lambda::CONTENT_TYPE_PROBLEM_JSON,
+        service::CONTENT_TYPE_PROBLEM_JSON
+    );
+    assert_eq!(
+        service::CONTENT_TYPE_PROBLEM_JSON,
+        "application/problem+json"
+    );
+}